@@ -0,0 +1,246 @@
+//! Compile the built-in presets into static slot tables.
+//!
+//! Each `presets/*.toml` becomes a `PresetData` static in
+//! `$OUT_DIR/presets.rs`, included by `src/builtin.rs`. Built-ins then load
+//! without parsing TOML at runtime, so the `toml` dependency is only needed
+//! for user-supplied themes. Inheritance is still merged at load time — a
+//! registry may replace a variant's parent.
+
+use std::error::Error;
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// Sections emitted as flat `key = "value"` tables, in `PresetData` order.
+const SECTIONS: [&str; 9] = [
+    "base",
+    "semantic",
+    "diff",
+    "surface",
+    "typography",
+    "syntax",
+    "editor",
+    "terminal",
+    "syntax_style",
+];
+
+/// Top-level tables with a dedicated `PresetData` field; every other table
+/// is an extension. `when` is rejected before extensions are collected.
+const KNOWN_TABLES: [&str; 5] = ["meta", "colors", "gradient", "platform", "when"];
+
+fn main() -> Result<(), Box<dyn Error>> {
+    println!("cargo::rerun-if-changed=presets");
+
+    let mut paths: Vec<_> = std::fs::read_dir("presets")?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<_, _>>()?;
+    paths.retain(|p| p.extension().is_some_and(|e| e == "toml"));
+    paths.sort();
+
+    let mut statics = String::new();
+    let mut lookup = String::new();
+    for path in &paths {
+        let id = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .ok_or_else(|| format!("non-UTF-8 preset file name: {}", path.display()))?;
+        let table: toml::Table = std::fs::read_to_string(path)?.parse()?;
+        let name = id.to_uppercase();
+        writeln!(
+            statics,
+            "static {name}: PresetData = {};",
+            preset_data(path, &table)?
+        )?;
+        writeln!(lookup, "        {id:?} => Some(&{name}),")?;
+    }
+
+    let out = format!(
+        "// Generated by build.rs from presets/*.toml. Do not edit.\n\n\
+         {statics}\n\
+         pub(crate) fn preset_data(id: &str) -> Option<&'static PresetData> {{\n    \
+         match id {{\n{lookup}        _ => None,\n    }}\n}}\n"
+    );
+    let out_dir = std::env::var("OUT_DIR")?;
+    std::fs::write(Path::new(&out_dir).join("presets.rs"), out)?;
+    Ok(())
+}
+
+fn preset_data(path: &Path, table: &toml::Table) -> Result<String, Box<dyn Error>> {
+    let context = |what: &str| format!("{}: {what}", path.display());
+
+    let meta = table
+        .get("meta")
+        .and_then(toml::Value::as_table)
+        .ok_or_else(|| context("missing [meta]"))?;
+    if table.get("base").is_none() {
+        return Err(context("missing [base]").into());
+    }
+    // Mode overlays are whole nested manifests; `PresetData` has no slot
+    // for them, so fail here instead of compiling the preset without them.
+    if table.contains_key("when") {
+        return Err(context("[when.*] mode overlays are not supported in built-in presets").into());
+    }
+
+    let mut out = String::from("PresetData {\n    meta: MetaData {\n");
+    for key in ["name", "preset_id", "schema_version", "style", "kind"] {
+        let value = meta
+            .get(key)
+            .and_then(toml::Value::as_str)
+            .ok_or_else(|| context(&format!("missing meta.{key}")))?;
+        writeln!(out, "        {key}: {value:?},")?;
+    }
+    let inherits = string_list(meta.get("inherits"), &context("meta.inherits"))?;
+    writeln!(out, "        inherits: &{inherits:?},")?;
+    let inherit_sections = meta
+        .get("inherit_sections")
+        .map(|value| {
+            value
+                .as_array()
+                .and_then(|items| {
+                    items
+                        .iter()
+                        .map(toml::Value::as_str)
+                        .collect::<Option<Vec<_>>>()
+                })
+                .ok_or_else(|| context("meta.inherit_sections: expected an array of strings"))
+        })
+        .transpose()?;
+    match inherit_sections {
+        Some(sections) => writeln!(out, "        inherit_sections: Some(&{sections:?}),")?,
+        None => writeln!(out, "        inherit_sections: None,")?,
+    }
+    let optional = [
+        "upstream_repo",
+        "author",
+        "license",
+        "version",
+        "description",
+    ];
+    for key in optional {
+        let value = meta.get(key).and_then(toml::Value::as_str);
+        writeln!(out, "        {key}: {value:?},")?;
+    }
+    let tags: Vec<&str> = meta
+        .get("tags")
+        .and_then(toml::Value::as_array)
+        .map(|tags| tags.iter().filter_map(toml::Value::as_str).collect())
+        .unwrap_or_default();
+    writeln!(out, "        tags: &{tags:?},")?;
+    out.push_str("    },\n");
+
+    let colors = string_pairs(table.get("colors"), &context("colors"))?;
+    writeln!(out, "    colors: {colors},")?;
+    for section in SECTIONS {
+        let pairs = string_pairs(table.get(section), &context(section))?;
+        writeln!(out, "    {section}: {pairs},")?;
+    }
+
+    out.push_str("    gradient: &[");
+    if let Some(gradients) = table.get("gradient").and_then(toml::Value::as_table) {
+        for (name, def) in gradients {
+            out.push_str(&gradient_data(name, def, &context("gradient"))?);
+        }
+    }
+    out.push_str("],\n    platform: &[");
+    if let Some(platforms) = table.get("platform").and_then(toml::Value::as_table) {
+        for (platform, section) in platforms {
+            let pairs = string_pairs(Some(section), &context(platform))?;
+            write!(out, "({platform:?}, {pairs}), ")?;
+        }
+    }
+    let extensions: toml::Table = table
+        .iter()
+        .filter(|(name, value)| {
+            value.is_table()
+                && !SECTIONS.contains(&name.as_str())
+                && !KNOWN_TABLES.contains(&name.as_str())
+        })
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect();
+    let extensions = match extensions.is_empty() {
+        true => String::new(),
+        false => toml::to_string(&extensions)?,
+    };
+    write!(out, "],\n    extensions: {extensions:?},\n}}")?;
+    Ok(out)
+}
+
+/// A string or an array of strings, as `inherits` accepts.
+fn string_list<'a>(
+    value: Option<&'a toml::Value>,
+    context: &str,
+) -> Result<Vec<&'a str>, Box<dyn Error>> {
+    let expected = || format!("{context}: expected a string or an array of strings");
+    match value {
+        None => Ok(Vec::new()),
+        Some(toml::Value::String(s)) => Ok(vec![s.as_str()]),
+        Some(toml::Value::Array(items)) => items
+            .iter()
+            .map(|item| item.as_str().ok_or_else(|| expected().into()))
+            .collect(),
+        Some(_) => Err(expected().into()),
+    }
+}
+
+fn string_pairs(section: Option<&toml::Value>, context: &str) -> Result<String, Box<dyn Error>> {
+    let mut out = String::from("&[");
+    let Some(section) = section else {
+        out.push(']');
+        return Ok(out);
+    };
+    let table = section
+        .as_table()
+        .ok_or_else(|| format!("{context}: expected a table"))?;
+    for (key, value) in table {
+        // `PresetData` has no field for per-slot opacity or unset slots, so
+        // fail here instead of dropping the opacity or compiling "unset" as
+        // a color that fails to resolve.
+        if value.is_table() {
+            return Err(format!(
+                "{context}.{key}: per-slot opacity is not supported in built-in presets"
+            )
+            .into());
+        }
+        let value = value
+            .as_str()
+            .ok_or_else(|| format!("{context}.{key}: expected a string"))?;
+        if value == "unset" {
+            return Err(
+                format!("{context}.{key}: \"unset\" is not supported in built-in presets").into(),
+            );
+        }
+        write!(out, "({key:?}, {value:?}), ")?;
+    }
+    out.push(']');
+    Ok(out)
+}
+
+fn gradient_data(name: &str, def: &toml::Value, context: &str) -> Result<String, Box<dyn Error>> {
+    let stops = def
+        .get("stops")
+        .and_then(toml::Value::as_array)
+        .ok_or_else(|| format!("{context}.{name}: missing stops"))?;
+    let space = def.get("space").and_then(toml::Value::as_str);
+
+    let mut out = format!("GradientData {{ name: {name:?}, space: {space:?}, stops: &[");
+    for stop in stops {
+        match (stop.as_str(), stop.get("color"), stop.get("at")) {
+            (Some(color), _, _) => write!(out, "StopData::Shorthand({color:?}), ")?,
+            (None, Some(color), Some(at)) => {
+                let color = color
+                    .as_str()
+                    .ok_or_else(|| format!("{context}.{name}: stop color must be a string"))?;
+                let at = at
+                    .as_float()
+                    .or_else(|| at.as_integer().map(|i| i as f64))
+                    .ok_or_else(|| format!("{context}.{name}: stop `at` must be a number"))?;
+                write!(
+                    out,
+                    "StopData::Explicit {{ color: {color:?}, at: {at:?} }}, "
+                )?;
+            }
+            _ => return Err(format!("{context}.{name}: malformed stop").into()),
+        }
+    }
+    out.push_str("] }, ");
+    Ok(out)
+}
@@ -0,0 +1,98 @@
+#ifndef PALETTE_CORE_H
+#define PALETTE_CORE_H
+
+/* Generated by cbindgen from src/capi.rs. Do not edit. */
+
+#include <stdbool.h>
+#include <stdint.h>
+
+typedef struct Palette Palette;
+
+// An 8-bit RGBA color.
+typedef struct PaletteRgb {
+  // Red channel.
+  uint8_t r;
+  // Green channel.
+  uint8_t g;
+  // Blue channel.
+  uint8_t b;
+  // Alpha channel; `255` is opaque. Below `255` for slots written as
+  // `#RRGGBBAA` or `{ color, opacity }`.
+  uint8_t a;
+} PaletteRgb;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Load a built-in preset by ID. Returns `NULL` on failure.
+//
+// # Safety
+//
+// `id` must be null or a NUL-terminated string.
+Palette *palette_load_preset(const char *id);
+
+// Load a theme TOML file, resolving inheritance from sibling files or
+// built-in presets. Returns `NULL` on failure.
+//
+// # Safety
+//
+// `path` must be null or a NUL-terminated string.
+Palette *palette_load_file(const char *path);
+
+// Release a palette handle. `NULL` is ignored.
+//
+// # Safety
+//
+// `palette` must be null or a handle from this library not yet freed.
+void palette_free(Palette *palette);
+
+// Read a slot by `"section.field"` path into `out`.
+//
+// Returns `false` when the slot is unset or the path is invalid; only the
+// latter records an error.
+//
+// # Safety
+//
+// `palette` must be null or a live handle, `path` null or a NUL-terminated
+// string, and `out` null or valid for writes.
+bool palette_get_slot(const Palette *palette, const char *path, PaletteRgb *out);
+
+// Render the palette as CSS custom properties. Returns `NULL` on failure;
+// release the result with [`palette_string_free`].
+//
+// # Safety
+//
+// `palette` must be null or a live handle.
+char *palette_to_css(const Palette *palette);
+
+// Render the palette as pretty-printed JSON. Returns `NULL` on failure;
+// release the result with [`palette_string_free`].
+//
+// # Safety
+//
+// `palette` must be null or a live handle.
+char *palette_to_json(const Palette *palette);
+
+// Release a string returned by this library. `NULL` is ignored.
+//
+// # Safety
+//
+// `s` must be null or a string from this library not yet freed.
+void palette_string_free(char *s);
+
+// The calling thread's last error message, or `NULL` if the last call
+// succeeded. Valid until the next call into the library on this thread.
+const char *palette_last_error(void);
+
+// The stable diagnostic code (e.g. `"PC0008"`) of the calling thread's last
+// error, or `NULL` if there is none or it did not come from theme loading
+// (such as a null argument). Valid until the next call into the library on
+// this thread.
+const char *palette_last_error_code(void);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* PALETTE_CORE_H */
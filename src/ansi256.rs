@@ -0,0 +1,89 @@
+//! xterm 256-color palette lookup for terminals without truecolor.
+
+use crate::color::Color;
+
+/// xterm's default colors for indices 0–15.
+const SYSTEM: [Color; 16] = [
+    Color::rgb(0x00, 0x00, 0x00),
+    Color::rgb(0x80, 0x00, 0x00),
+    Color::rgb(0x00, 0x80, 0x00),
+    Color::rgb(0x80, 0x80, 0x00),
+    Color::rgb(0x00, 0x00, 0x80),
+    Color::rgb(0x80, 0x00, 0x80),
+    Color::rgb(0x00, 0x80, 0x80),
+    Color::rgb(0xC0, 0xC0, 0xC0),
+    Color::rgb(0x80, 0x80, 0x80),
+    Color::rgb(0xFF, 0x00, 0x00),
+    Color::rgb(0x00, 0xFF, 0x00),
+    Color::rgb(0xFF, 0xFF, 0x00),
+    Color::rgb(0x00, 0x00, 0xFF),
+    Color::rgb(0xFF, 0x00, 0xFF),
+    Color::rgb(0x00, 0xFF, 0xFF),
+    Color::rgb(0xFF, 0xFF, 0xFF),
+];
+
+/// Channel values of the 6×6×6 color cube (indices 16–231).
+const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Index of the nearest [`CUBE`] level to `v`.
+fn cube_level(v: u8) -> u8 {
+    match v {
+        0..48 => 0,
+        48..115 => 1,
+        _ => (v - 35) / 40,
+    }
+}
+
+fn distance_sq(a: Color, b: Color) -> u32 {
+    let d = |x: u8, y: u8| u32::from(x.abs_diff(y)).pow(2);
+    d(a.r, b.r) + d(a.g, b.g) + d(a.b, b.b)
+}
+
+impl Color {
+    /// Nearest xterm 256-color index, for terminals without truecolor.
+    ///
+    /// Only the 6×6×6 cube (16–231) and gray ramp (232–255) are candidates:
+    /// terminals recolor indices 0–15 with their own theme, so those would
+    /// not render predictably. Alpha is ignored.
+    ///
+    /// ```
+    /// use palette_core::Color;
+    ///
+    /// assert_eq!(Color::from_hex("#FF0000").unwrap().to_ansi256(), 196);
+    /// assert_eq!(Color::from_hex("#7AA2F7").unwrap().to_ansi256(), 111);
+    /// ```
+    pub fn to_ansi256(&self) -> u8 {
+        let (r, g, b) = (cube_level(self.r), cube_level(self.g), cube_level(self.b));
+        let cube_idx = 16 + 36 * r + 6 * g + b;
+        let cube = Color::rgb(CUBE[r as usize], CUBE[g as usize], CUBE[b as usize]);
+
+        let avg = (u16::from(self.r) + u16::from(self.g) + u16::from(self.b)) / 3;
+        let gray_step = (avg.saturating_sub(3) / 10).min(23) as u8;
+        let gray_idx = 232 + gray_step;
+        let gray = Color::from_ansi256(gray_idx);
+
+        match distance_sq(*self, gray) < distance_sq(*self, cube) {
+            true => gray_idx,
+            false => cube_idx,
+        }
+    }
+
+    /// The color xterm uses for 256-color index `idx`.
+    ///
+    /// Indices 0–15 return xterm's default system colors, which terminal
+    /// themes usually override.
+    pub fn from_ansi256(idx: u8) -> Self {
+        match idx {
+            0..16 => SYSTEM[idx as usize],
+            16..232 => {
+                let i = idx - 16;
+                let level = |n: u8| CUBE[n as usize];
+                Color::rgb(level(i / 36), level(i / 6 % 6), level(i % 6))
+            }
+            _ => {
+                let v = 8 + 10 * (idx - 232);
+                Color::rgb(v, v, v)
+            }
+        }
+    }
+}
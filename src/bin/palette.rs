@@ -0,0 +1,411 @@
+//! `palette` — inspect, validate, export, convert, and watch themes from the shell.
+
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime};
+
+use clap::{Parser, Subcommand, ValueEnum};
+
+use palette_core::contrast::validate_palette;
+use palette_core::diagnostic::{Diagnostic, Location};
+use palette_core::error::PaletteError;
+use palette_core::export::{ExportFile, Exporter, exporters, find_exporter};
+use palette_core::manifest::{PaletteManifest, validate_fields};
+use palette_core::slot::Section;
+use palette_core::terminal::render_swatches;
+use palette_core::{ContrastLevel, Palette, Registry, SlotId, load_preset_file};
+
+type CliResult = Result<ExitCode, Box<dyn Error>>;
+
+/// Inspect, validate, export, and convert palette-core themes.
+#[derive(Parser)]
+#[command(name = "palette", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List built-in themes.
+    List {
+        /// Only themes with this style tag (e.g. `dark`, `light`).
+        #[arg(long)]
+        style: Option<String>,
+    },
+    /// Print every populated slot of a theme.
+    Show {
+        /// Built-in preset ID or path to a theme TOML file.
+        theme: String,
+    },
+    /// Print colored swatches for every populated slot of a theme.
+    Preview {
+        /// Built-in preset ID or path to a theme TOML file.
+        theme: String,
+    },
+    /// Render a theme for another tool.
+    Export {
+        /// Built-in preset ID or path to a theme TOML file.
+        theme: String,
+        /// Output format: `css`, `json`, or an exporter name.
+        #[arg(long, short)]
+        target: String,
+        /// Directory for file-based targets.
+        #[arg(long, short, default_value = ".")]
+        out: PathBuf,
+    },
+    /// Check a theme's foreground/background pairs against a WCAG level.
+    Validate {
+        /// Built-in preset ID or path to a theme TOML file.
+        theme: String,
+        /// Conformance level to check.
+        #[arg(long, value_enum, default_value_t = Level::Aa)]
+        level: Level,
+    },
+    /// Re-export a theme file whenever it (or the file it inherits from) changes.
+    Watch {
+        /// Theme TOML file to watch.
+        theme: PathBuf,
+        /// Output format: `css`, `json`, or an exporter name.
+        #[arg(long, short)]
+        target: String,
+        /// Output file for `css`/`json`, or directory for file-based targets.
+        #[arg(long, short)]
+        out: PathBuf,
+        /// Polling interval in milliseconds.
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+    /// Import a third-party theme file and write palette-core TOML.
+    Convert {
+        /// Theme file in any supported import format.
+        input: PathBuf,
+        /// Output file, or a directory to write one file per theme.
+        output: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Level {
+    Aa,
+    AaLarge,
+    Aaa,
+    AaaLarge,
+    Ui,
+}
+
+impl From<Level> for ContrastLevel {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::Aa => Self::AaNormal,
+            Level::AaLarge => Self::AaLarge,
+            Level::Aaa => Self::AaaNormal,
+            Level::AaaLarge => Self::AaaLarge,
+            Level::Ui => Self::UiComponent,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let result = match Cli::parse().command {
+        Command::List { style } => list(style.as_deref()),
+        Command::Show { theme } => show(&theme),
+        Command::Preview { theme } => preview(&theme),
+        Command::Export { theme, target, out } => export(&theme, &target, &out),
+        Command::Validate { theme, level } => validate(&theme, level.into()),
+        Command::Watch {
+            theme,
+            target,
+            out,
+            interval,
+        } => watch(&theme, &target, &out, Duration::from_millis(interval)),
+        Command::Convert { input, output } => convert(&input, &output),
+    };
+    result.unwrap_or_else(|err| {
+        report(err.as_ref());
+        ExitCode::FAILURE
+    })
+}
+
+/// Print an error, as a coded diagnostic when it came from the library.
+fn report(err: &(dyn Error + 'static)) {
+    if let Some(located) = err.downcast_ref::<Located>() {
+        eprintln!("{}: {}", located.location, located.error.diagnostic());
+        return;
+    }
+    match err.downcast_ref::<PaletteError>() {
+        Some(err) => eprintln!("{}", err.diagnostic()),
+        None => eprintln!("error: {err}"),
+    }
+}
+
+/// A library error with its position in the theme file that caused it.
+#[derive(Debug)]
+struct Located {
+    location: Location,
+    error: PaletteError,
+}
+
+impl std::fmt::Display for Located {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.location, self.error)
+    }
+}
+
+impl Error for Located {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Attach the line and column in `path` when the error points into it.
+fn locate(error: PaletteError, path: &Path) -> Box<dyn Error> {
+    let location = std::fs::read_to_string(path)
+        .ok()
+        .and_then(|text| error.location_in(Some(&path.display().to_string()), &text));
+    match location {
+        Some(location) => Box::new(Located { location, error }),
+        None => Box::new(error),
+    }
+}
+
+/// Load a theme by file path when one exists, otherwise by registry ID.
+fn load(theme: &str) -> Result<Palette, Box<dyn Error>> {
+    let path = Path::new(theme);
+    let palette = match path.is_file() {
+        true => load_preset_file(path).map_err(|err| locate(err, path))?,
+        false => Registry::new().load(theme)?,
+    };
+    Ok(palette)
+}
+
+fn list(style: Option<&str>) -> CliResult {
+    let registry = Registry::new();
+    let themes = registry
+        .list()
+        .filter(|info| style.is_none_or(|s| info.style.as_ref() == s));
+    for info in themes {
+        let tone = match info.is_light {
+            true => "light",
+            false => "dark",
+        };
+        println!("{:<24} {:<26} {} ({tone})", info.id, info.name, info.style);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn show(theme: &str) -> CliResult {
+    let palette = load(theme)?;
+    if let Some(meta) = &palette.meta {
+        println!("{} [{}] — {}", meta.name, meta.preset_id, meta.style);
+    }
+    for section in Section::ALL {
+        let slots: Vec<_> = SlotId::all()
+            .filter(|slot| slot.section() == section)
+            .filter_map(|slot| palette.get(slot).map(|color| (slot.field(), color)))
+            .collect();
+        if slots.is_empty() {
+            continue;
+        }
+        println!("\n[{section}]");
+        for (field, color) in slots {
+            println!("  {field:<24} {color}");
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn preview(theme: &str) -> CliResult {
+    let palette = load(theme)?;
+    if let Some(meta) = &palette.meta {
+        println!("{} [{}] — {}\n", meta.name, meta.preset_id, meta.style);
+    }
+    print!("{}", render_swatches(&palette));
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Where `export` and `watch` send a rendered theme.
+#[derive(Clone, Copy)]
+enum Target {
+    Css,
+    Json,
+    Files(&'static dyn Exporter),
+}
+
+impl Target {
+    fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "css" => Ok(Self::Css),
+            "json" => Ok(Self::Json),
+            _ => find_exporter(name).map(Self::Files).ok_or_else(|| {
+                let names: Vec<_> = exporters().iter().map(|e| e.target()).collect();
+                format!(
+                    "unknown target `{name}` (expected css, json, {})",
+                    names.join(", ")
+                )
+            }),
+        }
+    }
+
+    fn render(self, palette: &Palette) -> Result<Rendered, Box<dyn Error>> {
+        match self {
+            Self::Css => Ok(Rendered::Text(palette.to_css())),
+            Self::Json => Ok(Rendered::Text(format!("{}\n", palette.to_json()?))),
+            Self::Files(exporter) => Ok(Rendered::Files(exporter.export(palette))),
+        }
+    }
+}
+
+enum Rendered {
+    Text(String),
+    Files(Box<[ExportFile]>),
+}
+
+/// Write exporter output under `out`, printing each path written.
+fn write_files(files: &[ExportFile], out: &Path) -> Result<(), Box<dyn Error>> {
+    for file in files {
+        let path = out.join(file.path.as_ref());
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, &file.contents)?;
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+fn export(theme: &str, target: &str, out: &Path) -> CliResult {
+    let target = Target::parse(target)?;
+    match target.render(&load(theme)?)? {
+        Rendered::Text(text) => print!("{text}"),
+        Rendered::Files(files) => write_files(&files, out)?,
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn watch(theme: &Path, target: &str, out: &Path, interval: Duration) -> CliResult {
+    let target = Target::parse(target)?;
+    let mut files = watched_files(theme);
+    let mut last = None;
+    loop {
+        let stamp = modified(&files);
+        if last.as_ref() != Some(&stamp) {
+            match rebuild(theme, target, out) {
+                Ok(()) => println!("rebuilt {}", out.display()),
+                Err(err) => report(err.as_ref()),
+            }
+            // Keep the pre-rebuild stamp so edits made mid-rebuild are not
+            // missed. A changed parent list differs next tick and rebuilds once.
+            files = watched_files(theme);
+            last = Some(stamp);
+        }
+        std::thread::sleep(interval);
+    }
+}
+
+fn rebuild(theme: &Path, target: Target, out: &Path) -> Result<(), Box<dyn Error>> {
+    let palette = load_preset_file(theme).map_err(|err| locate(err, theme))?;
+    match target.render(&palette)? {
+        Rendered::Text(text) => std::fs::write(out, text)?,
+        Rendered::Files(files) => write_files(&files, out)?,
+    }
+    Ok(())
+}
+
+/// The theme file plus the sibling files it inherits from, if there are any.
+fn watched_files(theme: &Path) -> Vec<PathBuf> {
+    let parents: Vec<PathBuf> = std::fs::read_to_string(theme)
+        .ok()
+        .and_then(|toml| PaletteManifest::from_toml(&toml).ok())
+        .map(|manifest| {
+            manifest
+                .parents()
+                .iter()
+                .map(|id| theme.with_file_name(format!("{id}.toml")))
+                .filter(|path| path.is_file())
+                .collect()
+        })
+        .unwrap_or_default();
+    std::iter::once(theme.to_path_buf())
+        .chain(parents)
+        .collect()
+}
+
+fn modified(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+fn validate(theme: &str, level: ContrastLevel) -> CliResult {
+    let path = Path::new(theme);
+    if path.is_file() {
+        let source = std::fs::read_to_string(path)?;
+        let manifest = PaletteManifest::from_toml(&source).map_err(|err| locate(err, path))?;
+        for unknown in validate_fields(&manifest).iter() {
+            println!("{}", Diagnostic::from(unknown));
+        }
+        // A variant's values are only complete once merged, which `load`
+        // does below.
+        if manifest.parents().is_empty()
+            && let Err(errors) = Palette::from_manifest_collecting(&manifest)
+        {
+            let file = path.display().to_string();
+            for err in &errors {
+                match err.location_in(Some(&file), &source) {
+                    Some(location) => println!("{location}: {}", err.diagnostic()),
+                    None => println!("{}", err.diagnostic()),
+                }
+            }
+            println!("{} error(s)", errors.len());
+            return Ok(ExitCode::FAILURE);
+        }
+    }
+
+    let violations = validate_palette(&load(theme)?, level);
+    for v in &violations {
+        println!("{}", Diagnostic::from(v));
+    }
+    match violations.is_empty() {
+        true => {
+            println!("ok");
+            Ok(ExitCode::SUCCESS)
+        }
+        false => {
+            println!("{} contrast violation(s)", violations.len());
+            Ok(ExitCode::FAILURE)
+        }
+    }
+}
+
+fn convert(input: &Path, output: &Path) -> CliResult {
+    let manifests = palette_core::import::import_path(input)?;
+    match (output.is_dir(), manifests.as_ref()) {
+        (true, _) => {
+            for manifest in &manifests {
+                let id = manifest
+                    .meta
+                    .as_ref()
+                    .map_or("theme", |meta| meta.preset_id.as_ref());
+                let path = output.join(format!("{id}.toml"));
+                std::fs::write(&path, manifest.to_toml())?;
+                println!("{}", path.display());
+            }
+        }
+        (false, [manifest]) => {
+            std::fs::write(output, manifest.to_toml())?;
+            println!("{}", output.display());
+        }
+        (false, _) => {
+            return Err(format!(
+                "{} contains {} themes; pass a directory as the output",
+                input.display(),
+                manifests.len()
+            )
+            .into());
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,153 @@
+//! Fluent construction of palettes in code.
+//!
+//! [`PaletteBuilder`] has one method per slot, so a programmatic theme sets
+//! only the colors it has instead of filling eight structs of
+//! `Option<Color>`. Base and semantic slots use their field name; every
+//! other slot is prefixed with its section:
+//!
+//! ```
+//! use palette_core::{Color, PaletteBuilder};
+//!
+//! let palette = PaletteBuilder::new()
+//!     .background(Color::rgb(0x1a, 0x1b, 0x26))
+//!     .error(Color::rgb(0xf7, 0x76, 0x8e))
+//!     .syntax_keywords(Color::rgb(0xbb, 0x9a, 0xf7))
+//!     .terminal_red(Color::rgb(0xf7, 0x76, 0x8e))
+//!     .build();
+//! assert_eq!(palette.base.background, Some(Color::rgb(0x1a, 0x1b, 0x26)));
+//! assert_eq!(palette.base.foreground, None);
+//! ```
+
+use std::sync::Arc;
+
+use crate::color::Color;
+use crate::palette::{Palette, PaletteMeta};
+use crate::slot::SlotId;
+
+/// Builds a [`Palette`] slot by slot. See the [module docs](self).
+#[derive(Debug, Clone)]
+pub struct PaletteBuilder {
+    palette: Palette,
+}
+
+impl Default for PaletteBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Palette> for PaletteBuilder {
+    fn from(palette: Palette) -> Self {
+        Self { palette }
+    }
+}
+
+impl PaletteBuilder {
+    /// Start from a palette with every slot unset and no `meta`.
+    pub fn new() -> Self {
+        Self {
+            palette: Palette {
+                meta: None,
+                base: Default::default(),
+                semantic: Default::default(),
+                diff: Default::default(),
+                surface: Default::default(),
+                typography: Default::default(),
+                syntax: Default::default(),
+                editor: Default::default(),
+                terminal: Default::default(),
+                syntax_style: Default::default(),
+                gradients: Arc::from([]),
+                groups: Default::default(),
+                #[cfg(feature = "platform")]
+                platform: Default::default(),
+                #[cfg(feature = "toml")]
+                extensions: Default::default(),
+            },
+        }
+    }
+
+    /// Name the palette. Attribution fields are left unset.
+    pub fn meta(
+        mut self,
+        name: impl Into<Arc<str>>,
+        preset_id: impl Into<Arc<str>>,
+        style: impl Into<Arc<str>>,
+    ) -> Self {
+        self.palette.meta = Some(Arc::new(PaletteMeta {
+            name: name.into(),
+            preset_id: preset_id.into(),
+            style: style.into(),
+            author: None,
+            license: None,
+            version: None,
+            description: None,
+            tags: Box::default(),
+        }));
+        self
+    }
+
+    /// Set any slot, including those of [custom groups](crate::group).
+    pub fn slot(mut self, slot: SlotId, color: Color) -> Self {
+        self.palette.set(slot, Some(color));
+        self
+    }
+
+    /// The finished palette.
+    pub fn build(self) -> Palette {
+        self.palette
+    }
+}
+
+macro_rules! builder_methods {
+    ($(#[$_meta:meta])* BaseColors { $($field:ident),+ $(,)? }) => {
+        builder_methods!(@plain base, $($field),+);
+    };
+    ($(#[$_meta:meta])* SemanticColors { $($field:ident),+ $(,)? }) => {
+        builder_methods!(@plain semantic, $($field),+);
+    };
+    ($(#[$_meta:meta])* DiffColors { $($field:ident),+ $(,)? }) => {
+        builder_methods!(@prefixed diff, $($field),+);
+    };
+    ($(#[$_meta:meta])* SurfaceColors { $($field:ident),+ $(,)? }) => {
+        builder_methods!(@prefixed surface, $($field),+);
+    };
+    ($(#[$_meta:meta])* TypographyColors { $($field:ident),+ $(,)? }) => {
+        builder_methods!(@prefixed typography, $($field),+);
+    };
+    ($(#[$_meta:meta])* SyntaxColors { $($field:ident),+ $(,)? }) => {
+        builder_methods!(@prefixed syntax, $($field),+);
+    };
+    ($(#[$_meta:meta])* EditorColors { $($field:ident),+ $(,)? }) => {
+        builder_methods!(@prefixed editor, $($field),+);
+    };
+    ($(#[$_meta:meta])* AnsiColors { $($field:ident),+ $(,)? }) => {
+        builder_methods!(@prefixed terminal, $($field),+);
+    };
+    (@plain $section:ident, $($field:ident),+) => {
+        impl PaletteBuilder {
+            $(
+                #[doc = concat!("Set `", stringify!($section), ".", stringify!($field), "`.")]
+                pub fn $field(mut self, color: Color) -> Self {
+                    self.palette.$section.$field = Some(color);
+                    self
+                }
+            )+
+        }
+    };
+    (@prefixed $section:ident, $($field:ident),+) => {
+        pastey::paste! {
+            impl PaletteBuilder {
+                $(
+                    #[doc = concat!("Set `", stringify!($section), ".", stringify!($field), "`.")]
+                    pub fn [<$section _ $field>](mut self, color: Color) -> Self {
+                        self.palette.$section.$field = Some(color);
+                        self
+                    }
+                )+
+            }
+        }
+    };
+}
+
+crate::palette::color_fields!(builder_methods);
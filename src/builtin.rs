@@ -0,0 +1,178 @@
+//! Built-in presets as static slot tables, generated by `build.rs`.
+
+use std::sync::Arc;
+
+use crate::error::PaletteError;
+use crate::manifest::{
+    GradientSections, ManifestMeta, ManifestSection, PaletteManifest, RawGradientDef,
+    RawGradientStop, slot_key,
+};
+
+type Pairs = &'static [(&'static str, &'static str)];
+
+pub(crate) struct MetaData {
+    name: &'static str,
+    preset_id: &'static str,
+    schema_version: &'static str,
+    style: &'static str,
+    kind: &'static str,
+    inherits: &'static [&'static str],
+    inherit_sections: Option<&'static [&'static str]>,
+    upstream_repo: Option<&'static str>,
+    author: Option<&'static str>,
+    license: Option<&'static str>,
+    version: Option<&'static str>,
+    description: Option<&'static str>,
+    tags: &'static [&'static str],
+}
+
+// No built-in preset defines gradients yet, but the generator supports them.
+#[allow(dead_code)]
+pub(crate) enum StopData {
+    Shorthand(&'static str),
+    Explicit { color: &'static str, at: f64 },
+}
+
+pub(crate) struct GradientData {
+    name: &'static str,
+    space: Option<&'static str>,
+    stops: &'static [StopData],
+}
+
+/// One preset file's sections, exactly as written (inheritance unresolved).
+pub(crate) struct PresetData {
+    meta: MetaData,
+    colors: Pairs,
+    base: Pairs,
+    semantic: Pairs,
+    diff: Pairs,
+    surface: Pairs,
+    typography: Pairs,
+    syntax: Pairs,
+    editor: Pairs,
+    terminal: Pairs,
+    syntax_style: Pairs,
+    gradient: &'static [GradientData],
+    #[cfg_attr(not(feature = "platform"), allow(dead_code))]
+    platform: &'static [(&'static str, Pairs)],
+    /// Custom group and extension tables as TOML text, parsed only when
+    /// the `toml` feature is on.
+    #[cfg_attr(not(feature = "toml"), allow(dead_code))]
+    extensions: &'static str,
+}
+
+include!(concat!(env!("OUT_DIR"), "/presets.rs"));
+
+fn section(pairs: Pairs) -> ManifestSection {
+    pairs
+        .iter()
+        .map(|&(key, value)| (slot_key(key), Arc::from(value)))
+        .collect()
+}
+
+fn gradients(defs: &[GradientData]) -> GradientSections {
+    defs.iter()
+        .map(|def| {
+            let stops = def
+                .stops
+                .iter()
+                .map(|stop| match *stop {
+                    StopData::Shorthand(color) => RawGradientStop::Shorthand(color.to_owned()),
+                    StopData::Explicit { color, at } => RawGradientStop::Explicit {
+                        color: color.to_owned(),
+                        at,
+                    },
+                })
+                .collect();
+            let raw = RawGradientDef {
+                stops,
+                space: def.space.map(str::to_owned),
+            };
+            (Arc::from(def.name), raw)
+        })
+        .collect()
+}
+
+impl PresetData {
+    /// Build the unresolved manifest, as [`PaletteManifest::from_toml`] would
+    /// for the source file: custom group tables split from the extensions,
+    /// an older schema migrated, `[meta]` placeholders expanded, and field
+    /// aliases renamed.
+    pub(crate) fn manifest(&self) -> Result<PaletteManifest, PaletteError> {
+        let meta = &self.meta;
+        let compiled = ManifestMeta {
+            name: Arc::from(meta.name),
+            preset_id: Arc::from(meta.preset_id),
+            schema_version: Arc::from(meta.schema_version),
+            style: Arc::from(meta.style),
+            kind: Arc::from(meta.kind),
+            inherits: meta.inherits.iter().copied().map(Arc::from).collect(),
+            inherit_sections: meta
+                .inherit_sections
+                .map(|sections| sections.iter().copied().map(Arc::from).collect()),
+            upstream_repo: meta.upstream_repo.map(Arc::from),
+            author: meta.author.map(Arc::from),
+            license: meta.license.map(Arc::from),
+            version: meta.version.map(Arc::from),
+            description: meta.description.map(Arc::from),
+            tags: meta.tags.iter().copied().map(Arc::from).collect(),
+        };
+        let mut manifest = PaletteManifest {
+            meta: Some(Arc::new(compiled)),
+            colors: self
+                .colors
+                .iter()
+                .map(|&(name, value)| (Arc::from(name), Arc::from(value)))
+                .collect(),
+            base: section(self.base),
+            semantic: section(self.semantic),
+            diff: section(self.diff),
+            surface: section(self.surface),
+            typography: section(self.typography),
+            syntax: section(self.syntax),
+            editor: section(self.editor),
+            terminal: section(self.terminal),
+            syntax_style: section(self.syntax_style),
+            gradient: gradients(self.gradient),
+            groups: Default::default(),
+            #[cfg(feature = "platform")]
+            platform: self
+                .platform
+                .iter()
+                .map(|&(name, pairs)| (Arc::from(name), section(pairs)))
+                .collect(),
+            opacity: Default::default(),
+            unset: Default::default(),
+            modes: Default::default(),
+            #[cfg(feature = "toml")]
+            extensions: Default::default(),
+        };
+        #[cfg(feature = "toml")]
+        manifest.add_extra_tables(self.extensions)?;
+        if crate::schema::parse_version(meta.schema_version)? != crate::schema::CURRENT_VERSION {
+            return self.migrated(&manifest);
+        }
+        if let Some(meta) = manifest.meta.as_mut() {
+            Arc::make_mut(meta).expand_placeholders()?;
+        }
+        manifest.resolve_aliases();
+        Ok(manifest)
+    }
+
+    /// `manifest`, declaring an older schema, upgraded by the
+    /// [migrations](crate::schema) parsing runs. They rewrite theme
+    /// documents, so the manifest is parsed back from its own TOML.
+    #[cfg(feature = "toml")]
+    fn migrated(&self, manifest: &PaletteManifest) -> Result<PaletteManifest, PaletteError> {
+        PaletteManifest::from_toml(&manifest.to_toml())
+    }
+
+    /// Without the `toml` feature there is no document to migrate, so an
+    /// older schema is unsupported.
+    #[cfg(not(feature = "toml"))]
+    fn migrated(&self, _manifest: &PaletteManifest) -> Result<PaletteManifest, PaletteError> {
+        Err(PaletteError::UnsupportedSchemaVersion(Arc::from(
+            self.meta.schema_version,
+        )))
+    }
+}
@@ -0,0 +1,291 @@
+//! C ABI for loading palettes, reading slots, and exporting CSS/JSON.
+//!
+//! Build a library with
+//! `cargo rustc --release --features capi --crate-type cdylib` (or
+//! `staticlib`) and include `include/palette_core.h`, which `cbindgen`
+//! regenerates from this module using the repository's `cbindgen.toml`.
+//!
+//! Palettes are opaque `Palette *` handles released with [`palette_free`].
+//! Strings returned by the library are owned by the caller and released with
+//! [`palette_string_free`]. Functions that fail return `NULL` (or `false`)
+//! and record the error for the calling thread; read it with
+//! [`palette_last_error`] and [`palette_last_error_code`]. Every call that
+//! can fail clears the previous error first; [`palette_free`],
+//! [`palette_string_free`], and the error accessors leave it in place, so
+//! handles can be released before the error is read.
+//!
+//! [`palette_free`]: crate::capi::palette_free
+//! [`palette_string_free`]: crate::capi::palette_string_free
+//! [`palette_last_error`]: crate::capi::palette_last_error
+//! [`palette_last_error_code`]: crate::capi::palette_last_error_code
+
+#![allow(unsafe_code)]
+
+use std::cell::RefCell;
+use std::ffi::{CStr, CString, c_char};
+use std::path::Path;
+use std::ptr;
+
+use crate::error::PaletteError;
+use crate::palette::Palette;
+use crate::registry::{load_preset, load_preset_file};
+use crate::slot::SlotId;
+
+/// An 8-bit RGBA color.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PaletteRgb {
+    /// Red channel.
+    pub r: u8,
+    /// Green channel.
+    pub g: u8,
+    /// Blue channel.
+    pub b: u8,
+    /// Alpha channel; `255` is opaque. Below `255` for slots written as
+    /// `#RRGGBBAA` or `{ color, opacity }`.
+    pub a: u8,
+}
+
+struct LastError {
+    code: Option<CString>,
+    message: CString,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<LastError>> = const { RefCell::new(None) };
+}
+
+/// Convert to a C string, replacing interior NULs rather than failing.
+fn c_string(s: &str) -> CString {
+    CString::new(s.replace('\0', "\u{FFFD}")).unwrap_or_default()
+}
+
+fn clear_error() {
+    LAST_ERROR.with(|last| *last.borrow_mut() = None);
+}
+
+fn set_error(code: Option<&str>, message: &str) {
+    let error = LastError {
+        code: code.map(c_string),
+        message: c_string(message),
+    };
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(error));
+}
+
+fn set_palette_error(err: &PaletteError) {
+    set_error(Some(err.code()), &err.to_string());
+}
+
+/// Borrow a C string argument, recording an error when it is null or not
+/// UTF-8.
+///
+/// # Safety
+///
+/// `ptr` must be null or point to a NUL-terminated string that outlives `'a`.
+unsafe fn str_arg<'a>(ptr: *const c_char, name: &str) -> Option<&'a str> {
+    if ptr.is_null() {
+        set_error(None, &format!("`{name}` is null"));
+        return None;
+    }
+    // SAFETY: non-null, and the caller guarantees a valid C string.
+    let s = unsafe { CStr::from_ptr(ptr) }.to_str();
+    s.map_err(|_| set_error(None, &format!("`{name}` is not valid UTF-8")))
+        .ok()
+}
+
+/// Borrow a palette handle, recording an error when it is null.
+///
+/// # Safety
+///
+/// `ptr` must be null or a live handle returned by this library.
+unsafe fn palette_arg<'a>(ptr: *const Palette) -> Option<&'a Palette> {
+    // SAFETY: the caller guarantees the pointer is null or a live handle.
+    let palette = unsafe { ptr.as_ref() };
+    if palette.is_none() {
+        set_error(None, "`palette` is null");
+    }
+    palette
+}
+
+fn into_handle(result: Result<Palette, PaletteError>) -> *mut Palette {
+    match result {
+        Ok(palette) => Box::into_raw(Box::new(palette)),
+        Err(err) => {
+            set_palette_error(&err);
+            ptr::null_mut()
+        }
+    }
+}
+
+fn into_c_string(s: &str) -> *mut c_char {
+    c_string(s).into_raw()
+}
+
+/// Load a built-in preset by ID. Returns `NULL` on failure.
+///
+/// # Safety
+///
+/// `id` must be null or a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn palette_load_preset(id: *const c_char) -> *mut Palette {
+    clear_error();
+    // SAFETY: forwarded from the caller's contract.
+    match unsafe { str_arg(id, "id") } {
+        Some(id) => into_handle(load_preset(id)),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Load a theme TOML file, resolving inheritance from sibling files or
+/// built-in presets. Returns `NULL` on failure.
+///
+/// # Safety
+///
+/// `path` must be null or a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn palette_load_file(path: *const c_char) -> *mut Palette {
+    clear_error();
+    // SAFETY: forwarded from the caller's contract.
+    match unsafe { str_arg(path, "path") } {
+        Some(path) => into_handle(load_preset_file(Path::new(path))),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Release a palette handle. `NULL` is ignored.
+///
+/// # Safety
+///
+/// `palette` must be null or a handle from this library not yet freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn palette_free(palette: *mut Palette) {
+    if !palette.is_null() {
+        // SAFETY: the handle came from `Box::into_raw` and is freed once.
+        drop(unsafe { Box::from_raw(palette) });
+    }
+}
+
+/// Read a slot by `"section.field"` path into `out`.
+///
+/// Returns `false` when the slot is unset or the path is invalid; only the
+/// latter records an error.
+///
+/// # Safety
+///
+/// `palette` must be null or a live handle, `path` null or a NUL-terminated
+/// string, and `out` null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn palette_get_slot(
+    palette: *const Palette,
+    path: *const c_char,
+    out: *mut PaletteRgb,
+) -> bool {
+    clear_error();
+    // SAFETY: forwarded from the caller's contract.
+    let (Some(palette), Some(path)) = (unsafe { palette_arg(palette) }, unsafe {
+        str_arg(path, "path")
+    }) else {
+        return false;
+    };
+    if out.is_null() {
+        set_error(None, "`out` is null");
+        return false;
+    }
+    let slot = match path.parse::<SlotId>() {
+        Ok(slot) => slot,
+        Err(err) => {
+            set_palette_error(&err);
+            return false;
+        }
+    };
+    match palette.get(slot) {
+        Some(color) => {
+            let rgb = PaletteRgb {
+                r: color.r,
+                g: color.g,
+                b: color.b,
+                a: color.a,
+            };
+            // SAFETY: non-null, and the caller guarantees it is writable.
+            unsafe { out.write(rgb) };
+            true
+        }
+        None => false,
+    }
+}
+
+/// Render the palette as CSS custom properties. Returns `NULL` on failure;
+/// release the result with [`palette_string_free`].
+///
+/// # Safety
+///
+/// `palette` must be null or a live handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn palette_to_css(palette: *const Palette) -> *mut c_char {
+    clear_error();
+    // SAFETY: forwarded from the caller's contract.
+    match unsafe { palette_arg(palette) } {
+        Some(palette) => into_c_string(&palette.to_css()),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Render the palette as pretty-printed JSON. Returns `NULL` on failure;
+/// release the result with [`palette_string_free`].
+///
+/// # Safety
+///
+/// `palette` must be null or a live handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn palette_to_json(palette: *const Palette) -> *mut c_char {
+    clear_error();
+    // SAFETY: forwarded from the caller's contract.
+    let Some(palette) = (unsafe { palette_arg(palette) }) else {
+        return ptr::null_mut();
+    };
+    match palette.to_json() {
+        Ok(json) => into_c_string(&json),
+        Err(err) => {
+            set_error(None, &err.to_string());
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Release a string returned by this library. `NULL` is ignored.
+///
+/// # Safety
+///
+/// `s` must be null or a string from this library not yet freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn palette_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        // SAFETY: the string came from `CString::into_raw` and is freed once.
+        drop(unsafe { CString::from_raw(s) });
+    }
+}
+
+/// The calling thread's last error message, or `NULL` if the last call
+/// succeeded. Valid until the next call into the library on this thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn palette_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(ptr::null(), |err| err.message.as_ptr())
+    })
+}
+
+/// The stable diagnostic code (e.g. `"PC0008"`) of the calling thread's last
+/// error, or `NULL` if there is none or it did not come from theme loading
+/// (such as a null argument). Valid until the next call into the library on
+/// this thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn palette_last_error_code() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .and_then(|err| err.code.as_ref())
+            .map_or(ptr::null(), |code| code.as_ptr())
+    })
+}
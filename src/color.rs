@@ -1,9 +1,10 @@
 use std::fmt;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use crate::error::PaletteError;
 
-/// Returned when a hex string cannot be parsed as an RGB color.
+/// Returned when a hex string or CSS color function cannot be parsed as a color.
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 #[error("invalid hex color: {value}")]
 pub struct InvalidHex {
@@ -22,13 +23,17 @@ impl InvalidHex {
     }
 }
 
-/// 8-bit RGB color.
+/// 8-bit RGBA color.
 ///
-/// Constructed from a `#RRGGBB` hex string via [`Color::from_hex`] or directly
-/// from field values. Displays as uppercase hex (`#1A1A2E`).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-#[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
-#[cfg_attr(feature = "snapshot", serde(into = "String"))]
+/// Constructed from a `#RRGGBB` or `#RRGGBBAA` hex string via
+/// [`Color::from_hex`] or directly from field values. Displays as uppercase
+/// hex (`#1A1A2E`), with an alpha byte appended only when translucent
+/// (`#1A1A2E80`).
+///
+/// Serializes as its hex string and deserializes from any string
+/// [`Color::parse`] accepts, so it can sit directly in serde config types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[serde(into = "String")]
 pub struct Color {
     /// Red channel.
     pub r: u8,
@@ -36,46 +41,184 @@ pub struct Color {
     pub g: u8,
     /// Blue channel.
     pub b: u8,
+    /// Alpha channel, `255` for fully opaque.
+    pub a: u8,
+}
+
+/// Opaque black.
+impl Default for Color {
+    fn default() -> Self {
+        Self::rgb(0, 0, 0)
+    }
 }
 
 impl fmt::Display for Color {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+        write!(f, "#{:02X}{:02X}{:02X}", self.r, self.g, self.b)?;
+        match self.is_opaque() {
+            true => Ok(()),
+            false => write!(f, "{:02X}", self.a),
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = InvalidHex;
+
+    /// Parse with [`Color::parse`]: hex or a CSS `rgb()` / `hsl()` function.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for Color {
+    type Error = InvalidHex;
+
+    /// Parse with [`Color::parse`]: hex or a CSS `rgb()` / `hsl()` function.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse(value)
     }
 }
 
 impl Color {
-    /// Parse a `#RRGGBB` hex string into a [`Color`].
+    /// Opaque color from red, green, and blue channels.
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, a: 255 }
+    }
+
+    /// Alias of [`rgb`](Self::rgb) for readability in `const` and `static`
+    /// items.
+    ///
+    /// ```
+    /// use palette_core::Color;
+    ///
+    /// static FALLBACK_BG: Color = Color::const_rgb(0x1A, 0x1B, 0x2A);
+    /// assert_eq!(FALLBACK_BG, Color::rgb(26, 27, 42));
+    /// ```
+    pub const fn const_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::rgb(r, g, b)
+    }
+
+    /// Color from red, green, blue, and alpha channels.
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Same color with the alpha channel replaced.
+    pub const fn with_alpha(self, a: u8) -> Self {
+        Self { a, ..self }
+    }
+
+    /// Same color with the alpha channel dropped (fully opaque).
+    pub const fn opaque(self) -> Self {
+        self.with_alpha(255)
+    }
+
+    /// Returns `true` if the alpha channel is `255`.
+    pub const fn is_opaque(&self) -> bool {
+        self.a == 255
+    }
+
+    /// Alpha as a fraction in `[0.0, 1.0]`.
+    pub fn alpha_f64(&self) -> f64 {
+        f64::from(self.a) / 255.0
+    }
+
+    /// Parse a `#RRGGBB` or `#RRGGBBAA` hex string into a [`Color`].
+    ///
+    /// Colors without an alpha byte are fully opaque.
     pub fn from_hex(hex: &str) -> Result<Self, InvalidHex> {
+        let invalid = || InvalidHex {
+            value: Arc::from(hex),
+        };
         let digits = match hex.strip_prefix('#') {
-            Some(d) if d.len() == 6 && d.is_ascii() => d,
-            _ => {
-                return Err(InvalidHex {
-                    value: Arc::from(hex),
-                });
-            }
+            Some(d) if (d.len() == 6 || d.len() == 8) && d.is_ascii() => d,
+            _ => return Err(invalid()),
         };
+        let channel = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).map_err(|_| invalid());
 
-        let r = u8::from_str_radix(&digits[0..2], 16);
-        let g = u8::from_str_radix(&digits[2..4], 16);
-        let b = u8::from_str_radix(&digits[4..6], 16);
+        let a = match digits.len() == 8 {
+            true => channel(6)?,
+            false => 255,
+        };
+        Ok(Self::rgba(channel(0)?, channel(2)?, channel(4)?, a))
+    }
 
-        match (r, g, b) {
-            (Ok(r), Ok(g), Ok(b)) => Ok(Self { r, g, b }),
-            _ => Err(InvalidHex {
-                value: Arc::from(hex),
-            }),
+    /// Parse a hex color or a CSS `rgb()` / `hsl()` function.
+    ///
+    /// Accepts everything [`from_hex`](Self::from_hex) does, plus the
+    /// space-separated (`rgb(26 27 42 / 50%)`) and comma-separated
+    /// (`rgba(26, 27, 42, 0.5)`) function forms. `rgba` and `hsla` are
+    /// aliases. RGB channels are `0`–`255` or percentages; hue is in degrees
+    /// with an optional `deg` suffix; alpha is `0`–`1` or a percentage.
+    ///
+    /// ```
+    /// use palette_core::Color;
+    ///
+    /// let hex = Color::parse("#1A1B2A").unwrap();
+    /// assert_eq!(Color::parse("rgb(26 27 42)").unwrap(), hex);
+    /// assert_eq!(Color::parse("rgb(26, 27, 42)").unwrap(), hex);
+    /// assert_eq!(&*Color::parse("hsl(0 100% 50%)").unwrap().to_hex(), "#FF0000");
+    /// ```
+    pub fn parse(value: &str) -> Result<Self, InvalidHex> {
+        let invalid = || InvalidHex {
+            value: Arc::from(value),
+        };
+        let trimmed = value.trim();
+        if trimmed.starts_with('#') {
+            return Self::from_hex(trimmed).map_err(|_| invalid());
         }
+        let (name, args) = trimmed
+            .strip_suffix(')')
+            .and_then(|s| s.split_once('('))
+            .ok_or_else(invalid)?;
+        let args = split_args(args).ok_or_else(invalid)?;
+        let alpha = match args.get(3) {
+            Some(a) => parse_alpha(a).ok_or_else(invalid)?,
+            None => 255,
+        };
+        let color = match name.trim().to_ascii_lowercase().as_str() {
+            "rgb" | "rgba" => Self::rgb(
+                parse_rgb_channel(args[0]).ok_or_else(invalid)?,
+                parse_rgb_channel(args[1]).ok_or_else(invalid)?,
+                parse_rgb_channel(args[2]).ok_or_else(invalid)?,
+            ),
+            "hsl" | "hsla" => crate::manipulation::hsl_to_rgb(crate::manipulation::Hsl {
+                h: parse_hue(args[0]).ok_or_else(invalid)?,
+                s: parse_percentage(args[1]).ok_or_else(invalid)?,
+                l: parse_percentage(args[2]).ok_or_else(invalid)?,
+            }),
+            _ => return Err(invalid()),
+        };
+        Ok(color.with_alpha(alpha))
     }
 
-    /// Format as a `#RRGGBB` hex string.
+    /// Format as a `#RRGGBB` hex string, or `#RRGGBBAA` when translucent.
     pub fn to_hex(&self) -> Box<str> {
-        let mut buf = String::with_capacity(7);
+        let mut buf = String::with_capacity(9);
         use std::fmt::Write;
-        let _ = write!(buf, "#{:02X}{:02X}{:02X}", self.r, self.g, self.b);
+        let _ = write!(buf, "{self}");
         buf.into_boxed_str()
     }
 
+    /// Format for CSS: hex when opaque, `rgba()` when translucent.
+    ///
+    /// ```
+    /// use palette_core::Color;
+    ///
+    /// let overlay = Color::from_hex("#1A1B2680").unwrap();
+    /// assert_eq!(&*overlay.to_css(), "rgba(26, 27, 38, 0.502)");
+    /// assert_eq!(&*overlay.opaque().to_css(), "#1A1B26");
+    /// ```
+    pub fn to_css(&self) -> Box<str> {
+        self.css().to_string().into_boxed_str()
+    }
+
+    /// Displays as [`to_css`](Self::to_css) without allocating.
+    pub(crate) fn css(self) -> impl fmt::Display {
+        CssColor(self)
+    }
+
     /// WCAG 2.1 relative luminance midpoint threshold.
     ///
     /// Colors with `relative_luminance() > LUMINANCE_MIDPOINT` are perceptually
@@ -93,8 +236,162 @@ impl Color {
 
     /// WCAG 2.1 relative luminance. Returns a value in `[0.0, 1.0]`.
     pub fn relative_luminance(&self) -> f64 {
-        let lin = crate::manipulation::srgb_to_linear;
-        0.2126 * lin(self.r) + 0.7152 * lin(self.g) + 0.0722 * lin(self.b)
+        let [r, g, b] = self.to_linear();
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+}
+
+/// A [`Color`] from a `#RRGGBB` or `#RRGGBBAA` literal, checked at compile
+/// time.
+///
+/// ```
+/// use palette_core::Color;
+///
+/// static ACCENT: Color = palette_core::hex!("#7AA2F7");
+/// static SCRIM: Color = palette_core::hex!("#00000080");
+/// assert_eq!(ACCENT, Color::rgb(0x7A, 0xA2, 0xF7));
+/// assert_eq!(SCRIM.a, 0x80);
+/// ```
+///
+/// Malformed literals fail the build:
+///
+/// ```compile_fail
+/// static BAD: palette_core::Color = palette_core::hex!("#12345");
+/// ```
+#[macro_export]
+macro_rules! hex {
+    ($hex:literal) => {
+        const {
+            match $crate::color::__hex_const($hex) {
+                ::core::option::Option::Some(color) => color,
+                ::core::option::Option::None => {
+                    ::core::panic!(::core::concat!("invalid hex color: ", $hex))
+                }
+            }
+        }
+    };
+}
+
+/// `const` hex parser behind [`hex!`]. Not public API.
+#[doc(hidden)]
+pub const fn __hex_const(hex: &str) -> Option<Color> {
+    const fn nibble(byte: u8) -> Option<u8> {
+        match byte {
+            b'0'..=b'9' => Some(byte - b'0'),
+            b'a'..=b'f' => Some(byte - b'a' + 10),
+            b'A'..=b'F' => Some(byte - b'A' + 10),
+            _ => None,
+        }
+    }
+    const fn channel(bytes: &[u8], i: usize) -> Option<u8> {
+        match (nibble(bytes[i]), nibble(bytes[i + 1])) {
+            (Some(hi), Some(lo)) => Some(hi * 16 + lo),
+            _ => None,
+        }
+    }
+
+    let bytes = hex.as_bytes();
+    if !(bytes.len() == 7 || bytes.len() == 9) || bytes[0] != b'#' {
+        return None;
+    }
+    let a = match bytes.len() == 9 {
+        true => channel(bytes, 7),
+        false => Some(255),
+    };
+    match (channel(bytes, 1), channel(bytes, 3), channel(bytes, 5), a) {
+        (Some(r), Some(g), Some(b), Some(a)) => Some(Color::rgba(r, g, b, a)),
+        _ => None,
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ColorVisitor;
+
+        impl serde::de::Visitor<'_> for ColorVisitor {
+            type Value = Color;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a hex color string or CSS rgb()/hsl() function")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Color, E> {
+                Color::parse(value).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(ColorVisitor)
+    }
+}
+
+/// Split CSS function arguments into three channels and an optional alpha.
+///
+/// Comma-separated and space-separated (`/`-delimited alpha) forms are both
+/// accepted, but not mixed.
+fn split_args(args: &str) -> Option<Vec<&str>> {
+    let parts: Vec<&str> = match args.contains(',') {
+        true => args.split(',').map(str::trim).collect(),
+        false => {
+            let (channels, alpha) = match args.split_once('/') {
+                Some((channels, alpha)) => (channels, Some(alpha.trim())),
+                None => (args, None),
+            };
+            channels.split_whitespace().chain(alpha).collect()
+        }
+    };
+    let well_formed = parts.len() >= 3 && parts.len() <= 4 && !parts.contains(&"");
+    match well_formed {
+        true => Some(parts),
+        false => None,
+    }
+}
+
+/// A finite number, or a percentage of `percent_of` when `%`-suffixed.
+fn parse_number(raw: &str, percent_of: f64) -> Option<f64> {
+    let value = match raw.strip_suffix('%') {
+        Some(pct) => pct.parse::<f64>().ok()? / 100.0 * percent_of,
+        None => raw.parse::<f64>().ok()?,
+    };
+    value.is_finite().then_some(value)
+}
+
+/// An RGB channel: `0`–`255` or a percentage.
+fn parse_rgb_channel(raw: &str) -> Option<u8> {
+    parse_number(raw, 255.0).map(|v| v.round().clamp(0.0, 255.0) as u8)
+}
+
+/// Alpha: `0`–`1` or a percentage, as a byte.
+fn parse_alpha(raw: &str) -> Option<u8> {
+    parse_number(raw, 1.0).map(|v| (v.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
+
+/// Hue in degrees, normalized to `[0, 360)`.
+fn parse_hue(raw: &str) -> Option<f64> {
+    let degrees = raw.strip_suffix("deg").unwrap_or(raw);
+    match degrees.ends_with('%') {
+        true => None,
+        false => parse_number(degrees, 1.0).map(|h| h.rem_euclid(360.0)),
+    }
+}
+
+/// Saturation or lightness: `0`–`100`, with or without `%`, as `[0, 1]`.
+fn parse_percentage(raw: &str) -> Option<f64> {
+    parse_number(raw, 100.0).map(|v| (v / 100.0).clamp(0.0, 1.0))
+}
+
+/// CSS rendering of a [`Color`]; see [`Color::to_css`].
+struct CssColor(Color);
+
+impl fmt::Display for CssColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(c) = self;
+        match c.is_opaque() {
+            true => write!(f, "{c}"),
+            false => {
+                let alpha = (c.alpha_f64() * 1000.0).round() / 1000.0;
+                write!(f, "rgba({}, {}, {}, {alpha})", c.r, c.g, c.b)
+            }
+        }
     }
 }
 
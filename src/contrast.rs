@@ -1,9 +1,15 @@
+use std::sync::Arc;
+
 use crate::color::Color;
+use crate::diagnostic::Severity;
+use crate::error::PaletteError;
 use crate::palette::Palette;
 use crate::resolved::ResolvedPalette;
+use crate::slot::SlotId;
 
 /// WCAG 2.1 conformance level for contrast checking.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
 pub enum ContrastLevel {
     /// AA for normal text (≥ 4.5:1).
     AaNormal,
@@ -13,6 +19,9 @@ pub enum ContrastLevel {
     AaaNormal,
     /// AAA for large text (≥ 4.5:1).
     AaaLarge,
+    /// Non-text UI components and graphics: borders, focus indicators,
+    /// cursors (≥ 3.0:1, WCAG 1.4.11).
+    UiComponent,
 }
 
 impl ContrastLevel {
@@ -20,7 +29,7 @@ impl ContrastLevel {
     pub fn threshold(self) -> f64 {
         match self {
             Self::AaNormal | Self::AaaLarge => 4.5,
-            Self::AaLarge => 3.0,
+            Self::AaLarge | Self::UiComponent => 3.0,
             Self::AaaNormal => 7.0,
         }
     }
@@ -33,6 +42,7 @@ impl ContrastLevel {
 
 /// A foreground/background pair that fails a contrast check.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
 pub struct ContrastViolation {
     /// Dot-path label of the foreground slot (e.g. `"base.foreground"`).
     pub foreground_label: Box<str>,
@@ -46,6 +56,33 @@ pub struct ContrastViolation {
     pub ratio: f64,
     /// The conformance level that was not met.
     pub level: ContrastLevel,
+    /// [`Severity::Error`] when a core pair falls well below the threshold
+    /// (under [`SEVERE_FRACTION`] of it); [`Severity::Warning`] otherwise.
+    /// Cosmetic pairs — comments, line numbers, inlay hints, dimmed text,
+    /// borders — are always warnings.
+    pub severity: Severity,
+}
+
+/// Fraction of the threshold below which a core pair's violation becomes
+/// [`Severity::Error`]: 3.6:1 for AA normal text, 2.4:1 for UI components.
+pub const SEVERE_FRACTION: f64 = 0.8;
+
+/// Foreground slots whose failures never block a build.
+const COSMETIC_SLOTS: [&str; 6] = [
+    "base.foreground_dark",
+    "base.border",
+    "base.border_highlight",
+    "editor.inlay_hint_fg",
+    "typography.comment",
+    "typography.line_number",
+];
+
+fn violation_severity(foreground_label: &str, ratio: f64, level: ContrastLevel) -> Severity {
+    let cosmetic = COSMETIC_SLOTS.contains(&foreground_label);
+    match !cosmetic && ratio < level.threshold() * SEVERE_FRACTION {
+        true => Severity::Error,
+        false => Severity::Warning,
+    }
 }
 
 /// WCAG 2.1 contrast ratio between two colors. Returns `[1.0, 21.0]`.
@@ -79,7 +116,50 @@ impl Color {
     }
 }
 
-fn check_pair(
+impl Palette {
+    /// WCAG 2.1 contrast ratio between two slots named by `section.field`
+    /// path, e.g. `palette.contrast_between("syntax.keywords", "surface.float")`.
+    ///
+    /// # Errors
+    ///
+    /// [`PaletteError::UnknownField`] if a path names no slot, or
+    /// [`PaletteError::UnsetSlot`] if the theme leaves a slot empty.
+    pub fn contrast_between(&self, fg: &str, bg: &str) -> Result<f64, PaletteError> {
+        let fg = self.require(fg.parse()?)?;
+        let bg = self.require(bg.parse()?)?;
+        Ok(contrast_ratio(&fg, &bg))
+    }
+
+    fn require(&self, slot: SlotId) -> Result<Color, PaletteError> {
+        self.get(slot).ok_or_else(|| PaletteError::UnsetSlot {
+            section: Arc::from(slot.section().as_str()),
+            field: Arc::from(slot.field()),
+        })
+    }
+}
+
+/// The candidate with the highest contrast against `bg`, for labels and
+/// badges drawn over arbitrary surfaces. Ties go to the earlier candidate;
+/// an empty slice falls back to [`black_or_white`].
+pub fn best_foreground(bg: Color, candidates: &[Color]) -> Color {
+    let bg_lum = bg.relative_luminance();
+    candidates
+        .iter()
+        .map(|c| (*c, contrast_ratio_with_lum(c.relative_luminance(), bg_lum)))
+        .fold(None, |best: Option<(Color, f64)>, (c, ratio)| match best {
+            Some((_, best_ratio)) if best_ratio >= ratio => best,
+            _ => Some((c, ratio)),
+        })
+        .map_or_else(|| black_or_white(bg), |(c, _)| c)
+}
+
+/// Black or white, whichever contrasts more with `bg`.
+pub fn black_or_white(bg: Color) -> Color {
+    best_foreground(bg, &[Color::rgb(0, 0, 0), Color::rgb(255, 255, 255)])
+}
+
+/// Measure a pair whose slots are both set, passing or not.
+fn measure_pair(
     fg_prefix: &str,
     fg_name: &str,
     bg_prefix: &str,
@@ -92,18 +172,30 @@ fn check_pair(
         (Some(f), Some(b)) => (*f, *b),
         _ => return None,
     };
+    let foreground_label = format!("{fg_prefix}.{fg_name}");
     let ratio = contrast_ratio(&fg_color, &bg_color);
-    match level.passes(ratio) {
-        true => None,
-        false => Some(ContrastViolation {
-            foreground_label: format!("{fg_prefix}.{fg_name}").into_boxed_str(),
-            background_label: format!("{bg_prefix}.{bg_name}").into_boxed_str(),
-            foreground: fg_color,
-            background: bg_color,
-            ratio,
-            level,
-        }),
-    }
+    Some(ContrastViolation {
+        severity: violation_severity(&foreground_label, ratio, level),
+        foreground_label: foreground_label.into_boxed_str(),
+        background_label: format!("{bg_prefix}.{bg_name}").into_boxed_str(),
+        foreground: fg_color,
+        background: bg_color,
+        ratio,
+        level,
+    })
+}
+
+fn check_pair(
+    fg_prefix: &str,
+    fg_name: &str,
+    bg_prefix: &str,
+    bg_name: &str,
+    fg: Option<&Color>,
+    bg: Option<&Color>,
+    level: ContrastLevel,
+) -> Option<ContrastViolation> {
+    measure_pair(fg_prefix, fg_name, bg_prefix, bg_name, fg, bg, level)
+        .filter(|v| !v.level.passes(v.ratio))
 }
 
 /// Single source of truth for static foreground/background contrast pairs.
@@ -134,20 +226,46 @@ macro_rules! for_each_static_pair {
     };
 }
 
+/// Non-text UI pairs, checked only when the requested level is
+/// [`ContrastLevel::UiComponent`]; text levels skip them.
+macro_rules! for_each_ui_pair {
+    ($callback:ident ! ($($ctx:tt)*)) => {
+        $callback!($($ctx)*, base.border, base.background);
+        // Focused borders and focus rings
+        $callback!($($ctx)*, base.border_highlight, base.background);
+        $callback!($($ctx)*, editor.cursor, base.background);
+    };
+}
+
 /// Check all semantically paired slots in a palette for contrast violations.
 ///
-/// Returns an empty slice when every tested pair meets the given level.
+/// Text levels check foreground/background text pairs only; they never
+/// look at borders or the cursor. [`ContrastLevel::UiComponent`] checks
+/// only the non-text pairs WCAG 1.4.11 covers — borders, focused borders,
+/// and the cursor against the background — and skips every text pair, so
+/// a full audit runs both a text level and `UiComponent`. With the
+/// `platform` feature, text levels also check core foregrounds against
+/// each `[platform.*]` override. Returns an empty slice
+/// when every tested pair meets the given level.
 pub fn validate_palette(palette: &Palette, level: ContrastLevel) -> Box<[ContrastViolation]> {
-    let mut violations = Vec::with_capacity(16);
+    measure_palette(palette, level)
+        .into_iter()
+        .filter(|v| !v.level.passes(v.ratio))
+        .collect()
+}
+
+/// Every pair [`validate_palette`] checks, passing or not.
+fn measure_palette(palette: &Palette, level: ContrastLevel) -> Vec<ContrastViolation> {
+    let mut measured = Vec::with_capacity(64);
     let mut push = |v: Option<ContrastViolation>| {
         if let Some(v) = v {
-            violations.push(v);
+            measured.push(v);
         }
     };
 
     macro_rules! validate_static_pair {
         ($palette:ident, $level:ident, $fg_section:ident . $fg_field:ident, $bg_section:ident . $bg_field:ident) => {
-            push(check_pair(
+            push(measure_pair(
                 stringify!($fg_section),
                 stringify!($fg_field),
                 stringify!($bg_section),
@@ -159,35 +277,299 @@ pub fn validate_palette(palette: &Palette, level: ContrastLevel) -> Box<[Contras
         };
     }
 
-    for_each_static_pair!(validate_static_pair!(palette, level));
+    match level {
+        ContrastLevel::UiComponent => {
+            for_each_ui_pair!(validate_static_pair!(palette, level));
+        }
+        _ => {
+            for_each_static_pair!(validate_static_pair!(palette, level));
+
+            // Semantic over background (dynamic iteration)
+            for (name, color) in palette.semantic.populated_slots() {
+                push(measure_pair(
+                    "semantic",
+                    name,
+                    "base",
+                    "background",
+                    Some(color),
+                    palette.base.background.as_ref(),
+                    level,
+                ));
+            }
+
+            // Syntax over background (dynamic iteration)
+            for (name, color) in palette.syntax.populated_slots() {
+                push(measure_pair(
+                    "syntax",
+                    name,
+                    "base",
+                    "background",
+                    Some(color),
+                    palette.base.background.as_ref(),
+                    level,
+                ));
+            }
+
+            #[cfg(feature = "platform")]
+            measure_platforms(palette, level, &mut push);
+        }
+    }
+
+    measured
+}
 
-    // Semantic over background (dynamic iteration)
-    for (name, color) in palette.semantic.populated_slots() {
-        push(check_pair(
-            "semantic",
-            name,
-            "base",
+/// Core foregrounds over each `[platform.*]` override. A platform's own
+/// `foreground` stands in for `base.foreground`; an unset platform
+/// `background` falls back to `base.background`.
+#[cfg(feature = "platform")]
+fn measure_platforms(
+    palette: &Palette,
+    level: ContrastLevel,
+    push: &mut impl FnMut(Option<ContrastViolation>),
+) {
+    for (name, o) in &palette.platform {
+        // An empty override repeats the base pairs already checked.
+        match o.background.is_some() || o.foreground.is_some() {
+            true => {}
+            false => continue,
+        }
+        let prefix = format!("platform.{name}");
+        let (bg_prefix, bg) = match o.background {
+            Some(bg) => (prefix.as_str(), bg),
+            None => match palette.base.background {
+                Some(bg) => ("base", bg),
+                None => continue,
+            },
+        };
+        let (fg_prefix, fg) = match o.foreground {
+            Some(fg) => (prefix.as_str(), Some(fg)),
+            None => ("base", palette.base.foreground),
+        };
+        push(measure_pair(
+            fg_prefix,
+            "foreground",
+            bg_prefix,
             "background",
-            Some(color),
-            palette.base.background.as_ref(),
+            fg.as_ref(),
+            Some(&bg),
             level,
         ));
+        if o.background.is_some() {
+            push(measure_pair(
+                "base",
+                "foreground_dark",
+                bg_prefix,
+                "background",
+                palette.base.foreground_dark.as_ref(),
+                Some(&bg),
+                level,
+            ));
+        }
     }
+}
 
-    // Syntax over background (dynamic iteration)
-    for (name, color) in palette.syntax.populated_slots() {
-        push(check_pair(
-            "syntax",
-            name,
-            "base",
-            "background",
-            Some(color),
-            palette.base.background.as_ref(),
+/// Pass/fail counts for one palette section of a [`ContrastReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
+pub struct SectionSummary {
+    /// Section of the foreground slot (e.g. `"editor"`).
+    pub section: Box<str>,
+    /// Pairs checked.
+    pub checked: usize,
+    /// Pairs meeting the level.
+    pub passed: usize,
+    /// Pairs below the level.
+    pub failed: usize,
+}
+
+/// Summary of a [`validate_palette`] run, for CI dashboards and
+/// accessibility reports.
+///
+/// With the `snapshot` feature, serializes to JSON alongside the violations
+/// themselves.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
+pub struct ContrastReport {
+    /// Level the palette was checked against.
+    pub level: ContrastLevel,
+    /// Pairs checked; pairs with an unset slot are not counted.
+    pub checked: usize,
+    /// Pairs meeting the level.
+    pub passed: usize,
+    /// Pairs below the level.
+    pub failed: usize,
+    /// Lowest ratio among checked pairs, or `None` if nothing was checked.
+    pub worst_ratio: Option<f64>,
+    /// Counts by foreground section, in check order.
+    pub sections: Box<[SectionSummary]>,
+    /// The failing pairs, as [`validate_palette`] returns them.
+    pub violations: Box<[ContrastViolation]>,
+}
+
+impl ContrastReport {
+    /// Whether every checked pair meets the level.
+    pub fn is_passing(&self) -> bool {
+        self.failed == 0
+    }
+}
+
+/// Check a palette like [`validate_palette`] and summarize the result.
+///
+/// ```
+/// use palette_core::contrast::{ContrastLevel, contrast_report};
+/// use palette_core::load_preset;
+///
+/// let report = contrast_report(&load_preset("nord").unwrap(), ContrastLevel::AaNormal);
+/// assert_eq!(report.checked, report.passed + report.failed);
+/// assert_eq!(report.failed, report.violations.len());
+/// ```
+pub fn contrast_report(palette: &Palette, level: ContrastLevel) -> ContrastReport {
+    let measured = measure_palette(palette, level);
+    let mut sections: Vec<SectionSummary> = Vec::new();
+    for v in &measured {
+        let section = v
+            .foreground_label
+            .split_once('.')
+            .map_or(&*v.foreground_label, |(section, _)| section);
+        let index = match sections.iter().position(|s| &*s.section == section) {
+            Some(i) => i,
+            None => {
+                sections.push(SectionSummary {
+                    section: Box::from(section),
+                    checked: 0,
+                    passed: 0,
+                    failed: 0,
+                });
+                sections.len() - 1
+            }
+        };
+        let summary = &mut sections[index];
+        summary.checked += 1;
+        match level.passes(v.ratio) {
+            true => summary.passed += 1,
+            false => summary.failed += 1,
+        }
+    }
+
+    let worst_ratio = measured.iter().map(|v| v.ratio).min_by(f64::total_cmp);
+    let violations: Box<[ContrastViolation]> = measured
+        .into_iter()
+        .filter(|v| !level.passes(v.ratio))
+        .collect();
+    let checked = sections.iter().map(|s| s.checked).sum();
+    ContrastReport {
+        level,
+        checked,
+        passed: checked - violations.len(),
+        failed: violations.len(),
+        worst_ratio,
+        sections: sections.into_boxed_slice(),
+        violations,
+    }
+}
+
+/// A foreground/background pair with its own required level, for a
+/// [`ContrastPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ContrastPair {
+    /// Slot drawn on top.
+    pub foreground: SlotId,
+    /// Slot drawn underneath.
+    pub background: SlotId,
+    /// Level this pair must meet.
+    pub level: ContrastLevel,
+}
+
+/// Which pairs [`validate_with_policy`] checks, and at what level.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastPolicy {
+    /// Level for the built-in pairs [`validate_palette`] checks; `None`
+    /// checks only [`pairs`](Self::pairs).
+    pub level: Option<ContrastLevel>,
+    /// Extra pairs, each with its own level. A pair naming the same slots as
+    /// a built-in pair replaces it, so a single built-in pair can be held to
+    /// a stricter or looser level.
+    pub pairs: Vec<ContrastPair>,
+}
+
+impl ContrastPolicy {
+    /// The built-in pairs at `level`, with no extra pairs.
+    pub fn new(level: ContrastLevel) -> Self {
+        Self {
+            level: Some(level),
+            pairs: Vec::new(),
+        }
+    }
+
+    /// Add a pair that must meet `level`.
+    pub fn pair(mut self, foreground: SlotId, background: SlotId, level: ContrastLevel) -> Self {
+        self.pairs.push(ContrastPair {
+            foreground,
+            background,
             level,
-        ));
+        });
+        self
     }
+}
 
-    violations.into_boxed_slice()
+impl Default for ContrastPolicy {
+    /// The built-in pairs at AA for normal text.
+    fn default() -> Self {
+        Self::new(ContrastLevel::AaNormal)
+    }
+}
+
+/// Check a palette against a [`ContrastPolicy`].
+///
+/// Built-in pairs come first, in [`validate_palette`] order, followed by
+/// the policy's extra pairs. Pairs with an unset slot are skipped.
+///
+/// ```
+/// use palette_core::contrast::{ContrastLevel, ContrastPolicy, validate_with_policy};
+/// use palette_core::load_preset;
+///
+/// let policy = ContrastPolicy::new(ContrastLevel::AaLarge).pair(
+///     "syntax.comments".parse().unwrap(),
+///     "surface.float".parse().unwrap(),
+///     ContrastLevel::AaLarge,
+/// );
+/// let palette = load_preset("tokyonight").unwrap();
+/// for v in validate_with_policy(&palette, &policy).iter() {
+///     println!("{} on {}: {:.2}", v.foreground_label, v.background_label, v.ratio);
+/// }
+/// ```
+pub fn validate_with_policy(
+    palette: &Palette,
+    policy: &ContrastPolicy,
+) -> Box<[ContrastViolation]> {
+    let overridden = |v: &ContrastViolation| {
+        policy.pairs.iter().any(|p| {
+            *v.foreground_label == *p.foreground.to_string()
+                && *v.background_label == *p.background.to_string()
+        })
+    };
+    let builtin = policy
+        .level
+        .map(|level| validate_palette(palette, level))
+        .unwrap_or_default();
+    let extra = policy.pairs.iter().filter_map(|p| {
+        let fg = palette.get(p.foreground);
+        let bg = palette.get(p.background);
+        check_pair(
+            p.foreground.section().as_str(),
+            p.foreground.field(),
+            p.background.section().as_str(),
+            p.background.field(),
+            fg.as_ref(),
+            bg.as_ref(),
+            p.level,
+        )
+    });
+    builtin
+        .into_iter()
+        .filter(|v| !overridden(v))
+        .chain(extra)
+        .collect()
 }
 
 /// Nudge a foreground color's lightness until it meets the given contrast level
@@ -269,8 +651,8 @@ fn nudge_direction(fg: Color, bg_lum: f64, threshold: f64, lighten: bool) -> Opt
 }
 
 /// Adjust all semantically paired foreground slots on a resolved palette so
-/// they meet the given contrast level. Mirrors the pairs checked by
-/// [`validate_palette`].
+/// they meet the given contrast level. Mirrors the pairs and levels checked
+/// by [`validate_palette`].
 pub fn adjust_contrast(resolved: &mut ResolvedPalette, level: ContrastLevel) {
     macro_rules! adjust_static_pair {
         ($resolved:ident, $level:ident, $fg_section:ident . $fg_field:ident, $bg_section:ident . $bg_field:ident) => {
@@ -282,14 +664,115 @@ pub fn adjust_contrast(resolved: &mut ResolvedPalette, level: ContrastLevel) {
         };
     }
 
-    for_each_static_pair!(adjust_static_pair!(resolved, level));
+    match level {
+        ContrastLevel::UiComponent => {
+            for_each_ui_pair!(adjust_static_pair!(resolved, level));
+        }
+        _ => {
+            for_each_static_pair!(adjust_static_pair!(resolved, level));
 
-    // Semantic and syntax over background — cache bg luminance once.
-    let bg_lum = resolved.base.background.relative_luminance();
-    for (_, slot) in resolved.semantic.all_slots_mut() {
-        *slot = nudge_foreground_with_bg_lum(*slot, bg_lum, level);
+            // Semantic and syntax over background — cache bg luminance once.
+            let bg_lum = resolved.base.background.relative_luminance();
+            for (_, slot) in resolved.semantic.all_slots_mut() {
+                *slot = nudge_foreground_with_bg_lum(*slot, bg_lum, level);
+            }
+            for (_, slot) in resolved.syntax.all_slots_mut() {
+                *slot = nudge_foreground_with_bg_lum(*slot, bg_lum, level);
+            }
+        }
     }
-    for (_, slot) in resolved.syntax.all_slots_mut() {
-        *slot = nudge_foreground_with_bg_lum(*slot, bg_lum, level);
+}
+
+/// A foreground slot changed by [`fix_palette`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Adjustment {
+    /// Dot-path label of the adjusted slot (e.g. `"typography.comment"`).
+    pub slot: Box<str>,
+    /// The color before repair.
+    pub before: Color,
+    /// The repaired color.
+    pub after: Color,
+}
+
+/// Repair every failing pair [`validate_palette`] would report by nudging
+/// the foreground with [`nudge_foreground`].
+///
+/// Backgrounds and passing foregrounds are untouched, and unset slots stay
+/// unset. Each changed slot is reported once, in check order, with its
+/// original and final color. A foreground that no lightness can save is
+/// left as is and still shows up in a later [`validate_palette`] call.
+///
+/// ```
+/// use palette_core::Color;
+/// use palette_core::contrast::{ContrastLevel, fix_palette, validate_palette};
+/// use palette_core::generate::{Style, from_seed};
+///
+/// let mut palette = from_seed(Color::from_hex("#7AA2F7").unwrap(), Style::Dark);
+/// palette.typography.comment = palette.base.background_highlight;
+/// let (fixed, changes) = fix_palette(&palette, ContrastLevel::AaNormal);
+/// assert_eq!(&*changes[0].slot, "typography.comment");
+/// assert!(validate_palette(&fixed, ContrastLevel::AaNormal).is_empty());
+/// ```
+pub fn fix_palette(palette: &Palette, level: ContrastLevel) -> (Palette, Box<[Adjustment]>) {
+    let mut fixed = palette.clone();
+    let mut adjustments: Vec<Adjustment> = Vec::new();
+    let mut record = |slot: String, before: Color, after: Color| match adjustments
+        .iter_mut()
+        .find(|a| *a.slot == *slot)
+    {
+        Some(existing) => existing.after = after,
+        None => adjustments.push(Adjustment {
+            slot: slot.into_boxed_str(),
+            before,
+            after,
+        }),
+    };
+
+    macro_rules! fix_static_pair {
+        ($fixed:ident, $level:ident, $fg_section:ident . $fg_field:ident, $bg_section:ident . $bg_field:ident) => {
+            if let (Some(fg), Some(bg)) =
+                ($fixed.$fg_section.$fg_field, $fixed.$bg_section.$bg_field)
+            {
+                let after = nudge_foreground(fg, bg, $level);
+                if after != fg {
+                    $fixed.$fg_section.$fg_field = Some(after);
+                    record(
+                        concat!(stringify!($fg_section), ".", stringify!($fg_field)).to_owned(),
+                        fg,
+                        after,
+                    );
+                }
+            }
+        };
     }
+
+    match level {
+        ContrastLevel::UiComponent => {
+            for_each_ui_pair!(fix_static_pair!(fixed, level));
+        }
+        _ => {
+            for_each_static_pair!(fix_static_pair!(fixed, level));
+
+            // Semantic and syntax over background — cache bg luminance once.
+            if let Some(bg) = fixed.base.background {
+                let bg_lum = bg.relative_luminance();
+                for (name, slot) in fixed.semantic.populated_slots_mut() {
+                    let after = nudge_foreground_with_bg_lum(*slot, bg_lum, level);
+                    if after != *slot {
+                        record(format!("semantic.{name}"), *slot, after);
+                        *slot = after;
+                    }
+                }
+                for (name, slot) in fixed.syntax.populated_slots_mut() {
+                    let after = nudge_foreground_with_bg_lum(*slot, bg_lum, level);
+                    if after != *slot {
+                        record(format!("syntax.{name}"), *slot, after);
+                        *slot = after;
+                    }
+                }
+            }
+        }
+    }
+
+    (fixed, adjustments.into_boxed_slice())
 }
@@ -164,7 +164,7 @@ fn write_section<'a>(
             Some(name) => name,
             None => field,
         };
-        write_property(out, prefix, slot, color);
+        write_property(out, prefix, slot, &color.css());
     }
 }
 
@@ -178,6 +178,10 @@ impl Palette {
     }
 
     /// Complete CSS block with a custom selector and optional prefix.
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "export", level = "debug", skip_all,
+        fields(target = "css", id = self.preset_id()),
+    ))]
     pub fn to_css_scoped(&self, selector: &str, prefix: Option<&str>) -> String {
         let mut out = String::with_capacity(1024);
         let _ = writeln!(out, "{selector} {{");
@@ -210,6 +214,12 @@ fn write_declarations(out: &mut String, palette: &Palette, prefix: Option<&str>)
     write_section(out, prefix, "editor", palette.editor.populated_slots());
     write_section(out, prefix, "terminal", palette.terminal.populated_slots());
     write_style_section(out, prefix, &palette.syntax_style);
+    for group in palette.groups.iter() {
+        for (field, color) in group.populated_slots() {
+            let slot = format!("{}-{}", group.section(), field).replace('_', "-");
+            write_property(out, prefix, &slot, &color.css());
+        }
+    }
 }
 
 fn write_style_section(
@@ -0,0 +1,186 @@
+//! Color-vision-deficiency simulation.
+//!
+//! Simulates dichromat vision with the Viénot, Brettel & Mollon (1999)
+//! projection in linear sRGB, which is accurate for the red–green
+//! deficiencies that affect roughly 1 in 12 men. Tritanopia, which has no
+//! single-plane projection, uses the Machado, Oliveira & Fernandes (2009)
+//! full-severity matrix instead. Use it to check that status colors stay
+//! distinguishable, or see
+//! [`generate::status_colors`](crate::generate::status_colors) to pick ones
+//! that do.
+
+use crate::color::Color;
+use crate::manipulation::{linear_to_srgb, srgb_to_linear, srgb_to_oklab};
+use crate::palette::Palette;
+
+/// A dichromatic color-vision deficiency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
+pub enum Deficiency {
+    /// Missing L (long-wavelength) cones.
+    Protanopia,
+    /// Missing M (medium-wavelength) cones.
+    Deuteranopia,
+    /// Missing S (short-wavelength) cones.
+    Tritanopia,
+}
+
+impl Deficiency {
+    /// Every simulated deficiency.
+    pub const ALL: [Self; 3] = [Self::Protanopia, Self::Deuteranopia, Self::Tritanopia];
+
+    /// The red–green deficiencies, by far the most common.
+    pub const RED_GREEN: [Self; 2] = [Self::Protanopia, Self::Deuteranopia];
+
+    fn matrix(self) -> [[f64; 3]; 3] {
+        match self {
+            Self::Protanopia => [
+                [0.11238, 0.88762, 0.0],
+                [0.11238, 0.88762, 0.0],
+                [0.00401, -0.00401, 1.0],
+            ],
+            Self::Deuteranopia => [
+                [0.29275, 0.70725, 0.0],
+                [0.29275, 0.70725, 0.0],
+                [-0.02234, 0.02234, 1.0],
+            ],
+            Self::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        }
+    }
+}
+
+/// How `color` appears to a viewer with `deficiency`.
+pub fn simulate(color: Color, deficiency: Deficiency) -> Color {
+    let rgb = [
+        srgb_to_linear(color.r),
+        srgb_to_linear(color.g),
+        srgb_to_linear(color.b),
+    ];
+    let [r, g, b] = deficiency
+        .matrix()
+        .map(|row| row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2]);
+    Color {
+        r: linear_to_srgb(r),
+        g: linear_to_srgb(g),
+        b: linear_to_srgb(b),
+        a: color.a,
+    }
+}
+
+impl Color {
+    /// How this color appears to a viewer with `deficiency`. See [`simulate`].
+    pub fn simulate(self, deficiency: Deficiency) -> Color {
+        simulate(self, deficiency)
+    }
+}
+
+impl Palette {
+    /// Preview the whole palette as seen by a viewer with `deficiency`.
+    ///
+    /// Every slot, gradient stop, and custom group color is passed through
+    /// [`simulate`]; metadata and styles are kept.
+    pub fn simulate_cvd(&self, deficiency: Deficiency) -> Palette {
+        self.map_colors(|c| simulate(c, deficiency))
+    }
+}
+
+/// Perceptual (OKLab) distance between two colors.
+pub fn distance(a: Color, b: Color) -> f64 {
+    let (a, b) = (srgb_to_oklab(a), srgb_to_oklab(b));
+    ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+}
+
+/// Smallest pairwise [`distance`] among `colors` across normal vision and
+/// the [red–green](Deficiency::RED_GREEN) deficiencies. Fewer than two
+/// colors yields `f64::INFINITY`.
+pub fn min_distance(colors: &[Color]) -> f64 {
+    let mut min = f64::INFINITY;
+    for (i, &a) in colors.iter().enumerate() {
+        for &b in &colors[i + 1..] {
+            min = min.min(distance(a, b));
+            for deficiency in Deficiency::RED_GREEN {
+                min = min.min(distance(simulate(a, deficiency), simulate(b, deficiency)));
+            }
+        }
+    }
+    min
+}
+
+/// Two semantic colors that look too alike to a viewer with a
+/// [`Deficiency`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
+pub struct CvdViolation {
+    /// Dot-path label of the first slot (e.g. `"semantic.success"`).
+    pub first_label: Box<str>,
+    /// Dot-path label of the second slot (e.g. `"semantic.error"`).
+    pub second_label: Box<str>,
+    /// The first color, as defined.
+    pub first: Color,
+    /// The second color, as defined.
+    pub second: Color,
+    /// The deficiency under which the pair is hardest to tell apart.
+    pub deficiency: Deficiency,
+    /// CIEDE2000 difference between the simulated colors.
+    pub delta_e: f64,
+    /// The minimum difference that was required.
+    pub min_delta_e: f64,
+}
+
+/// Semantic slots that must stay mutually distinguishable.
+const STATUS_SLOTS: [&str; 4] = ["success", "warning", "error", "info"];
+
+/// Check that `semantic.success`, `warning`, `error`, and `info` stay at
+/// least `min_delta_e` apart (CIEDE2000) under protanopia and deuteranopia.
+///
+/// Each failing pair is reported once, under whichever deficiency brings it
+/// closest. Unset slots are skipped. A `min_delta_e` around 10 asks for
+/// colors that read as clearly different; see
+/// [`generate::repair_status_colors`](crate::generate::repair_status_colors)
+/// to fix a palette that fails.
+///
+/// ```
+/// use palette_core::cvd::validate_semantic;
+/// use palette_core::load_preset;
+///
+/// let palette = load_preset("tokyonight").unwrap();
+/// for v in validate_semantic(&palette, 10.0).iter() {
+///     println!("{} vs {} under {:?}: {:.1}", v.first_label, v.second_label, v.deficiency, v.delta_e);
+/// }
+/// ```
+pub fn validate_semantic(palette: &Palette, min_delta_e: f64) -> Box<[CvdViolation]> {
+    let slots: Vec<(&str, Color)> = palette
+        .semantic
+        .populated_slots()
+        .filter(|(name, _)| STATUS_SLOTS.contains(name))
+        .map(|(name, color)| (name, *color))
+        .collect();
+    let mut violations = Vec::new();
+    for (i, &(first_name, first)) in slots.iter().enumerate() {
+        for &(second_name, second) in &slots[i + 1..] {
+            let worst = Deficiency::RED_GREEN
+                .map(|d| (d, simulate(first, d).delta_e(&simulate(second, d))))
+                .into_iter()
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+            match worst {
+                Some((deficiency, delta_e)) if delta_e < min_delta_e => {
+                    violations.push(CvdViolation {
+                        first_label: format!("semantic.{first_name}").into_boxed_str(),
+                        second_label: format!("semantic.{second_name}").into_boxed_str(),
+                        first,
+                        second,
+                        deficiency,
+                        delta_e,
+                        min_delta_e,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+    violations.into_boxed_slice()
+}
@@ -0,0 +1,607 @@
+//! Structured diagnostics with stable codes.
+//!
+//! Every [`PaletteError`](crate::error::PaletteError) maps to a
+//! [`Diagnostic`](crate::diagnostic::Diagnostic) carrying a stable code,
+//! a severity, the section and field it concerns, and — where one can be
+//! derived — a suggested fix. Lint findings (unknown manifest keys, contrast
+//! failures, indistinct status colors) convert to diagnostics too — warnings,
+//! except contrast failures severe enough to be errors — so tooling can
+//! report everything through one type:
+//!
+//! ```
+//! use palette_core::registry::load_preset;
+//!
+//! let err = load_preset("tokyonite").unwrap_err();
+//! let diagnostic = err.diagnostic();
+//! assert_eq!(diagnostic.code, "PC0008");
+//! assert_eq!(diagnostic.suggestion.as_deref(), Some("did you mean `tokyonight`?"));
+//! ```
+//!
+//! Codes are never reused or renumbered. Load errors occupy
+//! `PC0001`–`PC0099` and lint findings `PC0100` onward.
+
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::contrast::ContrastViolation;
+use crate::cvd::CvdViolation;
+use crate::error::PaletteError;
+use crate::manifest::{
+    FIELD_ALIASES, PaletteManifest, UnknownField, known_fields, validate_fields,
+};
+use crate::registry::preset_ids;
+use crate::slot::SlotId;
+
+/// How serious a [`Diagnostic`] is. Serializes as `"error"` or `"warning"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The theme cannot be loaded or used as written.
+    Error,
+    /// The theme loads, but something is likely wrong.
+    Warning,
+}
+
+impl Severity {
+    /// Lowercase label: `"error"` or `"warning"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+        }
+    }
+}
+
+/// A problem report with a stable code and optional fix.
+///
+/// Serializes to a stable JSON shape for language servers, web editors, and
+/// the wasm bindings. Every key is always present; absent values are `null`:
+///
+/// ```json
+/// {
+///   "code": "PC0005",
+///   "severity": "error",
+///   "section": "base",
+///   "field": "background",
+///   "message": "invalid hex `#12` in [base].background",
+///   "suggestion": "use a `#RRGGBB`/`#RRGGBBAA` hex, `rgb()`/`hsl()`, or a `$name` from [colors]",
+///   "span": null
+/// }
+/// ```
+///
+/// `span` is `{ "start": 40, "end": 41 }` — byte offsets into the parsed
+/// TOML — for syntax errors, and for any located error built with
+/// [`PaletteError::diagnostic_in`]. Keys may be added in later versions, but
+/// never renamed or removed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    /// Stable identifier, e.g. `"PC0005"`.
+    pub code: &'static str,
+    /// Error or warning.
+    pub severity: Severity,
+    /// Section the problem is in (e.g. `"syntax"`, `"gradient.sunset"`).
+    pub section: Option<Arc<str>>,
+    /// Field within [`section`](Self::section), when one applies.
+    pub field: Option<Arc<str>>,
+    /// Human-readable description.
+    pub message: Box<str>,
+    /// Suggested fix, when one can be derived.
+    pub suggestion: Option<Box<str>>,
+    /// Byte range in the source text, for TOML syntax errors.
+    pub span: Option<Range<usize>>,
+}
+
+impl Diagnostic {
+    fn error(code: &'static str, message: impl fmt::Display) -> Self {
+        Self {
+            code,
+            severity: Severity::Error,
+            section: None,
+            field: None,
+            message: message.to_string().into_boxed_str(),
+            suggestion: None,
+            span: None,
+        }
+    }
+
+    fn at(mut self, section: &Arc<str>, field: Option<&Arc<str>>) -> Self {
+        self.section = Some(Arc::clone(section));
+        self.field = field.map(Arc::clone);
+        self
+    }
+
+    fn suggest(mut self, suggestion: impl Into<Option<String>>) -> Self {
+        self.suggestion = suggestion.into().map(String::into_boxed_str);
+        self
+    }
+}
+
+/// Formats as `error[PC0005]: message`, with a `help:` line when there is a
+/// suggestion.
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}[{}]: {}",
+            self.severity.as_str(),
+            self.code,
+            self.message
+        )?;
+        match &self.suggestion {
+            Some(suggestion) => write!(f, "\n  help: {suggestion}"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A position in a theme file: 1-based line and column (in characters),
+/// with the file path when known. Displays as `path:line:column`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Location {
+    /// File the source text came from.
+    pub path: Option<Arc<str>>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+}
+
+impl Location {
+    /// The line and column of byte `offset` in `source`. Offsets past the
+    /// end clamp to the end; offsets inside a character round down to it.
+    pub fn from_offset(path: Option<&str>, source: &str, offset: usize) -> Self {
+        let mut offset = offset.min(source.len());
+        while !source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            path: path.map(Arc::from),
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "{path}:{}:{}", self.line, self.column),
+            None => write!(f, "{}:{}", self.line, self.column),
+        }
+    }
+}
+
+impl PaletteError {
+    /// Stable diagnostic code for this error. See [`Diagnostic`].
+    ///
+    /// [`Inheritance`](Self::Inheritance) wrappers report the code of the
+    /// error they wrap.
+    pub fn code(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "toml")]
+            Self::Parse(_) => "PC0001",
+            Self::Io { .. } => "PC0002",
+            Self::MissingBase => "PC0003",
+            Self::MissingMeta => "PC0004",
+            Self::InvalidHex { .. } => "PC0005",
+            Self::InvalidStyle { .. } => "PC0006",
+            Self::UnknownField { .. } => "PC0007",
+            Self::UnknownPreset(_) => "PC0008",
+            Self::InsufficientStops { .. } => "PC0009",
+            Self::UnsortedStops => "PC0010",
+            Self::InvalidGradientPosition { .. } => "PC0011",
+            Self::MixedGradientStopKinds { .. } => "PC0012",
+            Self::InvalidGradientRef { .. } => "PC0013",
+            Self::InvalidColorSpace { .. } => "PC0014",
+            Self::Import { .. } => "PC0015",
+            Self::GroupConflict { .. } => "PC0016",
+            Self::UnsetSlot { .. } => "PC0017",
+            Self::UnknownColorVariable { .. } => "PC0018",
+            Self::UnknownSection(_) => "PC0019",
+            Self::UnsupportedSchemaVersion(_) => "PC0020",
+            Self::UnresolvedMetaPlaceholder { .. } => "PC0021",
+            Self::Inheritance { source, .. } => source.code(),
+        }
+    }
+
+    /// Byte range of the offending text in `source`, the TOML the failing
+    /// manifest was parsed from: the value for invalid values and unknown
+    /// variables, the key for unknown fields and sections, and the parser's
+    /// own span for syntax errors. `None` for errors with no position, and
+    /// for [`Inheritance`](Self::Inheritance) errors, whose offending text is
+    /// in another file.
+    #[cfg(feature = "toml")]
+    pub fn span_in(&self, source: &str) -> Option<Range<usize>> {
+        use crate::manifest::find_span;
+
+        match self {
+            Self::Parse(err) => err.span(),
+            Self::InvalidHex { section, field, .. }
+            | Self::InvalidStyle { section, field, .. }
+            | Self::UnknownColorVariable { section, field, .. } => {
+                find_span(source, section, Some(field), false)
+            }
+            Self::UnknownField { section, field } => find_span(source, section, Some(field), true),
+            Self::UnknownSection(section) => find_span(source, section, None, true),
+            Self::UnsupportedSchemaVersion(_) => {
+                find_span(source, "meta", Some("schema_version"), false)
+            }
+            Self::UnresolvedMetaPlaceholder { field, .. } => {
+                find_span(source, "meta", Some(field), false)
+            }
+            Self::InvalidColorSpace { gradient, .. } => {
+                find_span(source, &gradient_section(gradient), Some("space"), false)
+            }
+            Self::InvalidGradientRef { gradient, .. }
+            | Self::MixedGradientStopKinds { gradient } => {
+                find_span(source, &gradient_section(gradient), Some("stops"), false)
+            }
+            _ => None,
+        }
+    }
+
+    /// Where [`span_in`](Self::span_in) points, as a line and column in
+    /// the file at `path`.
+    #[cfg(feature = "toml")]
+    pub fn location_in(&self, path: Option<&str>, source: &str) -> Option<Location> {
+        self.span_in(source)
+            .map(|span| Location::from_offset(path, source, span.start))
+    }
+
+    /// [`diagnostic`](Self::diagnostic) with its `span` located in `source`.
+    #[cfg(feature = "toml")]
+    pub fn diagnostic_in(&self, source: &str) -> Diagnostic {
+        Diagnostic {
+            span: self.span_in(source),
+            ..self.diagnostic()
+        }
+    }
+
+    /// Structured form of this error, with context and a suggested fix where
+    /// one can be derived.
+    pub fn diagnostic(&self) -> Diagnostic {
+        let diagnostic = Diagnostic::error(self.code(), self);
+        match self {
+            Self::MissingBase => diagnostic.suggest(
+                "add a [base] section with at least `background` and `foreground`".to_owned(),
+            ),
+            Self::MissingMeta => diagnostic
+                .suggest("add a [meta] section with `name`, `preset_id`, and `style`".to_owned()),
+            Self::InvalidHex { section, field, .. } => diagnostic.at(section, Some(field)).suggest(
+                "use a `#RRGGBB`/`#RRGGBBAA` hex, `rgb()`/`hsl()`, or a `$name` from [colors]"
+                    .to_owned(),
+            ),
+            Self::InvalidStyle { section, field, .. } => diagnostic
+                .at(section, Some(field))
+                .suggest("use a comma-separated list of `bold`, `italic`, `underline`".to_owned()),
+            Self::UnknownField { section, field } => diagnostic
+                .at(section, Some(field))
+                .suggest(did_you_mean(field, section_fields(section))),
+            Self::UnknownPreset(id) => {
+                diagnostic.suggest(did_you_mean(id, preset_ids().iter().copied()))
+            }
+            Self::InsufficientStops { .. } => {
+                diagnostic.suggest("give the gradient at least two stops".to_owned())
+            }
+            Self::UnsortedStops => {
+                diagnostic.suggest("list stops in ascending `at` order".to_owned())
+            }
+            Self::InvalidGradientPosition { .. } => {
+                diagnostic.suggest("use an `at` value between 0.0 and 1.0".to_owned())
+            }
+            Self::MixedGradientStopKinds { gradient } => {
+                diagnostic.at(&gradient_section(gradient), None).suggest(
+                    "write every stop as a bare color or every stop as `{ color, at }`".to_owned(),
+                )
+            }
+            Self::InvalidGradientRef {
+                gradient,
+                reference,
+                ..
+            } => {
+                let slots: Vec<String> = SlotId::all().map(|slot| slot.to_string()).collect();
+                diagnostic
+                    .at(&gradient_section(gradient), None)
+                    .suggest(did_you_mean(reference, slots.iter().map(String::as_str)))
+            }
+            Self::InvalidColorSpace { gradient, .. } => diagnostic
+                .at(&gradient_section(gradient), Some(&Arc::from("space")))
+                .suggest("use `oklab`, `oklch`, `srgb`, or `hsl`".to_owned()),
+            #[cfg(feature = "toml")]
+            Self::Parse(err) => Diagnostic {
+                span: err.span(),
+                ..diagnostic
+            },
+            Self::GroupConflict { section } => diagnostic
+                .at(section, None)
+                .suggest("give the group a section name no other group uses".to_owned()),
+            Self::UnknownColorVariable {
+                section,
+                field,
+                name,
+            } => diagnostic
+                .at(section, Some(field))
+                .suggest(format!("define `{name}` in [colors]")),
+            Self::UnknownSection(section) => diagnostic.at(section, None).suggest(did_you_mean(
+                section,
+                crate::manifest::SECTION_NAMES.iter().copied(),
+            )),
+            Self::UnsupportedSchemaVersion(_) => diagnostic
+                .at(&Arc::from("meta"), Some(&Arc::from("schema_version")))
+                .suggest(format!(
+                    "this build reads schema versions up to \"{}\"; upgrade palette-core",
+                    crate::schema::CURRENT_VERSION
+                )),
+            Self::UnresolvedMetaPlaceholder { field, placeholder } => diagnostic
+                .at(&Arc::from("meta"), Some(field))
+                .suggest(placeholder_suggestion(field, placeholder)),
+            Self::UnsetSlot { section, field } => diagnostic
+                .at(section, Some(field))
+                .suggest(format!("set `{field}` in [{section}]")),
+            Self::Io { .. } | Self::Import { .. } => diagnostic,
+            Self::Inheritance { source, .. } => Diagnostic {
+                message: diagnostic.message,
+                ..source.diagnostic()
+            },
+        }
+    }
+}
+
+/// Serializes as the error's [`Diagnostic`], so every error has the same
+/// stable JSON shape.
+impl Serialize for PaletteError {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.diagnostic().serialize(serializer)
+    }
+}
+
+/// Unknown manifest keys are lint warnings (`PC0100`), not load errors.
+impl From<&UnknownField> for Diagnostic {
+    fn from(unknown: &UnknownField) -> Self {
+        Self {
+            code: "PC0100",
+            severity: Severity::Warning,
+            section: Some(Arc::from(unknown.section.as_ref())),
+            field: Some(Arc::from(unknown.field.as_ref())),
+            message: format!("unknown field {unknown}").into_boxed_str(),
+            suggestion: did_you_mean(&unknown.field, section_fields(&unknown.section))
+                .map(String::into_boxed_str),
+            span: None,
+        }
+    }
+}
+
+/// Contrast failures (`PC0101`) are located at the foreground slot and keep
+/// the violation's [`severity`](ContrastViolation::severity).
+impl From<&ContrastViolation> for Diagnostic {
+    fn from(v: &ContrastViolation) -> Self {
+        let (section, field) = v
+            .foreground_label
+            .split_once('.')
+            .unwrap_or((&v.foreground_label, ""));
+        Self {
+            code: "PC0101",
+            severity: v.severity,
+            section: Some(Arc::from(section)),
+            field: Some(Arc::from(field)),
+            message: format!(
+                "{} on {} has contrast {:.2}:1, below the required {:.1}:1",
+                v.foreground_label,
+                v.background_label,
+                v.ratio,
+                v.level.threshold()
+            )
+            .into_boxed_str(),
+            suggestion: Some(
+                format!(
+                    "try {} (nearest color that passes)",
+                    crate::contrast::nudge_foreground(v.foreground, v.background, v.level)
+                )
+                .into_boxed_str(),
+            ),
+            span: None,
+        }
+    }
+}
+
+/// Semantic colors that collapse under simulated color blindness are
+/// warnings (`PC0105`) located at the first slot of the pair.
+impl From<&CvdViolation> for Diagnostic {
+    fn from(v: &CvdViolation) -> Self {
+        let (section, field) = v
+            .first_label
+            .split_once('.')
+            .unwrap_or((&v.first_label, ""));
+        Self {
+            code: "PC0105",
+            severity: Severity::Warning,
+            section: Some(Arc::from(section)),
+            field: Some(Arc::from(field)),
+            message: format!(
+                "{} and {} differ by only {:.1} ΔE under {:?}, below the required {:.1}",
+                v.first_label, v.second_label, v.delta_e, v.deficiency, v.min_delta_e
+            )
+            .into_boxed_str(),
+            suggestion: Some(
+                "vary lightness as well as hue, or use generate::repair_status_colors"
+                    .to_owned()
+                    .into_boxed_str(),
+            ),
+            span: None,
+        }
+    }
+}
+
+/// Slots a complete theme should define. Everything else falls back or is
+/// derived, but most targets look wrong without these.
+const RECOMMENDED_BASE: &[&str] = &["background", "foreground"];
+const RECOMMENDED_SEMANTIC: &[&str] = &["success", "warning", "error", "info"];
+
+/// `PC0102`: a top-level table that is not a theme section.
+#[cfg(feature = "toml")]
+pub(crate) fn unknown_section(name: &str) -> Diagnostic {
+    Diagnostic {
+        code: "PC0102",
+        severity: Severity::Warning,
+        section: Some(Arc::from(name)),
+        field: None,
+        message: format!("unknown section [{name}]").into_boxed_str(),
+        suggestion: did_you_mean(name, crate::manifest::SECTION_NAMES.iter().copied())
+            .map(String::into_boxed_str),
+        span: None,
+    }
+}
+
+/// Unknown (`PC0100`) and deprecated (`PC0103`) keys in a manifest's own
+/// sections.
+pub(crate) fn field_warnings(manifest: &PaletteManifest) -> impl Iterator<Item = Diagnostic> {
+    validate_fields(manifest).into_iter().map(|unknown| {
+        // Mode overlays report `when.<mode>.<section>`; aliases are keyed by
+        // the section alone.
+        let section = unknown
+            .section
+            .rsplit_once('.')
+            .map_or(unknown.section.as_ref(), |(_, section)| section);
+        let renamed = FIELD_ALIASES
+            .iter()
+            .find(|&&(s, alias, _)| s == section && alias == unknown.field.as_ref());
+        match renamed {
+            Some(&(_, _, new)) => Diagnostic {
+                code: "PC0103",
+                severity: Severity::Warning,
+                section: Some(Arc::from(unknown.section.as_ref())),
+                field: Some(Arc::from(unknown.field.as_ref())),
+                message: format!("field {unknown} is deprecated").into_boxed_str(),
+                suggestion: Some(format!("rename it to `{new}`").into_boxed_str()),
+                span: None,
+            },
+            None => Diagnostic::from(&unknown),
+        }
+    })
+}
+
+/// `PC0104`: recommended slots the manifest leaves unset. Check the merged
+/// manifest for variants — a parent usually supplies these.
+pub(crate) fn missing_slot_warnings(
+    manifest: &PaletteManifest,
+) -> impl Iterator<Item = Diagnostic> + '_ {
+    let base = RECOMMENDED_BASE
+        .iter()
+        .map(|&field| ("base", &manifest.base, field));
+    let semantic = RECOMMENDED_SEMANTIC
+        .iter()
+        .map(|&field| ("semantic", &manifest.semantic, field));
+    base.chain(semantic)
+        .filter(|(_, slots, field)| !slots.contains_key(*field))
+        .map(|(section, _, field)| Diagnostic {
+            code: "PC0104",
+            severity: Severity::Warning,
+            section: Some(Arc::from(section)),
+            field: Some(Arc::from(field)),
+            message: format!("recommended slot {section}.{field} is not set").into_boxed_str(),
+            suggestion: None,
+            span: None,
+        })
+}
+
+fn gradient_section(gradient: &str) -> Arc<str> {
+    Arc::from(format!("gradient.{gradient}"))
+}
+
+fn section_fields(section: &str) -> impl Iterator<Item = &'static str> {
+    let section = match section {
+        "syntax_style" => "syntax",
+        other => other,
+    };
+    known_fields::fields_for_section(section)
+        .or_else(|| crate::group::lookup(section).map(|group| group.fields))
+        .unwrap_or_default()
+        .iter()
+        .copied()
+}
+
+/// `did you mean …?` for the closest candidate within a small edit distance.
+fn did_you_mean<'a>(input: &str, candidates: impl Iterator<Item = &'a str>) -> Option<String> {
+    let limit = (input.chars().count() / 3).max(2);
+    candidates
+        .map(|candidate| (edit_distance(input, candidate), candidate))
+        .filter(|&(distance, _)| distance <= limit)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| format!("did you mean `{candidate}`?"))
+}
+
+/// How to fix a `{placeholder}` in `[meta].field` that did not expand.
+fn placeholder_suggestion(field: &str, placeholder: &str) -> Option<String> {
+    let known = crate::manifest::META_FIELDS.contains(&placeholder);
+    match (placeholder == field, known) {
+        (true, _) => Some(format!("a field cannot contain its own `{{{field}}}`")),
+        (false, true) => Some(format!(
+            "set `{placeholder}` in [meta] without a placeholder that leads back to `{field}`"
+        )),
+        (false, false) => did_you_mean(placeholder, crate::manifest::META_FIELDS.iter().copied())
+            .or_else(|| Some("write `{{` for a literal brace".to_owned())),
+    }
+}
+
+/// Levenshtein distance over chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// [`miette`] integration, so reporters render codes, help, and — for TOML
+/// syntax errors — a labelled span of the offending source.
+///
+/// The span indexes into the text that was parsed; attach it with
+/// `miette::Report::with_source_code`. Other errors do not keep their source
+/// text; [`PaletteError::span_in`] finds their span given it.
+#[cfg(feature = "fancy-errors")]
+impl miette::Diagnostic for PaletteError {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new(PaletteError::code(self)))
+    }
+
+    fn severity(&self) -> Option<miette::Severity> {
+        Some(miette::Severity::Error)
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.diagnostic()
+            .suggestion
+            .map(|suggestion| Box::new(suggestion) as Box<dyn fmt::Display>)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        match self {
+            #[cfg(feature = "toml")]
+            Self::Parse(err) => {
+                let label =
+                    miette::LabeledSpan::new_with_span(Some(err.message().to_owned()), err.span()?);
+                Some(Box::new(std::iter::once(label)))
+            }
+            _ => None,
+        }
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn miette::Diagnostic> {
+        match self {
+            Self::Inheritance { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
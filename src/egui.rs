@@ -3,9 +3,9 @@
 use crate::color::Color;
 use crate::palette::Palette;
 
-/// Convert a [`Color`] to an egui [`Color32`](::egui::Color32).
+/// Convert a [`Color`] to an egui [`Color32`](::egui::Color32), keeping alpha.
 pub fn to_color32(color: &Color) -> ::egui::Color32 {
-    ::egui::Color32::from_rgb(color.r, color.g, color.b)
+    ::egui::Color32::from_rgba_unmultiplied(color.r, color.g, color.b, color.a)
 }
 
 macro_rules! apply_color {
@@ -4,6 +4,7 @@ use std::sync::Arc;
 #[derive(Debug, thiserror::Error)]
 pub enum PaletteError {
     /// TOML deserialization failed.
+    #[cfg(feature = "toml")]
     #[error("failed to parse manifest: {0}")]
     Parse(#[from] toml::de::Error),
 
@@ -105,4 +106,97 @@ pub enum PaletteError {
         /// The unrecognized color space string.
         value: Arc<str>,
     },
+
+    /// A third-party theme file could not be converted into a manifest.
+    #[error("failed to import {format} theme: {message}")]
+    Import {
+        /// Source format name (e.g. `"vscode"`).
+        format: Arc<str>,
+        /// Description of what went wrong.
+        message: Arc<str>,
+    },
+
+    /// A custom color group's section name is already taken by a built-in
+    /// section or by a group registered with different fields.
+    #[error("color group [{section}] conflicts with an existing section")]
+    GroupConflict {
+        /// The contested section name.
+        section: Arc<str>,
+    },
+
+    /// A top-level table is not a theme section. Only
+    /// [strict parsing](crate::manifest::PaletteManifest::from_toml_strict)
+    /// reports this; normal parsing ignores such tables.
+    #[error("unknown section [{0}]")]
+    UnknownSection(Arc<str>),
+
+    /// `schema_version` in `[meta]` is newer than this build reads, not a
+    /// whole number, or an older version with no
+    /// [migration](crate::schema::MIGRATIONS).
+    #[error("unsupported schema_version \"{0}\"")]
+    UnsupportedSchemaVersion(Arc<str>),
+
+    /// A `{field}` placeholder in a `[meta]` value names an unknown or unset
+    /// field, or leads back to the field it is in.
+    #[error("cannot expand `{{{placeholder}}}` in [meta].{field}")]
+    UnresolvedMetaPlaceholder {
+        /// The `[meta]` field containing the placeholder.
+        field: Arc<str>,
+        /// The name between the braces.
+        placeholder: Arc<str>,
+    },
+
+    /// A `"$name"` slot value names no entry in `[colors]`.
+    #[error("unknown color variable `${name}` in [{section}].{field}")]
+    UnknownColorVariable {
+        /// TOML section containing the reference.
+        section: Arc<str>,
+        /// Field name within the section.
+        field: Arc<str>,
+        /// The variable name, without the `$`.
+        name: Arc<str>,
+    },
+
+    /// A slot a lookup needs is not set in the palette.
+    #[error("slot [{section}].{field} is not set")]
+    UnsetSlot {
+        /// Section of the empty slot.
+        section: Arc<str>,
+        /// Field name within the section.
+        field: Arc<str>,
+    },
+
+    /// Resolving a variant's parent failed, or the parent supplied an
+    /// invalid value the variant inherits.
+    #[error("while resolving {}: {source}", format_chain(.chain))]
+    Inheritance {
+        /// Themes from the one requested to the parent where resolution
+        /// failed.
+        chain: Box<[ChainLink]>,
+        /// The error raised at the last link.
+        source: Box<PaletteError>,
+    },
+}
+
+/// One theme in an inheritance chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainLink {
+    /// Preset ID of the theme.
+    pub id: Arc<str>,
+    /// File the theme was read from, when it came from disk.
+    pub path: Option<Arc<str>>,
+}
+
+impl std::fmt::Display for ChainLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "{} ({path})", self.id),
+            None => f.write_str(&self.id),
+        }
+    }
+}
+
+fn format_chain(chain: &[ChainLink]) -> String {
+    let links: Vec<String> = chain.iter().map(ChainLink::to_string).collect();
+    links.join(" → ")
 }
@@ -0,0 +1,122 @@
+//! Figma Tokens Studio JSON export.
+//!
+//! Emits a single-file Tokens Studio document with one token set per palette,
+//! keyed by preset ID. Tokens are nested `section → field` with
+//! `{ "value": "#RRGGBB", "type": "color" }` leaves. A `$themes` entry per set
+//! lets designers switch between palettes as Figma variable modes.
+//!
+//! ```
+//! use palette_core::load_preset;
+//! use palette_core::export::figma::to_tokens_studio;
+//!
+//! let dark = load_preset("tokyonight").unwrap().resolve();
+//! let light = load_preset("tokyonight_day").unwrap().resolve();
+//! let json = to_tokens_studio(&[&dark, &light]);
+//! assert!(json.contains(r#""tokyonight_day": {"#));
+//! ```
+
+use std::fmt::Write;
+
+use super::{ExportFile, Exporter, resolved_slots, write_json_string};
+use crate::palette::Palette;
+use crate::resolved::ResolvedPalette;
+
+/// Default output file name.
+pub const FILE_NAME: &str = "tokens.json";
+
+/// [`Exporter`] for a single-set Tokens Studio document.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokensStudio;
+
+impl Exporter for TokensStudio {
+    fn target(&self) -> &'static str {
+        "figma"
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "export", level = "debug", skip_all,
+        fields(target = "figma", id = palette.preset_id()),
+    ))]
+    fn export(&self, palette: &Palette) -> Box<[ExportFile]> {
+        Box::new([ExportFile {
+            path: Box::from(FILE_NAME),
+            contents: to_tokens_studio(&[&palette.resolve()]),
+        }])
+    }
+}
+
+/// Token set name: the preset ID, or `palette-<index>` without metadata.
+fn set_name(palette: &ResolvedPalette, index: usize) -> String {
+    match &palette.meta {
+        Some(meta) => String::from(meta.preset_id.as_ref()),
+        None => format!("palette-{index}"),
+    }
+}
+
+fn write_set(out: &mut String, name: &str, palette: &ResolvedPalette) {
+    out.push_str("  ");
+    write_json_string(out, name);
+    out.push_str(": {\n");
+
+    let mut current: Option<&str> = None;
+    for (section, field, color) in resolved_slots(palette) {
+        match current {
+            Some(s) if s == section => out.push_str(",\n"),
+            Some(_) => {
+                let _ = write!(out, "\n    }},\n    \"{section}\": {{\n");
+            }
+            None => {
+                let _ = writeln!(out, "    \"{section}\": {{");
+            }
+        }
+        current = Some(section);
+        let _ = write!(
+            out,
+            "      \"{field}\": {{ \"value\": \"{}\", \"type\": \"color\" }}",
+            color.to_hex()
+        );
+    }
+    out.push_str("\n    }\n  }");
+}
+
+/// Render a Tokens Studio document with one token set per palette.
+///
+/// Sets appear in input order under `$metadata.tokenSetOrder`, and each set
+/// gets a matching `$themes` entry that enables only that set.
+pub fn to_tokens_studio(palettes: &[&ResolvedPalette]) -> String {
+    let names: Vec<String> = palettes
+        .iter()
+        .enumerate()
+        .map(|(i, p)| set_name(p, i))
+        .collect();
+
+    let mut out = String::with_capacity(palettes.len() * 12 * 1024);
+    out.push_str("{\n");
+    for (palette, name) in palettes.iter().zip(&names) {
+        write_set(&mut out, name, palette);
+        out.push_str(",\n");
+    }
+
+    out.push_str("  \"$themes\": [");
+    for (i, name) in names.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        out.push_str("\n    { \"id\": ");
+        write_json_string(&mut out, name);
+        out.push_str(", \"name\": ");
+        write_json_string(&mut out, name);
+        out.push_str(", \"selectedTokenSets\": { ");
+        write_json_string(&mut out, name);
+        out.push_str(": \"enabled\" } }");
+    }
+    out.push_str("\n  ],\n  \"$metadata\": { \"tokenSetOrder\": [");
+    for (i, name) in names.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        write_json_string(&mut out, name);
+    }
+    out.push_str("] }\n}\n");
+    out
+}
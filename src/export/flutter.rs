@@ -0,0 +1,138 @@
+//! Flutter/Dart source export.
+//!
+//! Emits a Dart file with a `PaletteColors` constants class (one `Color` per
+//! slot), a Material 3 `ColorScheme` built from the semantic and base groups,
+//! and a `ThemeData` getter wired to that scheme.
+//!
+//! | Material role | Palette slot |
+//! |---------------|--------------|
+//! | `primary` | `semantic.info` |
+//! | `secondary` | `semantic.success` |
+//! | `tertiary` | `semantic.warning` |
+//! | `error` | `semantic.error` |
+//! | `surface` | `base.background` |
+//! | `onSurface` | `base.foreground` |
+//! | `onSurfaceVariant` | `base.foreground_dark` |
+//! | `surfaceContainer` | `surface.float` |
+//! | `surfaceContainerHighest` | `base.background_highlight` |
+//! | `outline` | `base.border_highlight` |
+//! | `outlineVariant` | `base.border` |
+//!
+//! `on*` roles for accent colors pick whichever of `base.background` or
+//! `base.foreground` has the higher contrast against the accent.
+
+use std::fmt::Write;
+
+use super::{ExportFile, Exporter, camel_case, comment_text, resolved_slots};
+use crate::color::Color;
+use crate::palette::Palette;
+use crate::resolved::ResolvedPalette;
+
+/// Default output file name.
+pub const FILE_NAME: &str = "palette_colors.dart";
+
+/// [`Exporter`] for a Dart `ColorScheme` and color constants.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Flutter;
+
+impl Exporter for Flutter {
+    fn target(&self) -> &'static str {
+        "flutter"
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "export", level = "debug", skip_all,
+        fields(target = "flutter", id = palette.preset_id()),
+    ))]
+    fn export(&self, palette: &Palette) -> Box<[ExportFile]> {
+        Box::new([ExportFile {
+            path: Box::from(FILE_NAME),
+            contents: to_dart(&palette.resolve()),
+        }])
+    }
+}
+
+/// A Dart `Color(0xAARRGGBB)` literal.
+fn dart_color(color: &Color) -> String {
+    format!(
+        "Color(0x{:02X}{:02X}{:02X}{:02X})",
+        color.a, color.r, color.g, color.b
+    )
+}
+
+/// Whichever base text color reads best on `accent`.
+fn on_color(palette: &ResolvedPalette, accent: &Color) -> Color {
+    let bg = palette.base.background;
+    let fg = palette.base.foreground;
+    match accent.contrast_ratio(&bg) >= accent.contrast_ratio(&fg) {
+        true => bg,
+        false => fg,
+    }
+}
+
+/// Render the Dart source for a palette.
+pub fn to_dart(palette: &ResolvedPalette) -> String {
+    let mut out = String::with_capacity(16 * 1024);
+    let name = palette.meta.as_ref().map_or("palette", |m| m.name.as_ref());
+    let _ = writeln!(
+        out,
+        "// Generated by palette-core from {}. Do not edit.",
+        comment_text(name)
+    );
+    let _ = writeln!(out);
+    let _ = writeln!(out, "import 'package:flutter/material.dart';");
+    let _ = writeln!(out);
+
+    let _ = writeln!(out, "abstract final class PaletteColors {{");
+    for (section, field, color) in resolved_slots(palette) {
+        let _ = writeln!(
+            out,
+            "  static const Color {} = {};",
+            camel_case(section, field),
+            dart_color(&color)
+        );
+    }
+    let _ = writeln!(out, "}}");
+    let _ = writeln!(out);
+
+    let brightness = match palette.is_light() {
+        true => "light",
+        false => "dark",
+    };
+    let s = &palette.semantic;
+    let b = &palette.base;
+    let roles: [(&str, Color); 19] = [
+        ("primary", s.info),
+        ("onPrimary", on_color(palette, &s.info)),
+        ("secondary", s.success),
+        ("onSecondary", on_color(palette, &s.success)),
+        ("tertiary", s.warning),
+        ("onTertiary", on_color(palette, &s.warning)),
+        ("error", s.error),
+        ("onError", on_color(palette, &s.error)),
+        ("surface", b.background),
+        ("onSurface", b.foreground),
+        ("onSurfaceVariant", b.foreground_dark),
+        ("surfaceContainerLowest", b.background_dark),
+        ("surfaceContainer", palette.surface.float),
+        ("surfaceContainerHighest", b.background_highlight),
+        ("outline", b.border_highlight),
+        ("outlineVariant", b.border),
+        ("inverseSurface", b.foreground),
+        ("onInverseSurface", b.background),
+        ("surfaceTint", s.info),
+    ];
+
+    let _ = writeln!(out, "const ColorScheme paletteColorScheme = ColorScheme(");
+    let _ = writeln!(out, "  brightness: Brightness.{brightness},");
+    for (role, color) in &roles {
+        let _ = writeln!(out, "  {role}: {},", dart_color(color));
+    }
+    let _ = writeln!(out, ");");
+    let _ = writeln!(out);
+    let _ = writeln!(
+        out,
+        "ThemeData get paletteThemeData => ThemeData(colorScheme: paletteColorScheme);"
+    );
+    out
+}
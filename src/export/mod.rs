@@ -0,0 +1,144 @@
+//! File-based export targets for editors, platforms, and design tools.
+//!
+//! Each submodule exposes free functions over a
+//! [`ResolvedPalette`](crate::ResolvedPalette) plus a unit struct implementing
+//! [`Exporter`](crate::export::Exporter), so callers can either target one
+//! format directly or pick a format by name at runtime via
+//! [`find_exporter`](crate::export::find_exporter).
+//!
+//! ```
+//! use palette_core::load_preset;
+//! use palette_core::export::find_exporter;
+//!
+//! let palette = load_preset("tokyonight").unwrap();
+//! let exporter = find_exporter("xcassets").unwrap();
+//! for file in exporter.export(&palette).iter() {
+//!     println!("{}: {} bytes", file.path, file.contents.len());
+//! }
+//! ```
+
+use crate::color::Color;
+use crate::palette::Palette;
+use crate::resolved::ResolvedPalette;
+
+/// Figma Tokens Studio JSON token sets.
+pub mod figma;
+/// Flutter `ColorScheme` and color constants in Dart.
+pub mod flutter;
+/// qutebrowser `config.py` color assignments.
+pub mod qutebrowser;
+/// SwiftUI `Color` extension source.
+pub mod swiftui;
+/// Termux `colors.properties` terminal colors.
+pub mod termux;
+/// iOS/macOS asset catalog color sets.
+pub mod xcassets;
+/// Xcode `.xccolortheme` source editor themes.
+pub mod xcode;
+/// Zathura `zathurarc` color settings.
+pub mod zathura;
+
+/// A single generated file, relative to the export root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportFile {
+    /// Relative path using `/` separators (e.g. `"Palette.xcassets/Contents.json"`).
+    pub path: Box<str>,
+    /// Complete file contents.
+    pub contents: String,
+}
+
+/// A named export target that renders a palette into one or more files.
+pub trait Exporter {
+    /// Stable identifier used for lookup (e.g. `"xcassets"`).
+    fn target(&self) -> &'static str;
+
+    /// Render `palette` into output files. Empty slots are resolved against
+    /// [`Palette::default`] first, so every exported slot has a value.
+    fn export(&self, palette: &Palette) -> Box<[ExportFile]>;
+}
+
+/// All built-in exporters, in declaration order.
+pub fn exporters() -> &'static [&'static dyn Exporter] {
+    &[
+        &xcassets::Xcassets,
+        &swiftui::SwiftUi,
+        &flutter::Flutter,
+        &figma::TokensStudio,
+        &zathura::Zathura,
+        &qutebrowser::Qutebrowser,
+        &termux::Termux,
+        &xcode::Xcode,
+    ]
+}
+
+/// Look up a built-in exporter by its [`target`](Exporter::target) name.
+pub fn find_exporter(target: &str) -> Option<&'static dyn Exporter> {
+    exporters().iter().copied().find(|e| e.target() == target)
+}
+
+/// Iterate every resolved slot as `(section, field, color)` in group order.
+pub(crate) fn resolved_slots(
+    resolved: &ResolvedPalette,
+) -> impl Iterator<Item = (&'static str, &'static str, Color)> + '_ {
+    fn tag<'a>(
+        section: &'static str,
+        slots: impl Iterator<Item = (&'static str, &'a Color)>,
+    ) -> impl Iterator<Item = (&'static str, &'static str, Color)> {
+        slots.map(move |(field, color)| (section, field, *color))
+    }
+
+    tag("base", resolved.base.all_slots())
+        .chain(tag("semantic", resolved.semantic.all_slots()))
+        .chain(tag("diff", resolved.diff.all_slots()))
+        .chain(tag("surface", resolved.surface.all_slots()))
+        .chain(tag("typography", resolved.typography.all_slots()))
+        .chain(tag("syntax", resolved.syntax.all_slots()))
+        .chain(tag("editor", resolved.editor.all_slots()))
+        .chain(tag("terminal", resolved.terminal.all_slots()))
+}
+
+/// Join a section and snake_case field into a lowerCamelCase identifier
+/// (`"base"`, `"background_dark"` → `"baseBackgroundDark"`).
+pub(crate) fn camel_case(section: &str, field: &str) -> String {
+    let mut out = String::with_capacity(section.len() + field.len());
+    out.push_str(section);
+    for part in field.split('_') {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            out.push(first.to_ascii_uppercase());
+            out.push_str(chars.as_str());
+        }
+    }
+    out
+}
+
+/// `name` for a one-line `#` or `//` comment: control characters and
+/// Unicode line separators become spaces, so a theme name cannot end the
+/// comment and start a line of code.
+pub(crate) fn comment_text(name: &str) -> String {
+    name.replace(
+        |c: char| c.is_control() || matches!(c, '\u{2028}' | '\u{2029}'),
+        " ",
+    )
+}
+
+/// Append `s` as a quoted JSON string, escaping quotes, backslashes, and
+/// control characters.
+pub(crate) fn write_json_string(out: &mut String, s: &str) {
+    use std::fmt::Write;
+
+    out.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if u32::from(c) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", u32::from(c));
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
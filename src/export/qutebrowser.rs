@@ -0,0 +1,118 @@
+//! qutebrowser `config.py` color export.
+//!
+//! Emits `c.colors.*` assignments for the status bar, tabs, completion menu,
+//! hints, and messages. Save the output next to `config.py` and load it with
+//! `config.source("palette.py")`.
+
+use std::fmt::Write;
+
+use super::{ExportFile, Exporter, comment_text};
+use crate::color::Color;
+use crate::palette::Palette;
+use crate::resolved::ResolvedPalette;
+
+/// Default output file name.
+pub const FILE_NAME: &str = "palette.py";
+
+/// [`Exporter`] for qutebrowser color settings.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Qutebrowser;
+
+impl Exporter for Qutebrowser {
+    fn target(&self) -> &'static str {
+        "qutebrowser"
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "export", level = "debug", skip_all,
+        fields(target = "qutebrowser", id = palette.preset_id()),
+    ))]
+    fn export(&self, palette: &Palette) -> Box<[ExportFile]> {
+        Box::new([ExportFile {
+            path: Box::from(FILE_NAME),
+            contents: to_qutebrowser(&palette.resolve()),
+        }])
+    }
+}
+
+/// Render `c.colors.*` assignments for a palette.
+pub fn to_qutebrowser(palette: &ResolvedPalette) -> String {
+    let base = &palette.base;
+    let semantic = &palette.semantic;
+    let surface = &palette.surface;
+    let typography = &palette.typography;
+
+    let options: [(&str, Color); 50] = [
+        // Status bar
+        ("statusbar.normal.bg", surface.statusline),
+        ("statusbar.normal.fg", base.foreground),
+        ("statusbar.insert.bg", semantic.success),
+        ("statusbar.insert.fg", base.background),
+        ("statusbar.command.bg", surface.statusline),
+        ("statusbar.command.fg", base.foreground),
+        ("statusbar.passthrough.bg", semantic.info),
+        ("statusbar.passthrough.fg", base.background),
+        ("statusbar.private.bg", base.background_dark),
+        ("statusbar.private.fg", base.foreground_dark),
+        ("statusbar.caret.bg", semantic.hint),
+        ("statusbar.caret.fg", base.background),
+        ("statusbar.url.fg", base.foreground),
+        ("statusbar.url.hover.fg", typography.link),
+        ("statusbar.url.success.http.fg", semantic.warning),
+        ("statusbar.url.success.https.fg", semantic.success),
+        ("statusbar.url.warn.fg", semantic.warning),
+        ("statusbar.url.error.fg", semantic.error),
+        // Tabs
+        ("tabs.bar.bg", base.background_dark),
+        ("tabs.even.bg", base.background_dark),
+        ("tabs.even.fg", base.foreground_dark),
+        ("tabs.odd.bg", base.background_dark),
+        ("tabs.odd.fg", base.foreground_dark),
+        ("tabs.selected.even.bg", base.background),
+        ("tabs.selected.even.fg", base.foreground),
+        ("tabs.selected.odd.bg", base.background),
+        ("tabs.selected.odd.fg", base.foreground),
+        ("tabs.indicator.start", semantic.info),
+        ("tabs.indicator.stop", semantic.success),
+        ("tabs.indicator.error", semantic.error),
+        // Completion
+        ("completion.fg", base.foreground),
+        ("completion.even.bg", surface.menu),
+        ("completion.odd.bg", surface.menu),
+        ("completion.category.bg", surface.popup),
+        ("completion.category.fg", typography.title),
+        ("completion.item.selected.bg", surface.selection),
+        ("completion.item.selected.fg", typography.selection_text),
+        ("completion.item.selected.match.fg", surface.search),
+        ("completion.match.fg", surface.search),
+        ("completion.scrollbar.bg", surface.menu),
+        ("completion.scrollbar.fg", base.border_highlight),
+        // Hints
+        ("hints.bg", surface.search),
+        ("hints.fg", base.background),
+        ("hints.match.fg", semantic.info),
+        // Messages
+        ("messages.error.bg", semantic.error),
+        ("messages.error.fg", base.background),
+        ("messages.warning.bg", semantic.warning),
+        ("messages.warning.fg", base.background),
+        ("messages.info.bg", surface.float),
+        ("messages.info.fg", base.foreground),
+    ];
+
+    let mut out = String::with_capacity(4096);
+    let name = palette.meta.as_ref().map_or("palette", |m| m.name.as_ref());
+    let _ = writeln!(
+        out,
+        "# Generated by palette-core from {}.",
+        comment_text(name)
+    );
+    let _ = writeln!(
+        out,
+        "# Load from config.py with: config.source(\"{FILE_NAME}\")"
+    );
+    for (option, color) in &options {
+        let _ = writeln!(out, "c.colors.{option} = \"{}\"", color.opaque());
+    }
+    out
+}
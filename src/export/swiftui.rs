@@ -0,0 +1,118 @@
+//! SwiftUI `Color` extension source export.
+//!
+//! Emits a Swift file declaring one `static let` per palette slot on
+//! `extension Color`, named in lowerCamelCase (`Color.baseBackground`). When a
+//! dark palette is supplied, each constant becomes a dynamic color that follows
+//! the system appearance on both UIKit and AppKit.
+//!
+//! ```
+//! use palette_core::load_preset;
+//! use palette_core::export::swiftui::to_swiftui;
+//!
+//! let resolved = load_preset("nord").unwrap().resolve();
+//! let swift = to_swiftui(&resolved, None);
+//! assert!(swift.contains("static let baseBackground = Color("));
+//! ```
+
+use std::fmt::Write;
+
+use super::{ExportFile, Exporter, camel_case, comment_text, resolved_slots};
+use crate::color::Color;
+use crate::palette::Palette;
+use crate::resolved::ResolvedPalette;
+
+/// Default output file name.
+pub const FILE_NAME: &str = "Color+Palette.swift";
+
+const DYNAMIC_HELPER: &str = r#"
+private extension Color {
+    static func dynamic(light: Color, dark: Color) -> Color {
+        #if canImport(UIKit)
+        return Color(UIColor { $0.userInterfaceStyle == .dark ? UIColor(dark) : UIColor(light) })
+        #elseif canImport(AppKit)
+        return Color(NSColor(name: nil) { appearance in
+            appearance.bestMatch(from: [.darkAqua, .aqua]) == .darkAqua ? NSColor(dark) : NSColor(light)
+        })
+        #else
+        return light
+        #endif
+    }
+}
+"#;
+
+/// [`Exporter`] for a SwiftUI `Color` extension (single appearance).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SwiftUi;
+
+impl Exporter for SwiftUi {
+    fn target(&self) -> &'static str {
+        "swiftui"
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "export", level = "debug", skip_all,
+        fields(target = "swiftui", id = palette.preset_id()),
+    ))]
+    fn export(&self, palette: &Palette) -> Box<[ExportFile]> {
+        Box::new([ExportFile {
+            path: Box::from(FILE_NAME),
+            contents: to_swiftui(&palette.resolve(), None),
+        }])
+    }
+}
+
+/// A SwiftUI `Color` initializer literal in the sRGB color space.
+fn color_literal(color: &Color) -> String {
+    let channel = |v: u8| f64::from(v) / 255.0;
+    format!(
+        "Color(.sRGB, red: {:.3}, green: {:.3}, blue: {:.3}, opacity: {})",
+        channel(color.r),
+        channel(color.g),
+        channel(color.b),
+        (channel(color.a) * 1000.0).round() / 1000.0,
+    )
+}
+
+/// Render the Swift source for a `Color` extension.
+///
+/// `dark`, when given, turns every constant into a light/dark dynamic color.
+pub fn to_swiftui(light: &ResolvedPalette, dark: Option<&ResolvedPalette>) -> String {
+    let mut out = String::with_capacity(16 * 1024);
+    let name = light.meta.as_ref().map_or("palette", |m| m.name.as_ref());
+    let _ = writeln!(
+        out,
+        "// Generated by palette-core from {}. Do not edit.",
+        comment_text(name)
+    );
+    let _ = writeln!(out);
+    let _ = writeln!(out, "import SwiftUI");
+    if dark.is_some() {
+        let _ = writeln!(out, "#if canImport(UIKit)");
+        let _ = writeln!(out, "import UIKit");
+        let _ = writeln!(out, "#elseif canImport(AppKit)");
+        let _ = writeln!(out, "import AppKit");
+        let _ = writeln!(out, "#endif");
+    }
+    let _ = writeln!(out);
+    let _ = writeln!(out, "public extension Color {{");
+
+    let mut dark_slots = dark.map(resolved_slots);
+    for (section, field, color) in resolved_slots(light) {
+        let ident = camel_case(section, field);
+        let value = match dark_slots.as_mut().and_then(|slots| slots.next()) {
+            Some((_, _, d)) => format!(
+                ".dynamic(light: {}, dark: {})",
+                color_literal(&color),
+                color_literal(&d)
+            ),
+            None => color_literal(&color),
+        };
+        let _ = writeln!(out, "    static let {ident} = {value}");
+    }
+
+    let _ = writeln!(out, "}}");
+    if dark.is_some() {
+        out.push_str(DYNAMIC_HELPER);
+    }
+    out
+}
@@ -0,0 +1,56 @@
+//! Termux `colors.properties` export.
+//!
+//! Emits `key=#RRGGBB` lines for the foreground, background, cursor, and the
+//! 16 ANSI colors (`color0`–`color15`). Save the output as
+//! `~/.termux/colors.properties` and run `termux-reload-settings`.
+
+use std::fmt::Write;
+
+use super::{ExportFile, Exporter, comment_text};
+use crate::palette::Palette;
+use crate::resolved::ResolvedPalette;
+
+/// Default output file name.
+pub const FILE_NAME: &str = "colors.properties";
+
+/// [`Exporter`] for Termux terminal colors.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Termux;
+
+impl Exporter for Termux {
+    fn target(&self) -> &'static str {
+        "termux"
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "export", level = "debug", skip_all,
+        fields(target = "termux", id = palette.preset_id()),
+    ))]
+    fn export(&self, palette: &Palette) -> Box<[ExportFile]> {
+        Box::new([ExportFile {
+            path: Box::from(FILE_NAME),
+            contents: to_termux_properties(&palette.resolve()),
+        }])
+    }
+}
+
+/// Render a Termux `colors.properties` file for a palette.
+///
+/// `color0`–`color15` follow the ANSI slot order: the eight normal colors
+/// followed by their bright variants.
+pub fn to_termux_properties(palette: &ResolvedPalette) -> String {
+    let mut out = String::with_capacity(512);
+    let name = palette.meta.as_ref().map_or("palette", |m| m.name.as_ref());
+    let _ = writeln!(
+        out,
+        "# Generated by palette-core from {}.",
+        comment_text(name)
+    );
+    let _ = writeln!(out, "foreground={}", palette.base.foreground.opaque());
+    let _ = writeln!(out, "background={}", palette.base.background.opaque());
+    let _ = writeln!(out, "cursor={}", palette.editor.cursor.opaque());
+    for (i, (_, color)) in palette.terminal.all_slots().enumerate() {
+        let _ = writeln!(out, "color{i}={}", color.opaque());
+    }
+    out
+}
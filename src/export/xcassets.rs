@@ -0,0 +1,116 @@
+//! Xcode asset catalog (`.xcassets`) color set export.
+//!
+//! Emits one `<name>.colorset/Contents.json` per palette slot, named in
+//! lowerCamelCase (`baseBackground`, `syntaxKeywords`). When a dark palette is
+//! supplied, each color set gains a second entry with a `luminosity: dark`
+//! appearance so UIKit/SwiftUI switch automatically.
+//!
+//! ```
+//! use palette_core::load_preset;
+//! use palette_core::export::xcassets::to_xcassets;
+//!
+//! let light = load_preset("tokyonight_day").unwrap().resolve();
+//! let dark = load_preset("tokyonight").unwrap().resolve();
+//! let files = to_xcassets(&light, Some(&dark));
+//! assert_eq!(&*files[0].path, "Palette.xcassets/Contents.json");
+//! ```
+
+use std::fmt::Write;
+
+use super::{ExportFile, Exporter, camel_case, resolved_slots};
+use crate::color::Color;
+use crate::palette::Palette;
+use crate::resolved::ResolvedPalette;
+
+/// Root folder name of the generated catalog.
+pub const CATALOG_DIR: &str = "Palette.xcassets";
+
+const INFO: &str = r#"  "info" : {
+    "author" : "palette-core",
+    "version" : 1
+  }"#;
+
+/// [`Exporter`] for asset catalog color sets (light appearance only).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Xcassets;
+
+impl Exporter for Xcassets {
+    fn target(&self) -> &'static str {
+        "xcassets"
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "export", level = "debug", skip_all,
+        fields(target = "xcassets", id = palette.preset_id()),
+    ))]
+    fn export(&self, palette: &Palette) -> Box<[ExportFile]> {
+        to_xcassets(&palette.resolve(), None)
+    }
+}
+
+fn write_color_entry(out: &mut String, color: &Color, dark: bool) {
+    let _ = writeln!(out, "    {{");
+    if dark {
+        let _ = writeln!(
+            out,
+            r#"      "appearances" : [ {{ "appearance" : "luminosity", "value" : "dark" }} ],"#
+        );
+    }
+    let _ = writeln!(out, r#"      "color" : {{"#);
+    let _ = writeln!(out, r#"        "color-space" : "srgb","#);
+    let _ = writeln!(out, r#"        "components" : {{"#);
+    let _ = writeln!(out, r#"          "alpha" : "{:.3}","#, color.alpha_f64());
+    let _ = writeln!(out, r#"          "blue" : "0x{:02X}","#, color.b);
+    let _ = writeln!(out, r#"          "green" : "0x{:02X}","#, color.g);
+    let _ = writeln!(out, r#"          "red" : "0x{:02X}""#, color.r);
+    let _ = writeln!(out, "        }}");
+    let _ = writeln!(out, "      }},");
+    let _ = writeln!(out, r#"      "idiom" : "universal""#);
+    let _ = write!(out, "    }}");
+}
+
+/// Render a single color set's `Contents.json`.
+fn colorset_contents(light: &Color, dark: Option<&Color>) -> String {
+    let mut out = String::with_capacity(512);
+    let _ = writeln!(out, "{{");
+    let _ = writeln!(out, r#"  "colors" : ["#);
+    write_color_entry(&mut out, light, false);
+    if let Some(d) = dark {
+        out.push_str(",\n");
+        write_color_entry(&mut out, d, true);
+    }
+    let _ = writeln!(out, "\n  ],");
+    let _ = writeln!(out, "{INFO}");
+    let _ = writeln!(out, "}}");
+    out
+}
+
+/// Build the asset catalog: a root `Contents.json` followed by one color set
+/// per slot, in palette group order.
+///
+/// `dark`, when given, supplies the dark-appearance variant for every slot.
+pub fn to_xcassets(light: &ResolvedPalette, dark: Option<&ResolvedPalette>) -> Box<[ExportFile]> {
+    let mut files = Vec::with_capacity(140);
+    files.push(ExportFile {
+        path: format!("{CATALOG_DIR}/Contents.json").into_boxed_str(),
+        contents: format!("{{\n{INFO}\n}}\n"),
+    });
+
+    let mut dark_slots = dark.map(resolved_slots);
+    for (section, field, color) in resolved_slots(light) {
+        let dark_color = dark_slots
+            .as_mut()
+            .and_then(|slots| slots.next())
+            .map(|(_, _, c)| c);
+        files.push(ExportFile {
+            path: format!(
+                "{CATALOG_DIR}/{}.colorset/Contents.json",
+                camel_case(section, field)
+            )
+            .into_boxed_str(),
+            contents: colorset_contents(&color, dark_color.as_ref()),
+        });
+    }
+
+    files.into_boxed_slice()
+}
@@ -0,0 +1,167 @@
+//! Xcode `.xccolortheme` editor theme export.
+//!
+//! Emits an XML property list with source-editor colors: text syntax colors
+//! from the syntax group and background, selection, cursor, and current-line
+//! colors from the base and editor groups. Copy the file into
+//! `~/Library/Developer/Xcode/UserData/FontAndColorThemes/` and pick it under
+//! Settings → Themes.
+//!
+//! Xcode requires a font per syntax key, so every entry uses
+//! [`DEFAULT_FONT`](crate::export::xcode::DEFAULT_FONT).
+
+use std::fmt::Write;
+
+use super::{ExportFile, Exporter};
+use crate::color::Color;
+use crate::palette::Palette;
+use crate::resolved::ResolvedPalette;
+
+/// File extension Xcode looks for in its themes directory.
+pub const EXTENSION: &str = "xccolortheme";
+
+/// Font assigned to every syntax key (`"<PostScript name> - <size>"`).
+pub const DEFAULT_FONT: &str = "SFMono-Regular - 12.0";
+
+/// [`Exporter`] for an Xcode editor theme.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Xcode;
+
+impl Exporter for Xcode {
+    fn target(&self) -> &'static str {
+        "xcode"
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "export", level = "debug", skip_all,
+        fields(target = "xcode", id = palette.preset_id()),
+    ))]
+    fn export(&self, palette: &Palette) -> Box<[ExportFile]> {
+        let resolved = palette.resolve();
+        Box::new([ExportFile {
+            path: file_name(&resolved).into_boxed_str(),
+            contents: to_xccolortheme(&resolved),
+        }])
+    }
+}
+
+/// `<theme name>.xccolortheme`, with path separators replaced.
+///
+/// Xcode lists themes by file name, so this is what users see in Settings.
+pub fn file_name(palette: &ResolvedPalette) -> String {
+    let name = palette.meta.as_ref().map_or("Palette", |m| m.name.as_ref());
+    format!("{}.{EXTENSION}", name.replace(['/', '\\'], "-"))
+}
+
+/// Xcode's `"r g b a"` float color string.
+fn xcode_color(color: &Color) -> String {
+    let channel = |v: u8| f64::from(v) / 255.0;
+    format!(
+        "{:.6} {:.6} {:.6} {}",
+        channel(color.r),
+        channel(color.g),
+        channel(color.b),
+        match color.is_opaque() {
+            true => String::from("1"),
+            false => format!("{:.6}", channel(color.a)),
+        }
+    )
+}
+
+fn write_entry(out: &mut String, indent: &str, key: &str, value: &str) {
+    let _ = writeln!(out, "{indent}<key>{key}</key>");
+    let _ = writeln!(out, "{indent}<string>{value}</string>");
+}
+
+/// Render the `.xccolortheme` plist for a palette.
+pub fn to_xccolortheme(palette: &ResolvedPalette) -> String {
+    let base = &palette.base;
+    let editor = &palette.editor;
+    let syntax = &palette.syntax;
+    let typography = &palette.typography;
+
+    let chrome: [(&str, Color); 5] = [
+        ("DVTSourceTextBackground", base.background),
+        (
+            "DVTSourceTextCurrentLineHighlightColor",
+            base.background_highlight,
+        ),
+        ("DVTSourceTextInsertionPointColor", editor.cursor),
+        ("DVTSourceTextInvisiblesColor", typography.gutter),
+        ("DVTSourceTextSelectionColor", editor.selection_bg),
+    ];
+
+    let syntax_colors: [(&str, Color); 32] = [
+        ("xcode.syntax.attribute", syntax.attributes),
+        ("xcode.syntax.character", syntax.constants_char),
+        ("xcode.syntax.comment", syntax.comments),
+        ("xcode.syntax.comment.doc", syntax.comments_doc),
+        ("xcode.syntax.comment.doc.keyword", syntax.annotations),
+        ("xcode.syntax.declaration.other", syntax.functions),
+        ("xcode.syntax.declaration.type", syntax.types),
+        ("xcode.syntax.identifier.class", syntax.types),
+        ("xcode.syntax.identifier.class.system", syntax.types_builtin),
+        ("xcode.syntax.identifier.constant", syntax.constants),
+        ("xcode.syntax.identifier.constant.system", syntax.constants),
+        ("xcode.syntax.identifier.function", syntax.functions),
+        (
+            "xcode.syntax.identifier.function.system",
+            syntax.functions_builtin,
+        ),
+        ("xcode.syntax.identifier.macro", syntax.functions_macro),
+        (
+            "xcode.syntax.identifier.macro.system",
+            syntax.functions_macro,
+        ),
+        ("xcode.syntax.identifier.type", syntax.types),
+        ("xcode.syntax.identifier.type.system", syntax.types_builtin),
+        ("xcode.syntax.identifier.variable", syntax.variables),
+        (
+            "xcode.syntax.identifier.variable.system",
+            syntax.variables_builtin,
+        ),
+        ("xcode.syntax.keyword", syntax.keywords),
+        ("xcode.syntax.mark", typography.title),
+        ("xcode.syntax.markup.code", syntax.strings),
+        ("xcode.syntax.number", syntax.numbers),
+        ("xcode.syntax.plain", base.foreground),
+        ("xcode.syntax.preprocessor", syntax.keywords_import),
+        ("xcode.syntax.regex", syntax.strings_regex),
+        ("xcode.syntax.regex.capturename", syntax.variables),
+        ("xcode.syntax.regex.charname", syntax.strings_escape),
+        ("xcode.syntax.regex.number", syntax.numbers),
+        ("xcode.syntax.regex.other", syntax.operators),
+        ("xcode.syntax.string", syntax.strings),
+        ("xcode.syntax.url", typography.link),
+    ];
+
+    let mut out = String::with_capacity(8 * 1024);
+    let name = palette.meta.as_ref().map_or("palette", |m| m.name.as_ref());
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str(
+        "<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \
+         \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n",
+    );
+    let _ = writeln!(
+        out,
+        "<!-- Generated by palette-core from {}. Do not edit. -->",
+        name.replace("--", "- -")
+    );
+    out.push_str("<plist version=\"1.0\">\n<dict>\n");
+    write_entry(&mut out, "\t", "DVTFontAndColorVersion", "1");
+    for (key, color) in &chrome {
+        write_entry(&mut out, "\t", key, &xcode_color(color));
+    }
+
+    out.push_str("\t<key>DVTSourceTextSyntaxColors</key>\n\t<dict>\n");
+    for (key, color) in &syntax_colors {
+        write_entry(&mut out, "\t\t", key, &xcode_color(color));
+    }
+    out.push_str("\t</dict>\n");
+
+    out.push_str("\t<key>DVTSourceTextSyntaxFonts</key>\n\t<dict>\n");
+    for (key, _) in &syntax_colors {
+        write_entry(&mut out, "\t\t", key, DEFAULT_FONT);
+    }
+    out.push_str("\t</dict>\n</dict>\n</plist>\n");
+    out
+}
@@ -0,0 +1,89 @@
+//! Zathura `zathurarc` color export.
+//!
+//! Emits `set <option> "#RRGGBB"` lines covering the document view, status
+//! and input bars, notifications, completion, index, search highlights, and
+//! the recolor (dark mode) pair. Append the output to `zathurarc` or
+//! `include` it from there.
+
+use std::fmt::Write;
+
+use super::{ExportFile, Exporter, comment_text};
+use crate::color::Color;
+use crate::palette::Palette;
+use crate::resolved::ResolvedPalette;
+
+/// Default output file name.
+pub const FILE_NAME: &str = "zathurarc";
+
+/// [`Exporter`] for Zathura color settings.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Zathura;
+
+impl Exporter for Zathura {
+    fn target(&self) -> &'static str {
+        "zathura"
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "export", level = "debug", skip_all,
+        fields(target = "zathura", id = palette.preset_id()),
+    ))]
+    fn export(&self, palette: &Palette) -> Box<[ExportFile]> {
+        Box::new([ExportFile {
+            path: Box::from(FILE_NAME),
+            contents: to_zathurarc(&palette.resolve()),
+        }])
+    }
+}
+
+/// Render zathurarc `set` lines for a palette.
+///
+/// `recolor-lightcolor`/`recolor-darkcolor` map to the background and
+/// foreground, so enabling `set recolor true` renders pages in theme colors.
+pub fn to_zathurarc(palette: &ResolvedPalette) -> String {
+    let base = &palette.base;
+    let semantic = &palette.semantic;
+    let surface = &palette.surface;
+    let typography = &palette.typography;
+
+    let options: [(&str, Color); 26] = [
+        ("default-bg", base.background),
+        ("default-fg", base.foreground),
+        ("statusbar-bg", surface.statusline),
+        ("statusbar-fg", base.foreground),
+        ("inputbar-bg", base.background_dark),
+        ("inputbar-fg", base.foreground),
+        ("notification-bg", surface.float),
+        ("notification-fg", base.foreground),
+        ("notification-error-bg", semantic.error),
+        ("notification-error-fg", base.background),
+        ("notification-warning-bg", semantic.warning),
+        ("notification-warning-fg", base.background),
+        ("highlight-color", surface.search),
+        ("highlight-fg", base.background),
+        ("highlight-active-color", semantic.info),
+        ("completion-bg", surface.menu),
+        ("completion-fg", base.foreground),
+        ("completion-group-bg", surface.popup),
+        ("completion-group-fg", typography.title),
+        ("completion-highlight-bg", surface.selection),
+        ("completion-highlight-fg", typography.selection_text),
+        ("index-bg", base.background),
+        ("index-fg", base.foreground),
+        ("index-active-bg", surface.selection),
+        ("recolor-lightcolor", base.background),
+        ("recolor-darkcolor", base.foreground),
+    ];
+
+    let mut out = String::with_capacity(1024);
+    let name = palette.meta.as_ref().map_or("palette", |m| m.name.as_ref());
+    let _ = writeln!(
+        out,
+        "# Generated by palette-core from {}.",
+        comment_text(name)
+    );
+    for (option, color) in &options {
+        let _ = writeln!(out, "set {option} \"{}\"", color.opaque());
+    }
+    out
+}
@@ -0,0 +1,849 @@
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use crate::color::Color;
+use crate::contrast::{ContrastLevel, contrast_ratio, meets_level, nudge_foreground};
+use crate::cvd;
+use crate::manipulation::{OkLch, oklch_to_srgb_in_gamut, srgb_to_oklch};
+use crate::palette::{
+    AnsiColors, BaseColors, DiffColors, EditorColors, Palette, PaletteMeta, SemanticColors,
+    SurfaceColors, SyntaxColors, TypographyColors,
+};
+use crate::style::SyntaxStyles;
+
+/// Syntax hue offsets from the accent hue used by [`from_seed`], in
+/// [`Recipe::offsets`] order.
+const SEED_OFFSETS: [f64; 6] = [180.0, 120.0, 240.0, 60.0, 300.0, 330.0];
+
+/// OkLch hues for status and ANSI colors, independent of the accent so that
+/// red always reads as red.
+const HUE_RED: f64 = 25.0;
+const HUE_YELLOW: f64 = 85.0;
+const HUE_GREEN: f64 = 145.0;
+const HUE_CYAN: f64 = 200.0;
+const HUE_BLUE: f64 = 255.0;
+const HUE_MAGENTA: f64 = 325.0;
+
+/// Light or dark target for a generated palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Style {
+    /// Dark background, light text.
+    Dark,
+    /// Light background, dark text.
+    Light,
+}
+
+impl Style {
+    /// Style tag stored in [`PaletteMeta::style`]: `"dark"` or `"light"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Dark => "dark",
+            Self::Light => "light",
+        }
+    }
+
+    fn tones(self) -> &'static Tones {
+        match self {
+            Self::Dark => &DARK,
+            Self::Light => &LIGHT,
+        }
+    }
+}
+
+/// OkLch lightness targets for one [`Style`].
+struct Tones {
+    background: f64,
+    background_dark: f64,
+    background_highlight: f64,
+    surface: f64,
+    selection: f64,
+    border: f64,
+    foreground: f64,
+    foreground_dark: f64,
+    muted: f64,
+    /// Lightness of hued foregrounds (syntax, semantic, ANSI).
+    hue: f64,
+    /// Lightness of tinted diff and search backgrounds.
+    tinted_bg: f64,
+    /// Lightness shift from normal to bright ANSI colors.
+    bright: f64,
+    ansi_black: f64,
+    ansi_white: f64,
+}
+
+const DARK: Tones = Tones {
+    background: 0.20,
+    background_dark: 0.17,
+    background_highlight: 0.25,
+    surface: 0.23,
+    selection: 0.33,
+    border: 0.33,
+    foreground: 0.92,
+    foreground_dark: 0.74,
+    muted: 0.64,
+    hue: 0.76,
+    tinted_bg: 0.28,
+    bright: 0.08,
+    ansi_black: 0.24,
+    ansi_white: 0.86,
+};
+
+const LIGHT: Tones = Tones {
+    background: 0.98,
+    background_dark: 0.94,
+    background_highlight: 0.93,
+    surface: 0.96,
+    selection: 0.87,
+    border: 0.86,
+    foreground: 0.28,
+    foreground_dark: 0.45,
+    muted: 0.55,
+    hue: 0.55,
+    tinted_bg: 0.91,
+    bright: 0.08,
+    ansi_black: 0.30,
+    ansi_white: 0.88,
+};
+
+fn lch(l: f64, c: f64, h: f64) -> Color {
+    oklch_to_srgb_in_gamut(OkLch { l, c, h })
+}
+
+/// Tunable inputs shared by [`from_seed`] and [`random`].
+struct Recipe {
+    /// OkLch chroma of semantic, syntax, and ANSI hues.
+    chroma: f64,
+    /// Level every foreground is nudged to meet.
+    contrast: ContrastLevel,
+    /// Hue offsets from the accent for functions, types, strings, constants,
+    /// special tokens, and tags.
+    offsets: [f64; 6],
+    name: String,
+    preset_id: String,
+}
+
+/// Synthesize a complete palette around a single accent color.
+///
+/// Background, surface, border, and foreground scales are near-neutral
+/// tones tinted toward the accent hue; semantic and ANSI colors use fixed
+/// hues (red, yellow, green, cyan, blue, magenta) at the accent's chroma;
+/// syntax colors rotate around the accent hue. The accent itself becomes
+/// the cursor, keyword, and link color.
+///
+/// Every slot is populated, and every foreground that
+/// [`validate_palette`](crate::contrast::validate_palette) checks meets
+/// WCAG AA against its background — a failing accent is lightened or
+/// darkened where it is used as text.
+///
+/// ```
+/// use palette_core::Color;
+/// use palette_core::contrast::{ContrastLevel, validate_palette};
+/// use palette_core::generate::{Style, from_seed};
+///
+/// let brand = Color::from_hex("#7C3AED").unwrap();
+/// let palette = from_seed(brand, Style::Dark);
+/// assert!(validate_palette(&palette, ContrastLevel::AaNormal).is_empty());
+/// ```
+pub fn from_seed(accent: Color, style: Style) -> Palette {
+    let recipe = Recipe {
+        chroma: srgb_to_oklch(accent).c.clamp(0.08, 0.16),
+        contrast: ContrastLevel::AaNormal,
+        offsets: SEED_OFFSETS,
+        name: format!("Seed {accent}"),
+        preset_id: format!("seed_{}", accent.to_hex().trim_start_matches('#')).to_lowercase(),
+    };
+    synthesize(accent, style, &recipe)
+}
+
+/// Bounds for [`random`] palettes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constraints {
+    /// Style to generate; `None` picks dark or light from the seed.
+    pub style: Option<Style>,
+    /// OkLch chroma range for the accent and every hued color. Colors outside
+    /// the sRGB gamut are desaturated to fit, so results may fall below the
+    /// lower bound.
+    pub chroma: RangeInclusive<f64>,
+    /// OkLch lightness range for the accent.
+    pub lightness: RangeInclusive<f64>,
+    /// Contrast level every checked foreground meets against its background.
+    pub contrast: ContrastLevel,
+    /// Minimum separation in degrees between the accent hue and each syntax
+    /// hue, and between syntax hues. Clamped to `360 / 7`, the widest spread
+    /// seven hues allow.
+    pub hue_spread: f64,
+}
+
+impl Default for Constraints {
+    /// Moderate chroma, mid-range accent lightness, AA contrast, and 30° of
+    /// hue separation.
+    fn default() -> Self {
+        Self {
+            style: None,
+            chroma: 0.06..=0.16,
+            lightness: 0.55..=0.80,
+            contrast: ContrastLevel::AaNormal,
+            hue_spread: 30.0,
+        }
+    }
+}
+
+/// SplitMix64 — small, fast, and stable across platforms and releases, so a
+/// seed always reproduces the same palette.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn in_range(&mut self, range: &RangeInclusive<f64>) -> f64 {
+        range.start() + (range.end() - range.start()) * self.next_f64()
+    }
+}
+
+/// Generate a plausible random palette from `seed`.
+///
+/// The same seed and constraints always produce the same palette. The
+/// accent hue is uniform around the color wheel; its chroma and lightness
+/// fall within `constraints`; syntax hues are spread around the wheel with
+/// at least [`Constraints::hue_spread`] degrees between any two. Otherwise
+/// the palette is built like [`from_seed`], with foregrounds nudged to
+/// [`Constraints::contrast`].
+///
+/// ```
+/// use palette_core::contrast::{ContrastLevel, validate_palette};
+/// use palette_core::generate::{Constraints, random};
+///
+/// for seed in 0..8 {
+///     let palette = random(seed, &Constraints::default());
+///     assert!(validate_palette(&palette, ContrastLevel::AaNormal).is_empty());
+/// }
+/// ```
+pub fn random(seed: u64, constraints: &Constraints) -> Palette {
+    let mut rng = SplitMix64(seed);
+
+    let style = constraints
+        .style
+        .unwrap_or_else(|| match rng.next_u64() & 1 {
+            0 => Style::Dark,
+            _ => Style::Light,
+        });
+    let accent = lch(
+        rng.in_range(&constraints.lightness),
+        rng.in_range(&constraints.chroma),
+        rng.next_f64() * 360.0,
+    );
+
+    // Seven evenly spaced slots (accent at 0°), each jittered by at most
+    // half the slack between slot spacing and the requested spread, then
+    // shuffled across syntax roles.
+    let step = 360.0 / 7.0;
+    let slack = (step - constraints.hue_spread.clamp(0.0, step)) / 2.0;
+    let mut offsets: [f64; 6] = std::array::from_fn(|i| {
+        let jitter = (rng.next_f64() * 2.0 - 1.0) * slack;
+        (i + 1) as f64 * step + jitter
+    });
+    for i in (1..offsets.len()).rev() {
+        let j = (rng.next_u64() % (i as u64 + 1)) as usize;
+        offsets.swap(i, j);
+    }
+
+    let recipe = Recipe {
+        chroma: rng.in_range(&constraints.chroma),
+        contrast: constraints.contrast,
+        offsets,
+        name: format!("Random {seed}"),
+        preset_id: format!("random_{seed}"),
+    };
+    synthesize(accent, style, &recipe)
+}
+
+fn synthesize(accent: Color, style: Style, recipe: &Recipe) -> Palette {
+    let seed = srgb_to_oklch(accent);
+    let t = style.tones();
+    let h = seed.h;
+    let chroma = recipe.chroma;
+    let tint = (seed.c * 0.2).min(0.025);
+    let on = |fg: Color, bg: Color| nudge_foreground(fg, bg, recipe.contrast);
+
+    let neutral = |l: f64| lch(l, tint, h);
+    let hued = |hue: f64| lch(t.hue, chroma, hue);
+    let rotated = |offset: f64| hued((h + offset).rem_euclid(360.0));
+    let tinted_bg = |hue: f64| lch(t.tinted_bg, 0.05, hue);
+
+    let background = neutral(t.background);
+    let background_dark = neutral(t.background_dark);
+    let background_highlight = neutral(t.background_highlight);
+    let surface = neutral(t.surface);
+    let selection = lch(t.selection, chroma * 0.4, h);
+    let border = neutral(t.border);
+
+    let foreground = on(neutral(t.foreground), background);
+    let foreground_dark = on(neutral(t.foreground_dark), background);
+    let muted = on(neutral(t.muted), background);
+    let accent_fg = on(accent, background);
+
+    // Mid-luminance accents admit no cursor text color at AAA; fall back to
+    // the text-safe accent with the background as cursor text.
+    let cursor_text = on(background, accent);
+    let (cursor, cursor_text) = match recipe
+        .contrast
+        .passes(contrast_ratio(&cursor_text, &accent))
+    {
+        true => (accent, cursor_text),
+        false => (accent_fg, background),
+    };
+
+    let success = on(hued(HUE_GREEN), background);
+    let warning = on(hued(HUE_YELLOW), background);
+    let error = on(hued(HUE_RED), background);
+    let info = on(hued(HUE_BLUE), background);
+
+    let [functions, types, strings, constants, special, tag] =
+        recipe.offsets.map(|offset| on(rotated(offset), background));
+    let parameters = on(
+        lch(t.hue, chroma * 0.5, (h + 30.0).rem_euclid(360.0)),
+        background,
+    );
+    let properties = on(
+        lch(t.hue, chroma * 0.6, (h + 210.0).rem_euclid(360.0)),
+        background,
+    );
+
+    let added_bg = tinted_bg(HUE_GREEN);
+    let modified_bg = tinted_bg(HUE_YELLOW);
+    let removed_bg = tinted_bg(HUE_RED);
+    let search_bg = tinted_bg(HUE_YELLOW);
+
+    let ansi = |hue: f64, bright: bool| {
+        let l = match bright {
+            true => t.hue + t.bright,
+            false => t.hue,
+        };
+        lch(l, chroma, hue)
+    };
+
+    Palette {
+        meta: Some(Arc::new(PaletteMeta {
+            name: Arc::from(recipe.name.as_str()),
+            preset_id: Arc::from(recipe.preset_id.as_str()),
+            style: Arc::from(style.as_str()),
+            author: None,
+            license: None,
+            version: None,
+            description: None,
+            tags: Box::default(),
+        })),
+        base: BaseColors {
+            background: Some(background),
+            background_dark: Some(background_dark),
+            background_highlight: Some(background_highlight),
+            foreground: Some(on(on(foreground, background_dark), background_highlight)),
+            foreground_dark: Some(foreground_dark),
+            border: Some(border),
+            border_highlight: Some(lch(t.hue, chroma * 0.6, h)),
+        },
+        semantic: SemanticColors {
+            success: Some(success),
+            warning: Some(warning),
+            error: Some(error),
+            info: Some(info),
+            hint: Some(muted),
+        },
+        diff: DiffColors {
+            added: Some(success),
+            added_bg: Some(added_bg),
+            added_fg: Some(on(success, added_bg)),
+            modified: Some(warning),
+            modified_bg: Some(modified_bg),
+            modified_fg: Some(on(warning, modified_bg)),
+            removed: Some(error),
+            removed_bg: Some(removed_bg),
+            removed_fg: Some(on(error, removed_bg)),
+            text_bg: Some(selection),
+            ignored: Some(muted),
+        },
+        surface: SurfaceColors {
+            menu: Some(surface),
+            sidebar: Some(surface),
+            statusline: Some(surface),
+            float: Some(surface),
+            popup: Some(surface),
+            overlay: Some(surface),
+            highlight: Some(background_highlight),
+            selection: Some(selection),
+            focus: Some(background_highlight),
+            search: Some(search_bg),
+        },
+        typography: TypographyColors {
+            comment: Some(muted),
+            gutter: Some(border),
+            line_number: Some(muted),
+            selection_text: Some(foreground),
+            link: Some(accent_fg),
+            title: Some(accent_fg),
+        },
+        syntax: SyntaxColors {
+            keywords: Some(accent_fg),
+            keywords_fn: Some(accent_fg),
+            keywords_control: Some(accent_fg),
+            keywords_import: Some(accent_fg),
+            keywords_operator: Some(accent_fg),
+            functions: Some(functions),
+            functions_builtin: Some(functions),
+            functions_method: Some(functions),
+            functions_macro: Some(special),
+            variables: Some(foreground),
+            variables_builtin: Some(special),
+            parameters: Some(parameters),
+            properties: Some(properties),
+            types: Some(types),
+            types_builtin: Some(types),
+            constants: Some(constants),
+            constants_char: Some(constants),
+            numbers: Some(constants),
+            booleans: Some(constants),
+            strings: Some(strings),
+            strings_doc: Some(muted),
+            strings_escape: Some(special),
+            strings_regex: Some(special),
+            operators: Some(foreground_dark),
+            punctuation: Some(foreground_dark),
+            punctuation_bracket: Some(foreground_dark),
+            punctuation_special: Some(special),
+            annotations: Some(constants),
+            attributes: Some(constants),
+            attributes_builtin: Some(constants),
+            constructor: Some(types),
+            modules: Some(types),
+            labels: Some(tag),
+            tag: Some(tag),
+            tag_delimiter: Some(foreground_dark),
+            tag_attribute: Some(constants),
+            comments: Some(muted),
+            comments_doc: Some(muted),
+        },
+        editor: EditorColors {
+            cursor: Some(cursor),
+            cursor_text: Some(cursor_text),
+            match_paren: Some(constants),
+            selection_bg: Some(selection),
+            selection_fg: Some(on(foreground, selection)),
+            inlay_hint_bg: Some(background_highlight),
+            inlay_hint_fg: Some(on(muted, background_highlight)),
+            search_bg: Some(search_bg),
+            search_fg: Some(on(foreground, search_bg)),
+            diagnostic_error: Some(error),
+            diagnostic_warn: Some(warning),
+            diagnostic_info: Some(info),
+            diagnostic_hint: Some(muted),
+            diagnostic_underline_error: Some(error),
+            diagnostic_underline_warn: Some(warning),
+            diagnostic_underline_info: Some(info),
+            diagnostic_underline_hint: Some(muted),
+        },
+        terminal: AnsiColors {
+            black: Some(neutral(t.ansi_black)),
+            red: Some(ansi(HUE_RED, false)),
+            green: Some(ansi(HUE_GREEN, false)),
+            yellow: Some(ansi(HUE_YELLOW, false)),
+            blue: Some(ansi(HUE_BLUE, false)),
+            magenta: Some(ansi(HUE_MAGENTA, false)),
+            cyan: Some(ansi(HUE_CYAN, false)),
+            white: Some(neutral(t.ansi_white)),
+            bright_black: Some(neutral((t.ansi_black + t.ansi_white) / 2.0)),
+            bright_red: Some(ansi(HUE_RED, true)),
+            bright_green: Some(ansi(HUE_GREEN, true)),
+            bright_yellow: Some(ansi(HUE_YELLOW, true)),
+            bright_blue: Some(ansi(HUE_BLUE, true)),
+            bright_magenta: Some(ansi(HUE_MAGENTA, true)),
+            bright_cyan: Some(ansi(HUE_CYAN, true)),
+            bright_white: Some(neutral(0.98)),
+        },
+        syntax_style: SyntaxStyles::default(),
+        gradients: Arc::from([]),
+        groups: Default::default(),
+        #[cfg(feature = "platform")]
+        platform: crate::platform::PlatformOverrides::default(),
+        #[cfg(feature = "toml")]
+        extensions: Default::default(),
+    }
+}
+
+/// First populated color in `candidates`.
+fn first(candidates: &[Option<Color>]) -> Option<Color> {
+    candidates.iter().find_map(|c| *c)
+}
+
+/// Fill the terminal ANSI slots a palette leaves unset.
+///
+/// Slots the palette already defines are kept. Missing hues are borrowed
+/// from the semantic, syntax, and diff groups — red from `semantic.error`,
+/// green from `semantic.success` or `syntax.strings`, yellow from
+/// `semantic.warning`, blue from `semantic.info` or `syntax.functions`,
+/// magenta from `syntax.keywords`, cyan from `syntax.types` — and
+/// black/white from the base scale, swapped for light backgrounds so black
+/// stays dark. `bright_black` comes from the comment color; other bright
+/// slots default to their normal counterpart lightened (dark themes) or
+/// darkened (light themes) by 10%.
+///
+/// Slots stay `None` only when no source color exists either.
+///
+/// ```
+/// use palette_core::generate::ansi_from_palette;
+/// use palette_core::load_preset;
+///
+/// let mut palette = load_preset("tokyonight").unwrap();
+/// palette.terminal = Default::default();
+/// palette.terminal = ansi_from_palette(&palette);
+/// assert_eq!(palette.terminal.red, palette.semantic.error);
+/// ```
+pub fn ansi_from_palette(palette: &Palette) -> AnsiColors {
+    let base = &palette.base;
+    let semantic = &palette.semantic;
+    let syntax = &palette.syntax;
+    let diff = &palette.diff;
+    let typography = &palette.typography;
+    let term = &palette.terminal;
+
+    let light = base.background.is_some_and(|bg| bg.is_light());
+    let (dark_end, light_end) = match light {
+        true => (
+            [base.foreground, base.foreground_dark],
+            [base.background_dark, base.background],
+        ),
+        false => (
+            [base.background_dark, base.background],
+            [base.foreground_dark, base.foreground],
+        ),
+    };
+    let brighten = |color: Option<Color>| {
+        color.map(|c| match light {
+            true => c.darken(0.1),
+            false => c.lighten(0.1),
+        })
+    };
+
+    let black = term.black.or(first(&dark_end));
+    let red = term.red.or(first(&[
+        semantic.error,
+        diff.removed,
+        syntax.variables_builtin,
+    ]));
+    let green = term
+        .green
+        .or(first(&[semantic.success, syntax.strings, diff.added]));
+    let yellow = term
+        .yellow
+        .or(first(&[semantic.warning, syntax.constants, diff.modified]));
+    let blue = term
+        .blue
+        .or(first(&[semantic.info, syntax.functions, typography.link]));
+    let magenta = term.magenta.or(first(&[syntax.keywords, syntax.constants]));
+    let cyan = term
+        .cyan
+        .or(first(&[syntax.types, syntax.properties, syntax.operators]));
+    let white = term.white.or(first(&light_end));
+
+    AnsiColors {
+        black,
+        red,
+        green,
+        yellow,
+        blue,
+        magenta,
+        cyan,
+        white,
+        bright_black: term.bright_black.or(first(&[
+            typography.comment,
+            syntax.comments,
+            typography.line_number,
+        ])),
+        bright_red: term.bright_red.or(brighten(red)),
+        bright_green: term.bright_green.or(brighten(green)),
+        bright_yellow: term.bright_yellow.or(brighten(yellow)),
+        bright_blue: term.bright_blue.or(brighten(blue)),
+        bright_magenta: term.bright_magenta.or(brighten(magenta)),
+        bright_cyan: term.bright_cyan.or(brighten(cyan)),
+        bright_white: term
+            .bright_white
+            .or(first(&light_end[1..]).or(brighten(white))),
+    }
+}
+
+/// Rotate `color`'s OkLch hue by `rotation` degrees and scale its chroma,
+/// keeping lightness.
+fn retint(color: Color, rotation: f64, chroma_scale: f64) -> Color {
+    let c = srgb_to_oklch(color);
+    lch(c.l, c.c * chroma_scale, (c.h + rotation).rem_euclid(360.0))
+}
+
+/// Re-tint an existing palette's accent-bearing slots toward `accent`.
+///
+/// The palette's current accent is its `syntax.keywords` color (falling back
+/// to `typography.link`, then `editor.cursor`). Keyword, function, link,
+/// title, cursor, border-highlight, focus, and selection slots are rotated
+/// by the hue difference between the old and new accent and their chroma is
+/// scaled by the chroma ratio. Every other slot — backgrounds included — is
+/// left untouched.
+///
+/// Lightness is kept, so contrast barely moves; any foreground that met
+/// WCAG AA against the background before is nudged back to AA if the re-tint
+/// pushed it under, and a re-tinted focus, selection, or cursor background
+/// that would break its text pair keeps its original color.
+///
+/// The result's meta appends the accent to the name and preset ID, so it can
+/// be registered next to the original.
+///
+/// ```
+/// use palette_core::generate::adapt;
+/// use palette_core::{Color, load_preset};
+///
+/// let base = load_preset("tokyonight").unwrap();
+/// let branded = adapt(&base, Color::from_hex("#E11D48").unwrap());
+/// assert_eq!(branded.base.background, base.base.background);
+/// assert_ne!(branded.syntax.keywords, base.syntax.keywords);
+/// assert_eq!(&*branded.meta.unwrap().preset_id, "tokyonight_e11d48");
+/// ```
+pub fn adapt(palette: &Palette, accent: Color) -> Palette {
+    let mut adapted = palette.clone();
+    let old_accent = match first(&[
+        palette.syntax.keywords,
+        palette.typography.link,
+        palette.editor.cursor,
+    ]) {
+        Some(color) => srgb_to_oklch(color),
+        None => return adapted,
+    };
+    let new_accent = srgb_to_oklch(accent);
+    let rotation = new_accent.h - old_accent.h;
+    let chroma_scale = match old_accent.c < 0.02 {
+        true => 1.0,
+        false => (new_accent.c / old_accent.c).min(2.0),
+    };
+    let shift = |slot: &mut Option<Color>| {
+        *slot = slot.map(|c| retint(c, rotation, chroma_scale));
+    };
+
+    let background = palette.base.background;
+    let keep_readable = |slot: &mut Option<Color>, original: Option<Color>| {
+        shift(slot);
+        if let (Some(fg), Some(orig), Some(bg)) = (slot.as_mut(), original, background) {
+            match meets_level(&orig, &bg, ContrastLevel::AaNormal) {
+                true => *fg = nudge_foreground(*fg, bg, ContrastLevel::AaNormal),
+                false => {}
+            }
+        }
+    };
+    // A re-tinted background must not break the text drawn on it.
+    let keep_pair = |slot: &mut Option<Color>, text: Option<Color>| {
+        let original = *slot;
+        shift(slot);
+        if let (Some(new), Some(old), Some(text)) = (*slot, original, text) {
+            let broke = meets_level(&text, &old, ContrastLevel::AaNormal)
+                && !meets_level(&text, &new, ContrastLevel::AaNormal);
+            match broke {
+                true => *slot = original,
+                false => {}
+            }
+        }
+    };
+
+    let syntax = &palette.syntax;
+    let s = &mut adapted.syntax;
+    keep_readable(&mut s.keywords, syntax.keywords);
+    keep_readable(&mut s.keywords_fn, syntax.keywords_fn);
+    keep_readable(&mut s.keywords_control, syntax.keywords_control);
+    keep_readable(&mut s.keywords_import, syntax.keywords_import);
+    keep_readable(&mut s.keywords_operator, syntax.keywords_operator);
+    keep_readable(&mut s.functions, syntax.functions);
+    keep_readable(&mut s.functions_builtin, syntax.functions_builtin);
+    keep_readable(&mut s.functions_method, syntax.functions_method);
+    keep_readable(&mut s.functions_macro, syntax.functions_macro);
+
+    let typography = &palette.typography;
+    keep_readable(&mut adapted.typography.link, typography.link);
+    keep_readable(&mut adapted.typography.title, typography.title);
+
+    shift(&mut adapted.base.border_highlight);
+    keep_pair(&mut adapted.surface.focus, palette.base.foreground);
+    shift(&mut adapted.surface.selection);
+    keep_pair(
+        &mut adapted.editor.selection_bg,
+        palette.editor.selection_fg,
+    );
+    keep_pair(&mut adapted.editor.cursor, palette.editor.cursor_text);
+
+    let hex = accent.to_hex();
+    let hex = hex.trim_start_matches('#');
+    adapted.meta = palette.meta.as_ref().map(|meta| {
+        Arc::new(PaletteMeta {
+            name: Arc::from(format!("{} #{hex}", meta.name)),
+            preset_id: Arc::from(format!("{}_{}", meta.preset_id, hex.to_lowercase())),
+            ..PaletteMeta::clone(meta)
+        })
+    });
+    adapted
+}
+
+/// Status colors that stay distinguishable under protanopia and
+/// deuteranopia, from [`status_colors`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StatusColors {
+    /// Success and diff-added color.
+    pub success: Color,
+    /// Warning color.
+    pub warning: Color,
+    /// Error and diff-removed color.
+    pub error: Color,
+    /// Informational color.
+    pub info: Color,
+}
+
+/// OkLch hue window per status role, in [`StatusColors`] field order. The
+/// windows keep each role recognizable (errors stay red-orange) while
+/// leaving room to move away from the red–green confusion axis.
+const STATUS_HUES: [(f64, f64); 4] = [(125.0, 200.0), (60.0, 105.0), (10.0, 50.0), (220.0, 290.0)];
+const STATUS_CHROMA: f64 = 0.16;
+const STATUS_HUE_STEP: f64 = 5.0;
+
+/// Pick success, warning, error, and info colors for `background` that are
+/// as far apart as possible under normal vision, protanopia, and
+/// deuteranopia.
+///
+/// Each role searches its own hue window (so errors stay red-orange and
+/// success stays green-to-teal) across several lightness levels; a greedy
+/// search maximizes the smallest pairwise [`cvd::distance`] across all three
+/// views. Every color meets WCAG AA against `background`.
+/// Diff `added`/`removed` should use `success`/`error`.
+///
+/// ```
+/// use palette_core::Color;
+/// use palette_core::cvd::min_distance;
+/// use palette_core::generate::status_colors;
+///
+/// let s = status_colors(Color::from_hex("#1A1B26").unwrap());
+/// assert!(min_distance(&[s.success, s.warning, s.error, s.info]) > 0.05);
+/// ```
+pub fn status_colors(background: Color) -> StatusColors {
+    let levels: [f64; 4] = match background.is_light() {
+        true => [0.40, 0.48, 0.56, 0.64],
+        false => [0.62, 0.70, 0.78, 0.86],
+    };
+    let candidates: [Vec<(Color, f64)>; 4] = STATUS_HUES.map(|(lo, hi)| {
+        let center = (lo + hi) / 2.0;
+        let steps = ((hi - lo) / STATUS_HUE_STEP) as usize;
+        (0..=steps)
+            .flat_map(|i| {
+                let hue = lo + i as f64 * STATUS_HUE_STEP;
+                levels.map(|l| {
+                    let color = nudge_foreground(
+                        lch(l, STATUS_CHROMA, hue),
+                        background,
+                        ContrastLevel::AaNormal,
+                    );
+                    // Small pull toward the window center breaks ties.
+                    (color, (hue - center).abs() * 2e-4)
+                })
+            })
+            .collect()
+    });
+
+    let score = |picks: &[usize; 4]| {
+        let colors: [Color; 4] = std::array::from_fn(|r| candidates[r][picks[r]].0);
+        let penalty: f64 = (0..4).map(|r| candidates[r][picks[r]].1).sum();
+        cvd::min_distance(&colors) - penalty
+    };
+
+    // Start every role at its window center, second lightness level.
+    let mut picks: [usize; 4] = std::array::from_fn(|r| {
+        let center_hue = candidates[r].len() / levels.len() / 2;
+        center_hue * levels.len() + 1
+    });
+    let mut best = score(&picks);
+    for _ in 0..6 {
+        let mut improved = false;
+        for role in 0..4 {
+            for i in 0..candidates[role].len() {
+                let mut trial = picks;
+                trial[role] = i;
+                let s = score(&trial);
+                if s > best {
+                    best = s;
+                    picks = trial;
+                    improved = true;
+                }
+            }
+        }
+        match improved {
+            true => {}
+            false => break,
+        }
+    }
+
+    let [success, warning, error, info] = std::array::from_fn(|r| candidates[r][picks[r]].0);
+    StatusColors {
+        success,
+        warning,
+        error,
+        info,
+    }
+}
+
+/// Replace a palette's status colors with CVD-distinct ones from
+/// [`status_colors`].
+///
+/// Sets the four semantic slots, and replaces whichever of diff
+/// `added`/`removed`/`added_fg`/`removed_fg` and the editor error, warning,
+/// and info diagnostics the palette defines. Diff foregrounds are nudged to
+/// AA against their diff backgrounds. Everything else is untouched.
+pub fn repair_status_colors(palette: &Palette) -> Palette {
+    let background = palette
+        .base
+        .background
+        .or(Palette::default().base.background)
+        .unwrap_or_default();
+    let s = status_colors(background);
+    let mut repaired = palette.clone();
+
+    let replace = |slot: &mut Option<Color>, color: Color| {
+        if slot.is_some() {
+            *slot = Some(color);
+        }
+    };
+    let on = |color: Color, bg: Option<Color>| match bg {
+        Some(bg) => nudge_foreground(color, bg, ContrastLevel::AaNormal),
+        None => color,
+    };
+
+    let semantic = &mut repaired.semantic;
+    semantic.success = Some(s.success);
+    semantic.warning = Some(s.warning);
+    semantic.error = Some(s.error);
+    semantic.info = Some(s.info);
+
+    let diff = &mut repaired.diff;
+    replace(&mut diff.added, s.success);
+    replace(&mut diff.removed, s.error);
+    replace(&mut diff.added_fg, on(s.success, diff.added_bg));
+    replace(&mut diff.removed_fg, on(s.error, diff.removed_bg));
+
+    let editor = &mut repaired.editor;
+    replace(&mut editor.diagnostic_error, s.error);
+    replace(&mut editor.diagnostic_warn, s.warning);
+    replace(&mut editor.diagnostic_info, s.info);
+    replace(&mut editor.diagnostic_underline_error, s.error);
+    replace(&mut editor.diagnostic_underline_warn, s.warning);
+    replace(&mut editor.diagnostic_underline_info, s.info);
+    repaired
+}
@@ -36,10 +36,11 @@ use std::sync::Arc;
 use crate::color::Color;
 use crate::error::PaletteError;
 use crate::manipulation::{
-    lerp_oklab, lerp_oklch, oklab_to_srgb, oklch_to_oklab, srgb_to_oklab, srgb_to_oklch,
+    hsl_to_rgb, lerp_hsl, lerp_oklab, lerp_oklch, oklab_to_srgb, oklch_to_srgb_in_gamut,
+    rgb_to_hsl, srgb_to_oklab, srgb_to_oklch,
 };
 
-/// Interpolation color space for gradient stops.
+/// Interpolation color space for gradient stops and [`Color::lerp`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
 pub enum ColorSpace {
@@ -48,6 +49,34 @@ pub enum ColorSpace {
     OkLab,
     /// Polar interpolation with shortest-arc hue travel.
     OkLch,
+    /// Per-channel interpolation of gamma-encoded sRGB, as browsers do by
+    /// default. Cheap, but complementary pairs pass through muddy grays.
+    Srgb,
+    /// HSL interpolation with shortest-arc hue travel.
+    Hsl,
+}
+
+impl ColorSpace {
+    /// The name used in theme TOML and CSS `in <space>` clauses.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::OkLab => "oklab",
+            Self::OkLch => "oklch",
+            Self::Srgb => "srgb",
+            Self::Hsl => "hsl",
+        }
+    }
+
+    /// Look up a space by [`as_str`](Self::as_str) name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "oklab" => Some(Self::OkLab),
+            "oklch" => Some(Self::OkLch),
+            "srgb" => Some(Self::Srgb),
+            "hsl" => Some(Self::Hsl),
+            _ => None,
+        }
+    }
 }
 
 /// A color reference in an unresolved gradient definition.
@@ -88,6 +117,22 @@ impl GradientDef {
         Self { stops, space }
     }
 
+    /// Copy with `f` applied to every literal stop; token stops are kept.
+    pub(crate) fn map_literals(&self, mut f: impl FnMut(Color) -> Color) -> Self {
+        let stops = self
+            .stops
+            .iter()
+            .map(|(color, position)| {
+                let color = match color {
+                    GradientColor::Literal(c) => GradientColor::Literal(f(*c)),
+                    token @ GradientColor::Token { .. } => token.clone(),
+                };
+                (color, *position)
+            })
+            .collect();
+        Self::new(stops, self.space)
+    }
+
     /// The typed stops in this gradient definition.
     pub fn stops(&self) -> &[(GradientColor, f64)] {
         &self.stops
@@ -205,10 +250,7 @@ impl Gradient {
     pub fn to_css(&self) -> Box<str> {
         use std::fmt::Write;
 
-        let space_str = match self.space {
-            ColorSpace::OkLab => "oklab",
-            ColorSpace::OkLch => "oklch",
-        };
+        let space_str = self.space.as_str();
 
         let mut buf = String::with_capacity(64);
         let _ = write!(buf, "linear-gradient(in {space_str},");
@@ -216,14 +258,14 @@ impl Gradient {
         let evenly_spaced = is_evenly_spaced(&self.stops);
 
         for (i, stop) in self.stops.iter().enumerate() {
-            let hex = stop.color.to_hex();
+            let color = stop.color.to_css();
             match evenly_spaced {
                 true => {
-                    let _ = write!(buf, " {hex}");
+                    let _ = write!(buf, " {color}");
                 }
                 false => {
                     let pct = stop.position * 100.0;
-                    let _ = write!(buf, " {hex} {pct}%");
+                    let _ = write!(buf, " {color} {pct}%");
                 }
             }
             match i < self.stops.len() - 1 {
@@ -275,17 +317,42 @@ fn interpolate_at(stops: &[GradientStop], space: ColorSpace, t: f64) -> Color {
 
     // Exact stop hit — no interpolation needed
     let span = b.position - a.position;
-    match span <= f64::EPSILON {
-        true => return a.color,
-        false => {}
+    match () {
+        _ if span <= f64::EPSILON => return a.color,
+        _ if t == b.position => return b.color,
+        _ => {}
     }
 
     let local_t = (t - a.position) / span;
     interpolate_colors(a.color, b.color, space, local_t)
 }
 
+impl Color {
+    /// Interpolate toward `other` in `space`; `t` is clamped to \[0, 1\].
+    ///
+    /// Endpoints are exact and alpha is interpolated linearly. NaN returns
+    /// `self`.
+    ///
+    /// ```
+    /// use palette_core::{Color, ColorSpace};
+    ///
+    /// let red = Color::from_hex("#FF0000").unwrap();
+    /// let blue = Color::from_hex("#0000FF").unwrap();
+    /// assert_eq!(&*red.lerp(&blue, 0.5, ColorSpace::Srgb).to_hex(), "#800080");
+    /// assert_eq!(red.lerp(&blue, 1.0, ColorSpace::OkLab), blue);
+    /// ```
+    pub fn lerp(&self, other: &Color, t: f64, space: ColorSpace) -> Color {
+        match () {
+            _ if t.is_nan() || t <= 0.0 => *self,
+            _ if t >= 1.0 => *other,
+            _ => interpolate_colors(*self, *other, space, t),
+        }
+    }
+}
+
 fn interpolate_colors(a: Color, b: Color, space: ColorSpace, t: f64) -> Color {
-    match space {
+    let alpha = f64::from(a.a) + (f64::from(b.a) - f64::from(a.a)) * t;
+    let color = match space {
         ColorSpace::OkLab => {
             let lab_a = srgb_to_oklab(a);
             let lab_b = srgb_to_oklab(b);
@@ -294,7 +361,16 @@ fn interpolate_colors(a: Color, b: Color, space: ColorSpace, t: f64) -> Color {
         ColorSpace::OkLch => {
             let lch_a = srgb_to_oklch(a);
             let lch_b = srgb_to_oklch(b);
-            oklab_to_srgb(oklch_to_oklab(lerp_oklch(lch_a, lch_b, t)))
+            oklch_to_srgb_in_gamut(lerp_oklch(lch_a, lch_b, t))
         }
-    }
+        ColorSpace::Srgb => {
+            let mix = |x: u8, y: u8| {
+                let v = f64::from(x) + (f64::from(y) - f64::from(x)) * t;
+                v.round().clamp(0.0, 255.0) as u8
+            };
+            Color::rgb(mix(a.r, b.r), mix(a.g, b.g), mix(a.b, b.b))
+        }
+        ColorSpace::Hsl => hsl_to_rgb(lerp_hsl(rgb_to_hsl(a), rgb_to_hsl(b), t)),
+    };
+    color.with_alpha(alpha.round().clamp(0.0, 255.0) as u8)
 }
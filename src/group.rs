@@ -0,0 +1,346 @@
+//! Custom color groups defined outside this crate.
+//!
+//! Declare a typed group with [`custom_color_group!`](crate::custom_color_group)
+//! and [`register`](crate::group::register) it once at startup. From then on its TOML section is
+//! parsed, merged through inheritance, written by `to_toml`, exported as CSS
+//! variables and JSON, and reachable through [`SlotId`](crate::SlotId) like
+//! the built-in groups:
+//!
+//! ```
+//! use palette_core::manifest::PaletteManifest;
+//! use palette_core::{Palette, SlotId};
+//!
+//! palette_core::custom_color_group! {
+//!     /// Data-visualization colors.
+//!     pub struct ChartColors("chart") {
+//!         series_1,
+//!         series_2,
+//!         grid,
+//!     }
+//! }
+//!
+//! palette_core::group::register::<ChartColors>().unwrap();
+//!
+//! let toml = "[base]\nbackground = \"#1A1B26\"\n\n[chart]\nseries_1 = \"#7AA2F7\"\n";
+//! let palette = Palette::from_manifest(&PaletteManifest::from_toml(toml).unwrap()).unwrap();
+//!
+//! let chart: ChartColors = palette.group();
+//! assert_eq!(&*chart.series_1.unwrap().to_hex(), "#7AA2F7");
+//! assert_eq!(chart.grid, None);
+//!
+//! let slot: SlotId = "chart.series_1".parse().unwrap();
+//! assert_eq!(palette.get(slot), chart.series_1);
+//! assert!(palette.to_css().contains("--chart-series-1: #7AA2F7;"));
+//! ```
+//!
+//! Registration is process-wide: register groups before parsing themes that
+//! use them. Until then their sections are kept as untyped
+//! [extensions](crate::manifest::PaletteManifest::extensions).
+
+use std::sync::{Arc, PoisonError, RwLock};
+
+use crate::color::Color;
+use crate::error::PaletteError;
+use crate::manifest::{GroupSections, ManifestSection, SECTION_NAMES};
+use crate::palette::{Palette, resolve_color};
+
+/// A typed color group declared by a downstream crate.
+///
+/// Implement it with [`custom_color_group!`](crate::custom_color_group)
+/// rather than by hand, so the field list and struct stay in sync.
+pub trait ColorGroup: Sized {
+    /// TOML section name, also the CSS variable prefix and JSON key.
+    const SECTION: &'static str;
+    /// Slot names in declaration order.
+    const FIELDS: &'static [&'static str];
+
+    /// Build the group from a lookup of each slot's color.
+    fn from_slots(get: impl FnMut(&'static str) -> Option<Color>) -> Self;
+}
+
+/// Declare a [`ColorGroup`](crate::group::ColorGroup) struct with one
+/// `Option<Color>` field per slot.
+///
+/// ```
+/// palette_core::custom_color_group! {
+///     /// Data-visualization colors.
+///     pub struct ChartColors("chart") { series_1, series_2, grid }
+/// }
+/// ```
+#[macro_export]
+macro_rules! custom_color_group {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident($section:literal) { $($field:ident),+ $(,)? }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default, PartialEq, Eq)]
+        $vis struct $name {
+            $(
+                #[doc = concat!("`", stringify!($field), "` slot.")]
+                pub $field: ::core::option::Option<$crate::Color>,
+            )+
+        }
+
+        impl $crate::group::ColorGroup for $name {
+            const SECTION: &'static str = $section;
+            const FIELDS: &'static [&'static str] = &[$(stringify!($field)),+];
+
+            fn from_slots(
+                mut get: impl FnMut(&'static str) -> ::core::option::Option<$crate::Color>,
+            ) -> Self {
+                Self {
+                    $($field: get(stringify!($field)),)+
+                }
+            }
+        }
+    };
+}
+
+/// A registered group's section name and slot names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct GroupDef {
+    pub(crate) section: &'static str,
+    pub(crate) fields: &'static [&'static str],
+}
+
+/// Registered groups, sorted by section name.
+static GROUPS: RwLock<Vec<GroupDef>> = RwLock::new(Vec::new());
+
+/// Register `G` so its section takes part in parsing and export.
+///
+/// Registering the same group again is a no-op. Returns
+/// [`PaletteError::GroupConflict`] if the section name belongs to a
+/// built-in section or to a group registered with different fields.
+pub fn register<G: ColorGroup>() -> Result<(), PaletteError> {
+    let def = GroupDef {
+        section: G::SECTION,
+        fields: G::FIELDS,
+    };
+    let conflict = || PaletteError::GroupConflict {
+        section: Arc::from(def.section),
+    };
+    if SECTION_NAMES.contains(&def.section) {
+        return Err(conflict());
+    }
+    let mut groups = GROUPS.write().unwrap_or_else(PoisonError::into_inner);
+    match groups.binary_search_by(|g| g.section.cmp(def.section)) {
+        Ok(idx) if groups[idx] == def => Ok(()),
+        Ok(_) => Err(conflict()),
+        Err(idx) => {
+            groups.insert(idx, def);
+            Ok(())
+        }
+    }
+}
+
+/// The registered group named `section`.
+pub(crate) fn lookup(section: &str) -> Option<GroupDef> {
+    let groups = GROUPS.read().unwrap_or_else(PoisonError::into_inner);
+    groups
+        .binary_search_by(|g| g.section.cmp(section))
+        .ok()
+        .map(|idx| groups[idx])
+}
+
+/// Every registered group, sorted by section name.
+pub(crate) fn registered() -> Vec<GroupDef> {
+    GROUPS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+/// Resolved colors for one registered custom group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomGroup {
+    def: GroupDef,
+    slots: Box<[Option<Color>]>,
+}
+
+impl CustomGroup {
+    fn from_section(def: GroupDef, section: &ManifestSection) -> Result<Self, PaletteError> {
+        let slots = def
+            .fields
+            .iter()
+            .map(|field| resolve_color(section, def.section, field))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { def, slots })
+    }
+
+    /// TOML section name, e.g. `"chart"`.
+    pub fn section(&self) -> &'static str {
+        self.def.section
+    }
+
+    /// The color assigned to `field`, or `None` if unset or unknown.
+    pub fn get(&self, field: &str) -> Option<Color> {
+        self.def
+            .fields
+            .iter()
+            .position(|f| *f == field)
+            .and_then(|idx| self.slots[idx])
+    }
+
+    /// The color assigned to `field`, by reference.
+    pub(crate) fn slot(&self, field: &str) -> Option<&Color> {
+        self.def
+            .fields
+            .iter()
+            .position(|f| *f == field)
+            .and_then(|idx| self.slots[idx].as_ref())
+    }
+
+    /// Iterate over slots that have a color assigned.
+    pub fn populated_slots(&self) -> impl Iterator<Item = (&'static str, &Color)> {
+        self.def
+            .fields
+            .iter()
+            .zip(&self.slots)
+            .filter_map(|(field, color)| color.as_ref().map(|c| (*field, c)))
+    }
+
+    fn map(&self, f: &mut impl FnMut(Color) -> Color) -> Self {
+        Self {
+            def: self.def,
+            slots: self.slots.iter().map(|c| c.map(&mut *f)).collect(),
+        }
+    }
+
+    fn set(&mut self, field: &str, color: Option<Color>) {
+        if let Some(idx) = self.def.fields.iter().position(|f| *f == field) {
+            self.slots[idx] = color;
+        }
+    }
+
+    fn merge(&self, fallback: &Self) -> Self {
+        let slots = self
+            .def
+            .fields
+            .iter()
+            .zip(&self.slots)
+            .map(|(field, color)| color.or_else(|| fallback.get(field)))
+            .collect();
+        Self {
+            def: self.def,
+            slots,
+        }
+    }
+}
+
+/// A palette's custom groups, sorted by section name. Only groups the theme
+/// defines a section for are present.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CustomGroups(Arc<[CustomGroup]>);
+
+impl CustomGroups {
+    /// Resolve the sections of every registered group.
+    pub(crate) fn from_sections(sections: &GroupSections) -> Result<Self, PaletteError> {
+        if sections.is_empty() {
+            return Ok(Self::default());
+        }
+        registered()
+            .into_iter()
+            .filter_map(|def| {
+                sections
+                    .get(def.section)
+                    .map(|section| CustomGroup::from_section(def, section))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|groups| Self(groups.into()))
+    }
+
+    /// The group named `section`, if the theme defines it.
+    pub fn get(&self, section: &str) -> Option<&CustomGroup> {
+        self.0.iter().find(|g| g.section() == section)
+    }
+
+    /// Iterate over groups in section-name order.
+    pub fn iter(&self) -> impl Iterator<Item = &CustomGroup> {
+        self.0.iter()
+    }
+
+    /// Whether the palette has no custom groups.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Apply `f` to every populated slot.
+    pub(crate) fn map(&self, f: &mut impl FnMut(Color) -> Color) -> Self {
+        Self(self.0.iter().map(|group| group.map(f)).collect())
+    }
+
+    /// Assign `field` of the registered group `section`, adding the group
+    /// when the palette does not define it yet.
+    pub(crate) fn set(&mut self, section: &str, field: &str, color: Option<Color>) {
+        let mut groups = self.0.to_vec();
+        match groups.iter_mut().find(|g| g.section() == section) {
+            Some(group) => group.set(field, color),
+            None => {
+                let Some(def) = lookup(section) else {
+                    return;
+                };
+                let mut group = CustomGroup {
+                    def,
+                    slots: vec![None; def.fields.len()].into(),
+                };
+                group.set(field, color);
+                groups.push(group);
+                groups.sort_unstable_by_key(|group| group.section());
+            }
+        }
+        self.0 = groups.into();
+    }
+
+    /// Merge two palettes' groups slot by slot, preferring `self` values
+    /// over `fallback`.
+    pub(crate) fn merge(&self, fallback: &Self) -> Self {
+        let mut groups: Vec<CustomGroup> = self
+            .iter()
+            .map(|group| match fallback.get(group.section()) {
+                Some(fb) => group.merge(fb),
+                None => group.clone(),
+            })
+            .collect();
+        groups.extend(
+            fallback
+                .iter()
+                .filter(|group| self.get(group.section()).is_none())
+                .cloned(),
+        );
+        groups.sort_unstable_by_key(|group| group.section());
+        Self(groups.into())
+    }
+}
+
+/// Serializes as `{ section: { field: color | null } }`, which a flattened
+/// `Palette` places beside the built-in groups.
+#[cfg(feature = "snapshot")]
+impl serde::Serialize for CustomGroups {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        struct Slots<'a>(&'a CustomGroup);
+
+        impl serde::Serialize for Slots<'_> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_map(self.0.def.fields.iter().zip(&self.0.slots))
+            }
+        }
+
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for group in self.iter() {
+            map.serialize_entry(group.section(), &Slots(group))?;
+        }
+        map.end()
+    }
+}
+
+impl Palette {
+    /// The typed view of custom group `G`. Slots are `None` when the theme
+    /// leaves them unset or does not define the group at all.
+    pub fn group<G: ColorGroup>(&self) -> G {
+        let group = self.groups.get(G::SECTION);
+        G::from_slots(|field| group.and_then(|g| g.get(field)))
+    }
+}
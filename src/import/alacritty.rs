@@ -0,0 +1,216 @@
+//! Alacritty color scheme import.
+//!
+//! Reads the `[colors]` tables from an Alacritty config or theme file, in
+//! either the current TOML format or the legacy YAML format. Primary, cursor,
+//! selection, and search colors map onto the base and editor groups; the
+//! `normal` and `bright` tables fill the terminal ANSI group, which also seeds
+//! semantic colors and dimmed text.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use super::{
+    ANSI_FIELDS, Importer, fill_from_ansi, import_error, manifest_meta, parse_color, set_color,
+    style_from_background,
+};
+use crate::color::Color;
+use crate::error::PaletteError;
+use crate::manifest::PaletteManifest;
+
+const FORMAT: &str = "alacritty";
+
+/// Name used for the generated `[meta]`; Alacritty schemes carry no name, so
+/// callers importing several usually follow up with
+/// [`set_name`](super::set_name).
+pub const DEFAULT_NAME: &str = "Alacritty Theme";
+
+/// Color tables only Alacritty nests under `colors`, so a palette-core
+/// theme's own `[colors]` table is not mistaken for one.
+const TOML_TABLES: [&str; 3] = ["[colors.primary", "[colors.normal", "[colors.bright"];
+/// The same tables as legacy YAML keys, looked for only next to `colors:`.
+const YAML_TABLES: [&str; 3] = ["primary:", "normal:", "bright:"];
+
+/// [`Importer`] for Alacritty color schemes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Alacritty;
+
+impl Importer for Alacritty {
+    fn format(&self) -> &'static str {
+        FORMAT
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["toml", "yml", "yaml"]
+    }
+
+    fn detect(&self, contents: &str) -> bool {
+        TOML_TABLES.iter().any(|table| contents.contains(table))
+            || (contents.contains("colors:")
+                && YAML_TABLES.iter().any(|table| contents.contains(table)))
+    }
+
+    fn import(&self, contents: &str) -> Result<Box<[PaletteManifest]>, PaletteError> {
+        let manifest = match is_toml(contents) {
+            true => from_toml(contents)?,
+            false => from_yaml(contents)?,
+        };
+        Ok(Box::new([manifest]))
+    }
+
+    fn default_name(&self) -> Option<&'static str> {
+        Some(DEFAULT_NAME)
+    }
+}
+
+/// Whether `contents` is TOML rather than legacy YAML: a YAML config never
+/// starts a line with a `[table]` header.
+fn is_toml(contents: &str) -> bool {
+    contents
+        .lines()
+        .any(|line| line.trim_start().starts_with('['))
+}
+
+#[derive(Default, Deserialize)]
+struct RawConfig {
+    #[serde(default)]
+    colors: RawColors,
+}
+
+#[derive(Default, Deserialize)]
+struct RawColors {
+    #[serde(default)]
+    primary: RawTable,
+    #[serde(default)]
+    cursor: RawTable,
+    #[serde(default)]
+    selection: RawTable,
+    #[serde(default)]
+    search: RawSearch,
+    #[serde(default)]
+    normal: RawTable,
+    #[serde(default)]
+    bright: RawTable,
+}
+
+#[derive(Default, Deserialize)]
+struct RawSearch {
+    #[serde(default)]
+    matches: RawTable,
+    #[serde(default)]
+    focused_match: RawTable,
+}
+
+type RawTable = HashMap<String, RawValue>;
+
+/// A color entry: `"#RRGGBB"`, `"0xRRGGBB"`, an unquoted YAML hex integer,
+/// or a keyword such as `CellBackground`, which is skipped.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawValue {
+    Text(String),
+    Number(u32),
+    Other(serde::de::IgnoredAny),
+}
+
+impl RawValue {
+    fn color(&self) -> Option<Color> {
+        match self {
+            Self::Text(s) => parse_color(s, None),
+            Self::Number(n) => {
+                let [_, r, g, b] = n.to_be_bytes();
+                Some(Color::rgb(r, g, b))
+            }
+            Self::Other(_) => None,
+        }
+    }
+}
+
+fn get(table: &RawTable, key: &str) -> Option<Color> {
+    table.get(key).and_then(RawValue::color)
+}
+
+/// Convert an Alacritty TOML config or theme into a manifest.
+///
+/// Fails when the file sets none of the colors Alacritty themes use.
+pub fn from_toml(toml: &str) -> Result<PaletteManifest, PaletteError> {
+    let raw: RawConfig = toml::from_str(toml).map_err(|e| import_error(FORMAT, e))?;
+    convert(&raw.colors)
+}
+
+/// Convert a legacy Alacritty YAML config or theme into a manifest.
+///
+/// Fails when the file sets none of the colors Alacritty themes use.
+pub fn from_yaml(yaml: &str) -> Result<PaletteManifest, PaletteError> {
+    let raw: RawConfig = serde_yaml::from_str(yaml).map_err(|e| import_error(FORMAT, e))?;
+    convert(&raw.colors)
+}
+
+fn convert(colors: &RawColors) -> Result<PaletteManifest, PaletteError> {
+    let mut manifest = PaletteManifest::default();
+
+    let background = get(&colors.primary, "background");
+    let slots: [(&str, &str, Option<Color>); 12] = [
+        ("base", "background", background),
+        ("base", "foreground", get(&colors.primary, "foreground")),
+        (
+            "base",
+            "foreground_dark",
+            get(&colors.primary, "dim_foreground"),
+        ),
+        ("editor", "cursor", get(&colors.cursor, "cursor")),
+        ("editor", "cursor_text", get(&colors.cursor, "text")),
+        (
+            "editor",
+            "selection_bg",
+            get(&colors.selection, "background"),
+        ),
+        ("editor", "selection_fg", get(&colors.selection, "text")),
+        (
+            "editor",
+            "search_bg",
+            get(&colors.search.matches, "background"),
+        ),
+        (
+            "editor",
+            "search_fg",
+            get(&colors.search.matches, "foreground"),
+        ),
+        ("surface", "selection", get(&colors.selection, "background")),
+        (
+            "typography",
+            "selection_text",
+            get(&colors.selection, "text"),
+        ),
+        (
+            "surface",
+            "search",
+            get(&colors.search.focused_match, "background")
+                .or_else(|| get(&colors.search.matches, "background")),
+        ),
+    ];
+    for (section, field, color) in slots {
+        if let Some(color) = color {
+            set_color(&mut manifest, section, field, color);
+        }
+    }
+
+    let (normal, bright) = ANSI_FIELDS.split_at(8);
+    for (table, fields) in [(&colors.normal, normal), (&colors.bright, bright)] {
+        for (field, name) in fields.iter().zip(normal) {
+            if let Some(color) = get(table, name) {
+                set_color(&mut manifest, "terminal", field, color);
+            }
+        }
+    }
+    if manifest.base.is_empty() && manifest.editor.is_empty() && manifest.terminal.is_empty() {
+        return Err(import_error(FORMAT, "no Alacritty colors found"));
+    }
+    fill_from_ansi(&mut manifest);
+
+    manifest.meta = Some(manifest_meta(
+        DEFAULT_NAME,
+        style_from_background(background),
+    ));
+    Ok(manifest)
+}
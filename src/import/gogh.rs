@@ -0,0 +1,117 @@
+//! Gogh terminal scheme import.
+//!
+//! Reads Gogh's theme format — `name`, optional `variant`, `color_01`–
+//! `color_16`, `background`, `foreground`, and `cursor` — from either a
+//! single theme YAML file or the collection JSON (an array of themes). Each
+//! theme becomes its own manifest named after the theme, ready to register.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use super::{
+    ANSI_FIELDS, Importer, fill_from_ansi, import_error, manifest_meta, parse_color, set_color,
+    style_from_background,
+};
+use crate::error::PaletteError;
+use crate::manifest::PaletteManifest;
+
+const FORMAT: &str = "gogh";
+
+/// [`Importer`] for Gogh schemes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Gogh;
+
+impl Importer for Gogh {
+    fn format(&self) -> &'static str {
+        FORMAT
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["yml", "yaml", "json"]
+    }
+
+    fn detect(&self, contents: &str) -> bool {
+        contents.contains("color_01")
+    }
+
+    fn import(&self, contents: &str) -> Result<Box<[PaletteManifest]>, PaletteError> {
+        match contents.trim_start().starts_with(['[', '{']) {
+            true => from_json(contents),
+            false => from_yaml(contents),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawDocument {
+    List(Vec<RawTheme>),
+    Single(RawTheme),
+}
+
+#[derive(Deserialize)]
+struct RawTheme {
+    name: String,
+    #[serde(default)]
+    variant: Option<String>,
+    #[serde(flatten)]
+    colors: HashMap<String, serde_json::Value>,
+}
+
+/// Convert a Gogh theme YAML file (one theme or a list) into manifests.
+pub fn from_yaml(yaml: &str) -> Result<Box<[PaletteManifest]>, PaletteError> {
+    let raw: RawDocument = serde_yaml::from_str(yaml).map_err(|e| import_error(FORMAT, e))?;
+    Ok(convert_all(raw))
+}
+
+/// Convert Gogh theme JSON (one theme or the collection array) into
+/// manifests, in document order.
+pub fn from_json(json: &str) -> Result<Box<[PaletteManifest]>, PaletteError> {
+    let raw: RawDocument = serde_json::from_str(json).map_err(|e| import_error(FORMAT, e))?;
+    Ok(convert_all(raw))
+}
+
+fn convert_all(raw: RawDocument) -> Box<[PaletteManifest]> {
+    match raw {
+        RawDocument::List(themes) => themes.iter().map(convert).collect(),
+        RawDocument::Single(theme) => Box::new([convert(&theme)]),
+    }
+}
+
+fn convert(theme: &RawTheme) -> PaletteManifest {
+    let color = |key: &str| {
+        theme
+            .colors
+            .get(key)
+            .and_then(serde_json::Value::as_str)
+            .and_then(|v| parse_color(v, None))
+    };
+    let mut manifest = PaletteManifest::default();
+
+    let background = color("background");
+    let slots = [
+        ("base", "background", background),
+        ("base", "foreground", color("foreground")),
+        ("editor", "cursor", color("cursor")),
+    ];
+    for (section, field, value) in slots {
+        if let Some(value) = value {
+            set_color(&mut manifest, section, field, value);
+        }
+    }
+    for (i, field) in ANSI_FIELDS.iter().enumerate() {
+        if let Some(value) = color(&format!("color_{:02}", i + 1)) {
+            set_color(&mut manifest, "terminal", field, value);
+        }
+    }
+    fill_from_ansi(&mut manifest);
+
+    let style = match theme.variant.as_deref() {
+        Some("light") => "light",
+        Some("dark") => "dark",
+        _ => style_from_background(background),
+    };
+    manifest.meta = Some(manifest_meta(&theme.name, style));
+    manifest
+}
@@ -0,0 +1,511 @@
+//! Importers converting third-party theme formats into manifests.
+//!
+//! Each submodule parses one format and returns a
+//! [`PaletteManifest`](crate::manifest::PaletteManifest) with a generated
+//! `[meta]` section, ready for
+//! [`Palette::from_manifest`](crate::Palette::from_manifest) or
+//! [`Registry::add_manifest`](crate::Registry::add_manifest). Mapping is
+//! best-effort: slots the source format has no equivalent for stay empty and
+//! fall back to defaults on resolve.
+//!
+//! ```
+//! use palette_core::Palette;
+//! use palette_core::import::vscode;
+//!
+//! let json = r##"{
+//!     "name": "Example",
+//!     "type": "dark",
+//!     "colors": { "editor.background": "#1e1e2e", "editor.foreground": "#cdd6f4" },
+//!     "tokenColors": [{ "scope": "comment", "settings": { "foreground": "#6c7086" } }]
+//! }"##;
+//! let manifest = vscode::from_json(json).unwrap();
+//! let palette = Palette::from_manifest(&manifest).unwrap();
+//! assert_eq!(palette.base.background.unwrap().to_hex().as_ref(), "#1E1E2E");
+//! ```
+//!
+//! When the format is not known up front,
+//! [`detect_and_import`](crate::import::detect_and_import) sniffs it from the
+//! contents and file extension, and
+//! [`Registry::import_file`](crate::Registry::import_file) registers the
+//! result directly.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::color::Color;
+use crate::error::PaletteError;
+use crate::manifest::{ManifestMeta, ManifestSection, PaletteManifest, slot_key};
+use crate::scopes::SCOPE_MAP;
+
+/// Alacritty color schemes (TOML and legacy YAML).
+pub mod alacritty;
+/// Gogh terminal schemes (YAML theme files and collection JSON).
+pub mod gogh;
+/// pywal `colors.json` wallpaper palettes.
+pub mod pywal;
+/// TextMate / Sublime Text `.tmTheme` property lists.
+pub mod tmtheme;
+/// Visual Studio Code color theme JSON.
+pub mod vscode;
+/// Windows Terminal color schemes, fragments, and settings files.
+pub mod windows_terminal;
+
+/// A named import format that converts file contents into manifests.
+pub trait Importer {
+    /// Stable identifier used for lookup and in errors (e.g. `"vscode"`).
+    fn format(&self) -> &'static str;
+
+    /// Lowercase file extensions this format usually ships with, without the
+    /// leading dot.
+    fn extensions(&self) -> &'static [&'static str];
+
+    /// Cheap content sniff: `true` if `contents` looks like this format.
+    fn detect(&self, contents: &str) -> bool;
+
+    /// Convert `contents` into one manifest per theme it contains.
+    fn import(&self, contents: &str) -> Result<Box<[PaletteManifest]>, PaletteError>;
+
+    /// Name given to themes when the file does not carry one, if the format
+    /// falls back to a fixed default. Path-based imports replace it with the
+    /// file stem.
+    fn default_name(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+/// All built-in importers, in detection order.
+pub fn importers() -> &'static [&'static dyn Importer] {
+    &[
+        &tmtheme::TmTheme,
+        &pywal::Pywal,
+        &gogh::Gogh,
+        &windows_terminal::WindowsTerminal,
+        &vscode::VsCode,
+        &alacritty::Alacritty,
+    ]
+}
+
+/// Look up a built-in importer by its [`format`](Importer::format) name.
+pub fn find_importer(format: &str) -> Option<&'static dyn Importer> {
+    importers().iter().copied().find(|i| i.format() == format)
+}
+
+/// Pick the importer for `contents`.
+///
+/// Importers whose [`extensions`](Importer::extensions) include `extension`
+/// are sniffed first; if none of them match, every importer is tried.
+pub fn detect_format(contents: &str, extension: Option<&str>) -> Option<&'static dyn Importer> {
+    let ext = extension.map(str::to_ascii_lowercase);
+    let by_extension = importers().iter().copied().find(|i| {
+        ext.as_deref().is_some_and(|e| i.extensions().contains(&e)) && i.detect(contents)
+    });
+    by_extension.or_else(|| importers().iter().copied().find(|i| i.detect(contents)))
+}
+
+/// Decode UTF-8 contents (dropping a BOM) and pick their importer.
+fn sniff<'a>(
+    bytes: &'a [u8],
+    extension: Option<&str>,
+) -> Result<(&'static dyn Importer, &'a str), PaletteError> {
+    let contents = std::str::from_utf8(bytes).map_err(|e| import_error("unknown", e))?;
+    let contents = contents.strip_prefix('\u{feff}').unwrap_or(contents);
+    let importer = detect_format(contents, extension)
+        .ok_or_else(|| import_error("unknown", "unrecognized theme format"))?;
+    Ok((importer, contents))
+}
+
+/// Detect the format of `bytes` and import every theme it contains.
+///
+/// `extension` (without the dot) narrows detection when known.
+pub fn detect_and_import(
+    bytes: &[u8],
+    extension: Option<&str>,
+) -> Result<Box<[PaletteManifest]>, PaletteError> {
+    let (importer, contents) = sniff(bytes, extension)?;
+    importer.import(contents)
+}
+
+/// Read `path`, detect its format, and import every theme it contains.
+///
+/// Themes that fall back to the format's default name are renamed after the
+/// file stem, so importing several nameless files yields distinct IDs.
+pub fn import_path(path: &Path) -> Result<Box<[PaletteManifest]>, PaletteError> {
+    let bytes = std::fs::read(path).map_err(|source| PaletteError::Io {
+        path: Arc::from(path.to_string_lossy().as_ref()),
+        source,
+    })?;
+    let extension = path.extension().and_then(|e| e.to_str());
+    let (importer, contents) = sniff(&bytes, extension)?;
+
+    let mut manifests = importer.import(contents)?;
+    let stem = path.file_stem().and_then(|s| s.to_str());
+    if let (Some(default), Some(stem)) = (importer.default_name(), stem) {
+        for manifest in manifests.iter_mut() {
+            let is_default = manifest
+                .meta
+                .as_ref()
+                .is_some_and(|m| m.name.as_ref() == default);
+            if is_default {
+                set_name(manifest, stem);
+            }
+        }
+    }
+    Ok(manifests)
+}
+
+/// Rename an imported manifest, regenerating its preset ID from `name`.
+///
+/// Formats without an embedded name import under a fixed default; call this
+/// with e.g. the file stem before registering several of them.
+pub fn set_name(manifest: &mut PaletteManifest, name: &str) {
+    let style = manifest
+        .meta
+        .as_ref()
+        .map_or_else(|| Arc::from("dark"), |m| Arc::clone(&m.style));
+    manifest.meta = Some(manifest_meta(name, &style));
+}
+
+/// Build an [`PaletteError::Import`] for `format`.
+pub(crate) fn import_error(format: &str, message: impl std::fmt::Display) -> PaletteError {
+    PaletteError::Import {
+        format: Arc::from(format),
+        message: Arc::from(message.to_string()),
+    }
+}
+
+/// Parse `#RGB`, `#RGBA`, `#RRGGBB`, or `#RRGGBBAA` (`0x` also accepted in
+/// place of `#`), or a CSS `rgb()` / `hsl()` function.
+///
+/// Translucent colors are composited over `backdrop`; without one the alpha
+/// channel is kept.
+pub(crate) fn parse_color(value: &str, backdrop: Option<Color>) -> Option<Color> {
+    let color = match value.trim_end().ends_with(')') {
+        true => Color::parse(value).ok()?,
+        false => parse_hex(value)?,
+    };
+    match backdrop {
+        Some(bg) if !color.is_opaque() => Some(color.blend(bg, color.alpha_f64())),
+        _ => Some(color),
+    }
+}
+
+/// The hex forms accepted by [`parse_color`].
+fn parse_hex(value: &str) -> Option<Color> {
+    let value = value.trim();
+    let digits = value
+        .strip_prefix('#')
+        .or_else(|| value.strip_prefix("0x"))
+        .or_else(|| value.strip_prefix("0X"))?;
+    if !digits.is_ascii() {
+        return None;
+    }
+    let channel = |s: &str| u8::from_str_radix(s, 16).ok();
+    let short = |i: usize| channel(&digits[i..=i]).map(|v| v * 17);
+    let (r, g, b, a) = match digits.len() {
+        3 => (short(0)?, short(1)?, short(2)?, 255),
+        4 => (short(0)?, short(1)?, short(2)?, short(3)?),
+        6 => (
+            channel(&digits[0..2])?,
+            channel(&digits[2..4])?,
+            channel(&digits[4..6])?,
+            255,
+        ),
+        8 => (
+            channel(&digits[0..2])?,
+            channel(&digits[2..4])?,
+            channel(&digits[4..6])?,
+            channel(&digits[6..8])?,
+        ),
+        _ => return None,
+    };
+    Some(Color::rgba(r, g, b, a))
+}
+
+/// Lowercase `name`, collapsing runs of non-alphanumerics into `_`.
+pub(crate) fn preset_id(name: &str) -> Arc<str> {
+    let mut id = String::with_capacity(name.len());
+    for ch in name.chars() {
+        match ch.is_alphanumeric() {
+            true => id.extend(ch.to_lowercase()),
+            false => match id.ends_with('_') || id.is_empty() {
+                true => {}
+                false => id.push('_'),
+            },
+        }
+    }
+    let trimmed = id.trim_end_matches('_');
+    match trimmed.is_empty() {
+        true => Arc::from("imported"),
+        false => Arc::from(trimmed),
+    }
+}
+
+/// `"light"` or `"dark"` from a background color's luminance.
+pub(crate) fn style_from_background(background: Option<Color>) -> &'static str {
+    match background.is_some_and(|bg| bg.is_light()) {
+        true => "light",
+        false => "dark",
+    }
+}
+
+/// Generated `[meta]` for an imported theme with no inheritance.
+pub(crate) fn manifest_meta(name: &str, style: &str) -> Arc<ManifestMeta> {
+    Arc::new(ManifestMeta {
+        name: Arc::from(name),
+        preset_id: preset_id(name),
+        schema_version: Arc::from(crate::schema::CURRENT_VERSION.to_string()),
+        style: Arc::from(style),
+        kind: Arc::from("imported"),
+        inherits: Box::default(),
+        inherit_sections: None,
+        upstream_repo: None,
+        author: None,
+        license: None,
+        version: None,
+        description: None,
+        tags: Box::default(),
+    })
+}
+
+/// Mutable access to a manifest color section by name.
+pub(crate) fn section_mut<'a>(
+    manifest: &'a mut PaletteManifest,
+    section: &str,
+) -> Option<&'a mut ManifestSection> {
+    match section {
+        "base" => Some(&mut manifest.base),
+        "semantic" => Some(&mut manifest.semantic),
+        "diff" => Some(&mut manifest.diff),
+        "surface" => Some(&mut manifest.surface),
+        "typography" => Some(&mut manifest.typography),
+        "syntax" => Some(&mut manifest.syntax),
+        "editor" => Some(&mut manifest.editor),
+        "terminal" => Some(&mut manifest.terminal),
+        "syntax_style" => Some(&mut manifest.syntax_style),
+        _ => None,
+    }
+}
+
+/// Store `color` as `#RRGGBB` under `section.field`.
+pub(crate) fn set_color(manifest: &mut PaletteManifest, section: &str, field: &str, color: Color) {
+    if let Some(s) = section_mut(manifest, section) {
+        s.insert(slot_key(field), Arc::from(color.to_hex()));
+    }
+}
+
+/// Remove `//` and `/* */` comments and trailing commas outside strings, as
+/// accepted by VS Code and Windows Terminal settings files.
+pub(crate) fn strip_jsonc(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(ch) = chars.next() {
+        match (in_string, ch) {
+            (true, '\\') => {
+                out.push(ch);
+                if let Some(next) = chars.next() {
+                    out.push(next);
+                }
+            }
+            (_, '"') => {
+                in_string = !in_string;
+                out.push(ch);
+            }
+            (false, '/') if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            (false, '/') if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = '\0';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            (false, ']' | '}') => {
+                let trimmed = out.trim_end().len();
+                if out[..trimmed].ends_with(',') {
+                    out.truncate(trimmed - 1);
+                }
+                out.push(ch);
+            }
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// ANSI slot names in `color0`–`color15` order.
+pub(crate) const ANSI_FIELDS: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "white",
+    "bright_black",
+    "bright_red",
+    "bright_green",
+    "bright_yellow",
+    "bright_blue",
+    "bright_magenta",
+    "bright_cyan",
+    "bright_white",
+];
+
+/// Derive semantic colors and dimmed text from the ANSI table for
+/// terminal-only formats. Slots the source already set are kept.
+pub(crate) fn fill_from_ansi(manifest: &mut PaletteManifest) {
+    const DERIVED: &[(&str, &str, &str)] = &[
+        ("semantic", "success", "green"),
+        ("semantic", "warning", "yellow"),
+        ("semantic", "error", "red"),
+        ("semantic", "info", "blue"),
+        ("semantic", "hint", "cyan"),
+        ("base", "foreground_dark", "bright_black"),
+        ("typography", "comment", "bright_black"),
+        ("typography", "line_number", "bright_black"),
+    ];
+    for &(section, field, ansi) in DERIVED {
+        let Some(value) = manifest.terminal.get(ansi).cloned() else {
+            continue;
+        };
+        if let Some(s) = section_mut(manifest, section) {
+            s.entry(slot_key(field)).or_insert(value);
+        }
+    }
+}
+
+/// One scope rule from a TextMate-style theme (`tokenColors`, `.tmTheme`).
+#[derive(Debug, Clone)]
+pub(crate) struct ScopeRule {
+    /// A single scope selector (comma-separated lists are split beforehand).
+    pub(crate) scope: Box<str>,
+    pub(crate) foreground: Option<Color>,
+    /// Normalized `syntax_style` value (`"bold,italic"`), if the rule sets one.
+    pub(crate) font_style: Option<Box<str>>,
+}
+
+impl ScopeRule {
+    /// Split a comma-separated selector list into one rule per selector.
+    pub(crate) fn expand(
+        selectors: &str,
+        foreground: Option<Color>,
+        font_style: Option<&str>,
+    ) -> Vec<Self> {
+        let font_style = font_style.map(normalize_font_style);
+        selectors
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(move |scope| Self {
+                scope: Box::from(scope),
+                foreground,
+                font_style: font_style.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Convert a space-separated TextMate `fontStyle` into `syntax_style` form,
+/// keeping only bold, italic, and underline.
+fn normalize_font_style(font_style: &str) -> Box<str> {
+    let parts: Vec<&str> = font_style
+        .split_whitespace()
+        .filter(|p| matches!(*p, "bold" | "italic" | "underline"))
+        .collect();
+    parts.join(",").into_boxed_str()
+}
+
+/// Length of `rule` when it selects `target` by TextMate prefix semantics
+/// (`keyword` selects `keyword.control.import`).
+fn prefix_match(rule: &str, target: &str) -> Option<usize> {
+    match target.strip_prefix(rule) {
+        Some(rest) if rest.is_empty() || rest.starts_with('.') => Some(rule.len()),
+        _ => None,
+    }
+}
+
+/// The index of, and value `pick` extracts from, the most specific rule
+/// selecting any of `targets`, trying targets in order. Later rules win ties,
+/// matching how editors apply theme rules.
+fn best_rule<T>(
+    rules: &[ScopeRule],
+    targets: &[&str],
+    pick: impl Fn(&ScopeRule) -> Option<T>,
+) -> Option<(usize, T)> {
+    targets
+        .iter()
+        .filter(|t| !t.contains(' '))
+        .find_map(|target| {
+            let mut best: Option<(usize, usize, T)> = None;
+            for (index, rule) in rules.iter().enumerate() {
+                let (Some(len), Some(value)) = (prefix_match(&rule.scope, target), pick(rule))
+                else {
+                    continue;
+                };
+                match best.as_ref().is_some_and(|(b, _, _)| *b > len) {
+                    true => {}
+                    false => best = Some((len, index, value)),
+                }
+            }
+            best.map(|(_, index, value)| (index, value))
+        })
+}
+
+/// Fill `[syntax]` and `[syntax_style]` from scope rules via the shared
+/// syntax-field scope table.
+///
+/// Returns the selectors of rules that did not end up on any slot, in rule
+/// order without duplicates.
+pub(crate) fn apply_scope_rules(
+    manifest: &mut PaletteManifest,
+    rules: &[ScopeRule],
+) -> Box<[Box<str>]> {
+    let mut used = vec![false; rules.len()];
+    for &(field, targets) in SCOPE_MAP {
+        if let Some((index, color)) = best_rule(rules, targets, |r| r.foreground) {
+            used[index] = true;
+            set_color(manifest, "syntax", field, color);
+        }
+        match best_rule(rules, targets, |r| r.font_style.clone()) {
+            Some((index, style)) if !style.is_empty() => {
+                used[index] = true;
+                manifest
+                    .syntax_style
+                    .insert(slot_key(field), Arc::from(style));
+            }
+            _ => {}
+        }
+    }
+
+    if let Some((index, color)) = best_rule(rules, &["markup.heading"], |r| r.foreground) {
+        used[index] = true;
+        set_color(manifest, "typography", "title", color);
+    }
+    if let Some(comment) = manifest.syntax.get("comments").cloned() {
+        manifest
+            .typography
+            .entry(Arc::from("comment"))
+            .or_insert(comment);
+    }
+
+    let mut unmapped: Vec<Box<str>> = Vec::new();
+    for (rule, used) in rules.iter().zip(used) {
+        match used || unmapped.contains(&rule.scope) {
+            true => {}
+            false => unmapped.push(rule.scope.clone()),
+        }
+    }
+    unmapped.into_boxed_slice()
+}
@@ -0,0 +1,89 @@
+//! pywal `colors.json` import.
+//!
+//! Reads the JSON pywal writes to `~/.cache/wal/colors.json`: `special`
+//! background, foreground, and cursor map onto the base and editor groups,
+//! and `color0`–`color15` fill the terminal ANSI group, which also seeds
+//! semantic colors and dimmed text.
+//!
+//! The generated preset ID is always `pywal`, so an app can register the
+//! current wallpaper palette under a stable name, let user themes inherit
+//! from it, and re-import whenever `wal` runs.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use super::{
+    ANSI_FIELDS, Importer, fill_from_ansi, import_error, manifest_meta, parse_color, set_color,
+    style_from_background,
+};
+use crate::error::PaletteError;
+use crate::manifest::PaletteManifest;
+
+const FORMAT: &str = "pywal";
+
+/// Name of the generated `[meta]` (preset ID `pywal`).
+pub const DEFAULT_NAME: &str = "pywal";
+
+/// [`Importer`] for pywal `colors.json`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Pywal;
+
+impl Importer for Pywal {
+    fn format(&self) -> &'static str {
+        FORMAT
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["json"]
+    }
+
+    fn detect(&self, contents: &str) -> bool {
+        contents.contains("\"special\"") && contents.contains("\"color0\"")
+    }
+
+    fn import(&self, contents: &str) -> Result<Box<[PaletteManifest]>, PaletteError> {
+        from_json(contents).map(|m| Box::new([m]) as Box<[_]>)
+    }
+}
+
+#[derive(Deserialize)]
+struct RawColors {
+    special: HashMap<String, String>,
+    colors: HashMap<String, String>,
+}
+
+/// Convert a pywal `colors.json` document into a manifest.
+pub fn from_json(json: &str) -> Result<PaletteManifest, PaletteError> {
+    let raw: RawColors = serde_json::from_str(json).map_err(|e| import_error(FORMAT, e))?;
+    let special = |key: &str| raw.special.get(key).and_then(|v| parse_color(v, None));
+    let mut manifest = PaletteManifest::default();
+
+    let background = special("background");
+    let slots = [
+        ("base", "background", background),
+        ("base", "foreground", special("foreground")),
+        ("editor", "cursor", special("cursor")),
+    ];
+    for (section, field, color) in slots {
+        if let Some(color) = color {
+            set_color(&mut manifest, section, field, color);
+        }
+    }
+    for (i, field) in ANSI_FIELDS.iter().enumerate() {
+        let color = raw
+            .colors
+            .get(&format!("color{i}"))
+            .and_then(|v| parse_color(v, None));
+        if let Some(color) = color {
+            set_color(&mut manifest, "terminal", field, color);
+        }
+    }
+    fill_from_ansi(&mut manifest);
+
+    manifest.meta = Some(manifest_meta(
+        DEFAULT_NAME,
+        style_from_background(background),
+    ));
+    Ok(manifest)
+}
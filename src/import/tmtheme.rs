@@ -0,0 +1,146 @@
+//! TextMate / Sublime Text `.tmTheme` import.
+//!
+//! Parses the XML property list: the unscoped first `settings` entry maps
+//! onto base, editor, and typography slots, and scoped entries map onto
+//! syntax fields using TextMate prefix matching. Mapping is best-effort, so
+//! [`unmapped_scopes`](crate::import::tmtheme::TmThemeImport::unmapped_scopes)
+//! reports the selectors that did not land on any slot — useful for spotting
+//! what a port lost.
+//!
+//! Translucent colors (`#RRGGBBAA`) are composited over the theme background.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use super::{
+    Importer, ScopeRule, apply_scope_rules, import_error, manifest_meta, parse_color, set_color,
+    style_from_background,
+};
+use crate::error::PaletteError;
+use crate::manifest::PaletteManifest;
+
+const FORMAT: &str = "tmTheme";
+
+/// Name used when the plist has no `name` key.
+pub const DEFAULT_NAME: &str = "TextMate Theme";
+
+/// [`Importer`] for `.tmTheme` property lists.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TmTheme;
+
+impl Importer for TmTheme {
+    fn format(&self) -> &'static str {
+        FORMAT
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["tmtheme", "xml", "plist"]
+    }
+
+    fn detect(&self, contents: &str) -> bool {
+        contents.contains("<plist")
+    }
+
+    fn import(&self, contents: &str) -> Result<Box<[PaletteManifest]>, PaletteError> {
+        from_xml(contents).map(|i| Box::new([i.manifest]) as Box<[_]>)
+    }
+
+    fn default_name(&self) -> Option<&'static str> {
+        Some(DEFAULT_NAME)
+    }
+}
+
+/// `(section, field, global setting key)` for the unscoped settings entry.
+const GLOBAL_MAP: &[(&str, &str, &str)] = &[
+    ("base", "background", "background"),
+    ("base", "foreground", "foreground"),
+    ("base", "background_highlight", "lineHighlight"),
+    ("base", "border", "guide"),
+    ("base", "border_highlight", "activeGuide"),
+    ("editor", "cursor", "caret"),
+    ("editor", "selection_bg", "selection"),
+    ("editor", "selection_fg", "selectionForeground"),
+    ("editor", "search_bg", "findHighlight"),
+    ("editor", "search_fg", "findHighlightForeground"),
+    ("editor", "match_paren", "bracketsForeground"),
+    ("surface", "selection", "selection"),
+    ("surface", "search", "findHighlight"),
+    ("typography", "gutter", "gutter"),
+    ("typography", "line_number", "gutterForeground"),
+    ("typography", "selection_text", "selectionForeground"),
+    ("semantic", "error", "misspelling"),
+];
+
+/// A converted `.tmTheme` plus the scope selectors it could not map.
+#[derive(Debug, Clone)]
+pub struct TmThemeImport {
+    /// The converted manifest.
+    pub manifest: PaletteManifest,
+    /// Scope selectors from the theme that did not map onto any syntax slot,
+    /// in theme order without duplicates.
+    pub unmapped_scopes: Box<[Box<str>]>,
+}
+
+#[derive(Deserialize)]
+struct RawTheme {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    settings: Vec<RawItem>,
+}
+
+#[derive(Deserialize)]
+struct RawItem {
+    #[serde(default)]
+    scope: Option<String>,
+    #[serde(default)]
+    settings: HashMap<String, plist::Value>,
+}
+
+impl RawItem {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.settings.get(key).and_then(plist::Value::as_string)
+    }
+}
+
+/// Convert a `.tmTheme` XML plist into a manifest and an unmapped-scope
+/// report.
+pub fn from_xml(xml: &str) -> Result<TmThemeImport, PaletteError> {
+    let raw: RawTheme = plist::from_bytes(xml.as_bytes()).map_err(|e| import_error(FORMAT, e))?;
+
+    let globals = raw.settings.iter().find(|item| item.scope.is_none());
+    let background = globals
+        .and_then(|g| g.get("background"))
+        .and_then(|v| parse_color(v, None));
+
+    let mut manifest = PaletteManifest::default();
+    if let Some(globals) = globals {
+        for &(section, field, key) in GLOBAL_MAP {
+            if let Some(color) = globals.get(key).and_then(|v| parse_color(v, background)) {
+                set_color(&mut manifest, section, field, color);
+            }
+        }
+    }
+
+    let rules: Vec<ScopeRule> = raw
+        .settings
+        .iter()
+        .filter_map(|item| {
+            let scope = item.scope.as_deref()?;
+            let foreground = item
+                .get("foreground")
+                .and_then(|v| parse_color(v, background));
+            Some(ScopeRule::expand(scope, foreground, item.get("fontStyle")))
+        })
+        .flatten()
+        .collect();
+    let unmapped_scopes = apply_scope_rules(&mut manifest, &rules);
+
+    let name = raw.name.as_deref().unwrap_or(DEFAULT_NAME);
+    manifest.meta = Some(manifest_meta(name, style_from_background(background)));
+    Ok(TmThemeImport {
+        manifest,
+        unmapped_scopes,
+    })
+}
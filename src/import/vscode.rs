@@ -0,0 +1,354 @@
+//! Visual Studio Code color theme import.
+//!
+//! Reads the theme JSON an extension ships under `contributes.themes`
+//! (comments and trailing commas allowed, as VS Code accepts them). Workbench
+//! `colors` map onto the base, semantic, diff, surface, typography, editor,
+//! and terminal groups; `tokenColors` scope rules map onto syntax fields using
+//! TextMate prefix matching, so a rule for `keyword` also fills
+//! `keywords_control` unless a more specific rule exists.
+//!
+//! Translucent workbench colors (`#RRGGBBAA`) are composited over
+//! `editor.background`. `include` references to other theme files and
+//! `semanticTokenColors` are not followed.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use super::{
+    Importer, ScopeRule, apply_scope_rules, import_error, manifest_meta, parse_color, set_color,
+    strip_jsonc, style_from_background,
+};
+use crate::color::Color;
+use crate::error::PaletteError;
+use crate::manifest::PaletteManifest;
+
+const FORMAT: &str = "vscode";
+
+/// Name used when the theme JSON has no `name` key.
+pub const DEFAULT_NAME: &str = "VS Code Theme";
+
+/// [`Importer`] for VS Code color theme JSON.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VsCode;
+
+impl Importer for VsCode {
+    fn format(&self) -> &'static str {
+        FORMAT
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["json", "jsonc"]
+    }
+
+    fn detect(&self, contents: &str) -> bool {
+        contents.contains("\"tokenColors\"") || contents.contains("\"editor.background\"")
+    }
+
+    fn import(&self, contents: &str) -> Result<Box<[PaletteManifest]>, PaletteError> {
+        from_json(contents).map(|m| Box::new([m]) as Box<[_]>)
+    }
+
+    fn default_name(&self) -> Option<&'static str> {
+        Some(DEFAULT_NAME)
+    }
+}
+
+/// `(section, field, workbench keys)` — the first key present wins.
+const COLOR_MAP: &[(&str, &str, &[&str])] = &[
+    ("base", "background", &["editor.background"]),
+    (
+        "base",
+        "background_dark",
+        &["sideBar.background", "activityBar.background"],
+    ),
+    (
+        "base",
+        "background_highlight",
+        &["editor.lineHighlightBackground", "list.hoverBackground"],
+    ),
+    ("base", "foreground", &["editor.foreground", "foreground"]),
+    (
+        "base",
+        "foreground_dark",
+        &["descriptionForeground", "editorLineNumber.foreground"],
+    ),
+    (
+        "base",
+        "border",
+        &["editorGroup.border", "panel.border", "sideBar.border"],
+    ),
+    ("base", "border_highlight", &["focusBorder"]),
+    (
+        "semantic",
+        "success",
+        &[
+            "gitDecoration.addedResourceForeground",
+            "terminal.ansiGreen",
+        ],
+    ),
+    (
+        "semantic",
+        "warning",
+        &["editorWarning.foreground", "list.warningForeground"],
+    ),
+    (
+        "semantic",
+        "error",
+        &["editorError.foreground", "errorForeground"],
+    ),
+    ("semantic", "info", &["editorInfo.foreground"]),
+    ("semantic", "hint", &["editorHint.foreground"]),
+    (
+        "diff",
+        "added",
+        &[
+            "gitDecoration.addedResourceForeground",
+            "editorGutter.addedBackground",
+        ],
+    ),
+    (
+        "diff",
+        "added_bg",
+        &[
+            "diffEditor.insertedLineBackground",
+            "diffEditor.insertedTextBackground",
+        ],
+    ),
+    (
+        "diff",
+        "modified",
+        &[
+            "gitDecoration.modifiedResourceForeground",
+            "editorGutter.modifiedBackground",
+        ],
+    ),
+    (
+        "diff",
+        "removed",
+        &[
+            "gitDecoration.deletedResourceForeground",
+            "editorGutter.deletedBackground",
+        ],
+    ),
+    (
+        "diff",
+        "removed_bg",
+        &[
+            "diffEditor.removedLineBackground",
+            "diffEditor.removedTextBackground",
+        ],
+    ),
+    (
+        "diff",
+        "ignored",
+        &["gitDecoration.ignoredResourceForeground"],
+    ),
+    ("surface", "menu", &["menu.background"]),
+    ("surface", "sidebar", &["sideBar.background"]),
+    ("surface", "statusline", &["statusBar.background"]),
+    ("surface", "float", &["editorWidget.background"]),
+    (
+        "surface",
+        "popup",
+        &[
+            "editorSuggestWidget.background",
+            "editorHoverWidget.background",
+        ],
+    ),
+    ("surface", "overlay", &["quickInput.background"]),
+    ("surface", "highlight", &["list.hoverBackground"]),
+    ("surface", "selection", &["list.activeSelectionBackground"]),
+    ("surface", "focus", &["list.focusBackground"]),
+    (
+        "surface",
+        "search",
+        &[
+            "editor.findMatchHighlightBackground",
+            "editor.findMatchBackground",
+        ],
+    ),
+    ("typography", "gutter", &["editorGutter.background"]),
+    (
+        "typography",
+        "line_number",
+        &["editorLineNumber.foreground"],
+    ),
+    (
+        "typography",
+        "selection_text",
+        &["editor.selectionForeground"],
+    ),
+    ("typography", "link", &["textLink.foreground"]),
+    ("editor", "cursor", &["editorCursor.foreground"]),
+    ("editor", "cursor_text", &["editorCursor.background"]),
+    (
+        "editor",
+        "match_paren",
+        &["editorBracketMatch.border", "editorBracketMatch.background"],
+    ),
+    ("editor", "selection_bg", &["editor.selectionBackground"]),
+    ("editor", "selection_fg", &["editor.selectionForeground"]),
+    ("editor", "inlay_hint_bg", &["editorInlayHint.background"]),
+    ("editor", "inlay_hint_fg", &["editorInlayHint.foreground"]),
+    ("editor", "search_bg", &["editor.findMatchBackground"]),
+    ("editor", "diagnostic_error", &["editorError.foreground"]),
+    ("editor", "diagnostic_warn", &["editorWarning.foreground"]),
+    ("editor", "diagnostic_info", &["editorInfo.foreground"]),
+    ("editor", "diagnostic_hint", &["editorHint.foreground"]),
+    (
+        "editor",
+        "diagnostic_underline_error",
+        &["editorError.foreground"],
+    ),
+    (
+        "editor",
+        "diagnostic_underline_warn",
+        &["editorWarning.foreground"],
+    ),
+    (
+        "editor",
+        "diagnostic_underline_info",
+        &["editorInfo.foreground"],
+    ),
+    (
+        "editor",
+        "diagnostic_underline_hint",
+        &["editorHint.foreground"],
+    ),
+    ("terminal", "black", &["terminal.ansiBlack"]),
+    ("terminal", "red", &["terminal.ansiRed"]),
+    ("terminal", "green", &["terminal.ansiGreen"]),
+    ("terminal", "yellow", &["terminal.ansiYellow"]),
+    ("terminal", "blue", &["terminal.ansiBlue"]),
+    ("terminal", "magenta", &["terminal.ansiMagenta"]),
+    ("terminal", "cyan", &["terminal.ansiCyan"]),
+    ("terminal", "white", &["terminal.ansiWhite"]),
+    ("terminal", "bright_black", &["terminal.ansiBrightBlack"]),
+    ("terminal", "bright_red", &["terminal.ansiBrightRed"]),
+    ("terminal", "bright_green", &["terminal.ansiBrightGreen"]),
+    ("terminal", "bright_yellow", &["terminal.ansiBrightYellow"]),
+    ("terminal", "bright_blue", &["terminal.ansiBrightBlue"]),
+    (
+        "terminal",
+        "bright_magenta",
+        &["terminal.ansiBrightMagenta"],
+    ),
+    ("terminal", "bright_cyan", &["terminal.ansiBrightCyan"]),
+    ("terminal", "bright_white", &["terminal.ansiBrightWhite"]),
+];
+
+#[derive(Deserialize)]
+struct RawTheme {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default, rename = "type")]
+    kind: Option<String>,
+    #[serde(default)]
+    colors: HashMap<String, Option<String>>,
+    #[serde(default, rename = "tokenColors")]
+    token_colors: RawTokenColors,
+}
+
+/// `tokenColors` is either an inline rule list or a path to a `.tmTheme`,
+/// which is not followed.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawTokenColors {
+    Rules(Vec<RawTokenRule>),
+    Path(serde::de::IgnoredAny),
+}
+
+impl Default for RawTokenColors {
+    fn default() -> Self {
+        Self::Rules(Vec::new())
+    }
+}
+
+#[derive(Deserialize)]
+struct RawTokenRule {
+    #[serde(default)]
+    scope: Option<RawScope>,
+    #[serde(default)]
+    settings: RawTokenSettings,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawScope {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(Default, Deserialize)]
+struct RawTokenSettings {
+    #[serde(default)]
+    foreground: Option<String>,
+    #[serde(default, rename = "fontStyle")]
+    font_style: Option<String>,
+}
+
+/// Convert a VS Code color theme JSON document into a manifest.
+///
+/// The `[meta]` name comes from the theme's `name` (or [`DEFAULT_NAME`]);
+/// style comes from `type`, falling back to background luminance.
+pub fn from_json(json: &str) -> Result<PaletteManifest, PaletteError> {
+    let raw: RawTheme =
+        serde_json::from_str(&strip_jsonc(json)).map_err(|e| import_error(FORMAT, e))?;
+
+    let workbench = |key: &str, backdrop: Option<Color>| -> Option<Color> {
+        raw.colors
+            .get(key)
+            .and_then(Option::as_deref)
+            .and_then(|v| parse_color(v, backdrop))
+    };
+    let background = workbench("editor.background", None);
+
+    let mut manifest = PaletteManifest::default();
+    for &(section, field, keys) in COLOR_MAP {
+        if let Some(color) = keys.iter().find_map(|k| workbench(k, background)) {
+            set_color(&mut manifest, section, field, color);
+        }
+    }
+
+    let mut rules = Vec::new();
+    if let RawTokenColors::Rules(token_rules) = &raw.token_colors {
+        for rule in token_rules {
+            let foreground = rule
+                .settings
+                .foreground
+                .as_deref()
+                .and_then(|v| parse_color(v, background));
+            let font_style = rule.settings.font_style.as_deref();
+            match &rule.scope {
+                Some(RawScope::One(s)) => {
+                    rules.extend(ScopeRule::expand(s, foreground, font_style))
+                }
+                Some(RawScope::Many(list)) => {
+                    for s in list {
+                        rules.extend(ScopeRule::expand(s, foreground, font_style));
+                    }
+                }
+                // Unscoped rules carry global defaults in older themes.
+                None => {
+                    if let Some(fg) = foreground {
+                        manifest
+                            .base
+                            .entry("foreground".into())
+                            .or_insert_with(|| fg.to_hex().into());
+                    }
+                }
+            }
+        }
+    }
+    apply_scope_rules(&mut manifest, &rules);
+
+    let name = raw.name.as_deref().unwrap_or(DEFAULT_NAME);
+    let style = match raw.kind.as_deref() {
+        Some("light" | "hcLight") => "light",
+        Some("dark" | "hc" | "hcDark") => "dark",
+        _ => style_from_background(background),
+    };
+    manifest.meta = Some(manifest_meta(name, style));
+    Ok(manifest)
+}
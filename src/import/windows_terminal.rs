@@ -0,0 +1,141 @@
+//! Windows Terminal color scheme import.
+//!
+//! Accepts a single scheme object, a bare array of schemes, or any document
+//! with a `schemes` array — JSON fragment extensions and `settings.json`
+//! (comments allowed) both qualify. Each scheme becomes its own manifest named
+//! after the scheme, so a fragment with several schemes registers each one:
+//!
+//! ```
+//! use palette_core::Registry;
+//! use palette_core::import::windows_terminal;
+//!
+//! let fragment = r##"{ "schemes": [
+//!     { "name": "Night", "background": "#101010", "foreground": "#e0e0e0" },
+//!     { "name": "Day", "background": "#fafafa", "foreground": "#202020" }
+//! ] }"##;
+//! let mut reg = Registry::new();
+//! for manifest in windows_terminal::from_json(fragment).unwrap() {
+//!     reg.add_manifest(manifest).unwrap();
+//! }
+//! assert!(reg.load("day").is_ok());
+//! ```
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use super::{
+    ANSI_FIELDS, Importer, fill_from_ansi, import_error, manifest_meta, parse_color, set_color,
+    strip_jsonc, style_from_background,
+};
+use crate::error::PaletteError;
+use crate::manifest::PaletteManifest;
+
+const FORMAT: &str = "windows-terminal";
+
+/// Scheme keys in `color0`–`color15` order (Windows Terminal says `purple`
+/// for magenta).
+const SCHEME_KEYS: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "purple",
+    "cyan",
+    "white",
+    "brightBlack",
+    "brightRed",
+    "brightGreen",
+    "brightYellow",
+    "brightBlue",
+    "brightPurple",
+    "brightCyan",
+    "brightWhite",
+];
+
+/// [`Importer`] for Windows Terminal schemes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WindowsTerminal;
+
+impl Importer for WindowsTerminal {
+    fn format(&self) -> &'static str {
+        FORMAT
+    }
+
+    fn extensions(&self) -> &'static [&'static str] {
+        &["json"]
+    }
+
+    fn detect(&self, contents: &str) -> bool {
+        contents.contains("\"brightPurple\"") || contents.contains("\"schemes\"")
+    }
+
+    fn import(&self, contents: &str) -> Result<Box<[PaletteManifest]>, PaletteError> {
+        from_json(contents)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawDocument {
+    Settings { schemes: Vec<RawScheme> },
+    List(Vec<RawScheme>),
+    Single(RawScheme),
+}
+
+#[derive(Deserialize)]
+struct RawScheme {
+    name: String,
+    #[serde(flatten)]
+    colors: HashMap<String, serde_json::Value>,
+}
+
+/// Convert every Windows Terminal scheme in `json` into a manifest, in
+/// document order.
+pub fn from_json(json: &str) -> Result<Box<[PaletteManifest]>, PaletteError> {
+    let raw: RawDocument =
+        serde_json::from_str(&strip_jsonc(json)).map_err(|e| import_error(FORMAT, e))?;
+    let schemes = match raw {
+        RawDocument::Settings { schemes } | RawDocument::List(schemes) => schemes,
+        RawDocument::Single(scheme) => vec![scheme],
+    };
+    Ok(schemes.iter().map(convert).collect())
+}
+
+fn convert(scheme: &RawScheme) -> PaletteManifest {
+    let color = |key: &str| {
+        scheme
+            .colors
+            .get(key)
+            .and_then(serde_json::Value::as_str)
+            .and_then(|v| parse_color(v, None))
+    };
+    let mut manifest = PaletteManifest::default();
+
+    let background = color("background");
+    let slots = [
+        ("base", "background", background),
+        ("base", "foreground", color("foreground")),
+        ("editor", "cursor", color("cursorColor")),
+        ("editor", "selection_bg", color("selectionBackground")),
+        ("surface", "selection", color("selectionBackground")),
+    ];
+    for (section, field, value) in slots {
+        if let Some(value) = value {
+            set_color(&mut manifest, section, field, value);
+        }
+    }
+    for (field, key) in ANSI_FIELDS.iter().zip(SCHEME_KEYS) {
+        if let Some(value) = color(key) {
+            set_color(&mut manifest, "terminal", field, value);
+        }
+    }
+    fill_from_ansi(&mut manifest);
+
+    manifest.meta = Some(manifest_meta(
+        &scheme.name,
+        style_from_background(background),
+    ));
+    manifest
+}
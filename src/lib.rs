@@ -36,11 +36,29 @@
 //! | egui `Visuals` | `egui` | [`egui::to_egui_visuals`] |
 //! | syntect `Theme` | `syntect` | [`syntect::to_syntect_theme`] |
 //! | WASM/JS bindings | `wasm` | `wasm` module |
+//! | Xcode asset catalog | — | [`export::xcassets::to_xcassets`] |
+//! | SwiftUI `Color` extension | — | [`export::swiftui::to_swiftui`] |
+//! | Flutter `ColorScheme` (Dart) | — | [`export::flutter::to_dart`] |
+//! | Figma Tokens Studio JSON | — | [`export::figma::to_tokens_studio`] |
+//! | Zathura `zathurarc` | — | [`export::zathura::to_zathurarc`] |
+//! | qutebrowser `config.py` | — | [`export::qutebrowser::to_qutebrowser`] |
+//! | Termux `colors.properties` | — | [`export::termux::to_termux_properties`] |
+//! | Xcode `.xccolortheme` | — | [`export::xcode::to_xccolortheme`] |
 
-/// 8-bit RGB color type and hex parsing.
+#![cfg_attr(not(feature = "capi"), forbid(unsafe_code))]
+
+mod ansi256;
+/// Fluent construction of palettes in code.
+pub mod builder;
+mod builtin;
+/// 8-bit RGBA color type and hex parsing.
 pub mod color;
+/// Structured diagnostics with stable error codes.
+pub mod diagnostic;
 /// Error types for theme loading and parsing.
 pub mod error;
+/// Custom color groups defined outside this crate.
+pub mod group;
 /// Raw TOML manifest types before color resolution.
 pub mod manifest;
 /// Single-level manifest inheritance (variant over base).
@@ -49,22 +67,38 @@ pub mod merge;
 pub mod palette;
 /// Built-in preset registry and theme discovery.
 pub mod registry;
+/// Manifest schema versions and migrations.
+pub mod schema;
+/// Typed `section.field` color slot references.
+pub mod slot;
 
 /// WCAG 2.1 contrast ratio checking and palette validation.
 pub mod contrast;
 /// CSS custom-property export.
 pub mod css;
+/// Color-vision-deficiency simulation.
+pub mod cvd;
+/// File-based export targets (asset catalogs, editor and app configs).
+pub mod export;
+/// Palette synthesis: seed colors, random themes, derived ANSI tables.
+pub mod generate;
 /// Multi-stop color gradient with perceptual interpolation.
 pub mod gradient;
-/// HSL color manipulation: lighten, darken, saturate, blend.
+/// Color manipulation: lighten, darken, saturate, blend, palette tone transforms.
 pub mod manipulation;
 
+pub use builder::PaletteBuilder;
 pub use color::Color;
 pub use contrast::ContrastLevel;
 pub use error::PaletteError;
 pub use gradient::{ColorSpace, Gradient, GradientColor, GradientDef, GradientStop};
-pub use palette::{GradientDefs, Palette, PaletteMeta};
-pub use registry::{Registry, ThemeInfo, load_preset, load_preset_file, preset_ids};
+pub use palette::{Brightness, GradientDefs, Palette, PaletteMeta};
+pub use registry::{
+    LoadOptions, Registry, ThemeInfo, load_preset, preset, preset_ids, preset_manifest,
+};
+#[cfg(feature = "toml")]
+pub use registry::{load_preset_file, load_preset_file_with};
+pub use slot::SlotId;
 
 /// Text style modifiers for syntax tokens.
 pub mod style;
@@ -92,3 +126,14 @@ pub mod syntect;
 
 #[cfg(feature = "wasm")]
 pub mod wasm;
+
+/// Importers converting third-party theme formats into manifests.
+#[cfg(feature = "import")]
+pub mod import;
+
+#[cfg(any(feature = "syntect", feature = "import"))]
+mod scopes;
+
+/// C ABI for C, C++, and Zig consumers.
+#[cfg(feature = "capi")]
+pub mod capi;
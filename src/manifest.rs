@@ -1,12 +1,15 @@
-use std::collections::{BTreeMap, HashMap};
-use std::sync::Arc;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::{Arc, LazyLock};
 
 use serde::Deserialize;
 
+#[cfg(feature = "toml")]
+use crate::diagnostic::{self, Diagnostic};
 use crate::error::PaletteError;
 
 /// A single gradient stop in TOML: either a bare string or `{ color, at }`.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[serde(untagged)]
 pub enum RawGradientStop {
     /// Shorthand: `"#FF0000"` or `"base.foreground"` — position auto-assigned.
@@ -21,7 +24,7 @@ pub enum RawGradientStop {
 }
 
 /// Raw gradient definition as deserialized from TOML.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct RawGradientDef {
     /// Ordered color stops.
     pub stops: Vec<RawGradientStop>,
@@ -39,25 +42,210 @@ pub type ManifestSection = HashMap<Arc<str>, Arc<str>>;
 /// Platform-keyed overrides, e.g. `[platform.macos]`.
 pub type PlatformSections = BTreeMap<Arc<str>, ManifestSection>;
 
+/// Sections of registered [custom groups](crate::group), keyed by section
+/// name, e.g. `[chart]`.
+pub type GroupSections = BTreeMap<Arc<str>, ManifestSection>;
+
+/// Opacity given with a slot's color, `{ color = "#7aa2f7", opacity = 0.3 }`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlotOpacity {
+    /// Opacity in `[0.0, 1.0]`.
+    pub opacity: f64,
+    /// Pre-blend over the section's background into an opaque color
+    /// instead of carrying the opacity as alpha.
+    pub blend: bool,
+}
+
+/// Opacity of the slots in one section, keyed by slot name.
+pub type SectionOpacity = HashMap<Arc<str>, SlotOpacity>;
+
+/// [`SectionOpacity`] keyed by section name: `surface`, a custom group, or
+/// `platform.<name>`.
+pub type OpacitySections = BTreeMap<Arc<str>, SectionOpacity>;
+
+/// The value that removes an inherited slot: `background = "unset"` in a
+/// variant leaves `background` unset in the merged theme.
+pub const UNSET: &str = "unset";
+
+/// Slots a manifest sets to [`UNSET`], keyed by section name like
+/// [`OpacitySections`].
+pub type UnsetSlots = BTreeMap<Arc<str>, BTreeSet<Arc<str>>>;
+
+/// App-specific tables the theme schema does not define, e.g. `[x-myapp]`,
+/// kept verbatim and keyed by section name.
+#[cfg(feature = "toml")]
+pub type ExtensionSections = BTreeMap<Arc<str>, toml::Table>;
+
+/// Conditional overlays from `[when.<mode>]` tables, keyed by mode name.
+/// Each is a partial manifest without `[meta]` or a required `[base]`.
+pub type ModeSections = BTreeMap<Arc<str>, PaletteManifest>;
+
 /// The `[meta]` section of a theme TOML file.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct ManifestMeta {
     /// Human-readable theme name.
     pub name: Arc<str>,
     /// Machine identifier used for lookups.
     pub preset_id: Arc<str>,
-    /// Schema version string (e.g. `"1"`).
+    /// Schema version string (e.g. `"1"`), checked against
+    /// [`schema::CURRENT_VERSION`](crate::schema::CURRENT_VERSION).
     pub schema_version: Arc<str>,
     /// Visual style tag: `"dark"`, `"light"`, etc.
     pub style: Arc<str>,
     /// Theme kind (e.g. `"base"`, `"variant"`).
     pub kind: Arc<str>,
-    /// Parent preset ID for inheritance.
+    /// Parent preset IDs for inheritance, from `inherits = "id"` or
+    /// `inherits = ["id", "mixin"]`. Later parents win over earlier ones.
+    #[serde(default, deserialize_with = "parent_ids")]
+    pub inherits: Box<[Arc<str>]>,
+    /// Parent sections to inherit, by TOML name, e.g.
+    /// `inherit_sections = ["syntax", "terminal"]`. `None` inherits every
+    /// section. See [`MergeOptions`](crate::merge::MergeOptions).
     #[serde(default)]
-    pub inherits: Option<Arc<str>>,
+    pub inherit_sections: Option<Box<[Arc<str>]>>,
     /// Upstream repository URL, if ported from another project.
     #[serde(default)]
     pub upstream_repo: Option<Arc<str>>,
+    /// Who made the theme.
+    #[serde(default)]
+    pub author: Option<Arc<str>>,
+    /// License the theme is distributed under, ideally an SPDX identifier.
+    #[serde(default)]
+    pub license: Option<Arc<str>>,
+    /// The theme's own release version, unrelated to `schema_version`.
+    #[serde(default)]
+    pub version: Option<Arc<str>>,
+    /// One-line summary for theme pickers.
+    #[serde(default)]
+    pub description: Option<Arc<str>>,
+    /// Free-form labels for filtering, e.g. `["warm", "high-contrast"]`.
+    #[serde(default)]
+    pub tags: Box<[Arc<str>]>,
+}
+
+/// `[meta]` fields a `{field}` placeholder can name, in the order they are
+/// expanded. `schema_version` can be named but is never expanded itself.
+pub(crate) const META_FIELDS: &[&str] = &[
+    "name",
+    "preset_id",
+    "schema_version",
+    "style",
+    "kind",
+    "upstream_repo",
+    "author",
+    "license",
+    "version",
+    "description",
+];
+
+impl ManifestMeta {
+    /// The string value of the `[meta]` field `name`, if it is one of
+    /// [`META_FIELDS`] and set.
+    fn field(&self, name: &str) -> Option<&Arc<str>> {
+        match name {
+            "name" => Some(&self.name),
+            "preset_id" => Some(&self.preset_id),
+            "schema_version" => Some(&self.schema_version),
+            "style" => Some(&self.style),
+            "kind" => Some(&self.kind),
+            "upstream_repo" => self.upstream_repo.as_ref(),
+            "author" => self.author.as_ref(),
+            "license" => self.license.as_ref(),
+            "version" => self.version.as_ref(),
+            "description" => self.description.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn field_mut(&mut self, name: &str) -> Option<&mut Arc<str>> {
+        match name {
+            "name" => Some(&mut self.name),
+            "preset_id" => Some(&mut self.preset_id),
+            "style" => Some(&mut self.style),
+            "kind" => Some(&mut self.kind),
+            "upstream_repo" => self.upstream_repo.as_mut(),
+            "author" => self.author.as_mut(),
+            "license" => self.license.as_mut(),
+            "version" => self.version.as_mut(),
+            "description" => self.description.as_mut(),
+            _ => None,
+        }
+    }
+
+    /// Replace `{field}` placeholders in every string field with the value
+    /// of the named field, so `name = "Tokyo Night ({style})"` reads as
+    /// `"Tokyo Night (storm)"`. `{{` and `}}` stand for literal braces.
+    ///
+    /// # Errors
+    ///
+    /// [`PaletteError::UnresolvedMetaPlaceholder`] for a placeholder naming
+    /// an unknown or unset field, or one that leads back to the field it is
+    /// in.
+    pub(crate) fn expand_placeholders(&mut self) -> Result<(), PaletteError> {
+        let mut expanded = Vec::new();
+        for &field in META_FIELDS {
+            if field != "schema_version" && self.field(field).is_some() {
+                expanded.push((field, self.expand(field, &mut Vec::new())?));
+            }
+        }
+        for (field, value) in expanded {
+            if let Some(slot) = self.field_mut(field) {
+                *slot = value;
+            }
+        }
+        Ok(())
+    }
+
+    /// The value of `field` with its placeholders expanded. `stack` holds
+    /// the fields being expanded around this one, to catch cycles.
+    fn expand<'a>(
+        &self,
+        field: &'a str,
+        stack: &mut Vec<&'a str>,
+    ) -> Result<Arc<str>, PaletteError> {
+        let Some(value) = self.field(field) else {
+            return Ok(Arc::from(""));
+        };
+        if !value.contains(['{', '}']) {
+            return Ok(Arc::clone(value));
+        }
+        stack.push(field);
+        let mut out = String::with_capacity(value.len());
+        let mut rest = value.as_ref();
+        while let Some(i) = rest.find(['{', '}']) {
+            out.push_str(&rest[..i]);
+            let tail = &rest[i..];
+            match (tail.as_bytes()[0], tail.find('}')) {
+                (b'{', _) if tail.starts_with("{{") => {
+                    out.push('{');
+                    rest = &tail[2..];
+                }
+                (b'{', Some(end)) => {
+                    let placeholder = &tail[1..end];
+                    let unresolved = || PaletteError::UnresolvedMetaPlaceholder {
+                        field: Arc::from(field),
+                        placeholder: Arc::from(placeholder),
+                    };
+                    let name = META_FIELDS
+                        .iter()
+                        .copied()
+                        .find(|&name| name == placeholder)
+                        .filter(|name| !stack.contains(name) && self.field(name).is_some())
+                        .ok_or_else(unresolved)?;
+                    out.push_str(&self.expand(name, stack)?);
+                    rest = &tail[end + 1..];
+                }
+                _ => {
+                    let escaped = tail.starts_with("}}");
+                    out.push_str(&tail[..1]);
+                    rest = &tail[1 + usize::from(escaped)..];
+                }
+            }
+        }
+        out.push_str(rest);
+        stack.pop();
+        Ok(Arc::from(out))
+    }
 }
 
 /// Parsed but unresolved theme manifest.
@@ -65,10 +253,12 @@ pub struct ManifestMeta {
 /// Holds raw hex strings grouped by section. Convert to a [`Palette`](crate::Palette)
 /// via [`Palette::from_manifest`](crate::Palette::from_manifest) after resolving
 /// inheritance with [`merge_manifests`](crate::merge::merge_manifests).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct PaletteManifest {
     /// Theme identity and inheritance metadata.
     pub meta: Option<Arc<ManifestMeta>>,
+    /// Named colors from `[colors]`, referenced from any slot as `"$name"`.
+    pub colors: ManifestSection,
     /// Core background/foreground hex values.
     pub base: ManifestSection,
     /// Status color hex values (success, error, etc.).
@@ -89,39 +279,867 @@ pub struct PaletteManifest {
     pub syntax_style: ManifestSection,
     /// Named gradient definitions parsed from `[gradient.*]` sections.
     pub gradient: GradientSections,
+    /// Hex values for registered [custom groups](crate::group).
+    pub groups: GroupSections,
     /// Per-platform color overrides.
     #[cfg(feature = "platform")]
     pub platform: PlatformSections,
+    /// Opacity of slots written as `{ color, opacity }`, applied when the
+    /// palette is built.
+    pub opacity: OpacitySections,
+    /// Slots set to [`UNSET`], removed from the parent's values by
+    /// [`merge_manifests`](crate::merge::merge_manifests).
+    pub unset: UnsetSlots,
+    /// Tables that are neither theme sections nor registered custom groups.
+    #[cfg(feature = "toml")]
+    pub extensions: ExtensionSections,
+    /// Overlays merged in only when their mode is requested, see
+    /// [`with_modes`](Self::with_modes).
+    pub modes: ModeSections,
 }
 
 impl PaletteManifest {
     /// Parse a TOML string into a manifest. Requires a `[base]` section.
+    ///
+    /// A file declaring an older `schema_version` is
+    /// [migrated](crate::schema) first; a newer one fails with
+    /// [`PaletteError::UnsupportedSchemaVersion`].
+    #[cfg(feature = "toml")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "parse_manifest", level = "debug", skip_all,
+        fields(bytes = s.len(), id = tracing::field::Empty),
+    ))]
     pub fn from_toml(s: &str) -> Result<Self, PaletteError> {
         let raw: RawManifest = toml::from_str(s)?;
+        let declared = raw.meta.as_ref().map(|meta| meta.schema_version.as_ref());
+        let mut manifest = match crate::schema::is_current(declared)? {
+            true => Self::from_raw(raw)?,
+            false => Self::from_raw(migrated(s)?.try_into()?)?,
+        };
+        manifest.resolve_aliases();
+        Ok(manifest)
+    }
 
+    /// Parse like [`from_toml`](Self::from_toml), also collecting
+    /// recoverable issues as warning [`Diagnostic`]s: unknown sections and
+    /// keys, deprecated keys, and — for manifests that do not inherit —
+    /// missing recommended slots.
+    ///
+    /// Warnings never fail the parse; only the errors `from_toml` returns do.
+    #[cfg(feature = "toml")]
+    pub fn from_toml_with_warnings(s: &str) -> Result<(Self, Box<[Diagnostic]>), PaletteError> {
+        let (manifest, mut warnings) = Self::from_toml_linted(s)?;
+        if manifest.inherits_from().is_none() {
+            warnings.extend(diagnostic::missing_slot_warnings(&manifest));
+        }
+        Ok((manifest, warnings.into_boxed_slice()))
+    }
+
+    /// Parse like [`from_toml`](Self::from_toml), but reject what it
+    /// silently ignores: a top-level table that is not a theme section
+    /// fails with [`PaletteError::UnknownSection`], and a key that is not a
+    /// slot of its section (`forground`) with [`PaletteError::UnknownField`].
+    ///
+    /// Only the first problem is reported; use
+    /// [`from_toml_with_warnings`](Self::from_toml_with_warnings) to list
+    /// them all.
+    #[cfg(feature = "toml")]
+    pub fn from_toml_strict(s: &str) -> Result<Self, PaletteError> {
+        let table = migrated(s)?;
+        if let Some(key) = table.keys().find(|key| !is_section_name(key)) {
+            return Err(PaletteError::UnknownSection(Arc::from(key.as_str())));
+        }
+        let mut manifest = Self::from_raw(table.try_into()?)?;
+        manifest.resolve_aliases();
+        match validate_fields(&manifest).first() {
+            Some(unknown) => Err(PaletteError::UnknownField {
+                section: Arc::from(unknown.section.as_ref()),
+                field: Arc::from(unknown.field.as_ref()),
+            }),
+            None => Ok(manifest),
+        }
+    }
+
+    /// Parse, reporting unknown sections and unknown or deprecated keys.
+    #[cfg(feature = "toml")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "parse_manifest", level = "debug", skip_all,
+        fields(bytes = s.len(), id = tracing::field::Empty),
+    ))]
+    pub(crate) fn from_toml_linted(s: &str) -> Result<(Self, Vec<Diagnostic>), PaletteError> {
+        let table = migrated(s)?;
+        let mut warnings: Vec<Diagnostic> = table
+            .keys()
+            .filter(|key| !is_section_name(key))
+            .map(|key| diagnostic::unknown_section(key))
+            .collect();
+        let mut manifest = Self::from_raw(table.try_into()?)?;
+        warnings.extend(diagnostic::field_warnings(&manifest));
+        manifest.resolve_aliases();
+        Ok((manifest, warnings))
+    }
+
+    /// Finish a parse, checking for the required `[base]` and expanding
+    /// `[meta]` placeholders.
+    #[cfg(feature = "toml")]
+    fn from_raw(mut raw: RawManifest) -> Result<Self, PaletteError> {
+        if let Some(meta) = &mut raw.meta {
+            meta.expand_placeholders()?;
+        }
+        #[cfg(feature = "tracing")]
+        if let Some(meta) = &raw.meta {
+            tracing::Span::current().record("id", meta.preset_id.as_ref());
+        }
         match raw.base {
+            Some(_) => Self::from_overlay(raw),
             None => Err(PaletteError::MissingBase),
-            Some(base) => Ok(Self {
-                meta: raw.meta.map(Arc::new),
-                base,
-                semantic: raw.semantic,
-                diff: raw.diff,
-                surface: raw.surface,
-                typography: raw.typography,
-                syntax: raw.syntax,
-                editor: raw.editor,
-                terminal: raw.terminal,
-                syntax_style: raw.syntax_style,
-                gradient: raw.gradient,
-                #[cfg(feature = "platform")]
-                platform: raw.platform,
-            }),
         }
     }
 
-    /// The parent preset ID if this manifest uses inheritance.
+    /// Finish a parse where `[base]` may be missing, as in a `[when.<mode>]`
+    /// overlay.
+    #[cfg(feature = "toml")]
+    fn from_overlay(raw: RawManifest) -> Result<Self, PaletteError> {
+        let mut opacity = OpacitySections::new();
+        let mut unset = UnsetSlots::new();
+        let mut slots = |name: &str, section: InternedSection| {
+            if !section.opacity.is_empty() {
+                opacity.insert(Arc::from(name), section.opacity);
+            }
+            if !section.unset.is_empty() {
+                unset.insert(Arc::from(name), section.unset);
+            }
+            section.slots
+        };
+        let base = raw.base.map(|base| slots("base", base)).unwrap_or_default();
+        let semantic = slots("semantic", raw.semantic);
+        let diff = slots("diff", raw.diff);
+        let surface = slots("surface", raw.surface);
+        let typography = slots("typography", raw.typography);
+        let syntax = slots("syntax", raw.syntax);
+        let editor = slots("editor", raw.editor);
+        let terminal = slots("terminal", raw.terminal);
+        let groups = raw
+            .extra
+            .groups
+            .into_iter()
+            .map(|(name, section)| {
+                let section = slots(&name, section);
+                (name, section)
+            })
+            .collect();
+        #[cfg(feature = "platform")]
+        let platform = raw
+            .platform
+            .into_iter()
+            .map(|(name, section)| {
+                let section = slots(&format!("platform.{name}"), section);
+                (name, section)
+            })
+            .collect();
+        Ok(Self {
+            meta: raw.meta.map(Arc::new),
+            colors: raw.colors,
+            base,
+            semantic,
+            diff,
+            surface,
+            typography,
+            syntax,
+            editor,
+            terminal,
+            syntax_style: raw.syntax_style,
+            gradient: raw.gradient,
+            groups,
+            #[cfg(feature = "platform")]
+            platform,
+            opacity,
+            unset,
+            extensions: raw.extra.extensions,
+            modes: raw
+                .when
+                .into_iter()
+                .map(|(mode, overlay)| Ok((mode, Self::from_overlay(overlay)?)))
+                .collect::<Result<_, PaletteError>>()?,
+        })
+    }
+
+    /// This manifest with the `[when.<mode>]` overlay of each mode in
+    /// `modes` merged over it, in order, so a later mode wins where two set
+    /// the same slot. Modes the manifest does not define are skipped, and
+    /// `self` is borrowed when none apply.
+    ///
+    /// Apply modes after inheritance: a variant's merged manifest carries
+    /// its parents' overlays too.
+    ///
+    /// ```
+    /// use palette_core::manifest::PaletteManifest;
+    ///
+    /// let toml = "[base]\nbackground = \"#1a1b26\"\n\n\
+    ///             [when.high_contrast.base]\nbackground = \"#000000\"\n";
+    /// let manifest = PaletteManifest::from_toml(toml).unwrap();
+    /// let contrast = manifest.with_modes(&["high_contrast"]);
+    /// assert_eq!(contrast.base["background"].as_ref(), "#000000");
+    /// assert_eq!(manifest.base["background"].as_ref(), "#1a1b26");
+    /// ```
+    pub fn with_modes<S: AsRef<str>>(&self, modes: &[S]) -> Cow<'_, Self> {
+        let mut applied = Cow::Borrowed(self);
+        for mode in modes {
+            if let Some(overlay) = self.modes.get(mode.as_ref()) {
+                let mut merged = crate::merge::merge_manifests(overlay, &applied);
+                merged.meta = self.meta.clone();
+                applied = Cow::Owned(merged);
+            }
+        }
+        applied
+    }
+
+    /// Which slots this manifest overrides, adds, or leaves to `parent`.
+    /// See [`diff_manifests`](crate::merge::diff_manifests).
+    ///
+    /// ```
+    /// use palette_core::manifest::PaletteManifest;
+    ///
+    /// let parent = PaletteManifest::from_toml("[base]\nbackground = \"#1a1b26\"\n").unwrap();
+    /// let storm = PaletteManifest::from_toml("[base]\nbackground = \"#24283b\"\n").unwrap();
+    /// let diff = storm.diff(&parent);
+    /// assert_eq!(diff.overridden[0].field.as_ref(), "background");
+    /// ```
+    pub fn diff(&self, parent: &Self) -> crate::merge::ManifestDiff {
+        crate::merge::diff_manifests(self, parent)
+    }
+
+    /// Read `source`, top-level tables outside the fixed schema, as the
+    /// parser does: a table naming a registered [custom group](crate::group)
+    /// becomes that group's section, and every other table an extension.
+    #[cfg(feature = "toml")]
+    pub(crate) fn add_extra_tables(&mut self, source: &str) -> Result<(), PaletteError> {
+        let extra: RawExtra = toml::from_str(source)?;
+        for (name, section) in extra.groups {
+            if !section.opacity.is_empty() {
+                self.opacity.insert(Arc::clone(&name), section.opacity);
+            }
+            if !section.unset.is_empty() {
+                self.unset.insert(Arc::clone(&name), section.unset);
+            }
+            self.groups.insert(name, section.slots);
+        }
+        self.extensions.extend(extra.extensions);
+        Ok(())
+    }
+
+    /// Rename [`FIELD_ALIASES`] keys to their current field names, here and
+    /// in every mode overlay. An alias is dropped when its field is also
+    /// set.
+    pub(crate) fn resolve_aliases(&mut self) {
+        for &(section, alias, field) in FIELD_ALIASES {
+            let slots = match section {
+                "syntax_style" => Some(&mut self.syntax_style),
+                _ => self.color_section_mut(section),
+            };
+            let renamed = match slots.map(|slots| (slots.remove(alias), slots)) {
+                Some((Some(value), slots)) if !slots.contains_key(field) => {
+                    slots.insert(Arc::from(field), value);
+                    true
+                }
+                _ => false,
+            };
+            if let Some(opacity) = self.opacity.get_mut(section)
+                && let Some(slot) = opacity.remove(alias)
+                && renamed
+            {
+                opacity.insert(Arc::from(field), slot);
+            }
+        }
+        for overlay in self.modes.values_mut() {
+            overlay.resolve_aliases();
+        }
+    }
+
+    /// A flat slot section by its TOML name.
+    pub(crate) fn section(&self, name: &str) -> Option<&ManifestSection> {
+        match name {
+            "base" => Some(&self.base),
+            "semantic" => Some(&self.semantic),
+            "diff" => Some(&self.diff),
+            "surface" => Some(&self.surface),
+            "typography" => Some(&self.typography),
+            "syntax" => Some(&self.syntax),
+            "editor" => Some(&self.editor),
+            "terminal" => Some(&self.terminal),
+            "syntax_style" => Some(&self.syntax_style),
+            _ => self.groups.get(name),
+        }
+    }
+
+    /// A color section by its [`OpacitySections`] name: a flat section, a
+    /// custom group, or `platform.<name>`.
+    pub(crate) fn color_section(&self, name: &str) -> Option<&ManifestSection> {
+        match name.strip_prefix("platform.") {
+            #[cfg(feature = "platform")]
+            Some(platform) => self.platform.get(platform),
+            #[cfg(not(feature = "platform"))]
+            Some(_) => None,
+            None => self.section(name),
+        }
+    }
+
+    pub(crate) fn color_section_mut(&mut self, name: &str) -> Option<&mut ManifestSection> {
+        match name {
+            "base" => Some(&mut self.base),
+            "semantic" => Some(&mut self.semantic),
+            "diff" => Some(&mut self.diff),
+            "surface" => Some(&mut self.surface),
+            "typography" => Some(&mut self.typography),
+            "syntax" => Some(&mut self.syntax),
+            "editor" => Some(&mut self.editor),
+            "terminal" => Some(&mut self.terminal),
+            #[cfg(feature = "platform")]
+            _ if name.starts_with("platform.") => {
+                self.platform.get_mut(name.trim_start_matches("platform."))
+            }
+            _ => self.groups.get_mut(name),
+        }
+    }
+
+    /// Remove the slot an error names so a build can continue past it.
+    /// Returns whether anything was removed.
+    ///
+    /// A bad `[colors]` entry is replaced with a placeholder rather than
+    /// removed, so slots referencing it are not reported as well; a bad
+    /// gradient stop reference drops the whole gradient.
+    pub(crate) fn remove_slot(&mut self, section: &str, field: &str) -> bool {
+        match (section, section.strip_prefix("gradient.")) {
+            ("colors", _) => self
+                .colors
+                .insert(Arc::from(field), Arc::from("#000000"))
+                .is_some(),
+            (_, Some(gradient)) => self.gradient.remove(gradient).is_some(),
+            ("syntax_style", None) => self.syntax_style.remove(field).is_some(),
+            (_, None) => self
+                .color_section_mut(section)
+                .and_then(|slots| slots.remove(field))
+                .is_some(),
+        }
+    }
+
+    /// This manifest with every translucent slot replaced by its resolved
+    /// color: the color with its alpha scaled by the opacity, or — for
+    /// `blend = true` — the color pre-blended over the section's background
+    /// (a platform's own background, else `base.background`). Borrows
+    /// `self` when no slot has an opacity.
+    pub(crate) fn apply_opacity(&self) -> Result<Cow<'_, Self>, PaletteError> {
+        use crate::color::Color;
+
+        if self.opacity.is_empty() {
+            return Ok(Cow::Borrowed(self));
+        }
+        let background = |section: Option<&ManifestSection>| -> Option<Color> {
+            Color::parse(section?.get("background")?).ok()
+        };
+        let base_background = background(Some(&self.base));
+        let mut manifest = self.clone();
+        manifest.opacity.clear();
+        for (name, slots) in &self.opacity {
+            let section_background = match name.starts_with("platform.") {
+                true => background(self.color_section(name)).or(base_background),
+                false => base_background,
+            };
+            let Some(section) = manifest.color_section_mut(name) else {
+                continue;
+            };
+            for (field, slot) in slots {
+                let Some(value) = section.get(field) else {
+                    continue;
+                };
+                let color = Color::parse(value)
+                    .map_err(|e| e.into_palette_error(Arc::clone(name), Arc::clone(field)))?;
+                let resolved = match (slot.blend, section_background) {
+                    (true, Some(bg)) => color.blend(bg, slot.opacity),
+                    _ => {
+                        let alpha = f64::from(color.a) * slot.opacity.clamp(0.0, 1.0);
+                        color.with_alpha(alpha.round() as u8)
+                    }
+                };
+                section.insert(Arc::clone(field), Arc::from(resolved.to_hex()));
+            }
+        }
+        Ok(Cow::Owned(manifest))
+    }
+
+    /// This manifest with every `"$name"` reference replaced by its
+    /// `[colors]` entry. Borrows `self` when nothing needs replacing.
+    ///
+    /// [`Palette::from_manifest`](crate::Palette::from_manifest) expands
+    /// automatically, after inheritance has merged the parent's `[colors]`
+    /// under the variant's, so a variant can redefine a name its parent uses.
+    ///
+    /// # Errors
+    ///
+    /// [`PaletteError::InvalidHex`] for a `[colors]` entry that is not a
+    /// color, or [`PaletteError::UnknownColorVariable`] for a reference with
+    /// no entry.
+    pub fn expand_colors(&self) -> Result<Cow<'_, Self>, PaletteError> {
+        for (name, value) in &self.colors {
+            crate::color::Color::parse(value)
+                .map_err(|e| e.into_palette_error(Arc::from("colors"), Arc::clone(name)))?;
+        }
+        match self.has_color_references() {
+            false => return Ok(Cow::Borrowed(self)),
+            true => {}
+        }
+
+        let colors = &self.colors;
+        let expand = |section: &ManifestSection, name: &str| -> Result<_, PaletteError> {
+            section
+                .iter()
+                .map(|(field, value)| {
+                    expand_reference(value, name, field, |var| colors.get(var))
+                        .map(|value| (Arc::clone(field), Arc::clone(value)))
+                })
+                .collect::<Result<ManifestSection, _>>()
+        };
+        let expand_named = |sections: &BTreeMap<Arc<str>, ManifestSection>, prefix: &str| {
+            sections
+                .iter()
+                .map(|(name, section)| {
+                    let label = match prefix.is_empty() {
+                        true => name.to_string(),
+                        false => format!("{prefix}.{name}"),
+                    };
+                    expand(section, &label).map(|section| (Arc::clone(name), section))
+                })
+                .collect::<Result<BTreeMap<_, _>, PaletteError>>()
+        };
+        let expand_stop = |color: &str, gradient: &str| -> Result<String, PaletteError> {
+            match color.strip_prefix(COLOR_REFERENCE) {
+                None => Ok(color.to_owned()),
+                Some(var) => colors.get(var).map(|c| c.to_string()).ok_or_else(|| {
+                    PaletteError::UnknownColorVariable {
+                        section: Arc::from(format!("gradient.{gradient}")),
+                        field: Arc::from("stops"),
+                        name: Arc::from(var),
+                    }
+                }),
+            }
+        };
+        let gradient = self
+            .gradient
+            .iter()
+            .map(|(name, def)| {
+                let stops = def
+                    .stops
+                    .iter()
+                    .map(|stop| match stop {
+                        RawGradientStop::Shorthand(color) => {
+                            expand_stop(color, name).map(RawGradientStop::Shorthand)
+                        }
+                        RawGradientStop::Explicit { color, at } => expand_stop(color, name)
+                            .map(|color| RawGradientStop::Explicit { color, at: *at }),
+                    })
+                    .collect::<Result<_, PaletteError>>()?;
+                let def = RawGradientDef {
+                    stops,
+                    space: def.space.clone(),
+                };
+                Ok((Arc::clone(name), def))
+            })
+            .collect::<Result<_, PaletteError>>()?;
+
+        Ok(Cow::Owned(Self {
+            meta: self.meta.clone(),
+            colors: self.colors.clone(),
+            base: expand(&self.base, "base")?,
+            semantic: expand(&self.semantic, "semantic")?,
+            diff: expand(&self.diff, "diff")?,
+            surface: expand(&self.surface, "surface")?,
+            typography: expand(&self.typography, "typography")?,
+            syntax: expand(&self.syntax, "syntax")?,
+            editor: expand(&self.editor, "editor")?,
+            terminal: expand(&self.terminal, "terminal")?,
+            syntax_style: self.syntax_style.clone(),
+            gradient,
+            groups: expand_named(&self.groups, "")?,
+            #[cfg(feature = "platform")]
+            platform: expand_named(&self.platform, "platform")?,
+            opacity: self.opacity.clone(),
+            unset: self.unset.clone(),
+            #[cfg(feature = "toml")]
+            extensions: self.extensions.clone(),
+            modes: self.modes.clone(),
+        }))
+    }
+
+    /// Whether any color slot or gradient stop is a `"$name"` reference.
+    fn has_color_references(&self) -> bool {
+        let in_section = |s: &ManifestSection| s.values().any(|v| v.starts_with(COLOR_REFERENCE));
+        let flat = [
+            &self.base,
+            &self.semantic,
+            &self.diff,
+            &self.surface,
+            &self.typography,
+            &self.syntax,
+            &self.editor,
+            &self.terminal,
+        ];
+        #[cfg(feature = "platform")]
+        let platform = self.platform.values().any(in_section);
+        #[cfg(not(feature = "platform"))]
+        let platform = false;
+        flat.into_iter().any(in_section)
+            || self.groups.values().any(in_section)
+            || platform
+            || self
+                .gradient
+                .values()
+                .flat_map(|def| &def.stops)
+                .any(|stop| {
+                    let (RawGradientStop::Shorthand(color)
+                    | RawGradientStop::Explicit { color, .. }) = stop;
+                    color.starts_with(COLOR_REFERENCE)
+                })
+    }
+
+    /// The first parent preset ID if this manifest uses inheritance.
     pub fn inherits_from(&self) -> Option<&str> {
-        self.meta.as_ref().and_then(|m| m.inherits.as_deref())
+        self.parents().first().map(AsRef::as_ref)
+    }
+
+    /// Every parent preset ID, in merge order: later parents win over
+    /// earlier ones, and this manifest over all of them.
+    pub fn parents(&self) -> &[Arc<str>] {
+        self.meta.as_ref().map_or(&[], |m| &m.inherits)
+    }
+
+    /// The preset ID, for tracing fields.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn preset_id(&self) -> Option<&str> {
+        self.meta.as_ref().map(|m| m.preset_id.as_ref())
+    }
+
+    /// Serialize back to theme TOML that [`from_toml`](Self::from_toml)
+    /// reads unchanged.
+    ///
+    /// Known slots are written in declaration order, followed by any unknown
+    /// keys sorted by name. Empty sections are omitted.
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> String {
+        use std::fmt::Write as _;
+
+        fn quote(s: &str) -> toml::Value {
+            toml::Value::String(s.to_owned())
+        }
+
+        let mut out = String::new();
+        if let Some(meta) = &self.meta {
+            out.push_str("[meta]\n");
+            let fields = [
+                ("name", Some(&meta.name)),
+                ("preset_id", Some(&meta.preset_id)),
+                ("schema_version", Some(&meta.schema_version)),
+                ("style", Some(&meta.style)),
+                ("kind", Some(&meta.kind)),
+            ];
+            for (key, value) in fields {
+                if let Some(value) = value {
+                    let _ = writeln!(out, "{key} = {}", quote(value));
+                }
+            }
+            match &*meta.inherits {
+                [] => {}
+                [parent] => {
+                    let _ = writeln!(out, "inherits = {}", quote(parent));
+                }
+                parents => {
+                    let parents: Vec<String> =
+                        parents.iter().map(|p| quote(p).to_string()).collect();
+                    let _ = writeln!(out, "inherits = [{}]", parents.join(", "));
+                }
+            }
+            if let Some(sections) = &meta.inherit_sections {
+                let sections: Vec<String> = sections.iter().map(|s| quote(s).to_string()).collect();
+                let _ = writeln!(out, "inherit_sections = [{}]", sections.join(", "));
+            }
+            let attribution = [
+                ("upstream_repo", &meta.upstream_repo),
+                ("author", &meta.author),
+                ("license", &meta.license),
+                ("version", &meta.version),
+                ("description", &meta.description),
+            ];
+            for (key, value) in attribution {
+                if let Some(value) = value {
+                    let _ = writeln!(out, "{key} = {}", quote(value));
+                }
+            }
+            if !meta.tags.is_empty() {
+                let tags: Vec<String> = meta.tags.iter().map(|t| quote(t).to_string()).collect();
+                let _ = writeln!(out, "tags = [{}]", tags.join(", "));
+            }
+        }
+
+        self.write_sections(&mut out, &[]);
+        for (mode, overlay) in &self.modes {
+            overlay.write_sections(&mut out, &["when", mode]);
+        }
+        out
+    }
+
+    /// Write every section but `[meta]`, with headers nested under `path`.
+    #[cfg(feature = "toml")]
+    fn write_sections(&self, out: &mut String, path: &[&str]) {
+        use std::fmt::Write as _;
+
+        fn quote(s: &str) -> toml::Value {
+            toml::Value::String(s.to_owned())
+        }
+
+        fn write_section(
+            out: &mut String,
+            header: &str,
+            section: &ManifestSection,
+            order: &[&str],
+            (opacity, unset): (Option<&SectionOpacity>, Option<&BTreeSet<Arc<str>>>),
+        ) {
+            let unset = unset.filter(|fields| !fields.is_empty());
+            // `[base]` is required on read, so it is written even when empty.
+            if section.is_empty() && unset.is_none() && header != "base" {
+                return;
+            }
+            let mut unknown: Vec<&str> = section
+                .keys()
+                .chain(unset.into_iter().flatten())
+                .map(AsRef::as_ref)
+                .filter(|k| !order.contains(k))
+                .collect();
+            unknown.sort_unstable();
+            let _ = writeln!(out, "\n[{header}]");
+            for key in order.iter().copied().chain(unknown) {
+                match (section.get(key), opacity.and_then(|o| o.get(key))) {
+                    (Some(value), Some(slot)) => {
+                        let blend = match slot.blend {
+                            true => ", blend = true",
+                            false => "",
+                        };
+                        let _ = writeln!(
+                            out,
+                            "{key} = {{ color = {}, opacity = {}{blend} }}",
+                            quote(value),
+                            toml::Value::Float(slot.opacity)
+                        );
+                    }
+                    (Some(value), None) => {
+                        let _ = writeln!(out, "{key} = {}", quote(value));
+                    }
+                    (None, _) if unset.is_some_and(|fields| fields.contains(key)) => {
+                        let _ = writeln!(out, "{key} = {}", quote(UNSET));
+                    }
+                    (None, _) => {}
+                }
+            }
+        }
+
+        let prefix: String = path.iter().map(|key| quote_key(key) + ".").collect();
+        let slot_state = |name: &str| (self.opacity.get(name), self.unset.get(name));
+        write_section(
+            out,
+            &format!("{prefix}colors"),
+            &self.colors,
+            &[],
+            (None, None),
+        );
+
+        let sections = [
+            ("base", &self.base, known_fields::BASE),
+            ("semantic", &self.semantic, known_fields::SEMANTIC),
+            ("diff", &self.diff, known_fields::DIFF),
+            ("surface", &self.surface, known_fields::SURFACE),
+            ("typography", &self.typography, known_fields::TYPOGRAPHY),
+            ("syntax", &self.syntax, known_fields::SYNTAX),
+            ("editor", &self.editor, known_fields::EDITOR),
+            ("terminal", &self.terminal, known_fields::TERMINAL),
+            ("syntax_style", &self.syntax_style, known_fields::SYNTAX),
+        ];
+        for (name, section, order) in sections {
+            let header = format!("{prefix}{name}");
+            write_section(out, &header, section, order, slot_state(name));
+        }
+
+        for (name, section) in &self.groups {
+            let order = crate::group::lookup(name).map_or(&[][..], |group| group.fields);
+            let header = format!("{prefix}{}", quote_key(name));
+            write_section(out, &header, section, order, slot_state(name));
+        }
+
+        let mut gradients: Vec<_> = self.gradient.iter().collect();
+        gradients.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        for (name, def) in gradients {
+            let stops: Vec<String> = def
+                .stops
+                .iter()
+                .map(|stop| match stop {
+                    RawGradientStop::Shorthand(color) => quote(color).to_string(),
+                    RawGradientStop::Explicit { color, at } => {
+                        format!(
+                            "{{ color = {}, at = {} }}",
+                            quote(color),
+                            toml::Value::Float(*at)
+                        )
+                    }
+                })
+                .collect();
+            let _ = writeln!(out, "\n[{prefix}gradient.{}]", quote_key(name));
+            let _ = writeln!(out, "stops = [{}]", stops.join(", "));
+            if let Some(space) = &def.space {
+                let _ = writeln!(out, "space = {}", quote(space));
+            }
+        }
+
+        #[cfg(feature = "platform")]
+        for (platform, section) in &self.platform {
+            let header = format!("{prefix}platform.{}", quote_key(platform));
+            let state = slot_state(&format!("platform.{platform}"));
+            write_section(out, &header, section, known_fields::BASE, state);
+        }
+
+        for (name, table) in &self.extensions {
+            let mut document = toml::Table::new();
+            document.insert(name.to_string(), toml::Value::Table(table.clone()));
+            for key in path.iter().rev() {
+                let mut outer = toml::Table::new();
+                outer.insert((*key).to_owned(), toml::Value::Table(document));
+                document = outer;
+            }
+            if let Ok(text) = toml::to_string(&document) {
+                let _ = write!(out, "\n{text}");
+            }
+        }
+    }
+}
+
+/// Byte range of `[section].field` in `source`: the value, or the key when
+/// `key` is set. Without a field, the section's header key. Dotted sections
+/// (`platform.web`, `gradient.sunset`) descend into nested tables.
+#[cfg(feature = "toml")]
+pub(crate) fn find_span(
+    source: &str,
+    section: &str,
+    field: Option<&str>,
+    key: bool,
+) -> Option<std::ops::Range<usize>> {
+    use toml::de::{DeTable, DeValue};
+
+    let document = DeTable::parse(source).ok()?;
+    let mut table = document.get_ref();
+    let mut header = None;
+    for name in section.split('.') {
+        let (k, v) = table.get_key_value(name)?;
+        header = Some(k.span());
+        table = match v.get_ref() {
+            DeValue::Table(t) => t,
+            _ => return None,
+        };
+    }
+    match field {
+        None => header,
+        Some(field) => {
+            let (k, v) = table.get_key_value(field)?;
+            match key {
+                true => Some(k.span()),
+                false => Some(v.span()),
+            }
+        }
+    }
+}
+
+/// A built-in section or a registered custom group.
+#[cfg(feature = "toml")]
+fn is_section_name(key: &str) -> bool {
+    SECTION_NAMES.contains(&key) || crate::group::lookup(key).is_some()
+}
+
+/// Marks a slot value as a reference to a `[colors]` entry: `"$blue"`.
+const COLOR_REFERENCE: char = '$';
+
+/// `value`, or the `[colors]` entry it names when it is a `"$name"`
+/// reference. `section` and `field` locate the value for the error.
+pub(crate) fn expand_reference<'a>(
+    value: &'a Arc<str>,
+    section: &str,
+    field: &str,
+    lookup: impl FnOnce(&str) -> Option<&'a Arc<str>>,
+) -> Result<&'a Arc<str>, PaletteError> {
+    match value.strip_prefix(COLOR_REFERENCE) {
+        None => Ok(value),
+        Some(name) => lookup(name).ok_or_else(|| PaletteError::UnknownColorVariable {
+            section: Arc::from(section),
+            field: Arc::from(field),
+            name: Arc::from(name),
+        }),
+    }
+}
+
+/// Bare TOML key when possible, quoted otherwise.
+#[cfg(feature = "toml")]
+fn quote_key(key: &str) -> String {
+    let bare = !key.is_empty()
+        && key
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-');
+    match bare {
+        true => key.to_owned(),
+        false => toml::Value::String(key.to_owned()).to_string(),
+    }
+}
+
+/// The parts of a theme TOML needed to list it: `[meta]`, `[colors]`, and
+/// the base background. Every other section is skipped without being
+/// collected.
+#[cfg(feature = "toml")]
+pub(crate) struct ManifestHeader {
+    pub(crate) meta: Option<ManifestMeta>,
+    pub(crate) colors: ManifestSection,
+    pub(crate) background: Option<Arc<str>>,
+}
+
+#[cfg(feature = "toml")]
+impl ManifestHeader {
+    /// Scan a TOML string for its header. Requires a `[base]` section, like
+    /// [`PaletteManifest::from_toml`], so a header that parses here will
+    /// also parse in full.
+    pub(crate) fn from_toml(s: &str) -> Result<Self, PaletteError> {
+        #[derive(Deserialize)]
+        struct RawHeader {
+            #[serde(default)]
+            meta: Option<ManifestMeta>,
+            #[serde(default, deserialize_with = "section")]
+            colors: ManifestSection,
+            #[serde(default)]
+            base: Option<RawHeaderBase>,
+        }
+
+        #[derive(Deserialize)]
+        struct RawHeaderBase {
+            #[serde(default)]
+            background: Option<RawSlot>,
+            /// The [`FIELD_ALIASES`] entry for `background`.
+            #[serde(default)]
+            bg: Option<RawSlot>,
+        }
+
+        let mut raw: RawHeader = toml::from_str(s)?;
+        if let Some(meta) = &mut raw.meta {
+            meta.expand_placeholders()?;
+        }
+        match raw.base {
+            None => Err(PaletteError::MissingBase),
+            Some(base) => Ok(Self {
+                meta: raw.meta,
+                colors: raw.colors,
+                background: base.background.or(base.bg).map(RawSlot::into_color),
+            }),
+        }
     }
 }
 
@@ -189,6 +1207,287 @@ pub(crate) mod known_fields {
     }
 }
 
+/// Shared key for a slot name.
+///
+/// Known field names from [`known_fields`] map to one process-wide `Arc<str>`
+/// each, so filling a section costs a ref-count bump per key instead of an
+/// allocation. Unknown keys are allocated as before. A kebab-case spelling
+/// of a known field, `background-dark`, maps to its snake_case key.
+pub(crate) fn slot_key(name: &str) -> Arc<str> {
+    static KEYS: LazyLock<HashMap<&'static str, Arc<str>>> = LazyLock::new(|| {
+        [
+            known_fields::BASE,
+            known_fields::SEMANTIC,
+            known_fields::DIFF,
+            known_fields::SURFACE,
+            known_fields::TYPOGRAPHY,
+            known_fields::SYNTAX,
+            known_fields::EDITOR,
+            known_fields::TERMINAL,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|&field| (field, Arc::from(field)))
+        .collect()
+    });
+    KEYS.get(name)
+        .or_else(|| match name.contains('-') {
+            true => KEYS.get(name.replace('-', "_").as_str()),
+            false => None,
+        })
+        .map(Arc::clone)
+        .unwrap_or_else(|| Arc::from(name))
+}
+
+/// A [`ManifestSection`] deserialized with [`slot_key`] keys, with the
+/// opacity of slots written as `{ color, opacity }` and the slots set to
+/// [`UNSET`].
+#[cfg(feature = "toml")]
+#[derive(Default)]
+struct InternedSection {
+    slots: ManifestSection,
+    opacity: SectionOpacity,
+    unset: BTreeSet<Arc<str>>,
+}
+
+#[cfg(feature = "toml")]
+impl InternedSection {
+    /// Rename kebab-case keys that spell one of `fields` in snake_case, as
+    /// [`slot_key`] does for the built-in sections.
+    fn with_snake_case_keys(mut self, fields: &[&str]) -> Self {
+        let kebab: Vec<Arc<str>> = self
+            .slots
+            .keys()
+            .chain(&self.unset)
+            .filter(|key| key.contains('-') && fields.contains(&key.replace('-', "_").as_str()))
+            .cloned()
+            .collect();
+        for key in kebab {
+            let snake: Arc<str> = Arc::from(key.replace('-', "_"));
+            if let Some(color) = self.slots.remove(&key) {
+                self.slots.insert(Arc::clone(&snake), color);
+            }
+            if let Some(opacity) = self.opacity.remove(&key) {
+                self.opacity.insert(Arc::clone(&snake), opacity);
+            }
+            if self.unset.remove(&key) {
+                self.unset.insert(snake);
+            }
+        }
+        self
+    }
+}
+
+#[cfg(feature = "toml")]
+impl<'de> Deserialize<'de> for InternedSection {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SlotKey(Arc<str>);
+
+        impl<'de> Deserialize<'de> for SlotKey {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                std::borrow::Cow::<'de, str>::deserialize(deserializer).map(|k| Self(slot_key(&k)))
+            }
+        }
+
+        struct SectionVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SectionVisitor {
+            type Value = InternedSection;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a table of slot names to strings")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut section = InternedSection {
+                    slots: ManifestSection::with_capacity(map.size_hint().unwrap_or(0)),
+                    opacity: SectionOpacity::new(),
+                    unset: BTreeSet::new(),
+                };
+                while let Some((SlotKey(key), value)) = map.next_entry::<SlotKey, RawSlot>()? {
+                    let color = match value {
+                        RawSlot::Color(color) if color.as_ref() == UNSET => {
+                            section.unset.insert(key);
+                            continue;
+                        }
+                        RawSlot::Color(color) => color,
+                        RawSlot::Translucent(slot) => {
+                            let opacity = SlotOpacity {
+                                opacity: slot.opacity,
+                                blend: slot.blend,
+                            };
+                            section.opacity.insert(Arc::clone(&key), opacity);
+                            slot.color
+                        }
+                    };
+                    section.slots.insert(key, color);
+                }
+                Ok(section)
+            }
+        }
+
+        deserializer.deserialize_map(SectionVisitor)
+    }
+}
+
+/// A slot value: a color string, or `{ color, opacity, blend }`.
+#[cfg(feature = "toml")]
+enum RawSlot {
+    Color(Arc<str>),
+    Translucent(TranslucentSlot),
+}
+
+#[cfg(feature = "toml")]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TranslucentSlot {
+    color: Arc<str>,
+    opacity: f64,
+    #[serde(default)]
+    blend: bool,
+}
+
+#[cfg(feature = "toml")]
+impl RawSlot {
+    fn into_color(self) -> Arc<str> {
+        match self {
+            Self::Color(color) => color,
+            Self::Translucent(slot) => slot.color,
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+impl<'de> Deserialize<'de> for RawSlot {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SlotVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SlotVisitor {
+            type Value = RawSlot;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a color string or a `{ color, opacity }` table")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<RawSlot, E> {
+                Ok(RawSlot::Color(Arc::from(value)))
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(self, map: A) -> Result<RawSlot, A::Error> {
+                use serde::de::Error as _;
+
+                let slot = TranslucentSlot::deserialize(
+                    serde::de::value::MapAccessDeserializer::new(map),
+                )?;
+                match (0.0..=1.0).contains(&slot.opacity) {
+                    true => Ok(RawSlot::Translucent(slot)),
+                    false => Err(A::Error::custom(format!(
+                        "opacity {} is outside 0.0–1.0",
+                        slot.opacity
+                    ))),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(SlotVisitor)
+    }
+}
+
+/// `inherits` as a single ID or an array of IDs.
+fn parent_ids<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Box<[Arc<str>]>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Parents {
+        One(Arc<str>),
+        Many(Box<[Arc<str>]>),
+    }
+
+    match Parents::deserialize(d)? {
+        Parents::One(id) => Ok(Box::new([id])),
+        Parents::Many(ids) => Ok(ids),
+    }
+}
+
+/// Tables outside the fixed schema: sections of registered custom groups,
+/// and every other table as an extension. Non-table keys are skipped.
+#[cfg(feature = "toml")]
+#[derive(Default)]
+struct RawExtra {
+    groups: BTreeMap<Arc<str>, InternedSection>,
+    extensions: ExtensionSections,
+}
+
+#[cfg(feature = "toml")]
+impl<'de> Deserialize<'de> for RawExtra {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ExtraVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ExtraVisitor {
+            type Value = RawExtra;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a theme document")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut extra = RawExtra::default();
+                while let Some(key) = map.next_key::<std::borrow::Cow<'de, str>>()? {
+                    match crate::group::lookup(&key) {
+                        Some(group) => {
+                            let section: InternedSection = map.next_value()?;
+                            extra.groups.insert(
+                                Arc::from(group.section),
+                                section.with_snake_case_keys(group.fields),
+                            );
+                        }
+                        None if is_section_name(&key) => {
+                            map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                        None => {
+                            if let toml::Value::Table(table) = map.next_value()? {
+                                extra.extensions.insert(Arc::from(key.as_ref()), table);
+                            }
+                        }
+                    }
+                }
+                Ok(extra)
+            }
+        }
+
+        deserializer.deserialize_map(ExtraVisitor)
+    }
+}
+
+/// `s` as a table, upgraded to the current schema.
+#[cfg(feature = "toml")]
+fn migrated(s: &str) -> Result<toml::Table, PaletteError> {
+    let mut table: toml::Table = toml::from_str(s)?;
+    crate::schema::migrate(&mut table, crate::schema::MIGRATIONS)?;
+    Ok(table)
+}
+
+/// A section of plain strings, where `{ color, opacity }` and [`UNSET`] are
+/// rejected.
+#[cfg(feature = "toml")]
+fn section<'de, D: serde::Deserializer<'de>>(d: D) -> Result<ManifestSection, D::Error> {
+    use serde::de::Error as _;
+
+    let section = InternedSection::deserialize(d)?;
+    match (section.opacity.is_empty(), section.unset.is_empty()) {
+        (true, true) => Ok(section.slots),
+        (false, _) => Err(D::Error::custom("opacity is only allowed on color slots")),
+        (true, false) => Err(D::Error::custom(format!(
+            "\"{UNSET}\" is only allowed on color slots"
+        ))),
+    }
+}
+
 struct SortedFields {
     base: Box<[&'static str]>,
     semantic: Box<[&'static str]>,
@@ -210,6 +1509,7 @@ fn sort_fields(fields: &[&'static str]) -> Box<[&'static str]> {
 ///
 /// This is opt-in validation for theme lint tooling -- not called during
 /// normal [`PaletteManifest::from_manifest`](crate::Palette::from_manifest).
+/// Unknown keys are listed section by section, sorted by name within each.
 pub fn validate_fields(manifest: &PaletteManifest) -> Box<[UnknownField]> {
     use std::sync::LazyLock;
 
@@ -219,15 +1519,16 @@ pub fn validate_fields(manifest: &PaletteManifest) -> Box<[UnknownField]> {
         section: &ManifestSection,
         sorted: &[&str],
     ) {
-        for key in section.keys() {
-            match sorted.binary_search(&key.as_ref()) {
-                Ok(_) => {}
-                Err(_) => unknowns.push(UnknownField {
-                    section: Box::from(section_name),
-                    field: Box::from(key.as_ref()),
-                }),
-            }
-        }
+        let mut unknown: Vec<&str> = section
+            .keys()
+            .map(AsRef::as_ref)
+            .filter(|key| sorted.binary_search(key).is_err())
+            .collect();
+        unknown.sort_unstable();
+        unknowns.extend(unknown.into_iter().map(|key| UnknownField {
+            section: Box::from(section_name),
+            field: Box::from(key),
+        }));
     }
 
     // Sort each known-field slice once per process.
@@ -269,34 +1570,87 @@ pub fn validate_fields(manifest: &PaletteManifest) -> Box<[UnknownField]> {
         &s.syntax,
     );
 
+    for (name, section) in &manifest.groups {
+        if let Some(group) = crate::group::lookup(name) {
+            check_section(&mut unknowns, name, section, &sort_fields(group.fields));
+        }
+    }
+
+    for (mode, overlay) in &manifest.modes {
+        unknowns.extend(
+            validate_fields(overlay)
+                .into_iter()
+                .map(|unknown| UnknownField {
+                    section: format!("when.{mode}.{}", unknown.section).into_boxed_str(),
+                    field: unknown.field,
+                }),
+        );
+    }
+
     unknowns.into_boxed_slice()
 }
 
+/// Old slot names still read as their current field, as
+/// `(section, alias, field)`. Parsing renames an alias to its field, and
+/// [`from_toml_with_warnings`](PaletteManifest::from_toml_with_warnings)
+/// reports each use as a deprecated key.
+pub(crate) const FIELD_ALIASES: &[(&str, &str, &str)] = &[
+    ("base", "bg", "background"),
+    ("base", "fg", "foreground"),
+    ("syntax", "comment", "comments"),
+    ("syntax_style", "comment", "comments"),
+];
+
+/// Top-level table names a theme file may contain.
+pub(crate) const SECTION_NAMES: &[&str] = &[
+    "meta",
+    "colors",
+    "base",
+    "semantic",
+    "diff",
+    "surface",
+    "typography",
+    "syntax",
+    "editor",
+    "terminal",
+    "syntax_style",
+    "gradient",
+    "platform",
+    "when",
+];
+
+#[cfg(feature = "toml")]
 #[derive(Deserialize)]
 struct RawManifest {
     #[serde(default)]
     meta: Option<ManifestMeta>,
+    #[serde(default, deserialize_with = "section")]
+    colors: ManifestSection,
     #[serde(default)]
-    base: Option<ManifestSection>,
+    base: Option<InternedSection>,
     #[serde(default)]
-    semantic: ManifestSection,
+    semantic: InternedSection,
     #[serde(default)]
-    diff: ManifestSection,
+    diff: InternedSection,
     #[serde(default)]
-    surface: ManifestSection,
+    surface: InternedSection,
     #[serde(default)]
-    typography: ManifestSection,
+    typography: InternedSection,
     #[serde(default)]
-    syntax: ManifestSection,
+    syntax: InternedSection,
     #[serde(default)]
-    editor: ManifestSection,
-    #[serde(default)]
-    terminal: ManifestSection,
+    editor: InternedSection,
     #[serde(default)]
+    terminal: InternedSection,
+    #[serde(default, deserialize_with = "section")]
     syntax_style: ManifestSection,
     #[serde(default)]
     gradient: GradientSections,
     #[cfg(feature = "platform")]
     #[serde(default)]
-    platform: PlatformSections,
+    platform: BTreeMap<Arc<str>, InternedSection>,
+    #[serde(default)]
+    when: BTreeMap<Arc<str>, RawManifest>,
+    #[serde(flatten)]
+    extra: RawExtra,
 }
@@ -1,4 +1,10 @@
+use std::sync::Arc;
+
 use crate::color::Color;
+use crate::contrast::{ContrastLevel, fix_palette};
+use crate::gradient::{ColorSpace, Gradient, GradientStop};
+use crate::palette::Palette;
+use crate::slot::SlotId;
 
 /// OKLab perceptual color space (Björn Ottosson).
 ///
@@ -14,6 +20,20 @@ pub struct OkLab {
     pub b: f64,
 }
 
+/// CIE L\*a\*b\* (CIELAB) under the D65 white point.
+///
+/// L is lightness [0, 100], a and b are the green–red and blue–yellow
+/// opponent channels (roughly ±128 for sRGB colors).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lab {
+    /// Lightness \[0, 100\].
+    pub l: f64,
+    /// Green–red opponent channel.
+    pub a: f64,
+    /// Blue–yellow opponent channel.
+    pub b: f64,
+}
+
 /// OKLCH polar form of OKLab.
 ///
 /// L is lightness [0, 1], C is chroma (≥0), h is hue in degrees [0, 360).
@@ -38,7 +58,7 @@ pub(crate) fn srgb_to_linear(channel: u8) -> f64 {
     }
 }
 
-fn linear_to_srgb(c: f64) -> u8 {
+pub(crate) fn linear_to_srgb(c: f64) -> u8 {
     let s = match c <= 0.0031308 {
         true => 12.92 * c,
         false => 1.055 * c.powf(1.0 / 2.4) - 0.055,
@@ -46,6 +66,37 @@ fn linear_to_srgb(c: f64) -> u8 {
     (s * 255.0).round().clamp(0.0, 255.0) as u8
 }
 
+impl Color {
+    /// Linear-light `[r, g, b]` in `[0, 1]`, with the sRGB transfer curve
+    /// removed. Alpha is ignored.
+    ///
+    /// Blend, scale, or upload to the GPU in this space, then convert back
+    /// with [`from_linear`](Self::from_linear).
+    pub fn to_linear(&self) -> [f64; 3] {
+        [
+            srgb_to_linear(self.r),
+            srgb_to_linear(self.g),
+            srgb_to_linear(self.b),
+        ]
+    }
+
+    /// Opaque sRGB color from linear-light `[r, g, b]`. Channels outside
+    /// `[0, 1]` are clipped.
+    ///
+    /// ```
+    /// use palette_core::Color;
+    ///
+    /// let [r1, g1, b1] = Color::from_hex("#FF0000").unwrap().to_linear();
+    /// let [r2, g2, b2] = Color::from_hex("#0000FF").unwrap().to_linear();
+    /// let mix = Color::from_linear([(r1 + r2) / 2.0, (g1 + g2) / 2.0, (b1 + b2) / 2.0]);
+    /// assert_eq!(&*mix.to_hex(), "#BC00BC");
+    /// ```
+    pub fn from_linear(rgb: [f64; 3]) -> Self {
+        let [r, g, b] = rgb.map(linear_to_srgb);
+        Self::rgb(r, g, b)
+    }
+}
+
 // --- sRGB ↔ OKLab (Björn Ottosson matrices) ---
 
 /// Convert an sRGB [`Color`] to [`OkLab`].
@@ -74,6 +125,17 @@ pub fn srgb_to_oklab(color: Color) -> OkLab {
 
 /// Convert [`OkLab`] back to an sRGB [`Color`].
 pub fn oklab_to_srgb(lab: OkLab) -> Color {
+    let [r, g, b] = oklab_to_linear(lab);
+    Color {
+        r: linear_to_srgb(r),
+        g: linear_to_srgb(g),
+        b: linear_to_srgb(b),
+        a: 255,
+    }
+}
+
+/// Unclipped linear RGB for an [`OkLab`] value.
+fn oklab_to_linear(lab: OkLab) -> [f64; 3] {
     // OKLab → LMS' (inverse of M2)
     let l_ = lab.l + 0.3963377774 * lab.a + 0.2158037573 * lab.b;
     let m_ = lab.l - 0.1055613458 * lab.a - 0.0638541728 * lab.b;
@@ -85,15 +147,11 @@ pub fn oklab_to_srgb(lab: OkLab) -> Color {
     let s = s_ * s_ * s_;
 
     // LMS → linear RGB (inverse of M1)
-    let r = 4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s;
-    let g = -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s;
-    let b = -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s;
-
-    Color {
-        r: linear_to_srgb(r),
-        g: linear_to_srgb(g),
-        b: linear_to_srgb(b),
-    }
+    [
+        4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+        -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+        -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+    ]
 }
 
 // --- OKLab ↔ OKLCH ---
@@ -123,6 +181,198 @@ pub fn srgb_to_oklch(color: Color) -> OkLch {
     oklab_to_oklch(srgb_to_oklab(color))
 }
 
+/// Tolerance on linear channels when testing gamut membership, so colors
+/// that round-trip from 8-bit sRGB count as inside.
+const GAMUT_EPSILON: f64 = 1e-6;
+
+fn in_srgb_gamut(lch: OkLch) -> bool {
+    oklab_to_linear(oklch_to_oklab(lch))
+        .iter()
+        .all(|c| (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(c))
+}
+
+/// Bring `lch` inside the sRGB gamut by reducing chroma alone.
+///
+/// Lightness (clamped to `[0, 1]`) and hue are preserved, so the result is
+/// the most saturated displayable color of that tone instead of the hue
+/// shift that per-channel clipping produces. In-gamut colors are returned
+/// unchanged.
+///
+/// ```
+/// use palette_core::manipulation::{OkLch, clamp_to_srgb_gamut};
+///
+/// let vivid = OkLch { l: 0.7, c: 0.4, h: 145.0 };
+/// let fitted = clamp_to_srgb_gamut(vivid);
+/// assert_eq!((fitted.l, fitted.h), (vivid.l, vivid.h));
+/// assert!(fitted.c < vivid.c);
+/// ```
+pub fn clamp_to_srgb_gamut(lch: OkLch) -> OkLch {
+    let lch = OkLch {
+        l: lch.l.clamp(0.0, 1.0),
+        c: lch.c.max(0.0),
+        ..lch
+    };
+    if in_srgb_gamut(lch) {
+        return lch;
+    }
+    let mut lo = 0.0;
+    let mut hi = lch.c;
+    for _ in 0..32 {
+        let mid = (lo + hi) / 2.0;
+        match in_srgb_gamut(OkLch { c: mid, ..lch }) {
+            true => lo = mid,
+            false => hi = mid,
+        }
+    }
+    OkLch { c: lo, ..lch }
+}
+
+/// Convert [`OkLch`] to sRGB via [`clamp_to_srgb_gamut`], so the hue
+/// survives instead of being skewed by per-channel clipping.
+pub(crate) fn oklch_to_srgb_in_gamut(lch: OkLch) -> Color {
+    oklab_to_srgb(oklch_to_oklab(clamp_to_srgb_gamut(lch)))
+}
+
+impl Color {
+    /// This color in [`OkLab`]. Alpha is ignored.
+    pub fn to_oklab(&self) -> OkLab {
+        srgb_to_oklab(*self)
+    }
+
+    /// Opaque sRGB color from OKLab coordinates. Out-of-gamut channels are
+    /// clipped.
+    pub fn from_oklab(l: f64, a: f64, b: f64) -> Self {
+        oklab_to_srgb(OkLab { l, a, b })
+    }
+
+    /// This color in [`OkLch`]. Alpha is ignored.
+    ///
+    /// ```
+    /// use palette_core::Color;
+    ///
+    /// let accent = Color::from_hex("#7AA2F7").unwrap();
+    /// let lch = accent.to_oklch();
+    /// let muted = Color::from_oklch(lch.l, lch.c * 0.5, lch.h);
+    /// assert!(muted.to_oklch().c < lch.c);
+    /// ```
+    pub fn to_oklch(&self) -> OkLch {
+        srgb_to_oklch(*self)
+    }
+
+    /// Opaque sRGB color from OKLCH coordinates, hue in degrees.
+    ///
+    /// Out-of-gamut colors lose chroma until they fit, so lightness and hue
+    /// survive instead of being skewed by per-channel clipping.
+    pub fn from_oklch(l: f64, c: f64, h: f64) -> Self {
+        oklch_to_srgb_in_gamut(OkLch {
+            l,
+            c,
+            h: h.rem_euclid(360.0),
+        })
+    }
+}
+
+// --- sRGB ↔ CIELAB and CIEDE2000 ---
+
+/// Convert an sRGB [`Color`] to CIE [`Lab`] (D65).
+pub fn srgb_to_lab(color: Color) -> Lab {
+    let r = srgb_to_linear(color.r);
+    let g = srgb_to_linear(color.g);
+    let b = srgb_to_linear(color.b);
+
+    // Linear sRGB → XYZ, normalized to the D65 reference white
+    let x = (0.4124564 * r + 0.3575761 * g + 0.1804375 * b) / 0.95047;
+    let y = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+    let z = (0.0193339 * r + 0.1191920 * g + 0.9503041 * b) / 1.08883;
+
+    let f = |t: f64| match t > 216.0 / 24389.0 {
+        true => t.cbrt(),
+        false => (24389.0 / 27.0 * t + 16.0) / 116.0,
+    };
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+    Lab {
+        l: 116.0 * fy - 16.0,
+        a: 500.0 * (fx - fy),
+        b: 200.0 * (fy - fz),
+    }
+}
+
+/// CIEDE2000 color difference between two CIELAB colors.
+///
+/// Roughly, 1.0 is a just-noticeable difference and values above 10 read as
+/// clearly different colors. Follows Sharma, Wu & Dalal (2005) with unit
+/// weighting factors.
+pub fn delta_e_2000(lab1: Lab, lab2: Lab) -> f64 {
+    const POW25_7: f64 = 6_103_515_625.0; // 25^7
+
+    let c_bar = (lab1.a.hypot(lab1.b) + lab2.a.hypot(lab2.b)) / 2.0;
+    let g = 0.5 * (1.0 - (c_bar.powi(7) / (c_bar.powi(7) + POW25_7)).sqrt());
+    let (a1, a2) = ((1.0 + g) * lab1.a, (1.0 + g) * lab2.a);
+    let (c1, c2) = (a1.hypot(lab1.b), a2.hypot(lab2.b));
+    let hue = |b: f64, a: f64| match b == 0.0 && a == 0.0 {
+        true => 0.0,
+        false => b.atan2(a).to_degrees().rem_euclid(360.0),
+    };
+    let (h1, h2) = (hue(lab1.b, a1), hue(lab2.b, a2));
+    let chromatic = c1 * c2 != 0.0;
+
+    let dh = match (chromatic, h2 - h1) {
+        (false, _) => 0.0,
+        (true, d) if d > 180.0 => d - 360.0,
+        (true, d) if d < -180.0 => d + 360.0,
+        (true, d) => d,
+    };
+    let dl = lab2.l - lab1.l;
+    let dc = c2 - c1;
+    let dh_big = 2.0 * (c1 * c2).sqrt() * (dh / 2.0).to_radians().sin();
+
+    let l_bar = (lab1.l + lab2.l) / 2.0;
+    let c_bar = (c1 + c2) / 2.0;
+    let h_bar = match (chromatic, (h1 - h2).abs() <= 180.0, h1 + h2 < 360.0) {
+        (false, _, _) => h1 + h2,
+        (true, true, _) => (h1 + h2) / 2.0,
+        (true, false, true) => (h1 + h2 + 360.0) / 2.0,
+        (true, false, false) => (h1 + h2 - 360.0) / 2.0,
+    };
+
+    let cos_deg = |deg: f64| deg.to_radians().cos();
+    let t = 1.0 - 0.17 * cos_deg(h_bar - 30.0)
+        + 0.24 * cos_deg(2.0 * h_bar)
+        + 0.32 * cos_deg(3.0 * h_bar + 6.0)
+        - 0.20 * cos_deg(4.0 * h_bar - 63.0);
+    let d_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let r_c = 2.0 * (c_bar.powi(7) / (c_bar.powi(7) + POW25_7)).sqrt();
+    let l_dev = (l_bar - 50.0).powi(2);
+    let s_l = 1.0 + 0.015 * l_dev / (20.0 + l_dev).sqrt();
+    let s_c = 1.0 + 0.045 * c_bar;
+    let s_h = 1.0 + 0.015 * c_bar * t;
+    let r_t = -(2.0 * d_theta).to_radians().sin() * r_c;
+
+    let (l_term, c_term, h_term) = (dl / s_l, dc / s_c, dh_big / s_h);
+    (l_term.powi(2) + c_term.powi(2) + h_term.powi(2) + r_t * c_term * h_term).sqrt()
+}
+
+impl Color {
+    /// This color in CIE [`Lab`] (D65). Alpha is ignored.
+    pub fn to_lab(&self) -> Lab {
+        srgb_to_lab(*self)
+    }
+
+    /// CIEDE2000 difference from `other`; see [`delta_e_2000`].
+    ///
+    /// ```
+    /// use palette_core::Color;
+    ///
+    /// let a = Color::from_hex("#7AA2F7").unwrap();
+    /// let b = Color::from_hex("#7AA3F7").unwrap();
+    /// assert!(a.delta_e(&b) < 1.0); // visually identical
+    /// assert!(a.delta_e(&Color::from_hex("#F7768E").unwrap()) > 10.0);
+    /// ```
+    pub fn delta_e(&self, other: &Color) -> f64 {
+        delta_e_2000(self.to_lab(), other.to_lab())
+    }
+}
+
 // --- Interpolation helpers ---
 
 /// Linearly interpolate between two [`OkLab`] values.
@@ -142,6 +392,21 @@ pub fn lerp_oklch(a: OkLch, b: OkLch, t: f64) -> OkLch {
     OkLch { l, c, h }
 }
 
+/// Interpolate between two [`Hsl`] values with shortest-arc hue. A gray
+/// endpoint has no meaningful hue, so it takes the other endpoint's.
+pub(crate) fn lerp_hsl(a: Hsl, b: Hsl, t: f64) -> Hsl {
+    let (h0, h1) = match (a.s == 0.0, b.s == 0.0) {
+        (true, false) => (b.h, b.h),
+        (false, true) => (a.h, a.h),
+        _ => (a.h, b.h),
+    };
+    Hsl {
+        h: shortest_arc_lerp(h0, h1, t),
+        s: a.s + (b.s - a.s) * t,
+        l: a.l + (b.l - a.l) * t,
+    }
+}
+
 fn shortest_arc_lerp(h0: f64, h1: f64, t: f64) -> f64 {
     let mut diff = h1 - h0;
     match () {
@@ -152,6 +417,178 @@ fn shortest_arc_lerp(h0: f64, h1: f64, t: f64) -> f64 {
     (h0 + diff * t).rem_euclid(360.0)
 }
 
+/// Sample `steps` colors interpolated in OKLab from `from` to `to`.
+///
+/// - `steps == 0`: returns an empty slice
+/// - `steps == 1`: returns `[from]`
+/// - `steps >= 2`: endpoints are exact
+///
+/// Shorthand for a two-stop [`Gradient`] sampled with [`Gradient::sample`].
+pub fn gradient(from: Color, to: Color, steps: usize) -> Box<[Color]> {
+    let stops = [
+        GradientStop {
+            color: from,
+            position: 0.0,
+        },
+        GradientStop {
+            color: to,
+            position: 1.0,
+        },
+    ];
+    Gradient::new_unchecked(stops, ColorSpace::OkLab).sample(steps)
+}
+
+/// Sample `steps` colors interpolated in OKLCH from `from` to `to`.
+///
+/// Unlike [`gradient`], intermediate steps keep their chroma and travel the
+/// short way round the hue wheel, so every step of a chart series or heatmap
+/// scale stays vivid. Step counts behave as in [`gradient`].
+///
+/// ```
+/// use palette_core::Color;
+/// use palette_core::manipulation::ramp;
+///
+/// let blue = Color::from_hex("#7AA2F7").unwrap();
+/// let red = Color::from_hex("#F7768E").unwrap();
+/// let series = ramp(blue, red, 5);
+/// assert_eq!((series[0], series[4]), (blue, red));
+/// ```
+pub fn ramp(from: Color, to: Color, steps: usize) -> Box<[Color]> {
+    let stops = [
+        GradientStop {
+            color: from,
+            position: 0.0,
+        },
+        GradientStop {
+            color: to,
+            position: 1.0,
+        },
+    ];
+    Gradient::new_unchecked(stops, ColorSpace::OkLch).sample(steps)
+}
+
+/// OkLch lightness of the lightest step of [`Color::shades`].
+const SHADES_LIGHTEST: f64 = 0.97;
+/// OkLch lightness of the darkest step of [`Color::shades`].
+const SHADES_DARKEST: f64 = 0.22;
+
+impl Color {
+    /// `n` shades of this color's hue, from near-white to near-black.
+    ///
+    /// The scale runs through the color itself, which lands exactly on the
+    /// middle step when `n` is odd, like a design system's `50`–`900` scale.
+    /// Chroma eases off at the extremes where sRGB cannot hold it. `n == 0`
+    /// is empty and `n == 1` is `[self]`.
+    ///
+    /// ```
+    /// use palette_core::Color;
+    ///
+    /// let accent = Color::from_hex("#7AA2F7").unwrap();
+    /// let scale = accent.shades(9);
+    /// assert_eq!(scale[4], accent);
+    /// assert!(scale[0].relative_luminance() > scale[8].relative_luminance());
+    /// ```
+    pub fn shades(&self, n: usize) -> Box<[Color]> {
+        if n == 1 {
+            return Box::new([*self]);
+        }
+        let lch = self.to_oklch();
+        let at = |l: f64| oklch_to_srgb_in_gamut(OkLch { l, ..lch }).with_alpha(self.a);
+        let stops = [
+            GradientStop {
+                color: at(SHADES_LIGHTEST.max(lch.l)),
+                position: 0.0,
+            },
+            GradientStop {
+                color: *self,
+                position: 0.5,
+            },
+            GradientStop {
+                color: at(SHADES_DARKEST.min(lch.l)),
+                position: 1.0,
+            },
+        ];
+        Gradient::new_unchecked(stops, ColorSpace::OkLch).sample(n)
+    }
+}
+
+/// OkLch lightness of each [`ColorScale`] step, after Tailwind's palette.
+const SCALE_LIGHTNESS: [f64; 10] = [
+    0.97, 0.935, 0.885, 0.81, 0.71, 0.635, 0.56, 0.49, 0.43, 0.38,
+];
+/// Fraction of the source chroma kept at each [`ColorScale`] step; the
+/// palest tints and deepest shades stay muted.
+const SCALE_CHROMA: [f64; 10] = [0.2, 0.4, 0.65, 0.85, 1.0, 1.0, 1.0, 0.9, 0.8, 0.7];
+
+/// A Tailwind-style `50`–`900` tonal scale; see [`Color::scale`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ColorScale {
+    colors: [Color; 10],
+}
+
+impl ColorScale {
+    /// Step names, lightest first.
+    pub const STEPS: [u16; 10] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900];
+
+    /// The color at `step` (`50`, `100`, …, `900`), or `None` for any other
+    /// value.
+    pub fn get(&self, step: u16) -> Option<Color> {
+        Self::STEPS
+            .iter()
+            .position(|&s| s == step)
+            .map(|i| self.colors[i])
+    }
+
+    /// Colors in step order, lightest first.
+    pub fn colors(&self) -> &[Color; 10] {
+        &self.colors
+    }
+
+    /// `(step, color)` pairs, lightest first.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, Color)> + '_ {
+        Self::STEPS.into_iter().zip(self.colors)
+    }
+}
+
+impl Color {
+    /// A `50`–`900` tonal scale of this color's hue, like Tailwind or
+    /// Material tonal palettes.
+    ///
+    /// Unlike [`shades`](Self::shades), every step sits at a fixed lightness,
+    /// so scales built from different accents line up step for step. The
+    /// step nearest this color's own lightness is the color itself, so the
+    /// accent appears verbatim. Chroma tapers toward both ends and is
+    /// reduced further wherever sRGB cannot hold it.
+    ///
+    /// ```
+    /// use palette_core::Color;
+    ///
+    /// let accent = Color::from_hex("#7AA2F7").unwrap();
+    /// let scale = accent.scale();
+    /// assert!(scale.iter().any(|(_, c)| c == accent));
+    /// assert!(scale.get(50).unwrap().is_light());
+    /// assert!(!scale.get(900).unwrap().is_light());
+    /// ```
+    pub fn scale(&self) -> ColorScale {
+        let lch = self.to_oklch();
+        let nearest = SCALE_LIGHTNESS
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (*a - lch.l).abs().total_cmp(&(*b - lch.l).abs()))
+            .map_or(0, |(i, _)| i);
+        let colors = std::array::from_fn(|i| match i == nearest {
+            true => *self,
+            false => oklch_to_srgb_in_gamut(OkLch {
+                l: SCALE_LIGHTNESS[i],
+                c: lch.c * SCALE_CHROMA[i],
+                h: lch.h,
+            })
+            .with_alpha(self.a),
+        });
+        ColorScale { colors }
+    }
+}
+
 pub(crate) struct Hsl {
     pub(crate) h: f64, // [0, 360)
     pub(crate) s: f64, // [0, 1]
@@ -180,18 +617,22 @@ fn hsl_from_delta(r: f64, g: f64, b: f64, max: f64, l: f64, delta: f64) -> Hsl {
         false => delta / (2.0 * l),
     };
 
+    Hsl {
+        h: hue_from_delta(r, g, b, max, delta),
+        s,
+        l,
+    }
+}
+
+/// Hue in degrees `[0, 360)` of a chromatic color (`delta > 0`).
+fn hue_from_delta(r: f64, g: f64, b: f64, max: f64, delta: f64) -> f64 {
     let h_raw = match (max == r, max == g) {
         (true, _) if g >= b => (g - b) / delta,
         (true, _) => (g - b) / delta + 6.0,
         (_, true) => (b - r) / delta + 2.0,
         _ => (r - g) / delta + 4.0,
     };
-
-    Hsl {
-        h: h_raw * 60.0,
-        s,
-        l,
-    }
+    h_raw * 60.0
 }
 
 fn hue_to_channel(p: f64, q: f64, t: f64) -> f64 {
@@ -212,7 +653,12 @@ pub(crate) fn hsl_to_rgb(hsl: Hsl) -> Color {
     match hsl.s == 0.0 {
         true => {
             let v = clamp_channel(hsl.l);
-            Color { r: v, g: v, b: v }
+            Color {
+                r: v,
+                g: v,
+                b: v,
+                a: 255,
+            }
         }
         false => hsl_chromatic_to_rgb(hsl),
     }
@@ -229,6 +675,69 @@ fn hsl_chromatic_to_rgb(hsl: Hsl) -> Color {
         r: clamp_channel(hue_to_channel(p, q, h + 1.0 / 3.0)),
         g: clamp_channel(hue_to_channel(p, q, h)),
         b: clamp_channel(hue_to_channel(p, q, h - 1.0 / 3.0)),
+        a: 255,
+    }
+}
+
+// --- HSV ---
+
+/// HSV (hue, saturation, value) form of an sRGB color.
+///
+/// Achromatic colors (S = 0) have h = 0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsv {
+    /// Hue in degrees \[0, 360).
+    pub h: f64,
+    /// Saturation \[0, 1\].
+    pub s: f64,
+    /// Value (brightness) \[0, 1\].
+    pub v: f64,
+}
+
+impl Color {
+    /// This color in [`Hsv`]. Alpha is ignored.
+    pub fn to_hsv(&self) -> Hsv {
+        let r = f64::from(self.r) / 255.0;
+        let g = f64::from(self.g) / 255.0;
+        let b = f64::from(self.b) / 255.0;
+
+        let max = r.max(g).max(b);
+        let delta = max - r.min(g).min(b);
+        match delta == 0.0 {
+            true => Hsv {
+                h: 0.0,
+                s: 0.0,
+                v: max,
+            },
+            false => Hsv {
+                h: hue_from_delta(r, g, b, max, delta),
+                s: delta / max,
+                v: max,
+            },
+        }
+    }
+
+    /// Opaque color from HSV: hue in degrees, saturation and value in
+    /// `[0, 1]` (clamped).
+    ///
+    /// ```
+    /// use palette_core::Color;
+    ///
+    /// assert_eq!(&*Color::from_hsv(120.0, 1.0, 0.5).to_hex(), "#008000");
+    /// ```
+    pub fn from_hsv(h: f64, s: f64, v: f64) -> Self {
+        let s = s.clamp(0.0, 1.0);
+        let v = v.clamp(0.0, 1.0);
+        let l = v * (1.0 - s / 2.0);
+        let s_l = match l == 0.0 || l == 1.0 {
+            true => 0.0,
+            false => (v - l) / l.min(1.0 - l),
+        };
+        hsl_to_rgb(Hsl {
+            h: h.rem_euclid(360.0),
+            s: s_l,
+            l,
+        })
     }
 }
 
@@ -237,7 +746,7 @@ fn adjust_hsl(color: Color, amount: f64, adjust: fn(&mut Hsl, f64)) -> Color {
         true => {
             let mut hsl = rgb_to_hsl(color);
             adjust(&mut hsl, amount);
-            hsl_to_rgb(hsl)
+            hsl_to_rgb(hsl).with_alpha(color.a)
         }
         false => color,
     }
@@ -272,6 +781,56 @@ impl Color {
     }
 }
 
+impl Color {
+    /// Invert each RGB channel (`255 - c`), keeping alpha.
+    pub fn invert(self) -> Self {
+        Color {
+            r: 255 - self.r,
+            g: 255 - self.g,
+            b: 255 - self.b,
+            a: self.a,
+        }
+    }
+
+    /// The gray with the same WCAG relative luminance, keeping alpha.
+    ///
+    /// Contrast ratios against other grayscaled colors match the originals
+    /// up to 8-bit rounding, unlike averaging channels or zeroing HSL
+    /// saturation.
+    pub fn grayscale(self) -> Self {
+        let v = linear_to_srgb(self.relative_luminance());
+        Color::rgb(v, v, v).with_alpha(self.a)
+    }
+}
+
+fn adjust_oklab_lightness(color: Color, delta: f64) -> Color {
+    match delta.is_finite() {
+        true => {
+            let lch = srgb_to_oklch(color);
+            let l = (lch.l + delta).clamp(0.0, 1.0);
+            oklch_to_srgb_in_gamut(OkLch { l, ..lch }).with_alpha(color.a)
+        }
+        false => color,
+    }
+}
+
+impl Color {
+    /// Increase OKLab lightness by `amount` (0.0–1.0), keeping hue and chroma.
+    ///
+    /// Unlike [`lighten`](Self::lighten), equal amounts look like equal steps
+    /// on every hue, and saturated colors do not drift toward a neighboring
+    /// hue. Chroma is reduced only if the result would leave the sRGB gamut.
+    pub fn lighten_oklab(self, amount: f64) -> Self {
+        adjust_oklab_lightness(self, amount)
+    }
+
+    /// Decrease OKLab lightness by `amount` (0.0–1.0), keeping hue and chroma.
+    /// See [`lighten_oklab`](Self::lighten_oklab).
+    pub fn darken_oklab(self, amount: f64) -> Self {
+        adjust_oklab_lightness(self, -amount)
+    }
+}
+
 impl Color {
     /// Alpha-composite `self` over `bg`. See [`blend`].
     pub fn blend(self, bg: Color, alpha: f64) -> Color {
@@ -281,7 +840,9 @@ impl Color {
 
 /// Alpha-composite `fg` over `bg` in RGB space.
 ///
-/// `alpha` is clamped to `[0, 1]`. Non-finite alpha returns `bg`.
+/// `alpha` is clamped to `[0, 1]`. Non-finite alpha returns `bg`. The
+/// result is opaque when `bg` is; `fg`'s own alpha channel is ignored. To
+/// composite colors that carry their own alpha, use [`over`].
 pub fn blend(fg: Color, bg: Color, alpha: f64) -> Color {
     match alpha.is_finite() {
         true => {
@@ -294,8 +855,325 @@ pub fn blend(fg: Color, bg: Color, alpha: f64) -> Color {
                 r: mix(fg.r, bg.r),
                 g: mix(fg.g, bg.g),
                 b: mix(fg.b, bg.b),
+                a: mix(255, bg.a),
             }
         }
         false => bg,
     }
 }
+
+impl Color {
+    /// Composite `self` over `bg` using both colors' own alpha. See [`over`].
+    pub fn over(self, bg: Color) -> Color {
+        over(self, bg)
+    }
+}
+
+/// Porter–Duff source-over: composite `fg` over `bg`, each weighted by its
+/// own alpha channel.
+///
+/// Unlike [`blend`], no alpha scalar is passed; translucent layers carry
+/// their opacity with them, and the result is translucent only if both
+/// inputs are. Stacks of layers compose by chaining, innermost background
+/// last. Channels are mixed in gamma-encoded sRGB, as browsers do.
+///
+/// ```
+/// use palette_core::Color;
+///
+/// let surface = Color::from_hex("#1A1B26").unwrap();
+/// let hover = Color::from_hex("#FFFFFF14").unwrap();
+/// let selection = Color::from_hex("#7AA2F740").unwrap();
+/// let shown = selection.over(hover).over(surface);
+/// assert!(shown.is_opaque());
+/// ```
+pub fn over(fg: Color, bg: Color) -> Color {
+    let fa = fg.alpha_f64();
+    let ba = bg.alpha_f64() * (1.0 - fa);
+    let out_a = fa + ba;
+    match out_a > 0.0 {
+        true => {
+            let mix = |f: u8, b: u8| -> u8 {
+                let v = (f64::from(f) * fa + f64::from(b) * ba) / out_a;
+                v.round().clamp(0.0, 255.0) as u8
+            };
+            Color {
+                r: mix(fg.r, bg.r),
+                g: mix(fg.g, bg.g),
+                b: mix(fg.b, bg.b),
+                a: (out_a * 255.0).round().clamp(0.0, 255.0) as u8,
+            }
+        }
+        false => Color::rgba(0, 0, 0, 0),
+    }
+}
+
+impl Color {
+    /// Mix `weight` of `self` with the rest of `other`. See [`mix`].
+    pub fn mix(self, other: Color, weight: f64, space: ColorSpace) -> Color {
+        mix(self, other, weight, space)
+    }
+}
+
+/// Mix `weight` (0.0–1.0) of `a` with `1 - weight` of `b`, interpolating in
+/// `space`.
+///
+/// Matches Sass `mix()` and CSS `color-mix(in <space>, a <weight>, b)`:
+/// `weight == 1.0` returns `a` and `0.0` returns `b`. Unlike [`blend`], both
+/// colors are treated symmetrically and the mix can run in a perceptual
+/// space. Alpha is interpolated linearly rather than premultiplied.
+/// Non-finite weight returns `a` unchanged.
+///
+/// ```
+/// use palette_core::{Color, ColorSpace};
+/// use palette_core::manipulation::mix;
+///
+/// let bg = Color::from_hex("#1A1B26").unwrap();
+/// let fg = Color::from_hex("#C0CAF5").unwrap();
+/// // A raised surface 10% of the way from background to foreground.
+/// let raised = mix(fg, bg, 0.1, ColorSpace::OkLch);
+/// assert!(raised.relative_luminance() > bg.relative_luminance());
+/// ```
+pub fn mix(a: Color, b: Color, weight: f64, space: ColorSpace) -> Color {
+    match weight.is_finite() {
+        true => a.lerp(&b, 1.0 - weight.clamp(0.0, 1.0), space),
+        false => a,
+    }
+}
+
+// --- Palette interpolation ---
+
+impl Palette {
+    /// The palette `t` (0.0–1.0) of the way from `self` to `other`, for
+    /// animated theme transitions: call it once per frame with a rising `t`.
+    ///
+    /// Slots set in both palettes are interpolated in OkLab. A slot set in
+    /// only one keeps that color for the whole transition instead of
+    /// popping in or out. Everything that is not a slot (`meta`, syntax
+    /// styles, gradient definitions, platform overrides, extensions) comes
+    /// from whichever palette is nearer, switching at `t = 0.5`; token
+    /// gradient stops still follow their interpolated slots. `t` is clamped
+    /// and NaN counts as `0.0`.
+    ///
+    /// ```
+    /// use palette_core::load_preset;
+    ///
+    /// let night = load_preset("tokyonight").unwrap();
+    /// let day = load_preset("tokyonight_day").unwrap();
+    /// assert_eq!(night.interpolate(&day, 0.0), night);
+    /// assert_eq!(night.interpolate(&day, 1.0).base, day.base);
+    /// ```
+    pub fn interpolate(&self, other: &Palette, t: f64) -> Palette {
+        let t = match t.is_nan() {
+            true => 0.0,
+            false => t.clamp(0.0, 1.0),
+        };
+        let mut mid = match t < 0.5 {
+            true => self.clone(),
+            false => other.clone(),
+        };
+        for slot in SlotId::all() {
+            let color = match (self.get(slot), other.get(slot)) {
+                (Some(a), Some(b)) => Some(a.lerp(&b, t, ColorSpace::OkLab)),
+                (a, b) => a.or(b),
+            };
+            mid.set(slot, color);
+        }
+        mid
+    }
+}
+
+// --- Whole-palette tone transforms ---
+
+/// OkLch hue of the sepia tint (warm brown).
+const SEPIA_HUE: f64 = 70.0;
+/// OkLch chroma of the sepia tint.
+const SEPIA_CHROMA: f64 = 0.05;
+/// Chroma ceiling for [`Palette::monochrome`].
+const MONOCHROME_CHROMA: f64 = 0.10;
+
+/// The in-gamut color with the given OkLch hue and chroma whose WCAG relative
+/// luminance matches `target`.
+fn with_luminance(target: f64, chroma: f64, hue: f64) -> Color {
+    let mut lo = 0.0;
+    let mut hi = 1.0;
+    let mut best = oklch_to_srgb_in_gamut(OkLch {
+        l: 0.5,
+        c: chroma,
+        h: hue,
+    });
+    for _ in 0..24 {
+        let mid = (lo + hi) / 2.0;
+        best = oklch_to_srgb_in_gamut(OkLch {
+            l: mid,
+            c: chroma,
+            h: hue,
+        });
+        match best.relative_luminance() < target {
+            true => lo = mid,
+            false => hi = mid,
+        }
+    }
+    best
+}
+
+impl Palette {
+    /// Recolor every slot to a single OkLch `hue` (degrees).
+    ///
+    /// Each color keeps its WCAG relative luminance, so every contrast ratio
+    /// survives up to 8-bit rounding. Chroma is kept up to a modest ceiling,
+    /// so neutral slots stay near-gray and vivid slots become the most
+    /// saturated shades of `hue`. Useful for focus modes and single-ink
+    /// displays.
+    pub fn monochrome(&self, hue: f64) -> Palette {
+        let hue = hue.rem_euclid(360.0);
+        self.map_colors(|c| {
+            let chroma = srgb_to_oklch(c).c.min(MONOCHROME_CHROMA);
+            with_luminance(c.relative_luminance(), chroma, hue).with_alpha(c.a)
+        })
+    }
+
+    /// The opposite scheme of this palette: a light counterpart of a dark
+    /// theme, or a dark one of a light theme.
+    ///
+    /// Every color's OkLch lightness is mirrored (`L → 1 − L`) while hue and
+    /// chroma are kept, so accents stay recognizable. The result is then run
+    /// through [`fix_palette`] at [`ContrastLevel::AaNormal`], since mirrored
+    /// lightness alone does not guarantee readable text. A `style` tag is
+    /// replaced with `"light"` or `"dark"` to match the new background.
+    ///
+    /// ```
+    /// use palette_core::{Brightness, load_preset};
+    ///
+    /// let night = load_preset("tokyonight").unwrap();
+    /// let day = night.to_inverted_scheme();
+    /// assert_eq!(day.brightness(), Some(Brightness::Light));
+    /// assert_eq!(&*day.meta.unwrap().style, "light");
+    /// ```
+    pub fn to_inverted_scheme(&self) -> Palette {
+        let mut inverted = self.map_colors(|c| {
+            let lch = srgb_to_oklch(c);
+            oklch_to_srgb_in_gamut(OkLch {
+                l: 1.0 - lch.l,
+                ..lch
+            })
+            .with_alpha(c.a)
+        });
+        if let (Some(meta), Some(brightness)) = (&inverted.meta, inverted.brightness()) {
+            let mut meta = (**meta).clone();
+            meta.style = Arc::from(brightness.as_str());
+            inverted.meta = Some(Arc::new(meta));
+        }
+        fix_palette(&inverted, ContrastLevel::AaNormal).0
+    }
+
+    /// Recolor every slot to a uniform warm sepia tone.
+    ///
+    /// Like [`monochrome`](Self::monochrome), each color keeps its WCAG
+    /// relative luminance so contrast survives; unlike it, every slot gets
+    /// the same tint, which reads as aged paper for print and e-ink previews.
+    pub fn sepia(&self) -> Palette {
+        self.map_colors(|c| {
+            with_luminance(c.relative_luminance(), SEPIA_CHROMA, SEPIA_HUE).with_alpha(c.a)
+        })
+    }
+
+    /// [`Color::rotate_hue`] applied to every color, as in
+    /// [`map_colors`](Self::map_colors) — "gruvbox but colder" is
+    /// `load_preset("gruvbox_dark")?.rotate_hue(-30.0)`.
+    pub fn rotate_hue(&self, degrees: f64) -> Palette {
+        self.map_colors(|c| c.rotate_hue(degrees))
+    }
+
+    /// [`Color::saturate`] applied to every color.
+    pub fn saturate(&self, amount: f64) -> Palette {
+        self.map_colors(|c| c.saturate(amount))
+    }
+
+    /// [`Color::desaturate`] applied to every color.
+    pub fn desaturate(&self, amount: f64) -> Palette {
+        self.map_colors(|c| c.desaturate(amount))
+    }
+
+    /// [`Color::lighten`] applied to every color, backgrounds included.
+    pub fn lighten(&self, amount: f64) -> Palette {
+        self.map_colors(|c| c.lighten(amount))
+    }
+
+    /// [`Color::darken`] applied to every color, backgrounds included.
+    pub fn darken(&self, amount: f64) -> Palette {
+        self.map_colors(|c| c.darken(amount))
+    }
+}
+
+/// OkLab lightness step from a slot's color to its hover state.
+const HOVER_STEP: f64 = 0.05;
+/// OkLab lightness step to the active (selected, focused) state.
+const ACTIVE_STEP: f64 = 0.08;
+/// OkLab lightness step to the pressed state.
+const PRESSED_STEP: f64 = 0.12;
+/// Opacity of a disabled control over the background.
+const DISABLED_ALPHA: f64 = 0.4;
+
+/// Widget state variants derived from one slot; see
+/// [`Palette::interaction_states`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
+pub struct StateColors {
+    /// The slot's own color.
+    pub normal: Color,
+    /// Pointer over the control.
+    pub hover: Color,
+    /// Selected or focused.
+    pub active: Color,
+    /// Being clicked or held.
+    pub pressed: Color,
+    /// Unavailable: the color faded toward the background.
+    pub disabled: Color,
+}
+
+impl Palette {
+    /// Hover, active, pressed, and disabled variants of `slot`, or `None`
+    /// when the slot is unset.
+    ///
+    /// Each state moves the color's OkLab lightness a fixed step away from
+    /// `base.background` (lighter on dark themes, darker on light ones),
+    /// turning around when the color has no room left in that direction.
+    /// `hover` < `active` < `pressed` in distance from `normal`. `disabled`
+    /// is the color at 40% opacity over the background. The same rules on
+    /// every theme keep widget states uniform.
+    ///
+    /// ```
+    /// use palette_core::{SlotId, load_preset};
+    ///
+    /// let palette = load_preset("tokyonight").unwrap();
+    /// let button: SlotId = "surface.highlight".parse().unwrap();
+    /// let states = palette.interaction_states(button).unwrap();
+    /// assert!(states.hover.to_oklch().l > states.normal.to_oklch().l);
+    /// ```
+    pub fn interaction_states(&self, slot: SlotId) -> Option<StateColors> {
+        let normal = self.get(slot)?;
+        let background = self
+            .base
+            .background
+            .unwrap_or_else(|| match normal.is_light() {
+                true => Color::rgb(0, 0, 0),
+                false => Color::rgb(0xff, 0xff, 0xff),
+            });
+        let lightness = srgb_to_oklch(normal).l;
+        let lighter = match background.is_light() {
+            true => lightness - PRESSED_STEP < 0.0,
+            false => lightness + PRESSED_STEP <= 1.0,
+        };
+        let step = |amount: f64| match lighter {
+            true => normal.lighten_oklab(amount),
+            false => normal.darken_oklab(amount),
+        };
+        Some(StateColors {
+            normal,
+            hover: step(HOVER_STEP),
+            active: step(ACTIVE_STEP),
+            pressed: step(PRESSED_STEP),
+            disabled: normal.blend(background, DISABLED_ALPHA),
+        })
+    }
+}
@@ -1,7 +1,13 @@
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
-use crate::manifest::PaletteManifest;
+use crate::color::Color;
+#[cfg(feature = "toml")]
+use crate::manifest::ExtensionSections;
+use crate::manifest::{
+    ManifestMeta, ManifestSection, ModeSections, OpacitySections, PaletteManifest, UnsetSlots,
+};
 
 fn merge_map<V: Clone>(
     primary: &HashMap<Arc<str>, V>,
@@ -17,34 +23,120 @@ fn merge_map<V: Clone>(
     merged
 }
 
-#[cfg(feature = "platform")]
-fn merge_platform_sections(
-    primary: &crate::manifest::PlatformSections,
-    fallback: &crate::manifest::PlatformSections,
-) -> crate::manifest::PlatformSections {
-    let mut merged = crate::manifest::PlatformSections::new();
-    for (platform, section) in primary {
-        match fallback.get(platform) {
+/// Merge named sections (platforms, custom groups) slot by slot.
+fn merge_sections(
+    primary: &BTreeMap<Arc<str>, ManifestSection>,
+    fallback: &BTreeMap<Arc<str>, ManifestSection>,
+) -> BTreeMap<Arc<str>, ManifestSection> {
+    let mut merged = BTreeMap::new();
+    for (name, section) in primary {
+        match fallback.get(name) {
             Some(fb) => {
-                merged.insert(platform.clone(), merge_map(section, fb));
+                merged.insert(name.clone(), merge_map(section, fb));
             }
             None => {
-                merged.insert(platform.clone(), section.clone());
+                merged.insert(name.clone(), section.clone());
             }
         }
     }
-    for (platform, section) in fallback {
+    for (name, section) in fallback {
         merged
-            .entry(platform.clone())
+            .entry(name.clone())
             .or_insert_with(|| section.clone());
     }
     merged
 }
 
+/// Keep the variant's slot opacities, and the parent's for slots the
+/// variant does not set: a variant color written without an opacity is
+/// opaque.
+fn merge_opacity(variant: &PaletteManifest, base: &PaletteManifest) -> OpacitySections {
+    let mut merged = variant.opacity.clone();
+    for (name, slots) in &base.opacity {
+        let overridden = variant.color_section(name);
+        let inherited = slots
+            .iter()
+            .filter(|(field, _)| !overridden.is_some_and(|s| s.contains_key(*field)));
+        for (field, slot) in inherited {
+            merged
+                .entry(Arc::clone(name))
+                .or_default()
+                .entry(Arc::clone(field))
+                .or_insert(*slot);
+        }
+    }
+    merged
+}
+
+/// Both manifests' [`UNSET`](crate::manifest::UNSET) slots, so a merged
+/// `[when]` overlay still removes what either overlay removed.
+fn merge_unset(primary: &UnsetSlots, fallback: &UnsetSlots) -> UnsetSlots {
+    let mut merged = fallback.clone();
+    for (name, fields) in primary {
+        merged
+            .entry(Arc::clone(name))
+            .or_default()
+            .extend(fields.iter().cloned());
+    }
+    merged
+}
+
+/// Remove the parent slots `variant` sets to
+/// [`UNSET`](crate::manifest::UNSET) from `merged`.
+fn remove_unset(merged: &mut PaletteManifest, variant: &PaletteManifest) {
+    for (name, fields) in &variant.unset {
+        let own = variant.color_section(name);
+        let removed = fields
+            .iter()
+            .filter(|field| !own.is_some_and(|s| s.contains_key(*field)));
+        for field in removed {
+            if let Some(section) = merged.color_section_mut(name) {
+                section.remove(field);
+            }
+            if let Some(opacity) = merged.opacity.get_mut(name) {
+                opacity.remove(field);
+            }
+        }
+    }
+}
+
+/// Merge extension tables key by key; nested tables are replaced whole.
+#[cfg(feature = "toml")]
+pub(crate) fn merge_extensions(
+    primary: &ExtensionSections,
+    fallback: &ExtensionSections,
+) -> ExtensionSections {
+    let mut merged = fallback.clone();
+    for (name, table) in primary {
+        let entry = merged.entry(name.clone()).or_default();
+        for (key, value) in table {
+            entry.insert(key.clone(), value.clone());
+        }
+    }
+    merged
+}
+
+/// Merge `[when.<mode>]` overlays mode by mode, as whole manifests.
+fn merge_modes(primary: &ModeSections, fallback: &ModeSections) -> ModeSections {
+    let mut merged = fallback.clone();
+    for (mode, overlay) in primary {
+        let combined = match fallback.get(mode) {
+            Some(fb) => merge_manifests(overlay, fb),
+            None => overlay.clone(),
+        };
+        merged.insert(mode.clone(), combined);
+    }
+    merged
+}
+
 /// Overlay `variant` onto `base`, filling missing slots from the parent.
+///
+/// Slots the variant sets to [`UNSET`](crate::manifest::UNSET) are left
+/// out of the result, even when `base` sets them.
 pub fn merge_manifests(variant: &PaletteManifest, base: &PaletteManifest) -> PaletteManifest {
-    PaletteManifest {
+    let mut merged = PaletteManifest {
         meta: variant.meta.clone(),
+        colors: merge_map(&variant.colors, &base.colors),
         base: merge_map(&variant.base, &base.base),
         semantic: merge_map(&variant.semantic, &base.semantic),
         diff: merge_map(&variant.diff, &base.diff),
@@ -55,7 +147,414 @@ pub fn merge_manifests(variant: &PaletteManifest, base: &PaletteManifest) -> Pal
         terminal: merge_map(&variant.terminal, &base.terminal),
         syntax_style: merge_map(&variant.syntax_style, &base.syntax_style),
         gradient: merge_map(&variant.gradient, &base.gradient),
+        groups: merge_sections(&variant.groups, &base.groups),
         #[cfg(feature = "platform")]
-        platform: merge_platform_sections(&variant.platform, &base.platform),
+        platform: merge_sections(&variant.platform, &base.platform),
+        opacity: merge_opacity(variant, base),
+        unset: merge_unset(&variant.unset, &base.unset),
+        #[cfg(feature = "toml")]
+        extensions: merge_extensions(&variant.extensions, &base.extensions),
+        modes: merge_modes(&variant.modes, &base.modes),
+    };
+    remove_unset(&mut merged, variant);
+    merged
+}
+
+/// Which parent sections [`merge_manifests_with`] inherits.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MergeOptions {
+    /// Sections to take from the parent, by TOML name: `colors`, a flat
+    /// section such as `syntax`, `syntax_style`, `gradient`, a custom group,
+    /// `platform` or `platform.<name>`, an extension table, or `when` for
+    /// the mode overlays. `None` inherits every section.
+    pub sections: Option<Vec<Arc<str>>>,
+}
+
+impl MergeOptions {
+    /// Inherit `section`, as well as any sections already listed. The
+    /// first call stops every other section from being inherited.
+    pub fn section(mut self, section: impl Into<Arc<str>>) -> Self {
+        self.sections
+            .get_or_insert_with(Vec::new)
+            .push(section.into());
+        self
+    }
+
+    /// The options `variant` declares with `inherit_sections` in `[meta]`.
+    pub fn from_manifest(variant: &PaletteManifest) -> Self {
+        variant
+            .meta
+            .as_deref()
+            .map_or_else(Self::default, Self::from_meta)
+    }
+
+    /// The options declared by a variant's `[meta]` alone.
+    pub(crate) fn from_meta(meta: &ManifestMeta) -> Self {
+        Self {
+            sections: meta.inherit_sections.as_deref().map(<[Arc<str>]>::to_vec),
+        }
+    }
+}
+
+/// `parent` reduced to the sections `options` inherits. Borrows `parent`
+/// when every section is inherited.
+pub(crate) fn inherited_sections<'a>(
+    parent: &'a PaletteManifest,
+    options: &MergeOptions,
+) -> Cow<'a, PaletteManifest> {
+    let Some(sections) = &options.sections else {
+        return Cow::Borrowed(parent);
+    };
+    let listed = |name: &str| sections.iter().any(|s| s.as_ref() == name);
+    let kept = |name: &str| listed(name) || (name.starts_with("platform.") && listed("platform"));
+
+    let mut manifest = parent.clone();
+    let flat = [
+        ("colors", &mut manifest.colors),
+        ("base", &mut manifest.base),
+        ("semantic", &mut manifest.semantic),
+        ("diff", &mut manifest.diff),
+        ("surface", &mut manifest.surface),
+        ("typography", &mut manifest.typography),
+        ("syntax", &mut manifest.syntax),
+        ("editor", &mut manifest.editor),
+        ("terminal", &mut manifest.terminal),
+        ("syntax_style", &mut manifest.syntax_style),
+    ];
+    for (name, section) in flat {
+        if !kept(name) {
+            section.clear();
+        }
+    }
+    if !kept("gradient") {
+        manifest.gradient.clear();
+    }
+    if !kept("when") {
+        manifest.modes.clear();
+    }
+    manifest.groups.retain(|name, _| kept(name));
+    #[cfg(feature = "platform")]
+    manifest
+        .platform
+        .retain(|name, _| kept(&format!("platform.{name}")));
+    #[cfg(feature = "toml")]
+    manifest.extensions.retain(|name, _| kept(name));
+    manifest.opacity.retain(|name, _| kept(name));
+    manifest.unset.retain(|name, _| kept(name));
+    Cow::Owned(manifest)
+}
+
+/// [`merge_manifests`], inheriting only the parent sections `options`
+/// lists. The variant's own sections are always kept.
+///
+/// ```
+/// use palette_core::manifest::PaletteManifest;
+/// use palette_core::merge::{MergeOptions, merge_manifests_with};
+///
+/// let parent = PaletteManifest::from_toml(
+///     "[base]\nbackground = \"#1a1b26\"\n\n[terminal]\nred = \"#f7768e\"\n",
+/// ).unwrap();
+/// let variant = PaletteManifest::from_toml("[base]\n").unwrap();
+/// let options = MergeOptions::default().section("terminal");
+/// let merged = merge_manifests_with(&variant, &parent, &options);
+/// assert!(merged.base.is_empty());
+/// assert_eq!(merged.terminal["red"].as_ref(), "#f7768e");
+/// ```
+pub fn merge_manifests_with(
+    variant: &PaletteManifest,
+    base: &PaletteManifest,
+    options: &MergeOptions,
+) -> PaletteManifest {
+    merge_manifests(variant, &inherited_sections(base, options))
+}
+
+/// [`merge_manifests_with`], passing every color the variant inherits
+/// through `f` first. `f` receives the section and slot name, so it can
+/// pick which inherited values to rewrite:
+///
+/// ```
+/// use palette_core::manifest::PaletteManifest;
+/// use palette_core::merge::{MergeOptions, merge_manifests_mapped};
+///
+/// let parent = PaletteManifest::from_toml("[base]\nbackground = \"#808080\"\n").unwrap();
+/// let dimmed = PaletteManifest::from_toml("[base]\n").unwrap();
+/// let merged = merge_manifests_mapped(&dimmed, &parent, &MergeOptions::default(), |_, field, color| {
+///     match field.starts_with("background") {
+///         true => color.darken(0.05),
+///         false => color,
+///     }
+/// });
+/// assert_ne!(merged.base["background"].as_ref(), "#808080");
+/// ```
+///
+/// Slots the variant sets itself are never passed to `f`. Values that are
+/// not literal colors, such as `"$name"` references, are inherited
+/// unchanged; the `[colors]` entry they name is passed to `f` under the
+/// `colors` section instead. Style modifiers and gradient stops are not
+/// passed.
+pub fn merge_manifests_mapped(
+    variant: &PaletteManifest,
+    base: &PaletteManifest,
+    options: &MergeOptions,
+    mut f: impl FnMut(&str, &str, Color) -> Color,
+) -> PaletteManifest {
+    let mut inherited = inherited_sections(base, options).into_owned();
+    let names: Vec<Arc<str>> = slot_section_names(&inherited)
+        .filter(|name| name.as_ref() != "syntax_style")
+        .collect();
+    for name in names {
+        let own = slot_section(variant, &name);
+        let section = match name.as_ref() {
+            "colors" => Some(&mut inherited.colors),
+            _ => inherited.color_section_mut(&name),
+        };
+        let Some(section) = section else {
+            continue;
+        };
+        for (field, value) in section.iter_mut() {
+            if own.is_some_and(|s| s.contains_key(field)) {
+                continue;
+            }
+            if let Ok(color) = Color::parse(value) {
+                *value = Arc::from(f(&name, field, color).to_hex());
+            }
+        }
+    }
+    merge_manifests(variant, &inherited)
+}
+
+/// Merge `layers` ordered from lowest to highest priority, such as
+/// `[base, preset, user_overrides]`. Each layer wins over the ones before
+/// it, exactly as if it inherited from them, and an
+/// [`UNSET`](crate::manifest::UNSET) slot removes the value every lower
+/// layer gives it. The result keeps the `[meta]` of the highest layer that
+/// has one, so an override file can leave it out.
+///
+/// An empty slice gives an empty manifest.
+pub fn merge_layers(layers: &[&PaletteManifest]) -> PaletteManifest {
+    let Some((top, below)) = layers.split_last() else {
+        return PaletteManifest::default();
+    };
+    let mut merged = PaletteManifest::clone(top);
+    for layer in below.iter().rev() {
+        merged = merge_manifests(&merged, layer);
+    }
+    merged.meta = layers.iter().rev().find_map(|layer| layer.meta.clone());
+    merged
+}
+
+/// Which layer supplied each slot of a merged manifest, from
+/// [`merge_layers_with_provenance`]. Layers are numbered from 0 in the
+/// order they were passed, lowest priority first.
+///
+/// Sections are named as in [`SlotChange::section`]; gradients are listed
+/// under `gradient`, one entry per gradient name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Provenance(BTreeMap<Arc<str>, BTreeMap<Arc<str>, usize>>);
+
+impl Provenance {
+    /// The layer that supplied `[section].field`, or `None` when the
+    /// merged manifest does not set it.
+    pub fn source(&self, section: &str, field: &str) -> Option<usize> {
+        self.0.get(section)?.get(field).copied()
+    }
+
+    /// Every merged slot as `(section, field, layer)`, ordered by section,
+    /// then slot.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str, usize)> + '_ {
+        self.0.iter().flat_map(|(section, fields)| {
+            fields
+                .iter()
+                .map(move |(field, &layer)| (section.as_ref(), field.as_ref(), layer))
+        })
+    }
+
+    /// Every slot `layer` supplied.
+    pub fn from_layer(&self, layer: usize) -> impl Iterator<Item = (&str, &str)> + '_ {
+        self.iter()
+            .filter(move |&(_, _, source)| source == layer)
+            .map(|(section, field, _)| (section, field))
+    }
+}
+
+/// [`merge_layers`], also recording which layer each slot of the result
+/// came from.
+///
+/// For a variant over its parent, pass `[parent, variant]`: layer 0 is the
+/// parent and layer 1 the variant.
+///
+/// ```
+/// use palette_core::manifest::PaletteManifest;
+/// use palette_core::merge::merge_layers_with_provenance;
+///
+/// let parent = PaletteManifest::from_toml("[base]\nbackground = \"#1a1b26\"\nforeground = \"#c0caf5\"\n").unwrap();
+/// let storm = PaletteManifest::from_toml("[base]\nbackground = \"#24283b\"\n").unwrap();
+/// let (_, provenance) = merge_layers_with_provenance(&[&parent, &storm]);
+/// assert_eq!(provenance.source("base", "background"), Some(1));
+/// assert_eq!(provenance.source("base", "foreground"), Some(0));
+/// ```
+pub fn merge_layers_with_provenance(layers: &[&PaletteManifest]) -> (PaletteManifest, Provenance) {
+    let merged = merge_layers(layers);
+    let supplier = |has: &dyn Fn(&PaletteManifest) -> bool| {
+        layers.iter().rposition(|layer| has(layer)).unwrap_or(0)
+    };
+    let mut provenance = BTreeMap::<Arc<str>, BTreeMap<Arc<str>, usize>>::new();
+    for name in slot_section_names(&merged) {
+        let Some(section) = slot_section(&merged, &name).filter(|s| !s.is_empty()) else {
+            continue;
+        };
+        let fields = section
+            .keys()
+            .map(|field| {
+                let has = |layer: &PaletteManifest| {
+                    slot_section(layer, &name).is_some_and(|s| s.contains_key(field))
+                };
+                (Arc::clone(field), supplier(&has))
+            })
+            .collect();
+        provenance.insert(name, fields);
+    }
+    if !merged.gradient.is_empty() {
+        let fields = merged
+            .gradient
+            .keys()
+            .map(|name| {
+                let has = |layer: &PaletteManifest| layer.gradient.contains_key(name);
+                (Arc::clone(name), supplier(&has))
+            })
+            .collect();
+        provenance.insert(Arc::from("gradient"), fields);
+    }
+    (merged, Provenance(provenance))
+}
+
+/// One slot compared between a variant and its parent by
+/// [`diff_manifests`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlotChange {
+    /// Section name: `colors`, a flat section, a custom group, or
+    /// `platform.<name>`.
+    pub section: Arc<str>,
+    /// Slot name within the section.
+    pub field: Arc<str>,
+    /// The parent's value, when the parent sets the slot.
+    pub parent: Option<Arc<str>>,
+    /// The variant's value, when the variant sets the slot.
+    pub variant: Option<Arc<str>>,
+}
+
+/// Which slots a variant changes relative to its parent, from
+/// [`PaletteManifest::diff`]. Each list is ordered by section, then slot.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ManifestDiff {
+    /// Slots both set, to different values.
+    pub overridden: Box<[SlotChange]>,
+    /// Slots only the variant sets.
+    pub added: Box<[SlotChange]>,
+    /// Slots only the parent sets, which the variant inherits.
+    pub inherited: Box<[SlotChange]>,
+    /// Parent slots the variant sets to [`UNSET`](crate::manifest::UNSET).
+    pub removed: Box<[SlotChange]>,
+    /// Slots the variant sets to the value it would inherit anyway.
+    pub redundant: Box<[SlotChange]>,
+}
+
+impl ManifestDiff {
+    /// Whether the variant overrides, adds, or removes nothing.
+    pub fn is_unchanged(&self) -> bool {
+        self.overridden.is_empty() && self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Sections of `manifest` that hold slots, in [`to_toml`] order.
+///
+/// [`to_toml`]: PaletteManifest::to_toml
+fn slot_section_names(manifest: &PaletteManifest) -> impl Iterator<Item = Arc<str>> + '_ {
+    const FLAT: &[&str] = &[
+        "colors",
+        "base",
+        "semantic",
+        "diff",
+        "surface",
+        "typography",
+        "syntax",
+        "editor",
+        "terminal",
+        "syntax_style",
+    ];
+    let flat = FLAT.iter().map(|&name| Arc::from(name));
+    let groups = manifest.groups.keys().cloned();
+    #[cfg(feature = "platform")]
+    let platforms = manifest
+        .platform
+        .keys()
+        .map(|name| Arc::from(format!("platform.{name}")));
+    #[cfg(not(feature = "platform"))]
+    let platforms = std::iter::empty();
+    flat.chain(groups).chain(platforms)
+}
+
+/// A section [`slot_section_names`] lists.
+fn slot_section<'a>(manifest: &'a PaletteManifest, name: &str) -> Option<&'a ManifestSection> {
+    match name {
+        "colors" => Some(&manifest.colors),
+        _ => manifest.color_section(name),
+    }
+}
+
+/// Compare the slots `variant` sets against `parent`'s.
+///
+/// A slot's opacity counts as part of its value. `parent` should be fully
+/// resolved, so pass the merged manifest for a parent that itself inherits.
+/// Gradients, `[meta]`, extension tables, and `[when]` overlays are not
+/// compared.
+pub fn diff_manifests(variant: &PaletteManifest, parent: &PaletteManifest) -> ManifestDiff {
+    let mut names: Vec<Arc<str>> = Vec::new();
+    for name in slot_section_names(variant).chain(slot_section_names(parent)) {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let (mut overridden, mut added, mut inherited, mut removed, mut redundant) =
+        (Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new());
+    for name in names {
+        let ours = slot_section(variant, &name);
+        let theirs = slot_section(parent, &name);
+        let fields: std::collections::BTreeSet<&Arc<str>> = ours
+            .into_iter()
+            .chain(theirs)
+            .flat_map(HashMap::keys)
+            .collect();
+        for field in fields {
+            let value = |manifest: &PaletteManifest, slots: Option<&ManifestSection>| {
+                slots.and_then(|s| s.get(field)).map(|color| {
+                    let opacity = manifest.opacity.get(&name).and_then(|o| o.get(field));
+                    (Arc::clone(color), opacity.copied())
+                })
+            };
+            let (mine, parents) = (value(variant, ours), value(parent, theirs));
+            let unset = variant.unset.get(&name).is_some_and(|f| f.contains(field));
+            let list = match (&mine, &parents) {
+                (Some(mine), Some(parents)) if mine == parents => &mut redundant,
+                (Some(_), Some(_)) => &mut overridden,
+                (Some(_), None) => &mut added,
+                (None, _) if unset => &mut removed,
+                (None, _) => &mut inherited,
+            };
+            list.push(SlotChange {
+                section: Arc::clone(&name),
+                field: Arc::clone(field),
+                parent: parents.map(|(color, _)| color),
+                variant: mine.map(|(color, _)| color),
+            });
+        }
+    }
+    ManifestDiff {
+        overridden: overridden.into_boxed_slice(),
+        added: added.into_boxed_slice(),
+        inherited: inherited.into_boxed_slice(),
+        removed: removed.into_boxed_slice(),
+        redundant: redundant.into_boxed_slice(),
     }
 }
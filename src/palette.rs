@@ -3,21 +3,22 @@ use std::sync::Arc;
 use crate::color::Color;
 use crate::error::PaletteError;
 use crate::gradient::{ColorSpace, GradientColor, GradientDef};
-use crate::manifest::{ManifestSection, PaletteManifest, RawGradientStop};
+use crate::group::CustomGroups;
+use crate::manifest::{ManifestSection, PaletteManifest, RawGradientDef, RawGradientStop};
 use crate::style::SyntaxStyles;
 
 /// Named gradient definitions sorted by name. Immutable after construction;
 /// `Arc` keeps `Palette::clone()` a ref-count bump for gradient data.
 pub type GradientDefs = Arc<[(Arc<str>, GradientDef)]>;
 
-fn resolve_color(
+pub(crate) fn resolve_color(
     section: &ManifestSection,
     section_name: &str,
     field: &str,
 ) -> Result<Option<Color>, PaletteError> {
     match section.get(field) {
         None => Ok(None),
-        Some(hex) => Color::from_hex(hex)
+        Some(value) => Color::parse(value)
             .map(Some)
             .map_err(|e| e.into_palette_error(Arc::from(section_name), Arc::from(field))),
     }
@@ -52,6 +53,29 @@ macro_rules! color_group {
                 }
             }
 
+            /// The color in the slot named `field`, if it is set.
+            pub(crate) fn slot(&self, field: &str) -> Option<&Color> {
+                match field {
+                    $(stringify!($field) => self.$field.as_ref(),)+
+                    _ => None,
+                }
+            }
+
+            /// The slot named `field`, for assignment by name.
+            pub(crate) fn slot_mut(&mut self, field: &str) -> Option<&mut Option<Color>> {
+                match field {
+                    $(stringify!($field) => Some(&mut self.$field),)+
+                    _ => None,
+                }
+            }
+
+            /// Apply `f` to every populated slot.
+            pub fn map(&self, mut f: impl FnMut(Color) -> Color) -> Self {
+                Self {
+                    $($field: self.$field.map(&mut f),)+
+                }
+            }
+
             /// Iterate over slots that have a color assigned.
             pub fn populated_slots(&self) -> impl Iterator<Item = (&'static str, &Color)> {
                 [$(
@@ -60,6 +84,15 @@ macro_rules! color_group {
                 .into_iter()
                 .filter_map(|(name, color)| color.map(|c| (name, c)))
             }
+
+            /// Iterate mutably over slots that have a color assigned.
+            pub fn populated_slots_mut(&mut self) -> impl Iterator<Item = (&'static str, &mut Color)> {
+                [$(
+                    (stringify!($field), self.$field.as_mut()),
+                )+]
+                .into_iter()
+                .filter_map(|(name, color)| color.map(|c| (name, c)))
+            }
         }
     };
 }
@@ -287,7 +320,8 @@ macro_rules! color_fields {
 color_fields!(color_group);
 pub(crate) use color_fields;
 
-/// Theme identity: name, preset ID, and style tag (e.g. "dark", "light").
+/// Theme identity: name, preset ID, and style tag (e.g. "dark", "light"),
+/// plus the optional attribution from `[meta]`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
 pub struct PaletteMeta {
@@ -297,6 +331,57 @@ pub struct PaletteMeta {
     pub preset_id: Arc<str>,
     /// Visual style tag: `"dark"`, `"light"`, etc.
     pub style: Arc<str>,
+    /// Who made the theme.
+    #[cfg_attr(feature = "snapshot", serde(skip_serializing_if = "Option::is_none"))]
+    pub author: Option<Arc<str>>,
+    /// License the theme is distributed under.
+    #[cfg_attr(feature = "snapshot", serde(skip_serializing_if = "Option::is_none"))]
+    pub license: Option<Arc<str>>,
+    /// The theme's own release version.
+    #[cfg_attr(feature = "snapshot", serde(skip_serializing_if = "Option::is_none"))]
+    pub version: Option<Arc<str>>,
+    /// One-line summary for theme pickers.
+    #[cfg_attr(feature = "snapshot", serde(skip_serializing_if = "Option::is_none"))]
+    pub description: Option<Arc<str>>,
+    /// Free-form labels for filtering.
+    #[cfg_attr(feature = "snapshot", serde(skip_serializing_if = "<[_]>::is_empty"))]
+    pub tags: Box<[Arc<str>]>,
+}
+
+/// Whether a theme is dark or light, independent of its free-form
+/// [`style`](PaletteMeta::style) tag ("storm", "dawn", "mirage", ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "snapshot", derive(serde::Serialize))]
+pub enum Brightness {
+    /// Light text on a dark background.
+    Dark,
+    /// Dark text on a light background.
+    Light,
+}
+
+impl Brightness {
+    /// Classify a background color by WCAG relative luminance against
+    /// [`Color::LUMINANCE_MIDPOINT`].
+    pub fn of(background: Color) -> Self {
+        match background.is_light() {
+            true => Self::Light,
+            false => Self::Dark,
+        }
+    }
+
+    /// `"dark"` or `"light"`, the conventional `style` tags.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Dark => "dark",
+            Self::Light => "light",
+        }
+    }
+}
+
+impl std::fmt::Display for Brightness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// Resolved color palette ready for rendering.
@@ -331,13 +416,23 @@ pub struct Palette {
     pub syntax_style: SyntaxStyles,
     /// Named gradient definitions with validated token references, sorted by name.
     pub gradients: GradientDefs,
+    /// Registered [custom groups](crate::group) the theme defines.
+    #[cfg_attr(feature = "snapshot", serde(flatten))]
+    pub groups: CustomGroups,
     /// Per-platform color overrides.
     #[cfg(feature = "platform")]
     pub platform: crate::platform::PlatformOverrides,
+    /// App-specific tables carried over from the manifest, e.g. `[x-myapp]`.
+    #[cfg(feature = "toml")]
+    #[cfg_attr(
+        feature = "snapshot",
+        serde(skip_serializing_if = "std::collections::BTreeMap::is_empty")
+    )]
+    pub extensions: crate::manifest::ExtensionSections,
 }
 
 const fn c(r: u8, g: u8, b: u8) -> Option<Color> {
-    Some(Color { r, g, b })
+    Some(Color::rgb(r, g, b))
 }
 
 impl Default for Palette {
@@ -459,6 +554,7 @@ impl Default for Palette {
             },
             syntax_style: SyntaxStyles::default(),
             gradients: Arc::from([]),
+            groups: CustomGroups::default(),
             terminal: AnsiColors {
                 black: c(0x1a, 0x1a, 0x2e),
                 red: c(0xe0, 0x50, 0x50),
@@ -479,6 +575,8 @@ impl Default for Palette {
             },
             #[cfg(feature = "platform")]
             platform: crate::platform::PlatformOverrides::default(),
+            #[cfg(feature = "toml")]
+            extensions: Default::default(),
         }
     }
 }
@@ -507,20 +605,18 @@ fn validate_gradient_position(position: f64) -> Result<(), PaletteError> {
     }
 }
 
-/// Parse a hex literal into a `GradientColor::Literal`.
-fn parse_hex_stop(
+/// Parse a color literal into a `GradientColor::Literal`.
+fn parse_literal_stop(
     raw: &str,
     gradient_name: &Arc<str>,
     stop_index: usize,
 ) -> Result<GradientColor, PaletteError> {
-    Color::from_hex(raw)
-        .map(GradientColor::Literal)
-        .map_err(|e| {
-            e.into_palette_error(
-                gradient_section_name(gradient_name),
-                gradient_stop_field(stop_index),
-            )
-        })
+    Color::parse(raw).map(GradientColor::Literal).map_err(|e| {
+        e.into_palette_error(
+            gradient_section_name(gradient_name),
+            gradient_stop_field(stop_index),
+        )
+    })
 }
 
 /// Parse a `"section.field"` token reference, validating against known fields.
@@ -543,14 +639,15 @@ fn parse_token_stop(
     }
 }
 
-/// Parse a raw stop string as either a hex literal or a `"section.field"` token reference.
+/// Parse a raw stop string as either a color literal (hex or CSS function) or
+/// a `"section.field"` token reference.
 fn parse_gradient_stop_value(
     raw: &str,
     gradient_name: &Arc<str>,
     stop_index: usize,
 ) -> Result<GradientColor, PaletteError> {
-    match raw.starts_with('#') {
-        true => parse_hex_stop(raw, gradient_name, stop_index),
+    match raw.starts_with('#') || raw.ends_with(')') {
+        true => parse_literal_stop(raw, gradient_name, stop_index),
         false => parse_token_stop(raw, gradient_name, stop_index),
     }
 }
@@ -562,93 +659,279 @@ fn parse_color_space(
 ) -> Result<ColorSpace, PaletteError> {
     match space {
         None => Ok(ColorSpace::default()),
-        Some("oklab") => Ok(ColorSpace::OkLab),
-        Some("oklch") => Ok(ColorSpace::OkLch),
-        Some(other) => Err(PaletteError::InvalidColorSpace {
+        Some(name) => ColorSpace::from_name(name).ok_or_else(|| PaletteError::InvalidColorSpace {
             gradient: Arc::clone(gradient_name),
-            value: Arc::from(other),
+            value: Arc::from(name),
         }),
     }
 }
 
+/// Parse and validate one gradient definition.
+fn parse_gradient(name: &Arc<str>, raw_def: &RawGradientDef) -> Result<GradientDef, PaletteError> {
+    let space = parse_color_space(raw_def.space.as_deref(), name)?;
+
+    match raw_def.stops.len() < 2 {
+        true => {
+            return Err(PaletteError::InsufficientStops {
+                count: raw_def.stops.len(),
+            });
+        }
+        false => {}
+    }
+
+    let all_shorthand = raw_def
+        .stops
+        .iter()
+        .all(|s| matches!(s, RawGradientStop::Shorthand(_)));
+    let all_explicit = raw_def
+        .stops
+        .iter()
+        .all(|s| matches!(s, RawGradientStop::Explicit { .. }));
+    match all_shorthand || all_explicit {
+        true => {}
+        false => {
+            return Err(PaletteError::MixedGradientStopKinds {
+                gradient: Arc::clone(name),
+            });
+        }
+    }
+
+    let divisor = (raw_def.stops.len() - 1) as f64;
+
+    let typed_stops: Vec<(GradientColor, f64)> = raw_def
+        .stops
+        .iter()
+        .enumerate()
+        .map(|(i, raw_stop)| {
+            let (color_str, position) = match raw_stop {
+                RawGradientStop::Shorthand(s) => (s.as_str(), i as f64 / divisor),
+                RawGradientStop::Explicit { color, at } => (color.as_str(), *at),
+            };
+            validate_gradient_position(position)?;
+            let color = parse_gradient_stop_value(color_str, name, i)?;
+            Ok((color, position))
+        })
+        .collect::<Result<_, PaletteError>>()?;
+
+    let sorted = typed_stops.windows(2).all(|w| w[0].1 <= w[1].1);
+    match sorted {
+        true => {}
+        false => return Err(PaletteError::UnsortedStops),
+    }
+
+    Ok(GradientDef::new(typed_stops.into_boxed_slice(), space))
+}
+
+/// The `[section].field` an error names, when removing that slot would
+/// let the build continue past it.
+fn error_slot(err: &PaletteError) -> Option<(&str, &str)> {
+    match err {
+        PaletteError::InvalidHex { section, field, .. }
+        | PaletteError::InvalidStyle { section, field, .. }
+        | PaletteError::UnknownColorVariable { section, field, .. }
+        | PaletteError::UnknownField { section, field } => Some((section, field)),
+        _ => None,
+    }
+}
+
+fn is_gradient_error(err: &PaletteError) -> bool {
+    matches!(
+        err,
+        PaletteError::InsufficientStops { .. }
+            | PaletteError::UnsortedStops
+            | PaletteError::InvalidGradientPosition { .. }
+            | PaletteError::MixedGradientStopKinds { .. }
+            | PaletteError::InvalidGradientRef { .. }
+            | PaletteError::InvalidColorSpace { .. }
+    )
+}
+
+/// Remove every gradient that fails to parse, returning their errors in
+/// name order.
+fn take_invalid_gradients(manifest: &mut PaletteManifest) -> Vec<PaletteError> {
+    let expanded = match manifest.expand_colors() {
+        Ok(expanded) => expanded.into_owned(),
+        Err(_) => return Vec::new(),
+    };
+    let mut invalid: Vec<(Arc<str>, PaletteError)> = expanded
+        .gradient
+        .iter()
+        .filter_map(|(name, raw_def)| {
+            parse_gradient(name, raw_def)
+                .err()
+                .map(|err| (Arc::clone(name), err))
+        })
+        .collect();
+    invalid.sort_by(|(a, _), (b, _)| a.cmp(b));
+    invalid
+        .into_iter()
+        .map(|(name, err)| {
+            manifest.gradient.remove(&name);
+            err
+        })
+        .collect()
+}
+
 /// Parse and validate all gradient definitions from the manifest.
 ///
 /// Returns a sorted `Arc` slice for deterministic serialization and
 /// binary-search lookup. `Palette` is cloned in the registry cache,
 /// so `Arc` keeps that clone a ref-count bump.
 fn parse_gradients(manifest: &PaletteManifest) -> Result<GradientDefs, PaletteError> {
-    let mut gradients = Vec::with_capacity(manifest.gradient.len());
-
-    for (name, raw_def) in &manifest.gradient {
-        let space = parse_color_space(raw_def.space.as_deref(), name)?;
+    let mut gradients = manifest
+        .gradient
+        .iter()
+        .map(|(name, raw_def)| Ok((Arc::clone(name), parse_gradient(name, raw_def)?)))
+        .collect::<Result<Vec<_>, PaletteError>>()?;
 
-        match raw_def.stops.len() < 2 {
-            true => {
-                return Err(PaletteError::InsufficientStops {
-                    count: raw_def.stops.len(),
-                });
-            }
-            false => {}
-        }
+    gradients.sort_by(|(a, _), (b, _)| a.cmp(b));
+    Ok(gradients.into())
+}
 
-        let all_shorthand = raw_def
-            .stops
-            .iter()
-            .all(|s| matches!(s, RawGradientStop::Shorthand(_)));
-        let all_explicit = raw_def
-            .stops
+impl Palette {
+    /// Apply `f` to every color in the palette: all populated slots
+    /// (including custom groups), literal gradient stops, and platform
+    /// overrides. Token gradient stops follow
+    /// their slots automatically.
+    pub fn map_colors(&self, mut f: impl FnMut(Color) -> Color) -> Self {
+        let gradients = self
+            .gradients
             .iter()
-            .all(|s| matches!(s, RawGradientStop::Explicit { .. }));
-        match all_shorthand || all_explicit {
-            true => {}
-            false => {
-                return Err(PaletteError::MixedGradientStopKinds {
-                    gradient: Arc::clone(name),
-                });
-            }
+            .map(|(name, def)| (Arc::clone(name), def.map_literals(&mut f)))
+            .collect();
+        Self {
+            meta: self.meta.clone(),
+            base: self.base.map(&mut f),
+            semantic: self.semantic.map(&mut f),
+            diff: self.diff.map(&mut f),
+            surface: self.surface.map(&mut f),
+            typography: self.typography.map(&mut f),
+            syntax: self.syntax.map(&mut f),
+            editor: self.editor.map(&mut f),
+            terminal: self.terminal.map(&mut f),
+            syntax_style: self.syntax_style.clone(),
+            gradients,
+            groups: self.groups.map(&mut f),
+            #[cfg(feature = "platform")]
+            platform: self
+                .platform
+                .iter()
+                .map(|(name, o)| {
+                    let o = crate::platform::PlatformOverride {
+                        background: o.background.map(&mut f),
+                        foreground: o.foreground.map(&mut f),
+                    };
+                    (Arc::clone(name), o)
+                })
+                .collect(),
+            #[cfg(feature = "toml")]
+            extensions: self.extensions.clone(),
         }
+    }
 
-        let divisor = (raw_def.stops.len() - 1) as f64;
-
-        let typed_stops: Vec<(GradientColor, f64)> = raw_def
-            .stops
-            .iter()
-            .enumerate()
-            .map(|(i, raw_stop)| {
-                let (color_str, position) = match raw_stop {
-                    RawGradientStop::Shorthand(s) => (s.as_str(), i as f64 / divisor),
-                    RawGradientStop::Explicit { color, at } => (color.as_str(), *at),
-                };
-                validate_gradient_position(position)?;
-                let color = parse_gradient_stop_value(color_str, name, i)?;
-                Ok((color, position))
-            })
-            .collect::<Result<_, PaletteError>>()?;
-
-        let sorted = typed_stops.windows(2).all(|w| w[0].1 <= w[1].1);
-        match sorted {
-            true => {}
-            false => return Err(PaletteError::UnsortedStops),
+    /// Classify the theme by `base.background` luminance. Without a
+    /// background, falls back to a `style` tag of exactly `"dark"` or
+    /// `"light"`; returns `None` when neither settles it.
+    ///
+    /// ```
+    /// use palette_core::{Brightness, load_preset};
+    ///
+    /// let dawn = load_preset("rose_pine_dawn").unwrap();
+    /// assert_eq!(dawn.brightness(), Some(Brightness::Light));
+    /// ```
+    pub fn brightness(&self) -> Option<Brightness> {
+        match (self.base.background, self.meta.as_deref()) {
+            (Some(background), _) => Some(Brightness::of(background)),
+            (None, Some(meta)) => match meta.style.as_ref() {
+                "dark" => Some(Brightness::Dark),
+                "light" => Some(Brightness::Light),
+                _ => None,
+            },
+            (None, None) => None,
         }
+    }
 
-        gradients.push((
-            Arc::clone(name),
-            GradientDef::new(typed_stops.into_boxed_slice(), space),
-        ));
+    /// `true` when [`brightness`](Self::brightness) is [`Brightness::Dark`].
+    /// A palette that cannot be classified is neither dark nor light.
+    pub fn is_dark(&self) -> bool {
+        self.brightness() == Some(Brightness::Dark)
     }
 
-    gradients.sort_by(|(a, _), (b, _)| a.cmp(b));
-    Ok(gradients.into())
-}
+    /// Overlay the populated slots of `self` onto `other`, keeping `other`'s
+    /// value wherever `self` has none — the [`Palette`] counterpart of
+    /// [`merge_manifests`](crate::merge::merge_manifests), for composing
+    /// themes at runtime without going back through manifests.
+    ///
+    /// Gradients and platform overrides merge by name, and extension tables
+    /// key by key. The result keeps `self`'s `meta`, or `other`'s if `self`
+    /// has none.
+    ///
+    /// ```
+    /// use palette_core::{Color, Palette};
+    ///
+    /// let mut user = Palette::default();
+    /// user.base = Default::default();
+    /// user.base.background = Some(Color::rgb(0, 0, 0));
+    /// let merged = user.merged_over(&Palette::default());
+    /// assert_eq!(merged.base.background, Some(Color::rgb(0, 0, 0)));
+    /// assert_eq!(merged.base.foreground, Palette::default().base.foreground);
+    /// ```
+    pub fn merged_over(&self, other: &Self) -> Self {
+        let mut gradients: Vec<(Arc<str>, GradientDef)> = self.gradients.to_vec();
+        gradients.extend(
+            other
+                .gradients
+                .iter()
+                .filter(|(name, _)| !self.gradients.iter().any(|(own, _)| own == name))
+                .cloned(),
+        );
+        gradients.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        Self {
+            meta: self.meta.clone().or_else(|| other.meta.clone()),
+            base: self.base.merge(&other.base),
+            semantic: self.semantic.merge(&other.semantic),
+            diff: self.diff.merge(&other.diff),
+            surface: self.surface.merge(&other.surface),
+            typography: self.typography.merge(&other.typography),
+            syntax: self.syntax.merge(&other.syntax),
+            editor: self.editor.merge(&other.editor),
+            terminal: self.terminal.merge(&other.terminal),
+            syntax_style: self.syntax_style.merge(&other.syntax_style),
+            gradients: gradients.into(),
+            groups: self.groups.merge(&other.groups),
+            #[cfg(feature = "platform")]
+            platform: {
+                let mut platform = other.platform.clone();
+                for (name, o) in &self.platform {
+                    let fallback = other.platform.get(name);
+                    let merged = crate::platform::PlatformOverride {
+                        background: o.background.or(fallback.and_then(|f| f.background)),
+                        foreground: o.foreground.or(fallback.and_then(|f| f.foreground)),
+                    };
+                    platform.insert(Arc::clone(name), merged);
+                }
+                platform
+            },
+            #[cfg(feature = "toml")]
+            extensions: crate::merge::merge_extensions(&self.extensions, &other.extensions),
+        }
+    }
 
-impl Palette {
-    /// Build a palette from a parsed manifest, resolving hex strings to [`Color`] values.
+    /// Build a palette from a parsed manifest, resolving hex strings to
+    /// [`Color`] values and `"$name"` references to their `[colors]` entries.
     pub fn from_manifest(manifest: &PaletteManifest) -> Result<Self, PaletteError> {
+        let manifest = &*manifest.expand_colors()?;
+        let manifest = &*manifest.apply_opacity()?;
         let meta = manifest.meta.as_ref().map(|m| {
             Arc::new(PaletteMeta {
                 name: Arc::clone(&m.name),
                 preset_id: Arc::clone(&m.preset_id),
                 style: Arc::clone(&m.style),
+                author: m.author.clone(),
+                license: m.license.clone(),
+                version: m.version.clone(),
+                description: m.description.clone(),
+                tags: m.tags.clone(),
             })
         });
 
@@ -666,8 +949,80 @@ impl Palette {
             terminal: AnsiColors::from_section(&manifest.terminal, "terminal")?,
             syntax_style: SyntaxStyles::from_section(&manifest.syntax_style, "syntax_style")?,
             gradients,
+            groups: CustomGroups::from_sections(&manifest.groups)?,
             #[cfg(feature = "platform")]
             platform: crate::platform::from_sections(&manifest.platform)?,
+            #[cfg(feature = "toml")]
+            extensions: manifest.extensions.clone(),
         })
     }
+
+    /// Build a palette like [`from_manifest`](Self::from_manifest), but
+    /// report every problem instead of stopping at the first: each invalid
+    /// color or style, unknown `"$name"` reference, unknown platform key,
+    /// and invalid gradient, so a theme can be fixed in one pass.
+    ///
+    /// Gradient errors come first, in gradient-name order, followed by
+    /// slot errors sorted by section and field.
+    ///
+    /// ```
+    /// use palette_core::Palette;
+    /// use palette_core::manifest::PaletteManifest;
+    ///
+    /// let toml = "[base]\nbackground = \"#nothex\"\n\n[semantic]\nerror = \"$missing\"\n";
+    /// let manifest = PaletteManifest::from_toml(toml).unwrap();
+    /// let errors = Palette::from_manifest_collecting(&manifest).unwrap_err();
+    /// assert_eq!(errors.len(), 2);
+    /// ```
+    pub fn from_manifest_collecting(
+        manifest: &PaletteManifest,
+    ) -> Result<Self, Box<[PaletteError]>> {
+        let mut manifest = manifest.clone();
+        let mut errors = Vec::new();
+        let palette = loop {
+            let err = match Self::from_manifest(&manifest) {
+                Ok(palette) => break Some(palette),
+                Err(err) => err,
+            };
+            let removed = match error_slot(&err) {
+                Some((section, field)) => manifest.remove_slot(section, field),
+                None => false,
+            };
+            match (removed, is_gradient_error(&err)) {
+                (true, _) => errors.push(err),
+                // Not every gradient error names its gradient: check each
+                // one, and drop those that fail.
+                (false, true) => match take_invalid_gradients(&mut manifest) {
+                    found if found.is_empty() => {
+                        errors.push(err);
+                        break None;
+                    }
+                    found => errors.extend(found),
+                },
+                (false, false) => {
+                    errors.push(err);
+                    break None;
+                }
+            }
+        };
+        errors.sort_by(|a, b| error_slot(a).cmp(&error_slot(b)));
+        match (palette, errors.is_empty()) {
+            (Some(palette), true) => Ok(palette),
+            _ => Err(errors.into_boxed_slice()),
+        }
+    }
+
+    /// The [extension](crate::manifest::PaletteManifest::extensions) table
+    /// named `section`, e.g. `"x-myapp"`. Deserialize it into an app's own
+    /// config type with [`toml::Table::try_into`].
+    #[cfg(feature = "toml")]
+    pub fn extension(&self, section: &str) -> Option<&toml::Table> {
+        self.extensions.get(section)
+    }
+
+    /// The preset ID, for tracing fields.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn preset_id(&self) -> Option<&str> {
+        self.meta.as_ref().map(|m| m.preset_id.as_ref())
+    }
 }
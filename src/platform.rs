@@ -20,8 +20,8 @@ pub struct PlatformOverride {
 /// Map of platform name to its color overrides.
 pub type PlatformOverrides = BTreeMap<Arc<str>, PlatformOverride>;
 
-fn resolve_color(hex: &str, platform: &str, field: &str) -> Result<Color, PaletteError> {
-    Color::from_hex(hex).map_err(|e| {
+fn resolve_color(value: &str, platform: &str, field: &str) -> Result<Color, PaletteError> {
+    Color::parse(value).map_err(|e| {
         e.into_palette_error(Arc::from(format!("platform.{platform}")), Arc::from(field))
     })
 }
@@ -1,12 +1,17 @@
+use std::borrow::Cow;
 use std::cell::RefCell;
 use std::collections::HashMap;
-use std::path::Path;
-use std::sync::Arc;
+#[cfg(feature = "toml")]
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
 
 use crate::color::Color;
-use crate::error::PaletteError;
-use crate::manifest::{ManifestSection, PaletteManifest};
-use crate::merge::merge_manifests;
+use crate::diagnostic::{self, Diagnostic};
+use crate::error::{ChainLink, PaletteError};
+#[cfg(feature = "toml")]
+use crate::manifest::ManifestHeader;
+use crate::manifest::{ManifestMeta, PaletteManifest, expand_reference};
+use crate::merge::{MergeOptions, inherited_sections, merge_layers};
 use crate::palette::Palette;
 
 /// Display metadata for a theme, usable without parsing the full TOML.
@@ -20,6 +25,34 @@ pub struct ThemeInfo {
     pub style: Arc<str>,
     /// `true` if the resolved background is perceptually light (luminance > 0.179).
     pub is_light: bool,
+    /// Who made the theme, from `[meta]`.
+    pub author: Option<Arc<str>>,
+    /// License the theme is distributed under.
+    pub license: Option<Arc<str>>,
+    /// The theme's own release version.
+    pub version: Option<Arc<str>>,
+    /// One-line summary for theme pickers.
+    pub description: Option<Arc<str>>,
+    /// Free-form labels for filtering.
+    pub tags: Box<[Arc<str>]>,
+}
+
+/// How [`Registry::load_with`] and [`load_preset_file_with`] resolve a
+/// theme.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LoadOptions {
+    /// Modes whose `[when.<mode>]` overlays are merged over the resolved
+    /// theme, in order; a later mode wins where two set the same slot.
+    /// Modes a theme does not define are skipped.
+    pub modes: Vec<Arc<str>>,
+}
+
+impl LoadOptions {
+    /// Request `mode`, after any modes already requested.
+    pub fn mode(mut self, mode: impl Into<Arc<str>>) -> Self {
+        self.modes.push(mode.into());
+        self
+    }
 }
 
 struct BuiltinInfo {
@@ -29,19 +62,14 @@ struct BuiltinInfo {
 }
 
 macro_rules! presets {
-    ($($id:literal => $file:literal, $name:literal, $style:literal),+ $(,)?) => {
-        pub(crate) fn preset_toml(id: &str) -> Option<&'static str> {
-            match id {
-                $($id => Some(include_str!($file)),)+
-                _ => None,
-            }
-        }
-
+    ($($id:literal => $name:literal, $style:literal),+ $(,)?) => {
         /// All built-in preset IDs, in declaration order.
         pub fn preset_ids() -> &'static [&'static str] {
             &[$($id),+]
         }
 
+        const PRESET_COUNT: usize = [$($id),+].len();
+
         fn builtin_info() -> &'static [BuiltinInfo] {
             &[$(BuiltinInfo {
                 id: $id,
@@ -53,37 +81,45 @@ macro_rules! presets {
 }
 
 presets! {
-    "ayu_dark"              => "../presets/ayu_dark.toml",              "Ayu Dark",              "dark",
-    "ayu_light"             => "../presets/ayu_light.toml",             "Ayu Light",             "light",
-    "ayu_mirage"            => "../presets/ayu_mirage.toml",            "Ayu Mirage",            "mirage",
-    "catppuccin"            => "../presets/catppuccin.toml",            "Catppuccin Mocha",      "mocha",
-    "catppuccin_frappe"     => "../presets/catppuccin_frappe.toml",     "Catppuccin Frappe",     "frappe",
-    "catppuccin_latte"      => "../presets/catppuccin_latte.toml",      "Catppuccin Latte",      "latte",
-    "catppuccin_macchiato"  => "../presets/catppuccin_macchiato.toml",  "Catppuccin Macchiato",  "macchiato",
-    "dracula"               => "../presets/dracula.toml",               "Dracula",               "dark",
-    "everforest_dark"       => "../presets/everforest_dark.toml",       "Everforest Dark",       "dark",
-    "everforest_light"      => "../presets/everforest_light.toml",      "Everforest Light",      "light",
-    "github_dark"           => "../presets/github_dark.toml",           "GitHub Dark",           "dark",
-    "github_light"          => "../presets/github_light.toml",          "GitHub Light",          "light",
-    "golden_hour"           => "../presets/golden_hour.toml",           "Golden Hour",           "light",
-    "golden_hour_dusk"      => "../presets/golden_hour_dusk.toml",      "Golden Hour (Dusk)",    "dark",
-    "golden_hour_twilight"  => "../presets/golden_hour_twilight.toml",  "Golden Hour (Twilight)","dark",
-    "gruvbox_dark"          => "../presets/gruvbox_dark.toml",          "Gruvbox Dark",          "dark",
-    "gruvbox_light"         => "../presets/gruvbox_light.toml",         "Gruvbox Light",         "light",
-    "kanagawa"              => "../presets/kanagawa.toml",              "Kanagawa",              "dark",
-    "monokai"               => "../presets/monokai.toml",               "Monokai",               "dark",
-    "nord"                  => "../presets/nord.toml",                  "Nord",                  "dark",
-    "one_dark"              => "../presets/one_dark.toml",              "One Dark",              "dark",
-    "one_light"             => "../presets/one_light.toml",             "One Light",             "light",
-    "rose_pine"             => "../presets/rose_pine.toml",             "Rose Pine",             "dark",
-    "rose_pine_dawn"        => "../presets/rose_pine_dawn.toml",        "Rose Pine Dawn",        "dawn",
-    "rose_pine_moon"        => "../presets/rose_pine_moon.toml",        "Rose Pine Moon",        "moon",
-    "solarized_dark"        => "../presets/solarized_dark.toml",        "Solarized Dark",        "dark",
-    "solarized_light"       => "../presets/solarized_light.toml",       "Solarized Light",       "light",
-    "tokyonight"            => "../presets/tokyonight.toml",            "TokyoNight (Night)",    "night",
-    "tokyonight_day"        => "../presets/tokyonight_day.toml",        "TokyoNight Day",        "day",
-    "tokyonight_moon"       => "../presets/tokyonight_moon.toml",       "TokyoNight Moon",       "moon",
-    "tokyonight_storm"      => "../presets/tokyonight_storm.toml",      "TokyoNight Storm",      "storm",
+    "ayu_dark"              => "Ayu Dark",              "dark",
+    "ayu_light"             => "Ayu Light",             "light",
+    "ayu_mirage"            => "Ayu Mirage",            "mirage",
+    "catppuccin"            => "Catppuccin Mocha",      "mocha",
+    "catppuccin_frappe"     => "Catppuccin Frappe",     "frappe",
+    "catppuccin_latte"      => "Catppuccin Latte",      "latte",
+    "catppuccin_macchiato"  => "Catppuccin Macchiato",  "macchiato",
+    "dracula"               => "Dracula",               "dark",
+    "everforest_dark"       => "Everforest Dark",       "dark",
+    "everforest_light"      => "Everforest Light",      "light",
+    "github_dark"           => "GitHub Dark",           "dark",
+    "github_light"          => "GitHub Light",          "light",
+    "golden_hour"           => "Golden Hour",           "light",
+    "golden_hour_dusk"      => "Golden Hour (Dusk)",    "dark",
+    "golden_hour_twilight"  => "Golden Hour (Twilight)","dark",
+    "gruvbox_dark"          => "Gruvbox Dark",          "dark",
+    "gruvbox_light"         => "Gruvbox Light",         "light",
+    "kanagawa"              => "Kanagawa",              "dark",
+    "monokai"               => "Monokai",               "dark",
+    "nord"                  => "Nord",                  "dark",
+    "one_dark"              => "One Dark",              "dark",
+    "one_light"             => "One Light",             "light",
+    "rose_pine"             => "Rose Pine",             "dark",
+    "rose_pine_dawn"        => "Rose Pine Dawn",        "dawn",
+    "rose_pine_moon"        => "Rose Pine Moon",        "moon",
+    "solarized_dark"        => "Solarized Dark",        "dark",
+    "solarized_light"       => "Solarized Light",       "light",
+    "tokyonight"            => "TokyoNight (Night)",    "night",
+    "tokyonight_day"        => "TokyoNight Day",        "day",
+    "tokyonight_moon"       => "TokyoNight Moon",       "moon",
+    "tokyonight_storm"      => "TokyoNight Storm",      "storm",
+}
+
+/// Unresolved manifest for a built-in preset, from the tables `build.rs`
+/// compiles out of `presets/*.toml`.
+fn builtin_manifest(id: &str) -> Result<PaletteManifest, PaletteError> {
+    crate::builtin::preset_data(id)
+        .ok_or_else(|| PaletteError::UnknownPreset(Arc::from(id)))?
+        .manifest()
 }
 
 // ---------------------------------------------------------------------------
@@ -94,31 +130,161 @@ presets! {
 /// inheritance if the manifest declares `inherits`.
 ///
 /// Only one level of inheritance is supported: a variant may inherit from
-/// a base, but the base itself must be self-contained.
-fn resolve_with_inheritance<F>(toml_str: &str, resolve_parent: F) -> Result<Palette, PaletteError>
+/// one or more bases, but each base must be self-contained.
+#[cfg(feature = "toml")]
+fn resolve_with_inheritance<F>(
+    toml_str: &str,
+    path: Option<&Arc<str>>,
+    options: &LoadOptions,
+    resolve_parent: F,
+) -> Result<Palette, PaletteError>
 where
-    F: FnOnce(&str) -> Result<PaletteManifest, PaletteError>,
+    F: FnMut(&str) -> ParentLookup,
 {
     let manifest = PaletteManifest::from_toml(toml_str)?;
-    resolve_manifest_impl(&manifest, resolve_parent)
+    resolve_manifest_impl(&manifest, path, options, resolve_parent)
 }
 
-/// Shared body: check inheritance, merge if needed, build palette.
+/// A parent manifest and the file it came from, if any. The path is
+/// returned even when loading fails so errors can name it.
+type ParentLookup = (Option<Arc<str>>, Result<PaletteManifest, PaletteError>);
+
+/// A variant merged over its parents, with each parent's link to blame
+/// for errors, in merge order.
+struct Merged {
+    manifest: PaletteManifest,
+    variant: ChainLink,
+    parents: Box<[(ChainLink, PaletteManifest)]>,
+}
+
+/// Shared body: check inheritance, merge if needed, apply the requested
+/// modes, build palette.
+#[cfg_attr(feature = "tracing", tracing::instrument(
+    name = "resolve", level = "debug", skip_all,
+    fields(id = manifest.preset_id(), path = path.map(|p| p.as_ref())),
+))]
 fn resolve_manifest_impl<F>(
     manifest: &PaletteManifest,
+    path: Option<&Arc<str>>,
+    options: &LoadOptions,
     resolve_parent: F,
 ) -> Result<Palette, PaletteError>
 where
-    F: FnOnce(&str) -> Result<PaletteManifest, PaletteError>,
+    F: FnMut(&str) -> ParentLookup,
 {
-    let resolved = match manifest.inherits_from() {
-        None => return Palette::from_manifest(manifest),
-        Some(parent_id) => {
-            let parent = resolve_parent(parent_id)?;
-            merge_manifests(manifest, &parent)
+    let mut merged = merge_parent(manifest, path, resolve_parent)?;
+    if let Some(merged) = &mut merged {
+        merged.manifest = merged.manifest.with_modes(&options.modes).into_owned();
+    }
+    build_palette(&manifest.with_modes(&options.modes), merged.as_ref())
+}
+
+/// Merge `manifest` over its parents. `None` when it does not inherit.
+///
+/// Failure to find or parse a parent is wrapped in
+/// [`PaletteError::Inheritance`] with both links of the chain.
+fn merge_parent<F>(
+    manifest: &PaletteManifest,
+    path: Option<&Arc<str>>,
+    mut resolve_parent: F,
+) -> Result<Option<Merged>, PaletteError>
+where
+    F: FnMut(&str) -> ParentLookup,
+{
+    if manifest.parents().is_empty() {
+        return Ok(None);
+    }
+    let variant = ChainLink {
+        id: manifest
+            .meta
+            .as_ref()
+            .map_or_else(|| Arc::from(""), |meta| Arc::clone(&meta.preset_id)),
+        path: path.cloned(),
+    };
+    let mut parents = Vec::with_capacity(manifest.parents().len());
+    for parent_id in manifest.parents() {
+        let (parent_path, parent) = resolve_parent(parent_id);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            parent = parent_id.as_ref(),
+            parent_path = parent_path.as_deref(),
+            found = parent.is_ok(),
+            "inherits"
+        );
+        let link = ChainLink {
+            id: Arc::clone(parent_id),
+            path: parent_path,
+        };
+        match parent {
+            Ok(parent) => parents.push((link, parent)),
+            Err(source) => {
+                return Err(PaletteError::Inheritance {
+                    chain: Box::new([variant, link]),
+                    source: Box::new(source),
+                });
+            }
         }
+    }
+    let options = MergeOptions::from_manifest(manifest);
+    let inherited: Vec<Cow<'_, PaletteManifest>> = parents
+        .iter()
+        .map(|(_, parent)| inherited_sections(parent, &options))
+        .collect();
+    let layers: Vec<&PaletteManifest> = inherited
+        .iter()
+        .map(AsRef::as_ref)
+        .chain([manifest])
+        .collect();
+    Ok(Some(Merged {
+        manifest: merge_layers(&layers),
+        variant,
+        parents: parents.into_boxed_slice(),
+    }))
+}
+
+/// Build the palette, blaming the parent an invalid value came from when
+/// the variant does not define it itself.
+fn build_palette(
+    manifest: &PaletteManifest,
+    merged: Option<&Merged>,
+) -> Result<Palette, PaletteError> {
+    let Some(merged) = merged else {
+        return Palette::from_manifest(manifest);
+    };
+    Palette::from_manifest(&merged.manifest).map_err(|err| {
+        let culprit = match inherited_value(manifest, &err) {
+            Some((section, field)) => merged.parents.iter().rev().find(|(_, parent)| {
+                parent
+                    .color_section(section)
+                    .is_some_and(|slots| slots.contains_key(field))
+            }),
+            None => None,
+        };
+        match culprit {
+            Some((link, _)) => PaletteError::Inheritance {
+                chain: Box::new([merged.variant.clone(), link.clone()]),
+                source: Box::new(err),
+            },
+            None => err,
+        }
+    })
+}
+
+/// The slot an invalid-value error names, when the variant leaves it to
+/// its parents.
+fn inherited_value<'e>(
+    variant: &PaletteManifest,
+    err: &'e PaletteError,
+) -> Option<(&'e str, &'e str)> {
+    let (section, field) = match err {
+        PaletteError::InvalidHex { section, field, .. }
+        | PaletteError::InvalidStyle { section, field, .. } => (section, field),
+        _ => return None,
     };
-    Palette::from_manifest(&resolved)
+    variant
+        .section(section)
+        .is_some_and(|slots| !slots.contains_key(field.as_ref()))
+        .then_some((section.as_ref(), field.as_ref()))
 }
 
 // ---------------------------------------------------------------------------
@@ -129,9 +295,10 @@ where
 ///
 /// The `Arc<str>` path allocation is deferred to the error path so the
 /// happy path pays nothing.
+#[cfg(feature = "toml")]
 fn read_theme_file(path: &Path) -> Result<String, PaletteError> {
     std::fs::read_to_string(path).map_err(|source| PaletteError::Io {
-        path: Arc::from(path.to_string_lossy().as_ref()),
+        path: path_arc(path),
         source,
     })
 }
@@ -141,37 +308,96 @@ fn read_theme_file(path: &Path) -> Result<String, PaletteError> {
 ///
 /// Only one level of inheritance is supported: a variant may inherit from
 /// a base, but the base itself must be self-contained.
+#[cfg(feature = "toml")]
 pub fn load_preset_file(path: &Path) -> Result<Palette, PaletteError> {
+    load_preset_file_with(path, &LoadOptions::default())
+}
+
+/// Load a theme from a TOML file like [`load_preset_file`], merging in the
+/// `[when.<mode>]` overlays `options` requests.
+#[cfg(feature = "toml")]
+#[cfg_attr(feature = "tracing", tracing::instrument(
+    name = "load_preset_file", level = "debug", skip_all, fields(path = %path.display()),
+))]
+pub fn load_preset_file_with(path: &Path, options: &LoadOptions) -> Result<Palette, PaletteError> {
     let toml = read_theme_file(path)?;
-    resolve_with_inheritance(&toml, |parent_id| resolve_parent(path, parent_id))
+    let display = path_arc(path);
+    resolve_with_inheritance(&toml, Some(&display), options, |parent_id| {
+        resolve_parent(path, parent_id)
+    })
 }
 
-fn resolve_parent(child_path: &Path, parent_id: &str) -> Result<PaletteManifest, PaletteError> {
+#[cfg(feature = "toml")]
+fn resolve_parent(child_path: &Path, parent_id: &str) -> ParentLookup {
     let sibling = child_path
         .parent()
         .map(|dir| dir.join(format!("{parent_id}.toml")))
         .filter(|p| p.is_file());
 
-    match (sibling, preset_toml(parent_id)) {
-        (Some(path), _) => {
-            let toml = read_theme_file(&path)?;
-            PaletteManifest::from_toml(&toml)
+    match sibling {
+        Some(path) => {
+            let manifest =
+                read_theme_file(&path).and_then(|toml| PaletteManifest::from_toml(&toml));
+            (Some(path_arc(&path)), manifest)
         }
-        (None, Some(embedded)) => PaletteManifest::from_toml(embedded),
-        (None, None) => Err(PaletteError::UnknownPreset(Arc::from(parent_id))),
+        None => (None, builtin_manifest(parent_id)),
     }
 }
 
+#[cfg(feature = "toml")]
+fn path_arc(path: &Path) -> Arc<str> {
+    Arc::from(path.to_string_lossy().as_ref())
+}
+
+/// Resolved built-in palettes, indexed by position in [`preset_ids`].
+static BUILTIN_CACHE: [OnceLock<Arc<Palette>>; PRESET_COUNT] =
+    [const { OnceLock::new() }; PRESET_COUNT];
+
+/// Load a built-in preset by ID as a shared, cached palette.
+///
+/// The embedded TOML is parsed and resolved on the first call for each ID;
+/// later calls return a clone of the same `Arc` without allocating. Prefer
+/// this over [`load_preset`] when rendering many presets repeatedly, such
+/// as previews in a theme picker.
+///
+/// Returns [`PaletteError::UnknownPreset`] if the ID is not recognized.
+pub fn preset(id: &str) -> Result<Arc<Palette>, PaletteError> {
+    let slot = preset_ids()
+        .iter()
+        .position(|&builtin| builtin == id)
+        .map(|idx| &BUILTIN_CACHE[idx])
+        .ok_or_else(|| PaletteError::UnknownPreset(Arc::from(id)))?;
+    if let Some(cached) = slot.get() {
+        return Ok(Arc::clone(cached));
+    }
+    let palette = parse_builtin(id)?;
+    Ok(Arc::clone(slot.get_or_init(|| Arc::new(palette))))
+}
+
 /// Load a built-in preset by ID, resolving single-level inheritance.
 ///
+/// Returns an owned copy of the cached palette from [`preset`].
+///
 /// Returns [`PaletteError::UnknownPreset`] if the ID is not recognized.
 pub fn load_preset(id: &str) -> Result<Palette, PaletteError> {
-    let toml = preset_toml(id).ok_or_else(|| PaletteError::UnknownPreset(Arc::from(id)))?;
-    resolve_with_inheritance(toml, |parent_id| {
-        let parent_toml = preset_toml(parent_id)
-            .ok_or_else(|| PaletteError::UnknownPreset(Arc::from(parent_id)))?;
-        PaletteManifest::from_toml(parent_toml)
-    })
+    preset(id).map(|palette| Palette::clone(&palette))
+}
+
+/// The unresolved manifest of a built-in preset: its own sections as its
+/// source file declares them, with inheritance not merged.
+///
+/// Returns [`PaletteError::UnknownPreset`] if the ID is not recognized.
+pub fn preset_manifest(id: &str) -> Result<PaletteManifest, PaletteError> {
+    builtin_manifest(id)
+}
+
+fn parse_builtin(id: &str) -> Result<Palette, PaletteError> {
+    resolve_manifest_impl(
+        &builtin_manifest(id)?,
+        None,
+        &LoadOptions::default(),
+        |parent_id| (None, builtin_manifest(parent_id)),
+    )
 }
 
 // ---------------------------------------------------------------------------
@@ -181,6 +407,12 @@ pub fn load_preset(id: &str) -> Result<Palette, PaletteError> {
 enum Source {
     Builtin,
     Custom(Box<PaletteManifest>),
+    /// TOML text registered from disk, parsed in full on first load.
+    #[cfg(feature = "toml")]
+    Deferred {
+        toml: Box<str>,
+        path: Arc<str>,
+    },
 }
 
 struct Entry {
@@ -191,8 +423,9 @@ struct Entry {
 /// Unified theme registry combining built-in presets with custom themes.
 ///
 /// Built-in themes carry static metadata (name, style) without parsing TOML.
-/// Custom themes are added via files or directories and stored as pre-parsed
-/// manifests, avoiding a second TOML parse on load.
+/// Custom themes added from files or directories are scanned for `[meta]` and
+/// the base background only, and parsed in full on first load; manifests
+/// added directly are stored pre-parsed.
 ///
 /// # Thread safety
 ///
@@ -201,9 +434,17 @@ struct Entry {
 /// render thread. Do not replace `Arc` with `Rc` for internal keys — that
 /// would make `Registry` `!Send`, pinning it to a single thread.
 pub struct Registry {
+    catalog: Catalog,
+    cache: RefCell<HashMap<Arc<str>, Palette>>,
+}
+
+/// Registered entries and their ID index.
+///
+/// Split from the [`Registry`] cache so resolution can run from several
+/// threads: the catalog is `Sync`, the `RefCell` cache is not.
+struct Catalog {
     entries: Vec<Entry>,
     index: HashMap<Arc<str>, usize>,
-    cache: RefCell<HashMap<Arc<str>, Palette>>,
 }
 
 impl Registry {
@@ -212,13 +453,23 @@ impl Registry {
         let entries: Vec<Entry> = builtin_info()
             .iter()
             .map(|b| {
-                let is_light = is_light_from_preset(b.id);
+                let manifest = builtin_manifest(b.id).ok();
+                let is_light = manifest
+                    .as_ref()
+                    .and_then(|manifest| is_light_from_manifest(manifest).ok())
+                    .unwrap_or(false);
+                let meta = manifest.as_ref().and_then(|m| m.meta.as_deref());
                 Entry {
                     info: ThemeInfo {
                         id: Arc::from(b.id),
                         name: Arc::from(b.name),
                         style: Arc::from(b.style),
                         is_light,
+                        author: meta.and_then(|m| m.author.clone()),
+                        license: meta.and_then(|m| m.license.clone()),
+                        version: meta.and_then(|m| m.version.clone()),
+                        description: meta.and_then(|m| m.description.clone()),
+                        tags: meta.map(|m| m.tags.clone()).unwrap_or_default(),
                     },
                     source: Source::Builtin,
                 }
@@ -230,15 +481,14 @@ impl Registry {
             .map(|(i, e)| (Arc::clone(&e.info.id), i))
             .collect();
         Self {
-            entries,
-            index,
+            catalog: Catalog { entries, index },
             cache: RefCell::new(HashMap::new()),
         }
     }
 
     /// All registered themes in insertion order (built-ins first, then custom).
     pub fn list(&self) -> impl Iterator<Item = &ThemeInfo> {
-        self.entries.iter().map(|e| &e.info)
+        self.catalog.entries.iter().map(|e| &e.info)
     }
 
     /// Load a palette by ID, resolving single-level inheritance within the
@@ -246,56 +496,161 @@ impl Registry {
     ///
     /// Only one level of inheritance is supported: a variant may inherit
     /// from a base, but the base itself must be self-contained.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "registry_load", level = "debug", skip(self),)
+    )]
     pub fn load(&self, id: &str) -> Result<Palette, PaletteError> {
         if let Some(cached) = self.cache.borrow().get(id) {
+            #[cfg(feature = "tracing")]
+            tracing::trace!("cache hit");
             return Ok(cached.clone());
         }
-        let entry = self.find_entry(id)?;
-        let palette = match &entry.source {
-            Source::Builtin => {
-                let toml_str =
-                    preset_toml(id).ok_or_else(|| PaletteError::UnknownPreset(Arc::from(id)))?;
-                resolve_with_inheritance(toml_str, |parent_id| self.resolve_manifest(parent_id))?
-            }
-            Source::Custom(manifest) => {
-                resolve_manifest_impl(manifest, |parent_id| self.resolve_manifest(parent_id))?
-            }
-        };
+        let palette = self.catalog.resolve(id, &LoadOptions::default())?;
         self.cache
             .borrow_mut()
             .insert(Arc::from(id), palette.clone());
         Ok(palette)
     }
 
+    /// Load a palette like [`load`](Self::load), merging in the
+    /// `[when.<mode>]` overlays `options` requests.
+    ///
+    /// Palettes loaded with modes are not cached; with default options this
+    /// is [`load`](Self::load).
+    pub fn load_with(&self, id: &str, options: &LoadOptions) -> Result<Palette, PaletteError> {
+        match options.modes.is_empty() {
+            true => self.load(id),
+            false => self.catalog.resolve(id, options),
+        }
+    }
+
+    /// Load a palette like [`load`](Self::load), also returning recoverable
+    /// issues as warning [`Diagnostic`]s.
+    ///
+    /// Unknown and deprecated keys are reported for the theme's own
+    /// manifest; missing recommended slots are checked after inheritance is
+    /// merged, so a variant is not blamed for slots its parent supplies.
+    /// Unknown sections are only reported for themes added from files, the
+    /// one source whose TOML text is kept.
+    /// Always re-resolves the theme, since warnings are not cached.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "registry_load", level = "debug", skip(self),)
+    )]
+    pub fn load_with_warnings(
+        &self,
+        id: &str,
+    ) -> Result<(Palette, Box<[Diagnostic]>), PaletteError> {
+        let (palette, warnings) = self.catalog.resolve_with_warnings(id)?;
+        self.cache
+            .borrow_mut()
+            .insert(Arc::from(id), palette.clone());
+        Ok((palette, warnings))
+    }
+
+    /// Load every registered theme, in [`list`](Self::list) order.
+    ///
+    /// Themes not yet cached are resolved together — concurrently when the
+    /// `parallel` feature is enabled — and added to the cache. Fails with the
+    /// first error in list order.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+    pub fn load_all(&self) -> Result<Box<[Palette]>, PaletteError> {
+        let pending: Vec<&Arc<str>> = {
+            let cache = self.cache.borrow();
+            self.list()
+                .map(|info| &info.id)
+                .filter(|id| !cache.contains_key(id.as_ref()))
+                .collect()
+        };
+        #[cfg(feature = "tracing")]
+        tracing::debug!(uncached = pending.len(), "resolving");
+        let resolved = self.catalog.resolve_many(&pending);
+        for (id, palette) in pending.into_iter().zip(resolved) {
+            self.cache.borrow_mut().insert(Arc::clone(id), palette?);
+        }
+        let cache = self.cache.borrow();
+        self.list()
+            .map(|info| {
+                cache
+                    .get(info.id.as_ref())
+                    .cloned()
+                    .ok_or_else(|| PaletteError::UnknownPreset(Arc::clone(&info.id)))
+            })
+            .collect()
+    }
+
     /// Filter registered themes by style (e.g. "dark", "light").
     pub fn by_style(&self, style: &str) -> impl Iterator<Item = &ThemeInfo> {
-        self.entries
+        self.catalog
+            .entries
             .iter()
             .filter(move |e| e.info.style.as_ref() == style)
             .map(|e| &e.info)
     }
 
     /// Register a custom theme from a TOML file on disk.
+    ///
+    /// Only `[meta]` and the base background are read up front; the rest of
+    /// the file is parsed when the theme is first [`load`](Self::load)ed, so
+    /// errors in other sections surface there.
+    #[cfg(feature = "toml")]
     pub fn add_file(&mut self, path: &Path) -> Result<(), PaletteError> {
-        let toml = read_theme_file(path)?;
-        self.add_toml(&toml)
+        let (toml, header) = scan_theme_file(path)?;
+        self.add_deferred(path, toml, &header)
     }
 
     /// Register a custom theme from a TOML string.
     ///
     /// Parses the manifest once and stores it. Subsequent [`load`](Self::load)
     /// calls use the pre-parsed manifest directly.
+    #[cfg(feature = "toml")]
     pub fn add_toml(&mut self, toml: &str) -> Result<(), PaletteError> {
-        let manifest = PaletteManifest::from_toml(toml)?;
+        self.add_manifest(PaletteManifest::from_toml(toml)?)
+    }
+
+    /// Register a custom theme from an already-parsed manifest.
+    ///
+    /// Useful for manifests built in code or converted from other formats.
+    /// The manifest must carry a `[meta]` section.
+    pub fn add_manifest(&mut self, manifest: PaletteManifest) -> Result<(), PaletteError> {
         let info = theme_info_from_manifest_with_inheritance(&manifest, |parent_id| {
-            self.resolve_manifest(parent_id)
+            self.catalog.resolve_manifest(parent_id)
         })?;
         self.cache.borrow_mut().remove(&info.id);
-        self.upsert_entry(info, Source::Custom(Box::new(manifest)));
+        self.catalog
+            .upsert_entry(info, Source::Custom(Box::new(manifest)));
         Ok(())
     }
 
+    /// Import a third-party theme file and register every theme it contains.
+    ///
+    /// The format is detected from the extension and contents (see
+    /// [`import::import_path`](crate::import::import_path)). Returns the
+    /// preset IDs that were registered, in file order.
+    #[cfg(feature = "import")]
+    pub fn import_file(&mut self, path: &Path) -> Result<Box<[Arc<str>]>, PaletteError> {
+        let manifests = crate::import::import_path(path)?;
+        let mut ids = Vec::with_capacity(manifests.len());
+        for manifest in manifests {
+            let id = manifest.meta.as_ref().map(|m| Arc::clone(&m.preset_id));
+            self.add_manifest(manifest)?;
+            ids.extend(id);
+        }
+        Ok(ids.into_boxed_slice())
+    }
+
     /// Register all `.toml` files in a directory as custom themes.
+    ///
+    /// As with [`add_file`](Self::add_file), each file's full manifest is
+    /// parsed on first load. With the `parallel` feature, files are read and
+    /// scanned concurrently;
+    /// registration itself stays sequential, in directory order, so a file
+    /// that fails stops the scan with every earlier file registered.
+    #[cfg(feature = "toml")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        level = "debug", skip_all, fields(dir = %dir.display()),
+    ))]
     pub fn add_dir(&mut self, dir: &Path) -> Result<(), PaletteError> {
         let dir_arc: Arc<str> = Arc::from(dir.to_string_lossy().as_ref());
         let read_dir = std::fs::read_dir(dir).map_err(|source| PaletteError::Io {
@@ -303,6 +658,7 @@ impl Registry {
             source,
         })?;
 
+        let mut paths = Vec::new();
         for entry in read_dir {
             let entry = entry.map_err(|source| PaletteError::Io {
                 path: Arc::clone(&dir_arc),
@@ -310,11 +666,35 @@ impl Registry {
             })?;
             let path = entry.path();
             match path.extension().and_then(|e| e.to_str()) {
-                Some("toml") => self.add_file(&path)?,
+                Some("toml") => paths.push(path),
                 _ => continue,
             }
         }
 
+        #[cfg(feature = "tracing")]
+        tracing::debug!(files = paths.len(), "scanning");
+        for (path, scanned) in paths.iter().zip(scan_theme_files(&paths)) {
+            let (toml, header) = scanned?;
+            self.add_deferred(path, toml, &header)?;
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "toml")]
+    fn add_deferred(
+        &mut self,
+        path: &Path,
+        toml: String,
+        header: &ManifestHeader,
+    ) -> Result<(), PaletteError> {
+        let info =
+            theme_info_from_header(header, |parent_id| self.catalog.resolve_manifest(parent_id))?;
+        self.cache.borrow_mut().remove(&info.id);
+        let source = Source::Deferred {
+            toml: toml.into_boxed_str(),
+            path: path_arc(path),
+        };
+        self.catalog.upsert_entry(info, source);
         Ok(())
     }
 }
@@ -325,7 +705,7 @@ impl Default for Registry {
     }
 }
 
-impl Registry {
+impl Catalog {
     fn find_entry(&self, id: &str) -> Result<&Entry, PaletteError> {
         self.index
             .get(id)
@@ -333,15 +713,91 @@ impl Registry {
             .ok_or_else(|| PaletteError::UnknownPreset(Arc::from(id)))
     }
 
+    fn resolve(&self, id: &str, options: &LoadOptions) -> Result<Palette, PaletteError> {
+        let lookup = |parent_id: &str| self.lookup_parent(parent_id);
+        match &self.find_entry(id)?.source {
+            Source::Builtin => resolve_manifest_impl(&builtin_manifest(id)?, None, options, lookup),
+            Source::Custom(manifest) => resolve_manifest_impl(manifest, None, options, lookup),
+            #[cfg(feature = "toml")]
+            Source::Deferred { toml, path } => {
+                resolve_with_inheritance(toml, Some(path), options, lookup)
+            }
+        }
+    }
+
+    /// A parent's manifest, tagged with its file when registered from disk.
+    fn lookup_parent(&self, id: &str) -> ParentLookup {
+        (self.path_of(id), self.resolve_manifest(id))
+    }
+
+    /// The file a theme was registered from, if any.
+    fn path_of(&self, id: &str) -> Option<Arc<str>> {
+        match &self.find_entry(id).ok()?.source {
+            #[cfg(feature = "toml")]
+            Source::Deferred { path, .. } => Some(Arc::clone(path)),
+            _ => None,
+        }
+    }
+
+    fn resolve_with_warnings(
+        &self,
+        id: &str,
+    ) -> Result<(Palette, Box<[Diagnostic]>), PaletteError> {
+        let (manifest, mut warnings) = self.manifest_with_warnings(id)?;
+        let path = self.path_of(id);
+        let merged = merge_parent(&manifest, path.as_ref(), |parent_id| {
+            self.lookup_parent(parent_id)
+        })?;
+        let complete = merged.as_ref().map_or(&manifest, |m| &m.manifest);
+        warnings.extend(diagnostic::missing_slot_warnings(complete));
+        let palette = build_palette(&manifest, merged.as_ref())?;
+        Ok((palette, warnings.into_boxed_slice()))
+    }
+
+    /// The entry's own manifest plus its unknown-section and field warnings.
+    fn manifest_with_warnings(
+        &self,
+        id: &str,
+    ) -> Result<(PaletteManifest, Vec<Diagnostic>), PaletteError> {
+        match &self.find_entry(id)?.source {
+            #[cfg(feature = "toml")]
+            Source::Deferred { toml, .. } => PaletteManifest::from_toml_linted(toml),
+            _ => {
+                let manifest = self.resolve_manifest(id)?;
+                let warnings = diagnostic::field_warnings(&manifest).collect();
+                Ok((manifest, warnings))
+            }
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    fn resolve_many(&self, ids: &[&Arc<str>]) -> Vec<Result<Palette, PaletteError>> {
+        use rayon::prelude::*;
+        #[cfg(feature = "tracing")]
+        let span = tracing::Span::current();
+        ids.par_iter()
+            .map(|id| {
+                #[cfg(feature = "tracing")]
+                let _entered = span.enter();
+                self.resolve(id, &LoadOptions::default())
+            })
+            .collect()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    fn resolve_many(&self, ids: &[&Arc<str>]) -> Vec<Result<Palette, PaletteError>> {
+        ids.iter()
+            .map(|id| self.resolve(id, &LoadOptions::default()))
+            .collect()
+    }
+
     fn resolve_manifest(&self, id: &str) -> Result<PaletteManifest, PaletteError> {
         let entry = self.find_entry(id)?;
         match &entry.source {
-            Source::Builtin => {
-                let toml_str =
-                    preset_toml(id).ok_or_else(|| PaletteError::UnknownPreset(Arc::from(id)))?;
-                PaletteManifest::from_toml(toml_str)
-            }
+            Source::Builtin => builtin_manifest(id),
             Source::Custom(manifest) => Ok(PaletteManifest::clone(manifest)),
+            #[cfg(feature = "toml")]
+            Source::Deferred { toml, .. } => PaletteManifest::from_toml(toml),
         }
     }
 
@@ -359,43 +815,133 @@ impl Registry {
     }
 }
 
+#[cfg(feature = "toml")]
+type ScannedFile = (String, ManifestHeader);
+
+#[cfg(feature = "toml")]
+#[cfg_attr(feature = "tracing", tracing::instrument(
+    level = "trace", skip_all, fields(path = %path.display()),
+))]
+fn scan_theme_file(path: &Path) -> Result<ScannedFile, PaletteError> {
+    let toml = read_theme_file(path)?;
+    let header = ManifestHeader::from_toml(&toml)?;
+    Ok((toml, header))
+}
+
+#[cfg(all(feature = "toml", feature = "parallel"))]
+fn scan_theme_files(paths: &[PathBuf]) -> Vec<Result<ScannedFile, PaletteError>> {
+    use rayon::prelude::*;
+    #[cfg(feature = "tracing")]
+    let span = tracing::Span::current();
+    paths
+        .par_iter()
+        .map(|path| {
+            #[cfg(feature = "tracing")]
+            let _entered = span.enter();
+            scan_theme_file(path)
+        })
+        .collect()
+}
+
+#[cfg(all(feature = "toml", not(feature = "parallel")))]
+fn scan_theme_files(paths: &[PathBuf]) -> Vec<Result<ScannedFile, PaletteError>> {
+    paths.iter().map(|path| scan_theme_file(path)).collect()
+}
+
 fn theme_info_from_manifest(manifest: &PaletteManifest) -> Result<ThemeInfo, PaletteError> {
     let meta = manifest.meta.as_ref().ok_or(PaletteError::MissingMeta)?;
-    let is_light = is_light_from_section(&manifest.base)?;
-    Ok(ThemeInfo {
+    let is_light = is_light_from_manifest(manifest)?;
+    Ok(theme_info(meta, is_light))
+}
+
+fn theme_info(meta: &ManifestMeta, is_light: bool) -> ThemeInfo {
+    ThemeInfo {
         id: Arc::clone(&meta.preset_id),
         name: Arc::clone(&meta.name),
         style: Arc::clone(&meta.style),
         is_light,
-    })
+        author: meta.author.clone(),
+        license: meta.license.clone(),
+        version: meta.version.clone(),
+        description: meta.description.clone(),
+        tags: meta.tags.clone(),
+    }
 }
 
-fn theme_info_from_manifest_with_inheritance<F>(
-    manifest: &PaletteManifest,
+/// [`ThemeInfo`] from a scanned header. Parents are only parsed when the
+/// variant leaves its background to inheritance.
+#[cfg(feature = "toml")]
+fn theme_info_from_header<F>(
+    header: &ManifestHeader,
     resolve_parent: F,
 ) -> Result<ThemeInfo, PaletteError>
 where
-    F: FnOnce(&str) -> Result<PaletteManifest, PaletteError>,
+    F: FnMut(&str) -> Result<PaletteManifest, PaletteError>,
 {
-    let resolved = match manifest.inherits_from() {
-        Some(parent_id) => {
-            let parent = resolve_parent(parent_id)?;
-            merge_manifests(manifest, &parent)
-        }
-        None => manifest.clone(),
+    let meta = header.meta.as_ref().ok_or(PaletteError::MissingMeta)?;
+    // A `$name` background may be defined in a parent's `[colors]`.
+    let needs_parent = header
+        .background
+        .as_ref()
+        .is_none_or(|bg| bg.starts_with('$'));
+    let parents = match needs_parent {
+        true => meta
+            .inherits
+            .iter()
+            .map(|id| id.as_ref())
+            .map(resolve_parent)
+            .collect::<Result<Vec<_>, _>>()?,
+        false => Vec::new(),
     };
-
-    theme_info_from_manifest(&resolved)
+    // Only the sections `inherit_sections` keeps, as `load` would see them.
+    let options = MergeOptions::from_meta(meta);
+    let parents: Vec<Cow<'_, PaletteManifest>> = parents
+        .iter()
+        .map(|parent| inherited_sections(parent, &options))
+        .collect();
+    // Later parents win over earlier ones.
+    let lookup = |name: &str| {
+        header
+            .colors
+            .get(name)
+            .or_else(|| parents.iter().rev().find_map(|p| p.colors.get(name)))
+    };
+    let background = header
+        .background
+        .as_ref()
+        .or_else(|| parents.iter().rev().find_map(|p| p.base.get("background")))
+        .map(|bg| expand_reference(bg, "base", "background", lookup))
+        .transpose()?;
+    Ok(theme_info(
+        meta,
+        is_light_from_background(background.map(AsRef::as_ref))?,
+    ))
 }
 
-/// Compute `is_light` for a built-in preset from its embedded TOML.
-fn is_light_from_preset(id: &str) -> bool {
-    preset_toml(id)
-        .and_then(|toml| {
-            let manifest = PaletteManifest::from_toml(toml).ok()?;
-            is_light_from_section(&manifest.base).ok()
-        })
-        .unwrap_or(false)
+fn theme_info_from_manifest_with_inheritance<F>(
+    manifest: &PaletteManifest,
+    mut resolve_parent: F,
+) -> Result<ThemeInfo, PaletteError>
+where
+    F: FnMut(&str) -> Result<PaletteManifest, PaletteError>,
+{
+    let parents = manifest
+        .parents()
+        .iter()
+        .map(|parent_id| resolve_parent(parent_id))
+        .collect::<Result<Vec<_>, _>>()?;
+    let options = MergeOptions::from_manifest(manifest);
+    let inherited: Vec<Cow<'_, PaletteManifest>> = parents
+        .iter()
+        .map(|parent| inherited_sections(parent, &options))
+        .collect();
+    let layers: Vec<&PaletteManifest> = inherited
+        .iter()
+        .map(AsRef::as_ref)
+        .chain([manifest])
+        .collect();
+
+    theme_info_from_manifest(&merge_layers(&layers))
 }
 
 /// Check background luminance directly from a manifest base section.
@@ -403,9 +949,18 @@ fn is_light_from_preset(id: &str) -> bool {
 /// Avoids building a full Palette + ResolvedPalette just to read one field.
 /// Falls back to `Color::default()` (black) when the section has no
 /// `background` key. Returns an error when a hex value is present but malformed.
-fn is_light_from_section(base: &ManifestSection) -> Result<bool, PaletteError> {
-    let bg = match base.get("background") {
-        Some(hex) => Color::from_hex(hex)
+fn is_light_from_manifest(manifest: &PaletteManifest) -> Result<bool, PaletteError> {
+    let background = manifest
+        .base
+        .get("background")
+        .map(|bg| expand_reference(bg, "base", "background", |name| manifest.colors.get(name)))
+        .transpose()?;
+    is_light_from_background(background.map(AsRef::as_ref))
+}
+
+fn is_light_from_background(background: Option<&str>) -> Result<bool, PaletteError> {
+    let bg = match background {
+        Some(value) => Color::parse(value)
             .map_err(|e| e.into_palette_error(Arc::from("base"), Arc::from("background")))?,
         None => Color::default(),
     };
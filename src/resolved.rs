@@ -10,10 +10,11 @@ use std::sync::{Arc, LazyLock};
 use crate::color::Color;
 use crate::contrast::{ContrastLevel, adjust_contrast};
 use crate::gradient::{Gradient, GradientColor, GradientDef, GradientStop};
-use crate::palette::Palette;
+use crate::palette::{Brightness, Palette};
+use crate::slot::SlotId;
 use crate::style::ResolvedSyntaxStyles;
 
-static DEFAULT_PALETTE: LazyLock<Palette> = LazyLock::new(Palette::default);
+pub(crate) static DEFAULT_PALETTE: LazyLock<Palette> = LazyLock::new(Palette::default);
 
 macro_rules! resolved_group {
     ($(#[$_meta:meta])* $color_type:ident { $($field:ident),+ $(,)? }) => {
@@ -113,6 +114,11 @@ impl ResolvedPalette {
         self.base.background.is_light()
     }
 
+    /// Classify the theme by background luminance.
+    pub fn brightness(&self) -> Brightness {
+        Brightness::of(self.base.background)
+    }
+
     /// Look up a named gradient. Returns `None` if no gradient with that name exists.
     pub fn gradient(&self, name: &str) -> Option<&Gradient> {
         self.gradients
@@ -192,6 +198,11 @@ fn find_slot<'a>(mut slots: impl Iterator<Item = (&'static str, &'a Color)>, fie
 }
 
 impl ResolvedPalette {
+    /// The resolved color for `slot`.
+    pub fn get(&self, slot: SlotId) -> Color {
+        self.lookup_token(slot.section().as_str(), slot.field())
+    }
+
     /// Look up a resolved color by section and field name.
     ///
     /// Token references were validated at parse time, so an unrecognized
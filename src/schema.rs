@@ -0,0 +1,183 @@
+//! Manifest schema versions and the migrations between them.
+//!
+//! `schema_version` in `[meta]` names the layout a theme file was written
+//! against. Parsing checks it: a file from an older schema is upgraded by
+//! the [`MIGRATIONS`](crate::schema::MIGRATIONS) shipped with this build
+//! before it is read, and a file from a schema newer than
+//! [`CURRENT_VERSION`](crate::schema::CURRENT_VERSION) fails with
+//! [`PaletteError::UnsupportedSchemaVersion`] rather than loading with
+//! slots silently missing.
+//!
+//! ```
+//! use palette_core::manifest::PaletteManifest;
+//! use palette_core::PaletteError;
+//!
+//! let toml = "[meta]\nname = \"Next\"\npreset_id = \"next\"\nschema_version = \"99\"\n\
+//!             style = \"dark\"\nkind = \"preset-base\"\n\n[base]\n";
+//! let err = PaletteManifest::from_toml(toml).unwrap_err();
+//! assert!(matches!(err, PaletteError::UnsupportedSchemaVersion(_)));
+//! ```
+
+use std::sync::Arc;
+
+use crate::error::PaletteError;
+
+/// The schema version this build reads and writes.
+pub const CURRENT_VERSION: u32 = 1;
+
+/// One structural change between consecutive schema versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    /// A slot renamed within its section.
+    RenameSlot {
+        /// Section holding the slot.
+        section: &'static str,
+        /// Name in the older schema.
+        from: &'static str,
+        /// Name in the newer schema.
+        to: &'static str,
+    },
+    /// A slot moved to another section under the same name.
+    MoveSlot {
+        /// The slot name.
+        field: &'static str,
+        /// Section in the older schema.
+        from: &'static str,
+        /// Section in the newer schema.
+        to: &'static str,
+    },
+    /// Every slot of a section moved to another section.
+    MoveSection {
+        /// Section name in the older schema.
+        from: &'static str,
+        /// Section name in the newer schema.
+        to: &'static str,
+    },
+}
+
+/// The changes that upgrade a manifest from `version` to `version + 1`.
+///
+/// A slot that is already set at its new location keeps that value; the
+/// old one is dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Migration {
+    /// The schema version this migration upgrades from.
+    pub version: u32,
+    /// Changes applied in order.
+    pub changes: &'static [Change],
+}
+
+/// Migrations shipped with this build, one per schema bump.
+///
+/// Empty while [`CURRENT_VERSION`] is the first schema.
+pub const MIGRATIONS: &[Migration] = &[];
+
+/// Parse a declared `schema_version`.
+///
+/// # Errors
+///
+/// [`PaletteError::UnsupportedSchemaVersion`] when `declared` is not a
+/// whole number or is newer than [`CURRENT_VERSION`].
+pub fn parse_version(declared: &str) -> Result<u32, PaletteError> {
+    match declared.trim().parse::<u32>() {
+        Ok(version) if version <= CURRENT_VERSION => Ok(version),
+        _ => Err(PaletteError::UnsupportedSchemaVersion(Arc::from(declared))),
+    }
+}
+
+/// Whether a manifest declaring `declared` needs no migration. Manifests
+/// without `[meta]` are read as the current schema.
+#[cfg(feature = "toml")]
+pub(crate) fn is_current(declared: Option<&str>) -> Result<bool, PaletteError> {
+    match declared {
+        Some(declared) => Ok(parse_version(declared)? == CURRENT_VERSION),
+        None => Ok(true),
+    }
+}
+
+/// Upgrade a parsed theme document to [`CURRENT_VERSION`] with
+/// `migrations`, rewriting its `schema_version` when anything ran.
+///
+/// Parsing calls this with [`MIGRATIONS`]; pass your own list to test a
+/// migration before it ships. Documents without `[meta]` are left alone.
+///
+/// # Errors
+///
+/// [`PaletteError::UnsupportedSchemaVersion`] for a version
+/// [`parse_version`] rejects, or an older one with no migration from it.
+#[cfg(feature = "toml")]
+pub fn migrate(table: &mut toml::Table, migrations: &[Migration]) -> Result<(), PaletteError> {
+    let declared = match table
+        .get("meta")
+        .and_then(|meta| meta.get("schema_version"))
+        .and_then(toml::Value::as_str)
+    {
+        Some(declared) => Arc::<str>::from(declared),
+        None => return Ok(()),
+    };
+    let mut version = parse_version(&declared)?;
+    if version == CURRENT_VERSION {
+        return Ok(());
+    }
+    while version < CURRENT_VERSION {
+        let migration = migrations
+            .iter()
+            .find(|m| m.version == version)
+            .ok_or_else(|| PaletteError::UnsupportedSchemaVersion(Arc::clone(&declared)))?;
+        for change in migration.changes {
+            apply(table, *change);
+        }
+        version += 1;
+    }
+    if let Some(toml::Value::Table(meta)) = table.get_mut("meta") {
+        meta.insert(
+            "schema_version".to_owned(),
+            toml::Value::String(CURRENT_VERSION.to_string()),
+        );
+    }
+    Ok(())
+}
+
+#[cfg(feature = "toml")]
+fn apply(table: &mut toml::Table, change: Change) {
+    match change {
+        Change::RenameSlot { section, from, to } => {
+            move_slot(table, (section, from), (section, to));
+        }
+        Change::MoveSlot { field, from, to } => move_slot(table, (from, field), (to, field)),
+        Change::MoveSection { from, to } => {
+            if let Some(toml::Value::Table(slots)) = table.remove(from)
+                && let Some(target) = section_mut(table, to)
+            {
+                for (field, value) in slots {
+                    target.entry(field).or_insert(value);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+fn move_slot(table: &mut toml::Table, from: (&str, &str), to: (&str, &str)) {
+    let value = match table.get_mut(from.0) {
+        Some(toml::Value::Table(section)) => section.remove(from.1),
+        _ => None,
+    };
+    if let Some(value) = value
+        && let Some(target) = section_mut(table, to.0)
+    {
+        target.entry(to.1).or_insert(value);
+    }
+}
+
+/// The table named `name`, created when missing.
+#[cfg(feature = "toml")]
+fn section_mut<'a>(table: &'a mut toml::Table, name: &str) -> Option<&'a mut toml::Table> {
+    match table
+        .entry(name)
+        .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+    {
+        toml::Value::Table(section) => Some(section),
+        _ => None,
+    }
+}
@@ -0,0 +1,722 @@
+//! Typed references to individual color slots.
+//!
+//! A [`SlotId`] names one slot as a validated `section.field` pair — the same
+//! syntax gradient token stops use in TOML. Parse one from a string, or build
+//! it from a [`Section`](crate::slot::Section) and a field name:
+//!
+//! ```
+//! use palette_core::load_preset;
+//! use palette_core::slot::{Section, SlotId};
+//!
+//! let from: SlotId = "semantic.success".parse().unwrap();
+//! let to = SlotId::new(Section::Semantic, "error").unwrap();
+//!
+//! let palette = load_preset("tokyonight").unwrap();
+//! let heat = palette.gradient(from, to, 5);
+//! assert_eq!(heat.len(), 5);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::color::Color;
+use crate::error::PaletteError;
+use crate::group;
+use crate::manifest::known_fields;
+use crate::manipulation;
+use crate::palette::Palette;
+use crate::resolved::DEFAULT_PALETTE;
+
+/// A color group within a palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Section {
+    /// `[base]` — backgrounds, foregrounds, borders.
+    Base,
+    /// `[semantic]` — status colors.
+    Semantic,
+    /// `[diff]` — version-control highlighting.
+    Diff,
+    /// `[surface]` — UI surfaces.
+    Surface,
+    /// `[typography]` — text chrome.
+    Typography,
+    /// `[syntax]` — syntax-highlighting tokens.
+    Syntax,
+    /// `[editor]` — editor chrome.
+    Editor,
+    /// `[terminal]` — 16-color ANSI palette.
+    Terminal,
+    /// A registered [custom group](crate::group), by section name.
+    Custom(&'static str),
+}
+
+impl Section {
+    /// Every built-in section, in palette field order.
+    pub const ALL: [Self; 8] = [
+        Self::Base,
+        Self::Semantic,
+        Self::Diff,
+        Self::Surface,
+        Self::Typography,
+        Self::Syntax,
+        Self::Editor,
+        Self::Terminal,
+    ];
+
+    /// TOML section name, e.g. `"semantic"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Base => "base",
+            Self::Semantic => "semantic",
+            Self::Diff => "diff",
+            Self::Surface => "surface",
+            Self::Typography => "typography",
+            Self::Syntax => "syntax",
+            Self::Editor => "editor",
+            Self::Terminal => "terminal",
+            Self::Custom(name) => name,
+        }
+    }
+
+    /// Look up a section by its TOML name, including registered custom
+    /// groups.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|s| s.as_str() == name)
+            .or_else(|| group::lookup(name).map(|group| Self::Custom(group.section)))
+    }
+
+    /// Field names in this section, in declaration order.
+    pub fn fields(self) -> &'static [&'static str] {
+        match self {
+            Self::Base => known_fields::BASE,
+            Self::Semantic => known_fields::SEMANTIC,
+            Self::Diff => known_fields::DIFF,
+            Self::Surface => known_fields::SURFACE,
+            Self::Typography => known_fields::TYPOGRAPHY,
+            Self::Syntax => known_fields::SYNTAX,
+            Self::Editor => known_fields::EDITOR,
+            Self::Terminal => known_fields::TERMINAL,
+            Self::Custom(name) => group::lookup(name).map_or(&[], |group| group.fields),
+        }
+    }
+}
+
+impl fmt::Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A validated color slot reference, displayed and parsed as `section.field`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SlotId {
+    section: Section,
+    field: &'static str,
+}
+
+impl SlotId {
+    /// Build a slot reference. Returns [`PaletteError::UnknownField`] if
+    /// `field` is not a slot of `section`.
+    pub fn new(section: Section, field: &str) -> Result<Self, PaletteError> {
+        section
+            .fields()
+            .iter()
+            .find(|f| **f == field)
+            .map(|&field| Self { section, field })
+            .ok_or_else(|| PaletteError::UnknownField {
+                section: Arc::from(section.as_str()),
+                field: Arc::from(field),
+            })
+    }
+
+    /// The section this slot belongs to.
+    pub fn section(self) -> Section {
+        self.section
+    }
+
+    /// The field name within [`section`](Self::section).
+    pub fn field(self) -> &'static str {
+        self.field
+    }
+
+    /// Every slot in the palette, grouped by section in declaration order.
+    /// Registered custom groups follow the built-in sections.
+    pub fn all() -> impl Iterator<Item = Self> {
+        let custom = group::registered()
+            .into_iter()
+            .map(|group| Section::Custom(group.section));
+        Section::ALL.into_iter().chain(custom).flat_map(|section| {
+            section
+                .fields()
+                .iter()
+                .map(move |&field| Self { section, field })
+        })
+    }
+}
+
+impl fmt::Display for SlotId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.section, self.field)
+    }
+}
+
+impl FromStr for SlotId {
+    type Err = PaletteError;
+
+    /// Parse `"section.field"`. Unknown sections and fields both produce
+    /// [`PaletteError::UnknownField`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (section, field) = s.split_once('.').unwrap_or((s, ""));
+        match Section::from_name(section) {
+            Some(section) => Self::new(section, field),
+            None => Err(PaletteError::UnknownField {
+                section: Arc::from(section),
+                field: Arc::from(field),
+            }),
+        }
+    }
+}
+
+const fn slot(section: Section, field: &'static str) -> SlotId {
+    SlotId { section, field }
+}
+
+const BACKGROUND_SLOTS: &[SlotId] = &[
+    slot(Section::Base, "background"),
+    slot(Section::Base, "background_dark"),
+    slot(Section::Base, "background_highlight"),
+    slot(Section::Surface, "menu"),
+    slot(Section::Surface, "sidebar"),
+    slot(Section::Surface, "statusline"),
+    slot(Section::Surface, "float"),
+    slot(Section::Surface, "popup"),
+    slot(Section::Surface, "overlay"),
+    slot(Section::Surface, "highlight"),
+    slot(Section::Surface, "selection"),
+    slot(Section::Surface, "focus"),
+    slot(Section::Surface, "search"),
+];
+
+const FOREGROUND_SLOTS: &[SlotId] = &[
+    slot(Section::Base, "foreground"),
+    slot(Section::Base, "foreground_dark"),
+];
+
+/// How a fallback derives the missing color from its donor.
+#[derive(Clone, Copy)]
+enum Derive {
+    Copy,
+    Lighten(f64),
+    Darken(f64),
+}
+
+impl Derive {
+    fn apply(self, color: Color) -> Color {
+        match self {
+            Self::Copy => color,
+            Self::Lighten(amount) => color.lighten(amount),
+            Self::Darken(amount) => color.darken(amount),
+        }
+    }
+}
+
+/// `(slot, donor, derive)`: when `slot` is empty, fill it from `donor`.
+/// Applied top to bottom, so a donor filled by an earlier row passes its
+/// color on (`semantic.error` → `editor.diagnostic_error` →
+/// `editor.diagnostic_underline_error`).
+const FALLBACKS: &[(SlotId, SlotId, Derive)] = &[
+    (
+        slot(Section::Base, "background_dark"),
+        slot(Section::Base, "background"),
+        Derive::Darken(0.03),
+    ),
+    (
+        slot(Section::Base, "background_highlight"),
+        slot(Section::Base, "background"),
+        Derive::Lighten(0.05),
+    ),
+    (
+        slot(Section::Base, "foreground_dark"),
+        slot(Section::Base, "foreground"),
+        Derive::Darken(0.15),
+    ),
+    (
+        slot(Section::Base, "border_highlight"),
+        slot(Section::Base, "border"),
+        Derive::Lighten(0.1),
+    ),
+    (
+        slot(Section::Surface, "statusline"),
+        slot(Section::Base, "background_dark"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Surface, "sidebar"),
+        slot(Section::Base, "background_dark"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Surface, "float"),
+        slot(Section::Base, "background_dark"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Surface, "popup"),
+        slot(Section::Surface, "float"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Surface, "menu"),
+        slot(Section::Surface, "popup"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Surface, "highlight"),
+        slot(Section::Base, "background_highlight"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Typography, "comment"),
+        slot(Section::Syntax, "comments"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Syntax, "comments"),
+        slot(Section::Typography, "comment"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Typography, "line_number"),
+        slot(Section::Base, "foreground_dark"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Typography, "gutter"),
+        slot(Section::Typography, "line_number"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Typography, "link"),
+        slot(Section::Semantic, "info"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Editor, "cursor"),
+        slot(Section::Base, "foreground"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Editor, "cursor_text"),
+        slot(Section::Base, "background"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Editor, "selection_bg"),
+        slot(Section::Surface, "selection"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Editor, "selection_fg"),
+        slot(Section::Typography, "selection_text"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Editor, "search_bg"),
+        slot(Section::Surface, "search"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Editor, "diagnostic_error"),
+        slot(Section::Semantic, "error"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Editor, "diagnostic_warn"),
+        slot(Section::Semantic, "warning"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Editor, "diagnostic_info"),
+        slot(Section::Semantic, "info"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Editor, "diagnostic_hint"),
+        slot(Section::Semantic, "hint"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Editor, "diagnostic_underline_error"),
+        slot(Section::Editor, "diagnostic_error"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Editor, "diagnostic_underline_warn"),
+        slot(Section::Editor, "diagnostic_warn"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Editor, "diagnostic_underline_info"),
+        slot(Section::Editor, "diagnostic_info"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Editor, "diagnostic_underline_hint"),
+        slot(Section::Editor, "diagnostic_hint"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Diff, "added"),
+        slot(Section::Semantic, "success"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Diff, "modified"),
+        slot(Section::Semantic, "warning"),
+        Derive::Copy,
+    ),
+    (
+        slot(Section::Diff, "removed"),
+        slot(Section::Semantic, "error"),
+        Derive::Copy,
+    ),
+];
+
+impl Palette {
+    /// The color assigned to `slot`, or `None` if the theme leaves it unset.
+    pub fn get(&self, slot: SlotId) -> Option<Color> {
+        self.slot(slot).copied()
+    }
+
+    fn slot(&self, slot: SlotId) -> Option<&Color> {
+        let field = slot.field();
+        match slot.section() {
+            Section::Base => self.base.slot(field),
+            Section::Semantic => self.semantic.slot(field),
+            Section::Diff => self.diff.slot(field),
+            Section::Surface => self.surface.slot(field),
+            Section::Typography => self.typography.slot(field),
+            Section::Syntax => self.syntax.slot(field),
+            Section::Editor => self.editor.slot(field),
+            Section::Terminal => self.terminal.slot(field),
+            Section::Custom(name) => self.groups.get(name).and_then(|group| group.slot(field)),
+        }
+    }
+
+    /// Every slot in the palette, set or not, in [`SlotId::all`] order:
+    /// the built-in sections, then registered custom groups.
+    ///
+    /// ```
+    /// let palette = palette_core::load_preset("tokyonight").unwrap();
+    /// let unset: Vec<String> = palette
+    ///     .all_slots()
+    ///     .filter(|(_, _, color)| color.is_none())
+    ///     .map(|(section, field, _)| format!("{section}.{field}"))
+    ///     .collect();
+    /// assert!(!unset.contains(&"base.background".to_owned()));
+    /// ```
+    pub fn all_slots(&self) -> impl Iterator<Item = (Section, &'static str, Option<&Color>)> {
+        SlotId::all().map(|slot| (slot.section(), slot.field(), self.slot(slot)))
+    }
+
+    /// Apply `f` to every populated slot, including custom groups, with the
+    /// slot it came from. Unset slots stay unset. Unlike
+    /// [`map_colors`](Palette::map_colors), gradient literals and platform
+    /// overrides are left as they are, since they are not slots.
+    ///
+    /// ```
+    /// use palette_core::slot::Section;
+    ///
+    /// let palette = palette_core::load_preset("tokyonight").unwrap();
+    /// let focus = palette.map(|slot, color| match slot.section() {
+    ///     Section::Syntax => color.desaturate(0.5),
+    ///     _ => color,
+    /// });
+    /// assert_eq!(focus.base.background, palette.base.background);
+    /// assert_ne!(focus.syntax.keywords, palette.syntax.keywords);
+    /// ```
+    pub fn map(&self, mut f: impl FnMut(SlotId, Color) -> Color) -> Self {
+        let mut mapped = self.clone();
+        for slot in SlotId::all() {
+            if let Some(&color) = self.slot(slot) {
+                mapped.set(slot, Some(f(slot, color)));
+            }
+        }
+        mapped
+    }
+
+    /// Assign `slot`, or clear it with `None`.
+    pub fn set(&mut self, slot: SlotId, color: Option<Color>) {
+        let field = slot.field();
+        let target = match slot.section() {
+            Section::Base => self.base.slot_mut(field),
+            Section::Semantic => self.semantic.slot_mut(field),
+            Section::Diff => self.diff.slot_mut(field),
+            Section::Surface => self.surface.slot_mut(field),
+            Section::Typography => self.typography.slot_mut(field),
+            Section::Syntax => self.syntax.slot_mut(field),
+            Section::Editor => self.editor.slot_mut(field),
+            Section::Terminal => self.terminal.slot_mut(field),
+            Section::Custom(name) => return self.groups.set(name, field, color),
+        };
+        if let Some(target) = target {
+            *target = color;
+        }
+    }
+
+    /// [`get`](Self::get) for a slot named by a `section.field` path, e.g.
+    /// from a config file.
+    ///
+    /// ```
+    /// let palette = palette_core::load_preset("tokyonight").unwrap();
+    /// assert!(palette.get_path("syntax.keywords").unwrap().is_some());
+    /// assert!(palette.get_path("syntax.keywrods").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`PaletteError::UnknownField`] if `path` names no slot.
+    pub fn get_path(&self, path: &str) -> Result<Option<Color>, PaletteError> {
+        Ok(self.get(path.parse()?))
+    }
+
+    /// [`set`](Self::set) for a slot named by a `section.field` path.
+    ///
+    /// # Errors
+    ///
+    /// [`PaletteError::UnknownField`] if `path` names no slot.
+    pub fn set_path(&mut self, path: &str, color: Option<Color>) -> Result<(), PaletteError> {
+        self.set(path.parse()?, color);
+        Ok(())
+    }
+
+    /// Sample `steps` colors interpolated in OKLab from `from` to `to`.
+    ///
+    /// Slots the theme leaves unset take the value they would have after
+    /// [`resolve`](Self::resolve). Endpoints are exact; see
+    /// [`manipulation::gradient`] for the `steps` edge cases.
+    pub fn gradient(&self, from: SlotId, to: SlotId, steps: usize) -> Box<[Color]> {
+        let (a, b) = match (self.get(from), self.get(to)) {
+            (Some(a), Some(b)) => (a, b),
+            _ => {
+                let resolved = self.resolve();
+                (resolved.get(from), resolved.get(to))
+            }
+        };
+        manipulation::gradient(a, b, steps)
+    }
+
+    /// Every populated slot, ordered from darkest to lightest by WCAG
+    /// relative luminance. Slots of equal luminance keep declaration order.
+    pub fn slots_by_luminance(&self) -> Box<[(SlotId, Color)]> {
+        let mut slots: Vec<(SlotId, Color)> = SlotId::all()
+            .filter_map(|slot| self.get(slot).map(|color| (slot, color)))
+            .collect();
+        slots.sort_by(|(_, a), (_, b)| a.relative_luminance().total_cmp(&b.relative_luminance()));
+        slots.into_boxed_slice()
+    }
+
+    /// The darkest populated background: the `base.background*` slots and
+    /// every `[surface]` slot.
+    pub fn darkest_background(&self) -> Option<(SlotId, Color)> {
+        self.populated_among(BACKGROUND_SLOTS)
+            .min_by(|(_, a), (_, b)| a.relative_luminance().total_cmp(&b.relative_luminance()))
+    }
+
+    /// The lightest populated foreground: `base.foreground` or
+    /// `base.foreground_dark`.
+    pub fn lightest_foreground(&self) -> Option<(SlotId, Color)> {
+        self.populated_among(FOREGROUND_SLOTS)
+            .max_by(|(_, a), (_, b)| a.relative_luminance().total_cmp(&b.relative_luminance()))
+    }
+
+    /// Fill empty slots from related ones, so a sparse theme still renders
+    /// every surface: `editor.selection_bg` from `surface.selection`,
+    /// `typography.comment` from `syntax.comments`, `base.border_highlight`
+    /// from a lightened `base.border`, diagnostics from `[semantic]`, and so
+    /// on. Populated slots are never changed, and a slot whose donors are
+    /// all empty stays empty.
+    ///
+    /// ```
+    /// use palette_core::{Color, PaletteBuilder};
+    ///
+    /// let red = Color::rgb(0xf7, 0x76, 0x8e);
+    /// let sparse = PaletteBuilder::new().error(red).build();
+    /// let filled = sparse.with_fallbacks();
+    /// assert_eq!(filled.editor.diagnostic_error, Some(red));
+    /// assert_eq!(filled.editor.diagnostic_underline_error, Some(red));
+    /// assert_eq!(filled.diff.removed, Some(red));
+    /// ```
+    pub fn with_fallbacks(&self) -> Self {
+        let mut filled = self.clone();
+        for &(slot, donor, derive) in FALLBACKS {
+            if filled.get(slot).is_none()
+                && let Some(color) = filled.get(donor)
+            {
+                filled.set(slot, Some(derive.apply(color)));
+            }
+        }
+        filled
+    }
+
+    /// The color of `slot`, never `None`: the slot itself, else the first
+    /// populated donor along its [`with_fallbacks`](Self::with_fallbacks)
+    /// chain (with the same lighten/darken steps), else the slot in
+    /// [`Palette::default`]. Custom group slots missing everywhere resolve
+    /// to [`Color::default`].
+    ///
+    /// ```
+    /// use palette_core::{Color, PaletteBuilder, SlotId};
+    ///
+    /// let red = Color::rgb(0xf7, 0x76, 0x8e);
+    /// let palette = PaletteBuilder::new().error(red).build();
+    /// let underline: SlotId = "editor.diagnostic_underline_error".parse().unwrap();
+    /// assert_eq!(palette.effective(underline), red);
+    /// assert_eq!(palette.effective_error(), red);
+    /// assert_eq!(
+    ///     palette.effective_background(),
+    ///     palette_core::Palette::default().base.background.unwrap(),
+    /// );
+    /// ```
+    pub fn effective(&self, slot: SlotId) -> Color {
+        let mut derives = Vec::new();
+        let mut current = slot;
+        loop {
+            if let Some(color) = self.get(current) {
+                return derives.iter().rev().fold(color, |c, d: &Derive| d.apply(c));
+            }
+            match FALLBACKS.iter().find(|(s, _, _)| *s == current) {
+                Some(&(_, donor, derive)) if donor != slot && derives.len() < FALLBACKS.len() => {
+                    derives.push(derive);
+                    current = donor;
+                }
+                _ => break,
+            }
+        }
+        DEFAULT_PALETTE.get(slot).unwrap_or_default()
+    }
+
+    /// A stable 64-bit hash of every populated slot and the `meta` fields,
+    /// for invalidating caches of rendered output only when the palette
+    /// actually changes.
+    ///
+    /// Unlike [`std::hash::Hash`], the value is fixed across runs,
+    /// platforms, and crate versions (FNV-1a over `section.field` names and
+    /// RGBA bytes), so it can be persisted. Syntax styles, gradients, and
+    /// other non-slot data are not included.
+    ///
+    /// ```
+    /// use palette_core::{Color, load_preset};
+    ///
+    /// let palette = load_preset("tokyonight").unwrap();
+    /// let mut edited = palette.clone();
+    /// assert_eq!(edited.fingerprint(), palette.fingerprint());
+    /// edited.base.border = Some(Color::rgb(0xff, 0, 0));
+    /// assert_ne!(edited.fingerprint(), palette.fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        let mut hash = Fnv1a::new();
+        for (section, field, color) in self.all_slots() {
+            if let Some(color) = color {
+                hash.write_str(section.as_str());
+                hash.write_str(field);
+                hash.write(&[color.r, color.g, color.b, color.a]);
+            }
+        }
+        if let Some(meta) = &self.meta {
+            let optional = [
+                &meta.author,
+                &meta.license,
+                &meta.version,
+                &meta.description,
+            ];
+            hash.write_str(&meta.name);
+            hash.write_str(&meta.preset_id);
+            hash.write_str(&meta.style);
+            for value in optional {
+                match value {
+                    Some(value) => hash.write_str(value),
+                    None => hash.write(&[0xff]),
+                }
+            }
+            for tag in &meta.tags {
+                hash.write_str(tag);
+            }
+        }
+        hash.finish()
+    }
+
+    fn populated_among(&self, slots: &[SlotId]) -> impl Iterator<Item = (SlotId, Color)> {
+        slots
+            .iter()
+            .filter_map(|&slot| self.get(slot).map(|color| (slot, color)))
+    }
+}
+
+macro_rules! effective_accessors {
+    ($($name:ident => $section:ident . $field:ident),+ $(,)?) => {
+        impl Palette {
+            $(
+                #[doc = concat!(
+                    "[`effective`](Self::effective) color of `",
+                    stringify!($section), ".", stringify!($field), "`."
+                )]
+                pub fn $name(&self) -> Color {
+                    pastey::paste! {
+                        self.effective(slot(Section::[<$section:camel>], stringify!($field)))
+                    }
+                }
+            )+
+        }
+    };
+}
+
+effective_accessors! {
+    effective_background => base.background,
+    effective_foreground => base.foreground,
+    effective_border => base.border,
+    effective_border_highlight => base.border_highlight,
+    effective_success => semantic.success,
+    effective_warning => semantic.warning,
+    effective_error => semantic.error,
+    effective_info => semantic.info,
+    effective_hint => semantic.hint,
+    effective_selection_bg => editor.selection_bg,
+    effective_selection_fg => editor.selection_fg,
+    effective_cursor => editor.cursor,
+    effective_cursor_text => editor.cursor_text,
+    effective_comment => typography.comment,
+    effective_line_number => typography.line_number,
+}
+
+/// 64-bit FNV-1a, chosen for [`Palette::fingerprint`] because its output is
+/// specified and never changes between Rust releases.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(Self::PRIME);
+        }
+    }
+
+    /// Length-prefixed, so `("ab", "c")` and `("a", "bc")` differ.
+    fn write_str(&mut self, s: &str) {
+        self.write(&(s.len() as u64).to_le_bytes());
+        self.write(s.as_bytes());
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
@@ -4,6 +4,10 @@ use crate::palette::Palette;
 
 impl Palette {
     /// Serialize to a pretty-printed JSON string.
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "export", level = "debug", skip_all,
+        fields(target = "json", id = self.preset_id()),
+    ))]
     pub fn to_json(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string_pretty(self)
     }
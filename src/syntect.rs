@@ -27,95 +27,9 @@ use syntect::highlighting::{
 
 use crate::color::Color;
 use crate::resolved::ResolvedPalette;
+use crate::scopes::SCOPE_MAP;
 use crate::style::{ResolvedSyntaxStyles, StyleModifiers};
 
-/// Static mapping from palette-core syntax field names to TextMate scope selectors.
-///
-/// Each entry is `(field_name, &[scope_selector])`. One `ThemeItem` is emitted per
-/// scope selector string, all sharing the color and style of the named field.
-const SCOPE_MAP: &[(&str, &[&str])] = &[
-    ("keywords", &["keyword"]),
-    (
-        "keywords_fn",
-        &["keyword.declaration.function", "storage.type.function"],
-    ),
-    ("keywords_control", &["keyword.control"]),
-    ("keywords_import", &["keyword.control.import"]),
-    ("keywords_operator", &["keyword.operator"]),
-    ("functions", &["entity.name.function"]),
-    ("functions_builtin", &["support.function"]),
-    (
-        "functions_method",
-        &["entity.name.function.method", "meta.function-call.method"],
-    ),
-    (
-        "functions_macro",
-        &["entity.name.function.macro", "support.function.macro"],
-    ),
-    ("variables", &["variable"]),
-    ("variables_builtin", &["variable.language"]),
-    ("parameters", &["variable.parameter"]),
-    (
-        "properties",
-        &["variable.other.property", "entity.name.tag.yaml"],
-    ),
-    ("types", &["entity.name.type", "support.type"]),
-    ("types_builtin", &["storage.type", "support.type.builtin"]),
-    ("constants", &["constant", "constant.other"]),
-    ("constants_char", &["constant.character"]),
-    ("numbers", &["constant.numeric"]),
-    (
-        "booleans",
-        &["constant.language.boolean", "constant.language"],
-    ),
-    ("strings", &["string"]),
-    (
-        "strings_doc",
-        &[
-            "string.quoted.docstring",
-            "comment.block.documentation string",
-        ],
-    ),
-    ("strings_escape", &["constant.character.escape"]),
-    ("strings_regex", &["string.regexp"]),
-    (
-        "operators",
-        &["keyword.operator.assignment", "keyword.operator.arithmetic"],
-    ),
-    ("punctuation", &["punctuation"]),
-    (
-        "punctuation_bracket",
-        &["punctuation.section", "punctuation.definition.group"],
-    ),
-    ("punctuation_special", &["punctuation.special"]),
-    (
-        "annotations",
-        &["meta.annotation", "storage.type.annotation"],
-    ),
-    ("attributes", &["entity.other.attribute-name"]),
-    ("attributes_builtin", &["support.other.attribute"]),
-    (
-        "constructor",
-        &[
-            "entity.name.function.constructor",
-            "meta.function-call.constructor",
-        ],
-    ),
-    ("modules", &["entity.name.namespace", "entity.name.module"]),
-    ("labels", &["entity.name.label"]),
-    ("tag", &["entity.name.tag"]),
-    ("tag_delimiter", &["punctuation.definition.tag"]),
-    (
-        "tag_attribute",
-        &[
-            "entity.other.attribute-name.html",
-            "entity.other.attribute-name.jsx",
-        ],
-    ),
-    ("comments", &["comment"]),
-    ("comments_doc", &["comment.block.documentation"]),
-];
-
 /// Return the static scope mapping table.
 ///
 /// Each entry pairs a palette-core syntax field name with the TextMate scope
@@ -124,13 +38,13 @@ pub fn scope_mapping() -> &'static [(&'static str, &'static [&'static str])] {
     SCOPE_MAP
 }
 
-/// Convert a palette-core [`Color`] to a syntect [`SyntectColor`] with full opacity.
+/// Convert a palette-core [`Color`] to a syntect [`SyntectColor`], keeping alpha.
 fn to_syntect_color(color: &Color) -> SyntectColor {
     SyntectColor {
         r: color.r,
         g: color.g,
         b: color.b,
-        a: 0xFF,
+        a: color.a,
     }
 }
 
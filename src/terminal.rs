@@ -1,4 +1,5 @@
-//! Ratatui integration: convert a [`Palette`] into terminal-native colors.
+//! Ratatui integration: convert a [`Palette`] into terminal-native colors,
+//! or render it as ANSI swatches with [`render_swatches`].
 
 use ratatui::style::Color as RatatuiColor;
 use ratatui::style::Modifier;
@@ -238,3 +239,41 @@ pub fn to_resolved_terminal_theme(
         syntax_style: ResolvedTerminalSyntaxStyles::from_resolved(&resolved.syntax_style),
     }
 }
+
+/// Width of each swatch block in terminal cells.
+const SWATCH_WIDTH: usize = 6;
+
+/// Render every populated slot as a colored swatch block followed by its
+/// name and hex value, one line per slot under a `[section]` header.
+///
+/// Uses 24-bit ANSI escapes, so the result prints directly to any truecolor
+/// terminal — useful for eyeballing a theme before applying it.
+pub fn render_swatches(palette: &Palette) -> String {
+    use std::fmt::Write as _;
+
+    use crate::slot::{Section, SlotId};
+
+    let mut out = String::new();
+    for section in Section::ALL {
+        let slots: Vec<_> = SlotId::all()
+            .filter(|slot| slot.section() == section)
+            .filter_map(|slot| palette.get(slot).map(|color| (slot.field(), color)))
+            .collect();
+        if slots.is_empty() {
+            continue;
+        }
+        let separator = match out.is_empty() {
+            true => "",
+            false => "\n",
+        };
+        let _ = writeln!(out, "{separator}[{section}]");
+        for (field, color) in slots {
+            let _ = writeln!(
+                out,
+                "  \x1b[48;2;{};{};{}m{:SWATCH_WIDTH$}\x1b[0m  {field:<24} {color}",
+                color.r, color.g, color.b, ""
+            );
+        }
+    }
+    out
+}
@@ -4,6 +4,7 @@ use wasm_bindgen::prelude::*;
 
 use crate::color::Color;
 use crate::contrast::ContrastLevel;
+use crate::error::PaletteError;
 use crate::gradient::Gradient;
 use crate::palette::Palette;
 use crate::registry::{Registry, ThemeInfo};
@@ -12,20 +13,32 @@ fn to_js_error(err: impl std::fmt::Display) -> JsValue {
     JsValue::from_str(&err.to_string())
 }
 
+/// Throw a [`PaletteError`] as a plain object with the
+/// [`Diagnostic`](crate::diagnostic::Diagnostic) JSON shape
+/// (`{ code, severity, section, field, message, suggestion, span }`).
+fn to_js_diagnostic(err: PaletteError) -> JsValue {
+    serde_json::to_string(&err)
+        .ok()
+        .and_then(|json| js_sys::JSON::parse(&json).ok())
+        .unwrap_or_else(|| to_js_error(&err))
+}
+
 /// Parse a WCAG contrast level string into a [`ContrastLevel`] variant.
 ///
-/// | Input string  | Variant                       |
-/// |---------------|-------------------------------|
-/// | `"aa"`        | [`ContrastLevel::AaNormal`]   |
-/// | `"aa-large"`  | [`ContrastLevel::AaLarge`]    |
-/// | `"aaa"`       | [`ContrastLevel::AaaNormal`]  |
-/// | `"aaa-large"` | [`ContrastLevel::AaaLarge`]   |
+/// | Input string  | Variant                         |
+/// |---------------|---------------------------------|
+/// | `"aa"`        | [`ContrastLevel::AaNormal`]     |
+/// | `"aa-large"`  | [`ContrastLevel::AaLarge`]      |
+/// | `"aaa"`       | [`ContrastLevel::AaaNormal`]    |
+/// | `"aaa-large"` | [`ContrastLevel::AaaLarge`]     |
+/// | `"ui"`        | [`ContrastLevel::UiComponent`]  |
 pub(crate) fn parse_contrast_level(s: &str) -> Result<ContrastLevel, JsValue> {
     match s {
         "aa" => Ok(ContrastLevel::AaNormal),
         "aa-large" => Ok(ContrastLevel::AaLarge),
         "aaa" => Ok(ContrastLevel::AaaNormal),
         "aaa-large" => Ok(ContrastLevel::AaaLarge),
+        "ui" => Ok(ContrastLevel::UiComponent),
         _ => Err(JsValue::from_str(&format!("unknown contrast level: {s}"))),
     }
 }
@@ -63,6 +76,12 @@ impl JsColor {
             .map_err(to_js_error)
     }
 
+    pub fn parse(value: &str) -> Result<JsColor, JsValue> {
+        Color::parse(value)
+            .map(|c| Self { inner: c })
+            .map_err(to_js_error)
+    }
+
     #[wasm_bindgen(js_name = "toHex")]
     pub fn to_hex(&self) -> String {
         String::from(self.inner.to_hex())
@@ -83,6 +102,11 @@ impl JsColor {
         self.inner.b
     }
 
+    #[wasm_bindgen(getter)]
+    pub fn a(&self) -> u8 {
+        self.inner.a
+    }
+
     pub fn lighten(&self, amount: f64) -> JsColor {
         Self {
             inner: self.inner.lighten(amount),
@@ -280,7 +304,7 @@ impl JsPalette {
 }
 
 fn load_preset_palette(id: &str) -> Result<Palette, JsValue> {
-    crate::registry::load_preset(id).map_err(to_js_error)
+    crate::registry::load_preset(id).map_err(to_js_diagnostic)
 }
 
 #[wasm_bindgen(js_name = "loadPreset")]
@@ -341,6 +365,11 @@ pub struct JsThemeInfo {
     name: Arc<str>,
     style: Arc<str>,
     is_light: bool,
+    author: Option<Arc<str>>,
+    license: Option<Arc<str>>,
+    version: Option<Arc<str>>,
+    description: Option<Arc<str>>,
+    tags: Box<[Arc<str>]>,
 }
 
 impl JsThemeInfo {
@@ -350,6 +379,11 @@ impl JsThemeInfo {
             name: Arc::clone(&info.name),
             style: Arc::clone(&info.style),
             is_light: info.is_light,
+            author: info.author.clone(),
+            license: info.license.clone(),
+            version: info.version.clone(),
+            description: info.description.clone(),
+            tags: info.tags.clone(),
         }
     }
 }
@@ -386,6 +420,36 @@ impl JsThemeInfo {
     pub fn is_light(&self) -> bool {
         self.is_light
     }
+
+    /// Theme author, if the theme names one.
+    #[wasm_bindgen(getter)]
+    pub fn author(&self) -> Option<String> {
+        self.author.as_deref().map(str::to_owned)
+    }
+
+    /// Theme license, if the theme names one.
+    #[wasm_bindgen(getter)]
+    pub fn license(&self) -> Option<String> {
+        self.license.as_deref().map(str::to_owned)
+    }
+
+    /// The theme's own release version, if set.
+    #[wasm_bindgen(getter)]
+    pub fn version(&self) -> Option<String> {
+        self.version.as_deref().map(str::to_owned)
+    }
+
+    /// One-line theme summary, if set.
+    #[wasm_bindgen(getter)]
+    pub fn description(&self) -> Option<String> {
+        self.description.as_deref().map(str::to_owned)
+    }
+
+    /// Free-form theme labels.
+    #[wasm_bindgen(getter)]
+    pub fn tags(&self) -> Vec<String> {
+        self.tags.iter().map(|tag| tag.to_string()).collect()
+    }
 }
 
 #[wasm_bindgen]
@@ -419,12 +483,12 @@ impl JsRegistry {
         self.inner
             .load(id)
             .map(|p| JsPalette { inner: p })
-            .map_err(to_js_error)
+            .map_err(to_js_diagnostic)
     }
 
     #[wasm_bindgen(js_name = "addToml")]
     pub fn add_toml(&mut self, toml: &str) -> Result<(), JsValue> {
-        self.inner.add_toml(toml).map_err(to_js_error)
+        self.inner.add_toml(toml).map_err(to_js_diagnostic)
     }
 
     #[wasm_bindgen(js_name = "byStyle")]
@@ -0,0 +1,42 @@
+use palette_core::{Color, Palette, PaletteBuilder, SlotId};
+
+fn hex(s: &str) -> Color {
+    Color::from_hex(s).unwrap()
+}
+
+#[test]
+fn new_builder_leaves_every_slot_unset() {
+    let palette = PaletteBuilder::new().build();
+    assert!(SlotId::all().all(|slot| palette.get(slot).is_none()));
+    assert!(palette.meta.is_none());
+}
+
+#[test]
+fn methods_set_their_slots() {
+    let palette = PaletteBuilder::new()
+        .meta("Built", "built", "dark")
+        .background(hex("#1a1b26"))
+        .success(hex("#9ece6a"))
+        .diff_added(hex("#449dab"))
+        .editor_cursor(hex("#c0caf5"))
+        .terminal_bright_black(hex("#414868"))
+        .slot("syntax.strings".parse().unwrap(), hex("#9ece6a"))
+        .build();
+
+    assert_eq!(palette.base.background, Some(hex("#1a1b26")));
+    assert_eq!(palette.semantic.success, Some(hex("#9ece6a")));
+    assert_eq!(palette.diff.added, Some(hex("#449dab")));
+    assert_eq!(palette.editor.cursor, Some(hex("#c0caf5")));
+    assert_eq!(palette.terminal.bright_black, Some(hex("#414868")));
+    assert_eq!(palette.syntax.strings, Some(hex("#9ece6a")));
+    assert_eq!(palette.meta.unwrap().preset_id.as_ref(), "built");
+}
+
+#[test]
+fn builder_can_start_from_a_palette() {
+    let palette = PaletteBuilder::from(Palette::default())
+        .background(hex("#000000"))
+        .build();
+    assert_eq!(palette.base.background, Some(hex("#000000")));
+    assert_eq!(palette.base.foreground, Palette::default().base.foreground);
+}
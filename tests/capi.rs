@@ -0,0 +1,138 @@
+#![cfg(feature = "capi")]
+#![allow(unsafe_code)]
+
+use std::ffi::{CStr, CString, c_char};
+use std::ptr;
+
+use palette_core::capi::*;
+
+fn last_error_code() -> Option<String> {
+    let code = palette_last_error_code();
+    match code.is_null() {
+        true => None,
+        false => Some(unsafe { CStr::from_ptr(code) }.to_str().unwrap().to_owned()),
+    }
+}
+
+fn take_string(s: *mut c_char) -> String {
+    assert!(!s.is_null());
+    let owned = unsafe { CStr::from_ptr(s) }.to_str().unwrap().to_owned();
+    unsafe { palette_string_free(s) };
+    owned
+}
+
+#[test]
+fn load_preset_and_read_slot() {
+    let id = CString::new("tokyonight").unwrap();
+    let palette = unsafe { palette_load_preset(id.as_ptr()) };
+    assert!(!palette.is_null());
+    assert!(palette_last_error().is_null());
+
+    let path = CString::new("base.background").unwrap();
+    let mut rgb = PaletteRgb::default();
+    assert!(unsafe { palette_get_slot(palette, path.as_ptr(), &mut rgb) });
+    let expected = palette_core::preset("tokyonight")
+        .unwrap()
+        .base
+        .background
+        .unwrap();
+    assert_eq!(
+        (rgb.r, rgb.g, rgb.b, rgb.a),
+        (expected.r, expected.g, expected.b, expected.a)
+    );
+
+    let css = take_string(unsafe { palette_to_css(palette) });
+    assert!(css.contains("--bg"), "{css}");
+    let json = take_string(unsafe { palette_to_json(palette) });
+    assert!(json.contains("\"base\""), "{json}");
+
+    unsafe { palette_free(palette) };
+}
+
+#[test]
+fn unknown_preset_records_coded_error() {
+    let id = CString::new("nrod").unwrap();
+    let palette = unsafe { palette_load_preset(id.as_ptr()) };
+    assert!(palette.is_null());
+    assert_eq!(last_error_code().as_deref(), Some("PC0008"));
+    let message = unsafe { CStr::from_ptr(palette_last_error()) };
+    assert_eq!(message.to_str().unwrap(), "unknown preset: nrod");
+}
+
+#[test]
+fn invalid_slot_path_records_error() {
+    let id = CString::new("nord").unwrap();
+    let palette = unsafe { palette_load_preset(id.as_ptr()) };
+    let path = CString::new("base.nope").unwrap();
+    let mut rgb = PaletteRgb::default();
+    assert!(!unsafe { palette_get_slot(palette, path.as_ptr(), &mut rgb) });
+    assert!(!palette_last_error().is_null());
+    unsafe { palette_free(palette) };
+}
+
+#[test]
+fn null_arguments_record_uncoded_error() {
+    assert!(unsafe { palette_load_preset(ptr::null()) }.is_null());
+    assert!(!palette_last_error().is_null());
+    assert_eq!(last_error_code(), None);
+    assert!(unsafe { palette_to_css(ptr::null()) }.is_null());
+    unsafe { palette_free(ptr::null_mut()) };
+    unsafe { palette_string_free(ptr::null_mut()) };
+}
+
+#[test]
+fn freeing_keeps_the_last_error() {
+    let id = CString::new("nrod").unwrap();
+    assert!(unsafe { palette_load_preset(id.as_ptr()) }.is_null());
+    let css = unsafe { palette_to_css(ptr::null()) };
+    assert!(css.is_null());
+    unsafe { palette_free(ptr::null_mut()) };
+    unsafe { palette_string_free(ptr::null_mut()) };
+    assert!(!palette_last_error().is_null());
+}
+
+#[test]
+fn translucent_slot_keeps_alpha() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("glass.toml");
+    std::fs::write(
+        &file,
+        "[meta]\nname = \"Glass\"\npreset_id = \"glass\"\nschema_version = \"1\"\n\
+         style = \"dark\"\nkind = \"preset-base\"\n\n\
+         [base]\nbackground = \"#10102080\"\n",
+    )
+    .unwrap();
+    let path = CString::new(file.to_str().unwrap()).unwrap();
+    let palette = unsafe { palette_load_file(path.as_ptr()) };
+    assert!(!palette.is_null());
+
+    let slot = CString::new("base.background").unwrap();
+    let mut rgb = PaletteRgb::default();
+    assert!(unsafe { palette_get_slot(palette, slot.as_ptr(), &mut rgb) });
+    assert_eq!(
+        rgb,
+        PaletteRgb {
+            r: 0x10,
+            g: 0x10,
+            b: 0x20,
+            a: 0x80
+        }
+    );
+    unsafe { palette_free(palette) };
+}
+
+#[test]
+fn header_declares_every_export() {
+    let source = include_str!("../src/capi.rs");
+    let header = include_str!("../include/palette_core.h");
+    let exports = source
+        .lines()
+        .filter_map(|line| line.split_once("extern \"C\" fn "))
+        .map(|(_, rest)| rest.split('(').next().unwrap());
+    for name in exports {
+        assert!(
+            header.contains(&format!("{name}(")),
+            "{name} missing from header"
+        );
+    }
+}
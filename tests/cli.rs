@@ -0,0 +1,212 @@
+#![cfg(feature = "cli")]
+
+use std::process::{Command, Output};
+
+fn palette(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_palette"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn list_filters_by_style() {
+    let output = palette(&["list", "--style", "light"]);
+    assert!(output.status.success());
+    let text = stdout(&output);
+    assert!(text.contains("github_light"));
+    assert!(!text.contains("github_dark"));
+}
+
+#[test]
+fn show_prints_slots_by_section() {
+    let output = palette(&["show", "nord"]);
+    assert!(output.status.success());
+    let text = stdout(&output);
+    assert!(text.starts_with("Nord [nord]"));
+    assert!(text.contains("[base]"));
+    assert!(text.contains("#2E3440"));
+}
+
+#[test]
+fn export_css_writes_to_stdout() {
+    let output = palette(&["export", "nord", "--target", "css"]);
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with(":root {"));
+}
+
+#[test]
+fn export_file_target_writes_into_out_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    let out = dir.path().to_str().unwrap();
+    let output = palette(&["export", "nord", "--target", "zathura", "--out", out]);
+    assert!(output.status.success());
+    assert!(dir.path().join("zathurarc").is_file());
+}
+
+#[test]
+fn export_unknown_target_fails() {
+    let output = palette(&["export", "nord", "--target", "bogus"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown target"));
+}
+
+#[test]
+fn validate_passes_readable_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("plain.toml");
+    std::fs::write(
+        &path,
+        "[base]\nbackground = \"#000000\"\nforeground = \"#FFFFFF\"\n",
+    )
+    .unwrap();
+    let output = palette(&["validate", path.to_str().unwrap(), "--level", "aaa"]);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output).trim(), "ok");
+}
+
+#[test]
+fn validate_ui_level_checks_borders() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("faint.toml");
+    std::fs::write(
+        &path,
+        "[base]\nbackground = \"#000000\"\nforeground = \"#FFFFFF\"\nborder = \"#111111\"\n",
+    )
+    .unwrap();
+    let path = path.to_str().unwrap();
+    assert!(
+        palette(&["validate", path, "--level", "aa"])
+            .status
+            .success()
+    );
+
+    let output = palette(&["validate", path, "--level", "ui"]);
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("base.border"));
+}
+
+#[test]
+fn validate_fails_on_violations() {
+    let output = palette(&["validate", "nord", "--level", "aaa"]);
+    assert!(!output.status.success());
+    let text = stdout(&output);
+    assert!(text.contains("warning[PC0101]"));
+    assert!(text.contains("contrast violation"));
+}
+
+#[test]
+fn unknown_theme_reports_coded_diagnostic() {
+    let output = palette(&["show", "nrod"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("error[PC0008]"));
+    assert!(stderr.contains("did you mean `nord`?"));
+}
+
+#[test]
+fn invalid_value_reports_file_line_and_column() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("broken.toml");
+    std::fs::write(
+        &path,
+        "[base]\nbackground = \"#000000\"\nforeground = \"#12\"\n",
+    )
+    .unwrap();
+
+    let output = palette(&["show", path.to_str().unwrap()]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let expected = format!("{}:3:14: error[PC0005]", path.display());
+    assert!(stderr.starts_with(&expected), "{stderr}");
+}
+
+#[test]
+fn convert_writes_loadable_toml() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("scheme.json");
+    std::fs::write(
+        &input,
+        r##"{ "name": "Night", "background": "#101010", "foreground": "#e0e0e0", "brightPurple": "#ff00ff" }"##,
+    )
+    .unwrap();
+    let output_path = dir.path().join("night.toml");
+
+    let output = palette(&[
+        "convert",
+        input.to_str().unwrap(),
+        output_path.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+    let shown = palette(&["show", output_path.to_str().unwrap()]);
+    assert!(stdout(&shown).contains("#101010"));
+}
+
+#[test]
+fn preview_prints_swatches() {
+    let output = palette(&["preview", "nord"]);
+    assert!(output.status.success());
+    let text = stdout(&output);
+    assert!(text.contains("\x1b[48;2;46;52;64m"));
+    assert!(text.contains("#2E3440"));
+}
+
+fn wait_for(mut done: impl FnMut() -> bool) -> bool {
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+    while std::time::Instant::now() < deadline {
+        if done() {
+            return true;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(20));
+    }
+    false
+}
+
+#[test]
+fn watch_rebuilds_on_change() {
+    let dir = tempfile::tempdir().unwrap();
+    let theme = dir.path().join("live.toml");
+    let out = dir.path().join("live.css");
+    std::fs::write(&theme, "[base]\nbackground = \"#101010\"\n").unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_palette"))
+        .args(["watch", theme.to_str().unwrap(), "--target", "css"])
+        .args(["--out", out.to_str().unwrap(), "--interval", "20"])
+        .stdout(std::process::Stdio::null())
+        .spawn()
+        .unwrap();
+
+    let read = || std::fs::read_to_string(&out).unwrap_or_default();
+    let first = wait_for(|| read().contains("#101010"));
+    // Ensure the rewrite gets a distinct mtime on coarse-grained filesystems.
+    std::thread::sleep(std::time::Duration::from_millis(50));
+    std::fs::write(&theme, "[base]\nbackground = \"#202020\"\n").unwrap();
+    let second = wait_for(|| read().contains("#202020"));
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+    assert!(first, "initial export written");
+    assert!(second, "export rebuilt after edit");
+}
+
+#[test]
+fn validate_reports_every_invalid_value() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("broken.toml");
+    std::fs::write(
+        &path,
+        "[base]\nbackground = \"#nothex\"\nforeground = \"#FFFFFF\"\n\n\
+         [semantic]\nerror = \"$missing\"\n",
+    )
+    .unwrap();
+    let output = palette(&["validate", path.to_str().unwrap()]);
+    assert!(!output.status.success());
+    let text = stdout(&output);
+    assert!(text.contains("broken.toml:2:14"));
+    assert!(text.contains("$missing"));
+    assert!(text.contains("2 error(s)"));
+}
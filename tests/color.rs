@@ -10,7 +10,8 @@ fn from_hex_lowercase() {
         Color {
             r: 26,
             g: 27,
-            b: 42
+            b: 42,
+            a: 255
         }
     );
 }
@@ -23,7 +24,8 @@ fn from_hex_uppercase() {
         Color {
             r: 170,
             g: 187,
-            b: 204
+            b: 204,
+            a: 255
         }
     );
 }
@@ -36,7 +38,8 @@ fn from_hex_mixed_case() {
         Color {
             r: 170,
             g: 187,
-            b: 204
+            b: 204,
+            a: 255
         }
     );
 }
@@ -83,6 +86,7 @@ fn to_hex_uppercase_format() {
         r: 26,
         g: 27,
         b: 42,
+        a: 255,
     };
     assert_eq!(&*color.to_hex(), "#1A1B2A");
 }
@@ -93,6 +97,7 @@ fn roundtrip() {
         r: 0,
         g: 128,
         b: 255,
+        a: 255,
     };
     let hex = original.to_hex();
     let parsed = Color::from_hex(&hex).unwrap();
@@ -110,6 +115,7 @@ fn display_matches_to_hex() {
         r: 26,
         g: 27,
         b: 42,
+        a: 255,
     };
     assert_eq!(format!("{color}"), &*color.to_hex());
 }
@@ -120,8 +126,219 @@ fn display_in_format_string() {
         r: 255,
         g: 0,
         b: 128,
+        a: 255,
     };
     let mut buf = String::new();
     write!(buf, "color: {color}").unwrap();
     assert_eq!(buf, "color: #FF0080");
 }
+
+#[test]
+fn from_hex_with_alpha() {
+    let color = Color::from_hex("#1a1b2a80").unwrap();
+    assert_eq!(color, Color::rgba(26, 27, 42, 0x80));
+    assert!(!color.is_opaque());
+}
+
+#[test]
+fn from_hex_without_alpha_is_opaque() {
+    let color = Color::from_hex("#1A1B2A").unwrap();
+    assert_eq!(color.a, 255);
+    assert!(color.is_opaque());
+}
+
+#[test]
+fn from_hex_seven_digits_returns_error() {
+    assert!(Color::from_hex("#1A1B2A8").is_err());
+}
+
+#[test]
+fn translucent_hex_includes_alpha() {
+    let color = Color::rgba(26, 27, 42, 0x80);
+    assert_eq!(&*color.to_hex(), "#1A1B2A80");
+    assert_eq!(format!("{color}"), "#1A1B2A80");
+    assert_eq!(&*color.opaque().to_hex(), "#1A1B2A");
+}
+
+#[test]
+fn roundtrip_with_alpha() {
+    let original = Color::rgba(0, 128, 255, 0x33);
+    let parsed = Color::from_hex(&original.to_hex()).unwrap();
+    assert_eq!(parsed, original);
+}
+
+#[test]
+fn to_css_uses_rgba_only_when_translucent() {
+    assert_eq!(&*Color::rgb(26, 27, 42).to_css(), "#1A1B2A");
+    assert_eq!(
+        &*Color::rgba(26, 27, 42, 0x80).to_css(),
+        "rgba(26, 27, 42, 0.502)"
+    );
+    assert_eq!(&*Color::rgba(0, 0, 0, 0).to_css(), "rgba(0, 0, 0, 0)");
+}
+
+#[test]
+fn default_is_opaque_black() {
+    assert_eq!(Color::default(), Color::rgb(0, 0, 0));
+}
+
+#[test]
+fn parse_accepts_hex() {
+    assert_eq!(Color::parse("#1A1B2A").unwrap(), Color::rgb(26, 27, 42));
+    assert_eq!(
+        Color::parse("#1A1B2A80").unwrap(),
+        Color::rgba(26, 27, 42, 0x80)
+    );
+}
+
+#[test]
+fn parse_rgb_space_and_comma_forms() {
+    let expected = Color::rgb(26, 27, 42);
+    assert_eq!(Color::parse("rgb(26 27 42)").unwrap(), expected);
+    assert_eq!(Color::parse("rgb(26,27,42)").unwrap(), expected);
+    assert_eq!(Color::parse("  RGB( 26 , 27 , 42 )  ").unwrap(), expected);
+    assert_eq!(
+        Color::parse("rgb(100% 0% 50%)").unwrap(),
+        Color::rgb(255, 0, 128)
+    );
+}
+
+#[test]
+fn parse_rgb_with_alpha() {
+    let expected = Color::rgba(26, 27, 42, 128);
+    assert_eq!(Color::parse("rgb(26 27 42 / 50%)").unwrap(), expected);
+    assert_eq!(Color::parse("rgba(26, 27, 42, 0.5)").unwrap(), expected);
+}
+
+#[test]
+fn parse_hsl() {
+    assert_eq!(
+        Color::parse("hsl(0 100% 50%)").unwrap(),
+        Color::rgb(255, 0, 0)
+    );
+    assert_eq!(
+        Color::parse("hsl(120deg, 100%, 25%)").unwrap(),
+        Color::rgb(0, 128, 0)
+    );
+    assert_eq!(
+        Color::parse("hsl(220 30% 15%)").unwrap(),
+        Color::rgb(27, 34, 50)
+    );
+    assert_eq!(
+        Color::parse("hsla(240, 100%, 50%, 0.25)").unwrap(),
+        Color::rgba(0, 0, 255, 64)
+    );
+}
+
+#[test]
+fn parse_rejects_malformed_functions() {
+    for bad in [
+        "rgb(26 27)",
+        "rgb(26 27 42 1 2)",
+        "rgb(26, 27 42)",
+        "rgb(a b c)",
+        "rgb(26 27 42",
+        "hsl(50% 100% 50%)",
+        "hwb(0 0% 0%)",
+        "red",
+        "",
+    ] {
+        let err = Color::parse(bad).unwrap_err();
+        assert_eq!(&*err.value, bad);
+    }
+}
+
+#[test]
+fn from_str_parses_like_parse() {
+    let color: Color = "#1A1B2A".parse().unwrap();
+    assert_eq!(color, Color::rgb(26, 27, 42));
+    assert_eq!("rgb(26 27 42)".parse::<Color>().unwrap(), color);
+
+    let err = "nope".parse::<Color>().unwrap_err();
+    assert_eq!(&*err.value, "nope");
+}
+
+#[test]
+fn try_from_str() {
+    assert_eq!(
+        Color::try_from("#FF008080"),
+        Ok(Color::rgba(255, 0, 128, 128))
+    );
+    assert!(Color::try_from("#FF00").is_err());
+}
+
+#[cfg(feature = "snapshot")]
+#[test]
+fn serde_json_round_trip() {
+    let colors = vec![Color::rgb(26, 27, 42), Color::rgba(255, 0, 128, 64)];
+    let json = serde_json::to_string(&colors).unwrap();
+    assert_eq!(json, r##"["#1A1B2A","#FF008040"]"##);
+
+    let back: Vec<Color> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, colors);
+}
+
+#[cfg(feature = "snapshot")]
+#[test]
+fn serde_json_rejects_invalid_color() {
+    let err = serde_json::from_str::<Color>(r##""#12""##).unwrap_err();
+    assert!(err.to_string().contains("invalid hex color: #12"), "{err}");
+    assert!(serde_json::from_str::<Color>("12").is_err());
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn deserializes_from_toml_config() {
+    #[derive(serde::Deserialize)]
+    struct Config {
+        accent: Color,
+        overlay: Color,
+    }
+
+    let config: Config =
+        toml::from_str("accent = \"#7AA2F7\"\noverlay = \"rgb(0 0 0 / 50%)\"\n").unwrap();
+    assert_eq!(config.accent, Color::rgb(0x7A, 0xA2, 0xF7));
+    assert_eq!(config.overlay, Color::rgba(0, 0, 0, 128));
+}
+
+const ACCENT: Color = palette_core::hex!("#7aa2f7");
+static SCRIM: Color = palette_core::hex!("#00000080");
+const FALLBACK: Color = Color::const_rgb(26, 27, 42);
+
+#[test]
+fn hex_macro_matches_from_hex() {
+    assert_eq!(ACCENT, Color::from_hex("#7aa2f7").unwrap());
+    assert_eq!(SCRIM, Color::from_hex("#00000080").unwrap());
+    assert_eq!(palette_core::hex!("#FFFFFF"), Color::rgb(255, 255, 255));
+}
+
+#[test]
+fn const_rgb_is_opaque() {
+    assert_eq!(FALLBACK, Color::rgb(26, 27, 42));
+    assert!(FALLBACK.is_opaque());
+}
+
+#[test]
+fn from_ansi256_known_entries() {
+    assert_eq!(Color::from_ansi256(1), Color::rgb(0x80, 0, 0));
+    assert_eq!(Color::from_ansi256(16), Color::rgb(0, 0, 0));
+    assert_eq!(Color::from_ansi256(196), Color::rgb(255, 0, 0));
+    assert_eq!(Color::from_ansi256(231), Color::rgb(255, 255, 255));
+    assert_eq!(Color::from_ansi256(232), Color::rgb(8, 8, 8));
+    assert_eq!(Color::from_ansi256(255), Color::rgb(238, 238, 238));
+}
+
+#[test]
+fn ansi256_round_trips_cube_and_grays() {
+    for idx in 16..=255u8 {
+        assert_eq!(Color::from_ansi256(idx).to_ansi256(), idx, "index {idx}");
+    }
+}
+
+#[test]
+fn to_ansi256_picks_nearest() {
+    assert_eq!(Color::rgb(0x7A, 0xA2, 0xF7).to_ansi256(), 111);
+    // Near-gray lands on the gray ramp rather than a cube corner.
+    assert_eq!(Color::rgb(0x1A, 0x1B, 0x1A).to_ansi256(), 234);
+    assert_eq!(Color::rgb(255, 255, 255).to_ansi256(), 231);
+}
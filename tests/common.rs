@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 
+use palette_core::Palette;
 use palette_core::manifest::{ManifestSection, PaletteManifest};
 use std::collections::HashMap;
 
@@ -12,6 +13,7 @@ pub fn load_preset(name: &str) -> PaletteManifest {
 pub fn manifest_with_base(base: ManifestSection) -> PaletteManifest {
     PaletteManifest {
         meta: None,
+        colors: HashMap::new(),
         base,
         semantic: HashMap::new(),
         diff: HashMap::new(),
@@ -22,7 +24,23 @@ pub fn manifest_with_base(base: ManifestSection) -> PaletteManifest {
         terminal: HashMap::new(),
         syntax_style: HashMap::new(),
         gradient: HashMap::new(),
+        groups: Default::default(),
         #[cfg(feature = "platform")]
         platform: Default::default(),
+        opacity: Default::default(),
+        unset: Default::default(),
+        modes: Default::default(),
+        #[cfg(feature = "toml")]
+        extensions: Default::default(),
     }
 }
+
+/// The value of `field` in an imported manifest section, as written.
+pub fn hex(section: &ManifestSection, field: &str) -> String {
+    section.get(field).unwrap().to_string()
+}
+
+/// Build `manifest` into a palette, failing the test if it does not resolve.
+pub fn assert_builds_palette(manifest: &PaletteManifest) -> Palette {
+    Palette::from_manifest(manifest).unwrap()
+}
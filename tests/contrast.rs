@@ -5,8 +5,11 @@ use std::sync::Arc;
 
 use palette_core::color::Color;
 use palette_core::contrast::{
-    ContrastLevel, contrast_ratio, meets_level, nudge_foreground, validate_palette,
+    ContrastLevel, ContrastPolicy, best_foreground, black_or_white, contrast_ratio,
+    contrast_report, fix_palette, meets_level, nudge_foreground, validate_palette,
+    validate_with_policy,
 };
+use palette_core::diagnostic::Severity;
 use palette_core::manifest::PaletteManifest;
 use palette_core::palette::Palette;
 
@@ -76,6 +79,7 @@ fn level_thresholds() {
     assert!((ContrastLevel::AaLarge.threshold() - 3.0).abs() < 1e-10);
     assert!((ContrastLevel::AaaNormal.threshold() - 7.0).abs() < 1e-10);
     assert!((ContrastLevel::AaaLarge.threshold() - 4.5).abs() < 1e-10);
+    assert!((ContrastLevel::UiComponent.threshold() - 3.0).abs() < 1e-10);
 }
 
 #[test]
@@ -196,6 +200,7 @@ fn bad_palette_produces_violations() {
     base.insert(Arc::from("background"), Arc::from("#121212"));
     let manifest = PaletteManifest {
         meta: None,
+        colors: HashMap::new(),
         base,
         semantic: HashMap::new(),
         diff: HashMap::new(),
@@ -206,8 +211,14 @@ fn bad_palette_produces_violations() {
         terminal: HashMap::new(),
         syntax_style: HashMap::new(),
         gradient: HashMap::new(),
+        groups: Default::default(),
         #[cfg(feature = "platform")]
         platform: Default::default(),
+        opacity: Default::default(),
+        unset: Default::default(),
+        modes: Default::default(),
+        #[cfg(feature = "toml")]
+        extensions: Default::default(),
     };
     let palette = Palette::from_manifest(&manifest).unwrap();
     let violations = validate_palette(&palette, ContrastLevel::AaNormal);
@@ -288,6 +299,7 @@ fn fg_bg_pairs_covered_by_validation() {
 fn none_fields_skipped_without_error() {
     let manifest = PaletteManifest {
         meta: None,
+        colors: HashMap::new(),
         base: HashMap::new(),
         semantic: HashMap::new(),
         diff: HashMap::new(),
@@ -298,8 +310,14 @@ fn none_fields_skipped_without_error() {
         terminal: HashMap::new(),
         syntax_style: HashMap::new(),
         gradient: HashMap::new(),
+        groups: Default::default(),
         #[cfg(feature = "platform")]
         platform: Default::default(),
+        opacity: Default::default(),
+        unset: Default::default(),
+        modes: Default::default(),
+        #[cfg(feature = "toml")]
+        extensions: Default::default(),
     };
     let palette = Palette::from_manifest(&manifest).unwrap();
     let violations = validate_palette(&palette, ContrastLevel::AaNormal);
@@ -342,6 +360,329 @@ fn all_presets_focus_passes_aa_large() {
     }
 }
 
+// --- ContrastPolicy ---
+
+fn slot(path: &str) -> palette_core::SlotId {
+    path.parse().unwrap()
+}
+
+#[test]
+fn default_policy_matches_validate_palette() {
+    let mut palette = palette_core::load_preset("tokyonight").unwrap();
+    palette.base.foreground_dark = palette.base.background_dark;
+    assert_eq!(
+        validate_with_policy(&palette, &ContrastPolicy::default()),
+        validate_palette(&palette, ContrastLevel::AaNormal)
+    );
+}
+
+#[test]
+fn policy_checks_extra_pairs() {
+    let mut palette = palette_core::load_preset("tokyonight").unwrap();
+    palette.syntax.comments = palette.surface.float;
+    let policy = ContrastPolicy::new(ContrastLevel::AaNormal).pair(
+        slot("syntax.comments"),
+        slot("surface.float"),
+        ContrastLevel::AaLarge,
+    );
+
+    let violations = validate_with_policy(&palette, &policy);
+    let v = violations.last().unwrap();
+    assert_eq!(v.foreground_label.as_ref(), "syntax.comments");
+    assert_eq!(v.background_label.as_ref(), "surface.float");
+    assert_eq!(v.level, ContrastLevel::AaLarge);
+}
+
+#[test]
+fn policy_pair_overrides_builtin_level() {
+    let mut palette = palette_core::load_preset("tokyonight").unwrap();
+    // 4.54:1 — passes AA, fails AAA.
+    palette.base.foreground = Some(color("#767676"));
+    palette.base.background = Some(color("#FFFFFF"));
+    let is_core = |v: &&palette_core::contrast::ContrastViolation| {
+        v.foreground_label.as_ref() == "base.foreground"
+            && v.background_label.as_ref() == "base.background"
+    };
+
+    let lenient = ContrastPolicy::new(ContrastLevel::AaNormal);
+    assert!(
+        !validate_with_policy(&palette, &lenient)
+            .iter()
+            .any(|v| is_core(&v))
+    );
+
+    let strict = lenient.pair(
+        slot("base.foreground"),
+        slot("base.background"),
+        ContrastLevel::AaaNormal,
+    );
+    let violations = validate_with_policy(&palette, &strict);
+    let core: Vec<_> = violations.iter().filter(is_core).collect();
+    assert_eq!(core.len(), 1, "{core:?}");
+    assert_eq!(core[0].level, ContrastLevel::AaaNormal);
+}
+
+#[test]
+fn policy_without_level_checks_only_pairs() {
+    let mut palette = palette_core::load_preset("tokyonight").unwrap();
+    palette.base.foreground = palette.base.background;
+    let policy = ContrastPolicy {
+        level: None,
+        pairs: Vec::new(),
+    };
+    assert!(validate_with_policy(&palette, &policy).is_empty());
+
+    let policy = policy.pair(
+        slot("semantic.error"),
+        slot("surface.float"),
+        ContrastLevel::AaNormal,
+    );
+    palette.semantic.error = palette.surface.float;
+    let violations = validate_with_policy(&palette, &policy);
+    assert_eq!(violations.len(), 1);
+}
+
+#[test]
+fn policy_skips_unset_slots() {
+    let mut palette = palette_core::load_preset("tokyonight").unwrap();
+    palette.surface.float = None;
+    let policy = ContrastPolicy {
+        level: None,
+        pairs: Vec::new(),
+    }
+    .pair(
+        slot("syntax.comments"),
+        slot("surface.float"),
+        ContrastLevel::AaaNormal,
+    );
+    assert!(validate_with_policy(&palette, &policy).is_empty());
+}
+
+// --- fix_palette ---
+
+fn clean_palette() -> Palette {
+    palette_core::generate::from_seed(color("#7AA2F7"), palette_core::generate::Style::Dark)
+}
+
+#[test]
+fn fix_palette_leaves_clean_palette_alone() {
+    let palette = clean_palette();
+    assert!(validate_palette(&palette, ContrastLevel::AaNormal).is_empty());
+    let (fixed, changes) = fix_palette(&palette, ContrastLevel::AaNormal);
+    assert!(changes.is_empty());
+    assert_eq!(fixed, palette);
+}
+
+#[test]
+fn fix_palette_repairs_and_reports() {
+    let mut palette = clean_palette();
+    let bg = palette.base.background.unwrap();
+    let dim = bg.lighten(0.05);
+    palette.typography.comment = Some(dim);
+    palette.semantic.error = Some(dim);
+    palette.syntax.keywords = Some(dim);
+
+    let (fixed, changes) = fix_palette(&palette, ContrastLevel::AaNormal);
+    assert!(validate_palette(&fixed, ContrastLevel::AaNormal).is_empty());
+
+    let slots: Vec<&str> = changes.iter().map(|a| a.slot.as_ref()).collect();
+    assert_eq!(
+        slots,
+        ["typography.comment", "semantic.error", "syntax.keywords"]
+    );
+    for change in changes.iter() {
+        assert_eq!(change.before, dim);
+        assert!(meets_level(&change.after, &bg, ContrastLevel::AaNormal));
+    }
+    assert_eq!(fixed.typography.comment, Some(changes[0].after));
+    assert_eq!(fixed.base, palette.base);
+}
+
+#[test]
+fn fix_palette_reports_each_slot_once() {
+    let mut palette = clean_palette();
+    let bg = palette.base.background.unwrap();
+    // base.foreground is checked against four backgrounds.
+    palette.base.foreground = Some(bg);
+
+    let (fixed, changes) = fix_palette(&palette, ContrastLevel::AaNormal);
+    let fg_changes: Vec<_> = changes
+        .iter()
+        .filter(|a| a.slot.as_ref() == "base.foreground")
+        .collect();
+    assert_eq!(fg_changes.len(), 1);
+    assert_eq!(fg_changes[0].before, bg);
+    assert_eq!(fixed.base.foreground, Some(fg_changes[0].after));
+}
+
+#[test]
+fn fix_palette_keeps_unset_slots_unset() {
+    let mut palette = clean_palette();
+    palette.base.background = None;
+    palette.syntax.keywords = palette.base.background_dark;
+    let (fixed, _) = fix_palette(&palette, ContrastLevel::AaNormal);
+    assert_eq!(fixed.base.background, None);
+    assert_eq!(fixed.syntax.keywords, palette.syntax.keywords);
+}
+
+// --- Non-text UI components ---
+
+#[test]
+fn ui_level_checks_borders_and_cursor() {
+    let mut palette = palette_core::load_preset("tokyonight").unwrap();
+    let bg = palette.base.background;
+    palette.base.border = bg;
+    palette.base.border_highlight = bg;
+    palette.editor.cursor = bg;
+    // Text pairs are not part of the UI check.
+    palette.base.foreground = bg;
+
+    let violations = validate_palette(&palette, ContrastLevel::UiComponent);
+    let labels: Vec<&str> = violations
+        .iter()
+        .map(|v| v.foreground_label.as_ref())
+        .collect();
+    assert_eq!(
+        labels,
+        ["base.border", "base.border_highlight", "editor.cursor"]
+    );
+    assert!(violations.iter().all(|v| {
+        v.background_label.as_ref() == "base.background" && v.level == ContrastLevel::UiComponent
+    }));
+}
+
+#[test]
+fn text_levels_skip_ui_pairs() {
+    let mut palette =
+        palette_core::generate::from_seed(color("#7AA2F7"), palette_core::generate::Style::Dark);
+    palette.base.border = palette.base.background;
+    assert!(validate_palette(&palette, ContrastLevel::AaNormal).is_empty());
+    assert_eq!(
+        validate_palette(&palette, ContrastLevel::UiComponent).len(),
+        1
+    );
+}
+
+#[test]
+fn resolve_with_ui_contrast_fixes_borders() {
+    let mut palette = palette_core::load_preset("tokyonight").unwrap();
+    palette.base.border = palette.base.background.map(|c| c.lighten(0.02));
+    let resolved = palette.resolve_with_contrast(ContrastLevel::UiComponent);
+    assert!(meets_level(
+        &resolved.base.border,
+        &resolved.base.background,
+        ContrastLevel::UiComponent
+    ));
+    assert_eq!(resolved.base.foreground, palette.resolve().base.foreground);
+}
+
+#[test]
+fn fix_palette_repairs_ui_pairs() {
+    let mut palette = palette_core::load_preset("tokyonight").unwrap();
+    palette.editor.cursor = palette.base.background;
+    let (fixed, changes) = fix_palette(&palette, ContrastLevel::UiComponent);
+    assert!(validate_palette(&fixed, ContrastLevel::UiComponent).is_empty());
+    assert!(changes.iter().any(|a| a.slot.as_ref() == "editor.cursor"));
+}
+
+// --- ContrastReport ---
+
+#[test]
+fn report_counts_match_violations() {
+    let palette = palette_core::load_preset("nord").unwrap();
+    let report = contrast_report(&palette, ContrastLevel::AaaNormal);
+    let violations = validate_palette(&palette, ContrastLevel::AaaNormal);
+
+    assert_eq!(report.violations, violations);
+    assert_eq!(report.failed, violations.len());
+    assert_eq!(report.checked, report.passed + report.failed);
+    assert!(!report.is_passing());
+    let by_section: usize = report.sections.iter().map(|s| s.checked).sum();
+    assert_eq!(by_section, report.checked);
+    for s in report.sections.iter() {
+        assert_eq!(s.checked, s.passed + s.failed, "{}", s.section);
+        let failing = violations
+            .iter()
+            .filter(|v| v.foreground_label.starts_with(&format!("{}.", s.section)))
+            .count();
+        assert_eq!(s.failed, failing, "{}", s.section);
+    }
+}
+
+#[test]
+fn report_worst_ratio_is_minimum() {
+    let mut palette = clean_palette();
+    palette.typography.comment = palette.base.background;
+    let report = contrast_report(&palette, ContrastLevel::AaNormal);
+    assert_eq!(report.worst_ratio, Some(1.0));
+    assert_eq!(report.failed, 1);
+    let typography = report
+        .sections
+        .iter()
+        .find(|s| s.section.as_ref() == "typography")
+        .unwrap();
+    assert_eq!((typography.passed, typography.failed), (1, 1));
+}
+
+#[test]
+fn severe_core_violation_is_error() {
+    let mut palette = clean_palette();
+    palette.base.foreground = palette.base.background;
+    let violations = validate_palette(&palette, ContrastLevel::AaNormal);
+    let v = violations
+        .iter()
+        .find(|v| v.foreground_label.as_ref() == "base.foreground")
+        .unwrap();
+    assert_eq!(v.severity, Severity::Error);
+}
+
+#[test]
+fn cosmetic_violation_is_warning() {
+    let mut palette = clean_palette();
+    palette.typography.line_number = palette.base.background;
+    let violations = validate_palette(&palette, ContrastLevel::AaNormal);
+    let v = violations
+        .iter()
+        .find(|v| v.foreground_label.as_ref() == "typography.line_number")
+        .unwrap();
+    assert_eq!(v.ratio, 1.0);
+    assert_eq!(v.severity, Severity::Warning);
+}
+
+#[test]
+fn near_miss_core_violation_is_warning() {
+    let mut palette = clean_palette();
+    let bg = palette.base.background.unwrap();
+    // 4.0:1 is below AA but above 80% of it.
+    palette.base.foreground = Some(color("#7A7A7A"));
+    let ratio = contrast_ratio(&color("#7A7A7A"), &bg);
+    assert!((3.6..4.5).contains(&ratio), "ratio {ratio}");
+    let violations = validate_palette(&palette, ContrastLevel::AaNormal);
+    let v = violations
+        .iter()
+        .find(|v| v.foreground_label.as_ref() == "base.foreground")
+        .unwrap();
+    assert_eq!(v.severity, Severity::Warning);
+}
+
+#[test]
+fn report_on_clean_palette_passes() {
+    let report = contrast_report(&clean_palette(), ContrastLevel::AaNormal);
+    assert!(report.is_passing());
+    assert!(report.checked > 0);
+    assert!(report.worst_ratio.unwrap() >= 4.5);
+}
+
+#[test]
+fn report_on_empty_palette() {
+    let palette = Palette::from_manifest(&common::manifest_with_base(HashMap::new())).unwrap();
+    let report = contrast_report(&palette, ContrastLevel::AaNormal);
+    assert_eq!(report.checked, 0);
+    assert_eq!(report.worst_ratio, None);
+    assert!(report.sections.is_empty());
+    assert!(report.is_passing());
+}
+
 // --- nudge_foreground ---
 
 #[test]
@@ -417,6 +758,7 @@ fn resolve_with_contrast_fixes_bad_palette() {
     base.insert(Arc::from("background"), Arc::from("#505050"));
     let manifest = PaletteManifest {
         meta: None,
+        colors: HashMap::new(),
         base,
         semantic: HashMap::new(),
         diff: HashMap::new(),
@@ -427,8 +769,14 @@ fn resolve_with_contrast_fixes_bad_palette() {
         terminal: HashMap::new(),
         syntax_style: HashMap::new(),
         gradient: HashMap::new(),
+        groups: Default::default(),
         #[cfg(feature = "platform")]
         platform: Default::default(),
+        opacity: Default::default(),
+        unset: Default::default(),
+        modes: Default::default(),
+        #[cfg(feature = "toml")]
+        extensions: Default::default(),
     };
     let palette = Palette::from_manifest(&manifest).unwrap();
 
@@ -470,3 +818,60 @@ fn resolve_with_contrast_zero_violations_on_presets() {
         );
     }
 }
+
+#[test]
+fn contrast_between_slot_paths() {
+    let palette = clean_palette();
+    let ratio = palette
+        .contrast_between("base.foreground", "base.background")
+        .unwrap();
+    let expected = contrast_ratio(
+        &palette.base.foreground.unwrap(),
+        &palette.base.background.unwrap(),
+    );
+    assert_eq!(ratio, expected);
+}
+
+#[test]
+fn contrast_between_unset_slot_errors() {
+    let mut palette = clean_palette();
+    palette.surface.float = None;
+    let err = palette
+        .contrast_between("base.foreground", "surface.float")
+        .unwrap_err();
+    assert_eq!(err.code(), "PC0017");
+    assert_eq!(err.to_string(), "slot [surface].float is not set");
+}
+
+#[test]
+fn contrast_between_unknown_path_errors() {
+    let err = clean_palette()
+        .contrast_between("syntax.keyword", "base.background")
+        .unwrap_err();
+    assert_eq!(err.code(), "PC0007");
+}
+
+#[test]
+fn best_foreground_picks_highest_contrast() {
+    let bg = color("#1A1B26");
+    let candidates = [color("#3B4261"), color("#C0CAF5"), color("#7AA2F7")];
+    assert_eq!(best_foreground(bg, &candidates), color("#C0CAF5"));
+}
+
+#[test]
+fn best_foreground_prefers_first_on_tie() {
+    let bg = color("#808080");
+    let same = color("#FFFFFF");
+    assert_eq!(best_foreground(bg, &[same, same.with_alpha(128)]), same);
+}
+
+#[test]
+fn best_foreground_empty_falls_back_to_black_or_white() {
+    assert_eq!(best_foreground(color("#FAFAFA"), &[]), color("#000000"));
+}
+
+#[test]
+fn black_or_white_follows_background_lightness() {
+    assert_eq!(black_or_white(color("#1A1B26")), color("#FFFFFF"));
+    assert_eq!(black_or_white(color("#F0F0F0")), color("#000000"));
+}
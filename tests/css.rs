@@ -220,3 +220,23 @@ fn all_css_names_match_design_spec() {
         "typography: --text-comment"
     );
 }
+
+#[test]
+fn translucent_colors_use_rgba() {
+    let manifest = common::manifest_with_base(
+        [
+            ("background".into(), "#1A1B2A".into()),
+            ("border".into(), "#FFFFFF40".into()),
+        ]
+        .into_iter()
+        .collect(),
+    );
+    let palette = Palette::from_manifest(&manifest).unwrap();
+    let css = palette.to_css();
+
+    assert!(css.contains("--bg: #1A1B2A;"), "got:\n{css}");
+    assert!(
+        css.contains("--border: rgba(255, 255, 255, 0.251);"),
+        "got:\n{css}"
+    );
+}
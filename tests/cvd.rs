@@ -0,0 +1,137 @@
+use palette_core::color::Color;
+use palette_core::cvd::{Deficiency, distance, min_distance, simulate, validate_semantic};
+use palette_core::load_preset;
+
+fn color(hex: &str) -> Color {
+    Color::from_hex(hex).unwrap()
+}
+
+#[test]
+fn grays_are_unchanged() {
+    for hex in ["#000000", "#808080", "#FFFFFF"] {
+        for d in Deficiency::ALL {
+            let c = color(hex);
+            let s = simulate(c, d);
+            assert!(distance(c, s) < 0.01, "{hex} {d:?}: {s:?}");
+        }
+    }
+}
+
+#[test]
+fn red_and_green_collapse_for_dichromats() {
+    let red = color("#B85C3C");
+    let green = color("#6E8C3C");
+    let normal = distance(red, green);
+    for d in Deficiency::RED_GREEN {
+        let simulated = distance(simulate(red, d), simulate(green, d));
+        assert!(
+            simulated < normal * 0.65,
+            "{d:?}: {simulated} vs normal {normal}"
+        );
+    }
+}
+
+#[test]
+fn blue_and_yellow_stay_apart() {
+    let blue = color("#3060E0");
+    let yellow = color("#E0C030");
+    for d in Deficiency::RED_GREEN {
+        assert!(
+            distance(simulate(blue, d), simulate(yellow, d)) > 0.2,
+            "{d:?}"
+        );
+    }
+}
+
+#[test]
+fn min_distance_takes_the_worst_view() {
+    let red = color("#D04040");
+    let green = color("#40A040");
+    assert!(min_distance(&[red, green]) < distance(red, green));
+    assert_eq!(min_distance(&[red]), f64::INFINITY);
+}
+
+#[test]
+fn blue_and_yellow_collapse_for_tritanopes() {
+    let blue = color("#3060E0");
+    let yellow = color("#E0C030");
+    let red = color("#D04040");
+    let green = color("#40A040");
+    let d = Deficiency::Tritanopia;
+    let blue_yellow = distance(blue.simulate(d), yellow.simulate(d));
+    let red_green = distance(red.simulate(d), green.simulate(d));
+    assert!(blue_yellow < distance(blue, yellow) * 0.8, "{blue_yellow}");
+    assert!(red_green > 0.2, "{red_green}");
+}
+
+#[test]
+fn color_simulate_matches_free_function() {
+    let c = color("#7AA2F7");
+    for d in Deficiency::ALL {
+        assert_eq!(c.simulate(d), simulate(c, d));
+    }
+}
+
+#[test]
+fn simulate_cvd_maps_every_slot() {
+    let palette = load_preset("tokyonight").unwrap();
+    let simulated = palette.simulate_cvd(Deficiency::Deuteranopia);
+    assert_eq!(simulated.meta, palette.meta);
+    let (Some(fg), Some(sim)) = (palette.semantic.error, simulated.semantic.error) else {
+        panic!("tokyonight defines semantic.error");
+    };
+    assert_eq!(sim, fg.simulate(Deficiency::Deuteranopia));
+    assert_eq!(
+        simulated.base.background,
+        palette
+            .base
+            .background
+            .map(|c| c.simulate(Deficiency::Deuteranopia))
+    );
+}
+
+#[test]
+fn validate_semantic_flags_red_green_pair() {
+    let mut palette = load_preset("tokyonight").unwrap();
+    palette.semantic.success = Some(color("#6E8C3C"));
+    palette.semantic.error = Some(color("#B85C3C"));
+    palette.semantic.warning = Some(color("#E0C030"));
+    palette.semantic.info = Some(color("#3060E0"));
+
+    let violations = validate_semantic(&palette, 10.0);
+    assert_eq!(violations.len(), 1, "{violations:?}");
+    let v = &violations[0];
+    assert_eq!(
+        (v.first_label.as_ref(), v.second_label.as_ref()),
+        ("semantic.success", "semantic.error")
+    );
+    assert!(Deficiency::RED_GREEN.contains(&v.deficiency));
+    assert!(v.delta_e < 10.0);
+    assert_eq!(v.min_delta_e, 10.0);
+    assert_eq!(
+        v.delta_e,
+        simulate(v.first, v.deficiency).delta_e(&simulate(v.second, v.deficiency))
+    );
+}
+
+#[test]
+fn validate_semantic_passes_generated_status_colors() {
+    let bg = color("#1A1B26");
+    let status = palette_core::generate::status_colors(bg);
+    let mut palette = load_preset("tokyonight").unwrap();
+    palette.semantic.success = Some(status.success);
+    palette.semantic.warning = Some(status.warning);
+    palette.semantic.error = Some(status.error);
+    palette.semantic.info = Some(status.info);
+    assert!(validate_semantic(&palette, 5.0).is_empty());
+}
+
+#[test]
+fn validate_semantic_skips_unset_and_non_status_slots() {
+    let mut palette = load_preset("tokyonight").unwrap();
+    palette.semantic.success = None;
+    palette.semantic.warning = None;
+    palette.semantic.info = None;
+    palette.semantic.hint = palette.semantic.error;
+    assert!(validate_semantic(&palette, 100.0).is_empty());
+}
@@ -0,0 +1,317 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use palette_core::contrast::validate_palette;
+use palette_core::diagnostic::{Diagnostic, Location, Severity};
+use palette_core::error::{ChainLink, PaletteError};
+use palette_core::manifest::{PaletteManifest, validate_fields};
+use palette_core::{ContrastLevel, load_preset};
+
+fn all_errors() -> Vec<PaletteError> {
+    let s = |v: &str| Arc::<str>::from(v);
+    vec![
+        PaletteError::Io {
+            path: s("x.toml"),
+            source: std::io::Error::other("gone"),
+        },
+        PaletteError::MissingBase,
+        PaletteError::MissingMeta,
+        PaletteError::InvalidHex {
+            section: s("base"),
+            field: s("background"),
+            value: s("#12"),
+        },
+        PaletteError::InvalidStyle {
+            section: s("syntax_style"),
+            field: s("keywords"),
+            value: s("blink"),
+        },
+        PaletteError::UnknownField {
+            section: s("base"),
+            field: s("backgrund"),
+        },
+        PaletteError::UnknownPreset(s("nrod")),
+        PaletteError::InsufficientStops { count: 1 },
+        PaletteError::UnsortedStops,
+        PaletteError::InvalidGradientPosition { position: 1.5 },
+        PaletteError::MixedGradientStopKinds {
+            gradient: s("sunset"),
+        },
+        PaletteError::InvalidGradientRef {
+            gradient: s("sunset"),
+            stop_index: 0,
+            reference: s("semantic.eror"),
+        },
+        PaletteError::InvalidColorSpace {
+            gradient: s("sunset"),
+            value: s("hsl"),
+        },
+        PaletteError::Import {
+            format: s("vscode"),
+            message: s("bad"),
+        },
+        PaletteError::GroupConflict {
+            section: s("chart"),
+        },
+        PaletteError::UnsetSlot {
+            section: s("surface"),
+            field: s("float"),
+        },
+        PaletteError::UnknownColorVariable {
+            section: s("base"),
+            field: s("background"),
+            name: s("bleu"),
+        },
+        PaletteError::UnknownSection(s("semantc")),
+        PaletteError::UnsupportedSchemaVersion(s("2")),
+        PaletteError::UnresolvedMetaPlaceholder {
+            field: s("name"),
+            placeholder: s("styl"),
+        },
+    ]
+}
+
+#[test]
+fn error_codes_are_unique_and_stable() {
+    let codes: Vec<_> = all_errors().iter().map(PaletteError::code).collect();
+    let unique: HashSet<_> = codes.iter().collect();
+    assert_eq!(unique.len(), codes.len());
+    assert_eq!(PaletteError::MissingBase.code(), "PC0003");
+    assert_eq!(PaletteError::UnsortedStops.code(), "PC0010");
+}
+
+#[test]
+fn error_diagnostics_carry_code_and_severity() {
+    for err in all_errors() {
+        let diagnostic = err.diagnostic();
+        assert_eq!(diagnostic.code, err.code());
+        assert_eq!(diagnostic.severity, Severity::Error);
+        assert_eq!(diagnostic.message.as_ref(), err.to_string());
+    }
+}
+
+#[test]
+fn parse_error_has_code() {
+    let err = PaletteManifest::from_toml("not = [valid").unwrap_err();
+    assert_eq!(err.code(), "PC0001");
+}
+
+#[test]
+fn invalid_hex_locates_slot() {
+    let err = PaletteError::InvalidHex {
+        section: Arc::from("base"),
+        field: Arc::from("background"),
+        value: Arc::from("#12"),
+    };
+    let diagnostic = err.diagnostic();
+    assert_eq!(diagnostic.section.as_deref(), Some("base"));
+    assert_eq!(diagnostic.field.as_deref(), Some("background"));
+    assert!(diagnostic.suggestion.is_some());
+}
+
+#[test]
+fn unknown_names_suggest_closest_match() {
+    let suggestion = |err: PaletteError| err.diagnostic().suggestion;
+    assert_eq!(
+        suggestion(load_preset("tokyonite").unwrap_err()).as_deref(),
+        Some("did you mean `tokyonight`?")
+    );
+    assert_eq!(
+        suggestion(PaletteError::UnknownField {
+            section: Arc::from("base"),
+            field: Arc::from("backgrund"),
+        })
+        .as_deref(),
+        Some("did you mean `background`?")
+    );
+    assert_eq!(
+        suggestion(PaletteError::InvalidGradientRef {
+            gradient: Arc::from("sunset"),
+            stop_index: 0,
+            reference: Arc::from("semantic.eror"),
+        })
+        .as_deref(),
+        Some("did you mean `semantic.error`?")
+    );
+}
+
+#[test]
+fn unrelated_names_get_no_suggestion() {
+    let err = PaletteError::UnknownPreset(Arc::from("zzzzzzzzzz"));
+    assert_eq!(err.diagnostic().suggestion, None);
+}
+
+#[test]
+fn display_includes_code_and_help() {
+    let text = PaletteError::MissingBase.diagnostic().to_string();
+    assert!(text.starts_with("error[PC0003]: manifest missing required [base] section"));
+    assert!(text.contains("\n  help: "));
+}
+
+#[test]
+fn unknown_field_converts_to_warning() {
+    let toml = "[base]\nbackground = \"#000000\"\nforegrond = \"#FFFFFF\"\n";
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    let unknown = validate_fields(&manifest);
+    let diagnostic = Diagnostic::from(&unknown[0]);
+    assert_eq!(diagnostic.code, "PC0100");
+    assert_eq!(diagnostic.severity, Severity::Warning);
+    assert_eq!(diagnostic.field.as_deref(), Some("foregrond"));
+    assert_eq!(
+        diagnostic.suggestion.as_deref(),
+        Some("did you mean `foreground`?")
+    );
+}
+
+#[test]
+fn contrast_violation_keeps_severity() {
+    let palette = palette_core::preset("nord").unwrap();
+    let violations = validate_palette(&palette, ContrastLevel::AaaNormal);
+    let diagnostic = Diagnostic::from(&violations[0]);
+    assert_eq!(diagnostic.code, "PC0101");
+    assert_eq!(diagnostic.severity, violations[0].severity);
+    let label = violations[0].foreground_label.as_ref();
+    let (section, field) = label.split_once('.').unwrap();
+    assert_eq!(diagnostic.section.as_deref(), Some(section));
+    assert_eq!(diagnostic.field.as_deref(), Some(field));
+    assert!(diagnostic.suggestion.unwrap().starts_with("try #"));
+}
+
+#[test]
+fn cvd_violation_converts_to_warning() {
+    let mut palette = load_preset("nord").unwrap();
+    palette.semantic.success = Some(palette_core::Color::from_hex("#6E8C3C").unwrap());
+    palette.semantic.error = Some(palette_core::Color::from_hex("#B85C3C").unwrap());
+    let violations = palette_core::cvd::validate_semantic(&palette, 10.0);
+    let v = violations
+        .iter()
+        .find(|v| v.first_label.as_ref() == "semantic.success")
+        .unwrap();
+    let diagnostic = Diagnostic::from(v);
+    assert_eq!(diagnostic.code, "PC0105");
+    assert_eq!(diagnostic.severity, Severity::Warning);
+    assert_eq!(diagnostic.section.as_deref(), Some("semantic"));
+    assert_eq!(diagnostic.field.as_deref(), Some("success"));
+    assert!(diagnostic.message.contains("semantic.error"));
+}
+
+#[test]
+fn inheritance_wrapper_keeps_inner_code() {
+    let err = PaletteError::Inheritance {
+        chain: Box::new([
+            ChainLink {
+                id: Arc::from("orphan"),
+                path: Some(Arc::from("themes/orphan.toml")),
+            },
+            ChainLink {
+                id: Arc::from("nrod"),
+                path: None,
+            },
+        ]),
+        source: Box::new(PaletteError::UnknownPreset(Arc::from("nrod"))),
+    };
+    let diagnostic = err.diagnostic();
+    assert_eq!(diagnostic.code, "PC0008");
+    assert_eq!(
+        diagnostic.message.as_ref(),
+        "while resolving orphan (themes/orphan.toml) → nrod: unknown preset: nrod"
+    );
+    assert_eq!(
+        diagnostic.suggestion.as_deref(),
+        Some("did you mean `nord`?")
+    );
+}
+
+#[cfg(feature = "snapshot")]
+#[test]
+fn error_serializes_to_stable_json_shape() {
+    let err = PaletteError::InvalidHex {
+        section: Arc::from("base"),
+        field: Arc::from("background"),
+        value: Arc::from("#12"),
+    };
+    let json = serde_json::to_value(&err).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "code": "PC0005",
+            "severity": "error",
+            "section": "base",
+            "field": "background",
+            "message": "invalid hex `#12` in [base].background",
+            "suggestion": "use a `#RRGGBB`/`#RRGGBBAA` hex, `rgb()`/`hsl()`, or a `$name` from [colors]",
+            "span": null,
+        })
+    );
+}
+
+#[cfg(feature = "snapshot")]
+#[test]
+fn parse_error_serializes_span() {
+    let toml = "[base]\nbackground = [oops\n";
+    let err = PaletteManifest::from_toml(toml).unwrap_err();
+    let json = serde_json::to_value(&err).unwrap();
+    assert_eq!(json["code"], "PC0001");
+    let start = json["span"]["start"].as_u64().unwrap() as usize;
+    let end = json["span"]["end"].as_u64().unwrap() as usize;
+    assert!(start <= end && end <= toml.len());
+}
+
+#[cfg(feature = "snapshot")]
+#[test]
+fn warning_severity_serializes_lowercase() {
+    let palette = palette_core::preset("nord").unwrap();
+    let violations = validate_palette(&palette, ContrastLevel::AaaNormal);
+    let warning = violations
+        .iter()
+        .find(|v| v.severity == Severity::Warning)
+        .unwrap();
+    let json = serde_json::to_value(Diagnostic::from(warning)).unwrap();
+    assert_eq!(json["severity"], "warning");
+    assert_eq!(json["code"], "PC0101");
+}
+
+const LOCATED_TOML: &str = "[base]\nbackground = \"#000000\"\nborder = \"#zz\"\n";
+
+#[test]
+fn invalid_hex_is_located_in_source() {
+    let manifest = PaletteManifest::from_toml(LOCATED_TOML).unwrap();
+    let err = palette_core::Palette::from_manifest(&manifest).unwrap_err();
+    let span = err.span_in(LOCATED_TOML).unwrap();
+    assert_eq!(&LOCATED_TOML[span], "\"#zz\"");
+
+    let location = err.location_in(Some("theme.toml"), LOCATED_TOML).unwrap();
+    assert_eq!((location.line, location.column), (3, 10));
+    assert_eq!(location.to_string(), "theme.toml:3:10");
+    assert!(err.diagnostic_in(LOCATED_TOML).span.is_some());
+    assert_eq!(err.diagnostic().span, None);
+}
+
+#[test]
+fn parse_error_is_located_in_source() {
+    let toml = "[base]\nbackground = [oops\n";
+    let err = PaletteManifest::from_toml(toml).unwrap_err();
+    let location = err.location_in(None, toml).unwrap();
+    assert_eq!(location.line, 2);
+    assert_eq!(location.path, None);
+}
+
+#[test]
+fn unknown_field_points_at_key() {
+    let toml = "[base]\nforground = \"#ffffff\"\n";
+    let err = PaletteManifest::from_toml_strict(toml).unwrap_err();
+    assert_eq!(&toml[err.span_in(toml).unwrap()], "forground");
+}
+
+#[test]
+fn location_from_offset_counts_characters() {
+    let source = "a = \"é\"\nb = 1";
+    let location = Location::from_offset(None, source, source.find('b').unwrap());
+    assert_eq!((location.line, location.column), (2, 1));
+    // The closing quote follows the two-byte `é`.
+    let location = Location::from_offset(None, source, 7);
+    assert_eq!((location.line, location.column), (1, 7));
+    // Inside `é` rounds down to its start.
+    let location = Location::from_offset(None, source, 6);
+    assert_eq!((location.line, location.column), (1, 6));
+}
@@ -20,6 +20,7 @@ fn single_color_converts_to_color32() {
         r: 26,
         g: 27,
         b: 42,
+        a: 255,
     };
     assert_eq!(to_color32(&color), Color32::from_rgb(26, 27, 42));
 }
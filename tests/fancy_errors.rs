@@ -0,0 +1,55 @@
+#![cfg(feature = "fancy-errors")]
+
+use std::sync::Arc;
+
+use miette::Diagnostic;
+use palette_core::error::{ChainLink, PaletteError};
+use palette_core::manifest::PaletteManifest;
+
+#[test]
+fn code_severity_and_help_come_from_the_diagnostic() {
+    let err = PaletteError::UnknownPreset(Arc::from("nrod"));
+    let code = Diagnostic::code(&err).map(|c| c.to_string());
+    assert_eq!(code.as_deref(), Some("PC0008"));
+    assert_eq!(err.severity(), Some(miette::Severity::Error));
+    let help = err.help().map(|h| h.to_string());
+    assert_eq!(help.as_deref(), Some("did you mean `nord`?"));
+}
+
+#[test]
+fn parse_error_labels_the_offending_span() {
+    let toml = "[base]\nbackground = \"#000000\"\nforeground = [oops\n";
+    let err = PaletteManifest::from_toml(toml).unwrap_err();
+    let labels: Vec<_> = err.labels().unwrap().collect();
+    assert_eq!(labels.len(), 1);
+    let offset = labels[0].offset();
+    assert!(offset >= toml.find("foreground").unwrap(), "{offset}");
+    assert!(labels[0].label().is_some());
+}
+
+#[test]
+fn errors_without_spans_have_no_labels() {
+    assert!(PaletteError::MissingBase.labels().is_none());
+}
+
+#[test]
+fn report_renders_with_source_code() {
+    let toml = "[base]\nbackground = [oops\n";
+    let err = PaletteManifest::from_toml(toml).unwrap_err();
+    let report = miette::Report::new(err).with_source_code(toml);
+    let rendered = format!("{report:?}");
+    assert!(rendered.contains("PC0001"), "{rendered}");
+}
+
+#[test]
+fn inheritance_exposes_wrapped_diagnostic() {
+    let err = PaletteError::Inheritance {
+        chain: Box::new([ChainLink {
+            id: Arc::from("orphan"),
+            path: None,
+        }]),
+        source: Box::new(PaletteError::UnknownPreset(Arc::from("missing"))),
+    };
+    let inner = err.diagnostic_source().unwrap();
+    assert_eq!(inner.to_string(), "unknown preset: missing");
+}
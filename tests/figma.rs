@@ -0,0 +1,70 @@
+use palette_core::export::figma::{FILE_NAME, to_tokens_studio};
+use palette_core::export::find_exporter;
+use palette_core::load_preset;
+use palette_core::palette::Palette;
+
+#[test]
+fn set_keyed_by_preset_id() {
+    let resolved = load_preset("tokyonight").unwrap().resolve();
+    let json = to_tokens_studio(&[&resolved]);
+
+    assert!(json.contains(r#"  "tokyonight": {"#), "got:\n{json}");
+    assert!(json.contains(r#""tokenSetOrder": ["tokyonight"]"#));
+}
+
+#[test]
+fn tokens_nest_section_and_field() {
+    let resolved = load_preset("tokyonight").unwrap().resolve();
+    let json = to_tokens_studio(&[&resolved]);
+    let expected = format!(
+        r#""background": {{ "value": "{}", "type": "color" }}"#,
+        resolved.base.background
+    );
+
+    assert!(json.contains(r#""base": {"#));
+    assert!(json.contains(r#""terminal": {"#));
+    assert!(json.contains(&expected), "missing {expected}");
+}
+
+#[test]
+fn one_theme_per_set() {
+    let dark = load_preset("tokyonight").unwrap().resolve();
+    let light = load_preset("tokyonight_day").unwrap().resolve();
+    let json = to_tokens_studio(&[&dark, &light]);
+
+    assert!(json.contains(r#""selectedTokenSets": { "tokyonight": "enabled" }"#));
+    assert!(json.contains(r#""selectedTokenSets": { "tokyonight_day": "enabled" }"#));
+    assert!(json.contains(r#""tokenSetOrder": ["tokyonight", "tokyonight_day"]"#));
+}
+
+#[test]
+fn palette_without_meta_uses_indexed_name() {
+    let resolved = Palette::default().resolve();
+    let json = to_tokens_studio(&[&resolved]);
+
+    assert!(json.contains(r#"  "palette-0": {"#));
+}
+
+#[cfg(feature = "snapshot")]
+#[test]
+fn output_is_valid_json() {
+    let dark = load_preset("catppuccin").unwrap().resolve();
+    let light = load_preset("catppuccin_latte").unwrap().resolve();
+    let value: serde_json::Value =
+        serde_json::from_str(&to_tokens_studio(&[&dark, &light])).unwrap();
+
+    assert_eq!(
+        value["catppuccin"]["base"]["background"]["value"],
+        dark.base.background.to_string()
+    );
+    assert_eq!(value["$themes"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn exporter_writes_tokens_file() {
+    let palette = load_preset("nord").unwrap();
+    let files = find_exporter("figma").unwrap().export(&palette);
+
+    assert_eq!(files.len(), 1);
+    assert_eq!(&*files[0].path, FILE_NAME);
+}
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use palette_core::export::find_exporter;
+use palette_core::export::flutter::{FILE_NAME, to_dart};
+use palette_core::load_preset;
+
+fn dart_color(c: palette_core::Color) -> String {
+    format!("Color(0xFF{:02X}{:02X}{:02X})", c.r, c.g, c.b)
+}
+
+#[test]
+fn imports_material() {
+    let resolved = load_preset("tokyonight").unwrap().resolve();
+    let dart = to_dart(&resolved);
+
+    assert!(dart.contains("import 'package:flutter/material.dart';"));
+}
+
+#[test]
+fn constants_class_has_every_slot() {
+    let resolved = load_preset("tokyonight").unwrap().resolve();
+    let dart = to_dart(&resolved);
+
+    assert!(dart.contains("abstract final class PaletteColors {"));
+    assert!(dart.contains(&format!(
+        "static const Color baseBackground = {};",
+        dart_color(resolved.base.background)
+    )));
+    assert!(dart.contains("static const Color diffAddedBg = "));
+    assert!(dart.contains("static const Color editorDiagnosticUnderlineHint = "));
+}
+
+#[test]
+fn color_scheme_maps_semantic_roles() {
+    let resolved = load_preset("tokyonight").unwrap().resolve();
+    let dart = to_dart(&resolved);
+
+    assert!(dart.contains(&format!(
+        "  primary: {},",
+        dart_color(resolved.semantic.info)
+    )));
+    assert!(dart.contains(&format!(
+        "  error: {},",
+        dart_color(resolved.semantic.error)
+    )));
+    assert!(dart.contains(&format!(
+        "  surface: {},",
+        dart_color(resolved.base.background)
+    )));
+    assert!(dart.contains(&format!(
+        "  onSurface: {},",
+        dart_color(resolved.base.foreground)
+    )));
+}
+
+#[test]
+fn brightness_follows_background() {
+    let dark = to_dart(&load_preset("tokyonight").unwrap().resolve());
+    let light = to_dart(&load_preset("tokyonight_day").unwrap().resolve());
+
+    assert!(dark.contains("brightness: Brightness.dark,"));
+    assert!(light.contains("brightness: Brightness.light,"));
+}
+
+#[test]
+fn on_colors_are_base_text_colors() {
+    let resolved = load_preset("tokyonight").unwrap().resolve();
+    let dart = to_dart(&resolved);
+    let candidates = [
+        format!("  onPrimary: {},", dart_color(resolved.base.background)),
+        format!("  onPrimary: {},", dart_color(resolved.base.foreground)),
+    ];
+
+    assert!(candidates.iter().any(|c| dart.contains(c.as_str())));
+}
+
+#[test]
+fn line_breaks_in_theme_name_stay_in_the_comment() {
+    let mut resolved = load_preset("nord").unwrap().resolve();
+    if let Some(meta) = resolved.meta.as_mut() {
+        Arc::make_mut(meta).name = Arc::from("Nord\r\nimport 'evil.dart';");
+    }
+    let dart = to_dart(&resolved);
+
+    assert!(dart.starts_with(
+        "// Generated by palette-core from Nord  import 'evil.dart';. Do not edit.\n"
+    ));
+    assert!(!dart.contains("\nimport 'evil.dart';"));
+}
+
+#[test]
+fn exporter_writes_dart_file() {
+    let palette = load_preset("nord").unwrap();
+    let files = find_exporter("flutter").unwrap().export(&palette);
+
+    assert_eq!(files.len(), 1);
+    assert_eq!(&*files[0].path, FILE_NAME);
+    assert!(files[0].contents.contains("ThemeData get paletteThemeData"));
+}
@@ -0,0 +1,445 @@
+use palette_core::color::Color;
+use palette_core::contrast::{ContrastLevel, meets_level, validate_palette};
+use palette_core::cvd::min_distance;
+use palette_core::generate::{
+    Constraints, Style, adapt, ansi_from_palette, from_seed, random, repair_status_colors,
+    status_colors,
+};
+use palette_core::load_preset;
+use palette_core::manipulation::srgb_to_oklch;
+use palette_core::palette::{AnsiColors, Palette};
+
+fn color(hex: &str) -> Color {
+    Color::from_hex(hex).unwrap()
+}
+
+const SEEDS: &[&str] = &[
+    "#7C3AED", "#E11D48", "#16A34A", "#F59E0B", "#0EA5E9", "#808080", "#000000", "#FFFFFF",
+    "#FFFF00",
+];
+
+fn hue_distance(a: f64, b: f64) -> f64 {
+    let d = (a - b).rem_euclid(360.0);
+    d.min(360.0 - d)
+}
+
+// --- Contrast ---
+
+#[test]
+fn every_seed_passes_aa_in_both_styles() {
+    for hex in SEEDS {
+        for style in [Style::Dark, Style::Light] {
+            let palette = from_seed(color(hex), style);
+            let violations = validate_palette(&palette, ContrastLevel::AaNormal);
+            assert!(
+                violations.is_empty(),
+                "{hex} {style:?}: {:?}",
+                violations
+                    .iter()
+                    .map(|v| (&v.foreground_label, v.ratio))
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+}
+
+// --- Style ---
+
+#[test]
+fn background_lightness_follows_style() {
+    for hex in SEEDS {
+        let dark = from_seed(color(hex), Style::Dark);
+        let light = from_seed(color(hex), Style::Light);
+        assert!(!dark.base.background.unwrap().is_light(), "{hex}");
+        assert!(light.base.background.unwrap().is_light(), "{hex}");
+    }
+}
+
+#[test]
+fn meta_records_seed_and_style() {
+    let palette = from_seed(color("#7c3aed"), Style::Light);
+    let meta = palette.meta.unwrap();
+    assert_eq!(&*meta.name, "Seed #7C3AED");
+    assert_eq!(&*meta.preset_id, "seed_7c3aed");
+    assert_eq!(&*meta.style, "light");
+}
+
+// --- Accent ---
+
+#[test]
+fn readable_accent_is_kept_verbatim() {
+    let accent = color("#A78BFA");
+    let palette = from_seed(accent, Style::Dark);
+    assert_eq!(palette.editor.cursor, Some(accent));
+    assert_eq!(palette.syntax.keywords, Some(accent));
+    assert_eq!(palette.typography.link, Some(accent));
+}
+
+#[test]
+fn unreadable_accent_is_nudged_for_text_only() {
+    let accent = color("#1E1B4B");
+    let palette = from_seed(accent, Style::Dark);
+    assert_eq!(palette.editor.cursor, Some(accent));
+    assert_ne!(palette.syntax.keywords, Some(accent));
+}
+
+#[test]
+fn background_is_tinted_toward_accent_hue() {
+    let accent = color("#0EA5E9");
+    let bg = from_seed(accent, Style::Dark).base.background.unwrap();
+    let distance = hue_distance(srgb_to_oklch(bg).h, srgb_to_oklch(accent).h);
+    assert!(distance < 30.0, "background hue off by {distance}");
+}
+
+// --- Coverage ---
+
+#[test]
+fn every_slot_is_populated() {
+    let palette = from_seed(color("#E11D48"), Style::Dark);
+    assert_eq!(palette.base.populated_slots().count(), 7);
+    assert_eq!(palette.semantic.populated_slots().count(), 5);
+    assert_eq!(palette.diff.populated_slots().count(), 11);
+    assert_eq!(palette.surface.populated_slots().count(), 10);
+    assert_eq!(palette.typography.populated_slots().count(), 6);
+    assert_eq!(palette.syntax.populated_slots().count(), 38);
+    assert_eq!(palette.editor.populated_slots().count(), 17);
+    assert_eq!(palette.terminal.populated_slots().count(), 16);
+}
+
+#[test]
+fn semantic_hues_are_fixed_regardless_of_accent() {
+    for hex in ["#7C3AED", "#16A34A"] {
+        let palette = from_seed(color(hex), Style::Dark);
+        let error = srgb_to_oklch(palette.semantic.error.unwrap()).h;
+        let success = srgb_to_oklch(palette.semantic.success.unwrap()).h;
+        assert!(hue_distance(error, 25.0) < 20.0, "{hex}: error hue {error}");
+        assert!(
+            hue_distance(success, 145.0) < 20.0,
+            "{hex}: success hue {success}"
+        );
+    }
+}
+
+#[test]
+fn generation_is_deterministic() {
+    let a = from_seed(color("#F59E0B"), Style::Light);
+    let b = from_seed(color("#F59E0B"), Style::Light);
+    assert_eq!(a, b);
+}
+
+// --- Random ---
+
+#[test]
+fn random_is_reproducible_per_seed() {
+    let constraints = Constraints::default();
+    assert_eq!(random(42, &constraints), random(42, &constraints));
+    assert_ne!(random(42, &constraints), random(43, &constraints));
+}
+
+#[test]
+fn random_meets_requested_contrast() {
+    for level in [ContrastLevel::AaNormal, ContrastLevel::AaaNormal] {
+        let constraints = Constraints {
+            contrast: level,
+            ..Constraints::default()
+        };
+        for seed in 0..200 {
+            let palette = random(seed, &constraints);
+            let violations = validate_palette(&palette, level);
+            assert!(
+                violations.is_empty(),
+                "seed {seed} {level:?}: {violations:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn random_accent_respects_bounds() {
+    let constraints = Constraints {
+        chroma: 0.05..=0.10,
+        lightness: 0.60..=0.70,
+        ..Constraints::default()
+    };
+    for seed in 0..200 {
+        let accent = srgb_to_oklch(random(seed, &constraints).editor.cursor.unwrap());
+        assert!(accent.c <= 0.11, "seed {seed}: chroma {}", accent.c);
+        assert!(
+            (0.59..=0.71).contains(&accent.l),
+            "seed {seed}: lightness {}",
+            accent.l
+        );
+    }
+}
+
+#[test]
+fn random_honors_fixed_style() {
+    let constraints = Constraints {
+        style: Some(Style::Light),
+        ..Constraints::default()
+    };
+    for seed in 0..50 {
+        let palette = random(seed, &constraints);
+        assert!(palette.base.background.unwrap().is_light(), "seed {seed}");
+        assert_eq!(&*palette.meta.unwrap().style, "light");
+    }
+}
+
+#[test]
+fn random_picks_both_styles_when_unconstrained() {
+    let light = (0..50)
+        .filter(|&seed| {
+            random(seed, &Constraints::default())
+                .base
+                .background
+                .unwrap()
+                .is_light()
+        })
+        .count();
+    assert!(light > 0 && light < 50, "{light} of 50 light");
+}
+
+#[test]
+fn random_syntax_hues_are_spread() {
+    let constraints = Constraints {
+        chroma: 0.12..=0.14,
+        hue_spread: 40.0,
+        ..Constraints::default()
+    };
+    for seed in 0..50 {
+        let palette = random(seed, &constraints);
+        let syntax = &palette.syntax;
+        let hues: Vec<f64> = [
+            syntax.keywords,
+            syntax.functions,
+            syntax.types,
+            syntax.strings,
+            syntax.constants,
+            syntax.punctuation_special,
+            syntax.tag,
+        ]
+        .iter()
+        .map(|c| srgb_to_oklch(c.unwrap()).h)
+        .collect();
+        for (i, a) in hues.iter().enumerate() {
+            for b in &hues[i + 1..] {
+                // Allow for hue drift from gamut mapping and contrast nudging.
+                assert!(hue_distance(*a, *b) > 25.0, "seed {seed}: {hues:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn random_meta_names_the_seed() {
+    let meta = random(7, &Constraints::default()).meta.unwrap();
+    assert_eq!(&*meta.name, "Random 7");
+    assert_eq!(&*meta.preset_id, "random_7");
+}
+
+// --- ANSI derivation ---
+
+fn without_terminal(id: &str) -> Palette {
+    let mut palette = load_preset(id).unwrap();
+    palette.terminal = AnsiColors::default();
+    palette
+}
+
+#[test]
+fn ansi_fills_every_slot_from_a_full_palette() {
+    for id in ["tokyonight", "github_light"] {
+        let ansi = ansi_from_palette(&without_terminal(id));
+        assert_eq!(ansi.populated_slots().count(), 16, "{id}");
+    }
+}
+
+#[test]
+fn ansi_borrows_semantic_hues() {
+    let palette = without_terminal("tokyonight");
+    let ansi = ansi_from_palette(&palette);
+    assert_eq!(ansi.red, palette.semantic.error);
+    assert_eq!(ansi.green, palette.semantic.success);
+    assert_eq!(ansi.yellow, palette.semantic.warning);
+    assert_eq!(ansi.blue, palette.semantic.info);
+    assert_eq!(ansi.magenta, palette.syntax.keywords);
+    assert_eq!(ansi.cyan, palette.syntax.types);
+    assert_eq!(ansi.bright_black, palette.typography.comment);
+}
+
+#[test]
+fn ansi_keeps_existing_slots() {
+    let mut palette = without_terminal("tokyonight");
+    let red = color("#FF0000");
+    palette.terminal.red = Some(red);
+    let ansi = ansi_from_palette(&palette);
+    assert_eq!(ansi.red, Some(red));
+    assert_ne!(ansi.bright_red, Some(red));
+
+    let full = load_preset("tokyonight").unwrap();
+    assert_eq!(ansi_from_palette(&full), full.terminal);
+}
+
+#[test]
+fn ansi_black_stays_dark_on_light_themes() {
+    for id in ["tokyonight", "github_light"] {
+        let ansi = ansi_from_palette(&without_terminal(id));
+        let black = ansi.black.unwrap().relative_luminance();
+        let white = ansi.white.unwrap().relative_luminance();
+        assert!(black < white, "{id}: black {black} white {white}");
+    }
+}
+
+#[test]
+fn ansi_bright_variants_shift_away_from_background() {
+    let dark = ansi_from_palette(&without_terminal("tokyonight"));
+    let light = ansi_from_palette(&without_terminal("github_light"));
+    let lum = |c: Option<Color>| c.unwrap().relative_luminance();
+    assert!(lum(dark.bright_red) > lum(dark.red));
+    assert!(lum(light.bright_red) < lum(light.red));
+}
+
+#[test]
+fn ansi_leaves_slots_without_sources_empty() {
+    let ansi = ansi_from_palette(&Palette {
+        syntax: Default::default(),
+        semantic: Default::default(),
+        diff: Default::default(),
+        typography: Default::default(),
+        terminal: AnsiColors::default(),
+        ..Palette::default()
+    });
+    assert_eq!(ansi.red, None);
+    assert_eq!(ansi.bright_red, None);
+    assert!(ansi.black.is_some());
+}
+
+// --- Adapt ---
+
+#[test]
+fn adapt_keeps_backgrounds_and_untouched_groups() {
+    let base = load_preset("tokyonight").unwrap();
+    let adapted = adapt(&base, color("#E11D48"));
+    assert_eq!(adapted.base.background, base.base.background);
+    assert_eq!(adapted.base.foreground, base.base.foreground);
+    assert_eq!(adapted.semantic, base.semantic);
+    assert_eq!(adapted.terminal, base.terminal);
+    assert_eq!(adapted.syntax.strings, base.syntax.strings);
+}
+
+#[test]
+fn adapt_moves_keywords_to_accent_hue() {
+    let accent = color("#E11D48");
+    for id in ["tokyonight", "github_light", "catppuccin"] {
+        let base = load_preset(id).unwrap();
+        let adapted = adapt(&base, accent);
+        let keywords = srgb_to_oklch(adapted.syntax.keywords.unwrap());
+        let distance = hue_distance(keywords.h, srgb_to_oklch(accent).h);
+        assert!(distance < 15.0, "{id}: keyword hue off by {distance}");
+    }
+}
+
+#[test]
+fn adapt_preserves_aa_conformance() {
+    let labels = |palette: &Palette| -> Vec<Box<str>> {
+        validate_palette(palette, ContrastLevel::AaNormal)
+            .iter()
+            .map(|v| format!("{} on {}", v.foreground_label, v.background_label).into())
+            .collect()
+    };
+    for id in [
+        "tokyonight",
+        "github_light",
+        "catppuccin",
+        "gruvbox_dark",
+        "solarized_light",
+    ] {
+        let base = load_preset(id).unwrap();
+        let before = labels(&base);
+        for hex in SEEDS {
+            for pair in labels(&adapt(&base, color(hex))) {
+                assert!(before.contains(&pair), "{id} {hex}: new violation {pair}");
+            }
+        }
+    }
+}
+
+#[test]
+fn adapt_renames_meta() {
+    let base = load_preset("tokyonight").unwrap();
+    let meta = adapt(&base, color("#7c3aed")).meta.unwrap();
+    assert_eq!(&*meta.preset_id, "tokyonight_7c3aed");
+    assert!(meta.name.ends_with(" #7C3AED"), "{}", meta.name);
+    assert_eq!(meta.style, base.meta.unwrap().style);
+}
+
+#[test]
+fn adapt_without_accent_slots_is_identity() {
+    let mut base = Palette::default();
+    base.syntax = Default::default();
+    base.typography.link = None;
+    base.editor.cursor = None;
+    assert_eq!(adapt(&base, color("#E11D48")), base);
+}
+
+// --- CVD-distinct status colors ---
+
+#[test]
+fn status_colors_are_readable_and_distinct() {
+    for hex in ["#1A1B26", "#FFFFFF", "#FDF6E3", "#282828"] {
+        let bg = color(hex);
+        let s = status_colors(bg);
+        let all = [s.success, s.warning, s.error, s.info];
+        for c in all {
+            assert!(meets_level(&c, &bg, ContrastLevel::AaNormal), "{hex}: {c}");
+        }
+        let spread = min_distance(&all);
+        assert!(spread > 0.06, "{hex}: min distance {spread}");
+    }
+}
+
+#[test]
+fn status_colors_keep_role_hues() {
+    let s = status_colors(color("#1A1B26"));
+    let hue = |c: Color| srgb_to_oklch(c).h;
+    assert!(
+        hue_distance(hue(s.error), 30.0) < 30.0,
+        "error {}",
+        hue(s.error)
+    );
+    assert!(
+        hue_distance(hue(s.info), 255.0) < 45.0,
+        "info {}",
+        hue(s.info)
+    );
+}
+
+#[test]
+fn repair_beats_typical_red_green_semantics() {
+    let palette = load_preset("gruvbox_dark").unwrap();
+    let semantics = |p: &Palette| {
+        let s = &p.semantic;
+        [s.success, s.warning, s.error, s.info].map(Option::unwrap)
+    };
+    let repaired = repair_status_colors(&palette);
+    assert!(min_distance(&semantics(&repaired)) > min_distance(&semantics(&palette)));
+    assert_eq!(repaired.diff.added, repaired.semantic.success);
+    assert_eq!(repaired.editor.diagnostic_error, repaired.semantic.error);
+    assert_eq!(repaired.base, palette.base);
+    assert_eq!(repaired.syntax, palette.syntax);
+}
+
+#[test]
+fn repair_keeps_diff_foregrounds_readable() {
+    for id in ["tokyonight", "github_light"] {
+        let repaired = repair_status_colors(&load_preset(id).unwrap());
+        let diff = &repaired.diff;
+        for (fg, bg) in [
+            (diff.added_fg, diff.added_bg),
+            (diff.removed_fg, diff.removed_bg),
+        ] {
+            if let (Some(fg), Some(bg)) = (fg, bg) {
+                assert!(meets_level(&fg, &bg, ContrastLevel::AaNormal), "{id}");
+            }
+        }
+    }
+}
@@ -41,6 +41,7 @@ fn two_stop_gradient_midpoint() {
         r: 128,
         g: 128,
         b: 128,
+        a: 255,
     };
     // OkLab perceptual midpoint differs from sRGB linear midpoint
     assert_ne!(
@@ -303,13 +304,22 @@ stops = ["base.background", "base.foreground"]
         .expect("gradient 'fade' should exist");
     let mid = gradient.at(0.5);
     // Midpoint should differ from both endpoints
-    assert_ne!(mid, Color { r: 0, g: 0, b: 0 });
+    assert_ne!(
+        mid,
+        Color {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 255
+        }
+    );
     assert_ne!(
         mid,
         Color {
             r: 255,
             g: 255,
             b: 255,
+            a: 255,
         }
     );
     // Should be a valid non-black color (some luminance)
@@ -382,3 +392,94 @@ fn gradient_to_css_evenly_spaced_omits_positions() {
         "evenly spaced should omit positions: {css}"
     );
 }
+
+#[test]
+fn alpha_interpolates_between_stops() {
+    let g = Gradient::new(
+        vec![stop("#FF000000", 0.0), stop("#FF0000FF", 1.0)],
+        ColorSpace::OkLab,
+    )
+    .unwrap();
+    assert_eq!(g.at(0.5).a, 128);
+}
+
+#[test]
+fn srgb_and_hsl_spaces_from_toml() {
+    let toml = r##"
+[base]
+background = "#FF0000"
+foreground = "#0000FF"
+
+[gradient.flat]
+stops = ["base.background", "base.foreground"]
+space = "srgb"
+
+[gradient.wheel]
+stops = ["base.background", "base.foreground"]
+space = "hsl"
+"##;
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    let resolved = Palette::from_manifest(&manifest).unwrap().resolve();
+
+    let flat = resolved.gradient("flat").unwrap();
+    assert_eq!(flat.space(), ColorSpace::Srgb);
+    assert_eq!(flat.at(0.5), color("#800080"));
+    assert!(flat.to_css().starts_with("linear-gradient(in srgb,"));
+
+    // Red → blue the short way round the wheel passes through magenta.
+    let wheel = resolved.gradient("wheel").unwrap();
+    assert_eq!(wheel.at(0.5), color("#FF00FF"));
+}
+
+#[test]
+fn color_space_names_round_trip() {
+    for space in [
+        ColorSpace::OkLab,
+        ColorSpace::OkLch,
+        ColorSpace::Srgb,
+        ColorSpace::Hsl,
+    ] {
+        assert_eq!(ColorSpace::from_name(space.as_str()), Some(space));
+    }
+    assert_eq!(ColorSpace::from_name("lab"), None);
+}
+
+#[test]
+fn color_lerp_endpoints_are_exact() {
+    let a = color("#7AA2F7");
+    let b = color("#F7768E");
+    for space in [
+        ColorSpace::OkLab,
+        ColorSpace::OkLch,
+        ColorSpace::Srgb,
+        ColorSpace::Hsl,
+    ] {
+        assert_eq!(a.lerp(&b, 0.0, space), a);
+        assert_eq!(a.lerp(&b, 1.0, space), b);
+        assert_eq!(a.lerp(&b, -3.0, space), a);
+        assert_eq!(a.lerp(&b, f64::NAN, space), a);
+    }
+}
+
+#[test]
+fn color_lerp_hsl_gray_endpoint_keeps_hue() {
+    let red = color("#FF0000");
+    let gray = color("#808080");
+    let mid = red.lerp(&gray, 0.5, ColorSpace::Hsl);
+    assert!(
+        mid.r > mid.g && mid.g == mid.b,
+        "expected a muted red, got {mid}"
+    );
+}
+
+#[test]
+fn color_lerp_matches_gradient() {
+    let a = color("#2563EB");
+    let b = color("#F59E0B");
+    let g = Gradient::new(
+        vec![stop("#2563EB", 0.0), stop("#F59E0B", 1.0)],
+        ColorSpace::OkLch,
+    )
+    .unwrap();
+    assert_eq!(a.lerp(&b, 0.3, ColorSpace::OkLch), g.at(0.3));
+}
@@ -0,0 +1,212 @@
+use palette_core::diagnostic::Diagnostic;
+use palette_core::group::{self, ColorGroup};
+use palette_core::manifest::{PaletteManifest, validate_fields};
+use palette_core::merge::merge_manifests;
+use palette_core::slot::Section;
+use palette_core::{Color, Palette, PaletteError, SlotId};
+
+palette_core::custom_color_group! {
+    /// Data-visualization colors.
+    pub struct ChartColors("chart") {
+        series_1,
+        series_2,
+        grid,
+    }
+}
+
+palette_core::custom_color_group! {
+    struct ChartClash("chart") { series_1 }
+}
+
+palette_core::custom_color_group! {
+    struct BaseClash("base") { background }
+}
+
+// Registration is process-wide and tests run in parallel, so every test
+// registers the group it needs.
+fn register() {
+    group::register::<ChartColors>().unwrap();
+}
+
+const THEME: &str = r##"
+[meta]
+name = "Charts"
+preset_id = "charts"
+schema_version = "1"
+style = "dark"
+kind = "preset-base"
+
+[base]
+background = "#1A1B26"
+
+[chart]
+series_1 = "#7AA2F7"
+grid = "#292E42"
+"##;
+
+fn hex(s: &str) -> Color {
+    Color::from_hex(s).unwrap()
+}
+
+fn chart_palette() -> Palette {
+    register();
+    Palette::from_manifest(&PaletteManifest::from_toml(THEME).unwrap()).unwrap()
+}
+
+#[test]
+fn registered_group_is_parsed_and_typed() {
+    let chart: ChartColors = chart_palette().group();
+    assert_eq!(chart.series_1, Some(hex("#7AA2F7")));
+    assert_eq!(chart.series_2, None);
+    assert_eq!(chart.grid, Some(hex("#292E42")));
+}
+
+#[test]
+fn kebab_case_group_keys_are_read_as_snake_case() {
+    register();
+    let toml = THEME.replace("series_1", "series-1");
+    let manifest = PaletteManifest::from_toml(&toml).unwrap();
+    let chart: ChartColors = Palette::from_manifest(&manifest).unwrap().group();
+    assert_eq!(chart.series_1, Some(hex("#7AA2F7")));
+}
+
+#[test]
+fn group_slots_can_be_set_by_path() {
+    register();
+    let mut palette = Palette::default();
+    palette
+        .set_path("chart.series_2", Some(hex("#FF0000")))
+        .unwrap();
+    let chart: ChartColors = palette.group();
+    assert_eq!(chart.series_2, Some(hex("#FF0000")));
+    assert_eq!(chart.series_1, None);
+
+    let mut palette = chart_palette();
+    palette.set_path("chart.grid", None).unwrap();
+    assert_eq!(palette.get_path("chart.grid").unwrap(), None);
+    assert_eq!(
+        palette.get_path("chart.series_1").unwrap(),
+        Some(hex("#7AA2F7"))
+    );
+}
+
+#[test]
+fn registered_group_is_not_an_extension() {
+    assert!(chart_palette().extension("chart").is_none());
+}
+
+#[test]
+fn missing_group_reads_as_empty() {
+    register();
+    let palette = palette_core::load_preset("nord").unwrap();
+    assert!(palette.groups.is_empty());
+    assert_eq!(palette.group::<ChartColors>(), ChartColors::default());
+}
+
+#[test]
+fn reregistering_is_a_noop_and_conflicts_are_rejected() {
+    register();
+    register();
+    assert!(matches!(
+        group::register::<ChartClash>(),
+        Err(PaletteError::GroupConflict { section }) if section.as_ref() == "chart"
+    ));
+    let err = group::register::<BaseClash>().unwrap_err();
+    assert_eq!(err.code(), "PC0016");
+}
+
+#[test]
+fn invalid_hex_names_the_group() {
+    register();
+    let toml = "[base]\nbackground = \"#000000\"\n\n[chart]\ngrid = \"#12\"\n";
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    let err = Palette::from_manifest(&manifest).unwrap_err();
+    assert!(matches!(
+        err,
+        PaletteError::InvalidHex { ref section, ref field, .. }
+            if section.as_ref() == "chart" && field.as_ref() == "grid"
+    ));
+}
+
+#[test]
+fn variant_inherits_group_slots() {
+    register();
+    let base = PaletteManifest::from_toml(THEME).unwrap();
+    let variant_toml = "[base]\nbackground = \"#000000\"\n\n[chart]\nseries_2 = \"#9ECE6A\"\n";
+    let variant = PaletteManifest::from_toml(variant_toml).unwrap();
+    let merged = merge_manifests(&variant, &base);
+    let chart: ChartColors = Palette::from_manifest(&merged).unwrap().group();
+    assert_eq!(chart.series_1, Some(hex("#7AA2F7")));
+    assert_eq!(chart.series_2, Some(hex("#9ECE6A")));
+}
+
+#[test]
+fn slot_ids_cover_group_slots() {
+    let palette = chart_palette();
+    let slot: SlotId = "chart.series_1".parse().unwrap();
+    assert_eq!(slot.section(), Section::Custom("chart"));
+    assert_eq!(palette.get(slot), Some(hex("#7AA2F7")));
+    let all: Vec<String> = SlotId::all().map(|s| s.to_string()).collect();
+    assert!(all.contains(&"chart.grid".to_owned()));
+    assert!("chart.nope".parse::<SlotId>().is_err());
+}
+
+#[test]
+fn css_uses_section_prefixed_names() {
+    let css = chart_palette().to_css();
+    assert!(css.contains("  --chart-series-1: #7AA2F7;\n"), "{css}");
+    assert!(css.contains("  --chart-grid: #292E42;\n"), "{css}");
+}
+
+#[test]
+fn to_toml_round_trips_group_sections() {
+    register();
+    let manifest = PaletteManifest::from_toml(THEME).unwrap();
+    let toml = manifest.to_toml();
+    assert!(toml.contains("\n[chart]\nseries_1 = \"#7AA2F7\"\ngrid = \"#292E42\"\n"));
+    let reparsed = PaletteManifest::from_toml(&toml).unwrap();
+    assert_eq!(reparsed.groups, manifest.groups);
+}
+
+#[test]
+fn map_colors_reaches_group_slots() {
+    let inverted = chart_palette().map_colors(|c| Color {
+        r: 255 - c.r,
+        g: 255 - c.g,
+        b: 255 - c.b,
+        a: c.a,
+    });
+    let chart: ChartColors = inverted.group();
+    assert_eq!(chart.series_1, Some(hex("#855D08")));
+}
+
+#[test]
+fn lint_checks_group_fields() {
+    register();
+    let toml = "[base]\nbackground = \"#000000\"\n\n[chart]\nseries_3 = \"#FFFFFF\"\n";
+    let (manifest, warnings) = PaletteManifest::from_toml_with_warnings(toml).unwrap();
+    assert!(warnings.iter().all(|w| w.code != "PC0102"), "{warnings:?}");
+    let unknown = validate_fields(&manifest);
+    assert_eq!(unknown.len(), 1);
+    let diagnostic = Diagnostic::from(&unknown[0]);
+    assert_eq!(diagnostic.section.as_deref(), Some("chart"));
+    assert_eq!(
+        diagnostic.suggestion.as_deref(),
+        Some("did you mean `series_1`?")
+    );
+}
+
+#[test]
+fn trait_exposes_section_and_fields() {
+    assert_eq!(ChartColors::SECTION, "chart");
+    assert_eq!(ChartColors::FIELDS, ["series_1", "series_2", "grid"]);
+}
+
+#[cfg(feature = "snapshot")]
+#[test]
+fn json_places_groups_beside_builtin_sections() {
+    let json = chart_palette().to_json_value().unwrap();
+    assert_eq!(json["chart"]["series_1"], "#7AA2F7");
+    assert_eq!(json["chart"]["series_2"], serde_json::Value::Null);
+    assert!(json["base"].is_object());
+}
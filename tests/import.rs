@@ -0,0 +1,147 @@
+#![cfg(feature = "import")]
+
+use std::io::Write;
+
+use palette_core::Registry;
+use palette_core::error::PaletteError;
+use palette_core::import::{
+    detect_and_import, detect_format, find_importer, import_path, importers,
+};
+
+const VSCODE: &str =
+    r##"{ "name": "Code Test", "colors": { "editor.background": "#1e1e1e" }, "tokenColors": [] }"##;
+const WT: &str = r##"{ "name": "Term Test", "background": "#0c0c0c", "brightPurple": "#b4009e" }"##;
+const PYWAL: &str =
+    r##"{ "special": { "background": "#101010" }, "colors": { "color0": "#101010" } }"##;
+const GOGH: &str = "name: 'Gogh Test'\ncolor_01: '#000000'\nbackground: '#111111'\n";
+const ALACRITTY: &str = "[colors.primary]\nbackground = \"#1d1f21\"\n";
+const TMTHEME: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<plist version="1.0"><dict><key>settings</key><array>
+<dict><key>settings</key><dict><key>background</key><string>#272822</string></dict></dict>
+</array></dict></plist>"##;
+
+#[test]
+fn every_importer_is_findable_by_format() {
+    for importer in importers() {
+        let found = find_importer(importer.format()).unwrap();
+        assert_eq!(found.format(), importer.format());
+    }
+    assert!(find_importer("nonexistent").is_none());
+}
+
+#[test]
+fn detects_each_format_from_contents() {
+    let cases = [
+        (VSCODE, "vscode"),
+        (WT, "windows-terminal"),
+        (PYWAL, "pywal"),
+        (GOGH, "gogh"),
+        (ALACRITTY, "alacritty"),
+        (TMTHEME, "tmTheme"),
+    ];
+    for (contents, format) in cases {
+        let importer = detect_format(contents, None).unwrap();
+        assert_eq!(importer.format(), format, "for {contents}");
+    }
+}
+
+#[test]
+fn palette_core_colors_table_is_not_alacritty() {
+    let theme = "[colors]\nnight = \"#1a1b26\"\n\n[base]\nbackground = \"$night\"\n";
+
+    assert!(detect_format(theme, Some("toml")).is_none());
+}
+
+#[test]
+fn unfamiliar_extension_falls_back_to_contents() {
+    assert_eq!(
+        detect_format(VSCODE, Some("txt")).unwrap().format(),
+        "vscode"
+    );
+    assert_eq!(
+        detect_format(ALACRITTY, Some("TOML")).unwrap().format(),
+        "alacritty"
+    );
+}
+
+#[test]
+fn detect_and_import_bytes() {
+    let manifests = detect_and_import(WT.as_bytes(), Some("json")).unwrap();
+
+    assert_eq!(manifests.len(), 1);
+    assert_eq!(
+        manifests[0].meta.as_ref().unwrap().preset_id.as_ref(),
+        "term_test"
+    );
+}
+
+#[test]
+fn detect_and_import_skips_bom() {
+    let bytes = [b"\xEF\xBB\xBF".as_slice(), VSCODE.as_bytes()].concat();
+
+    assert!(detect_and_import(&bytes, None).is_ok());
+}
+
+#[test]
+fn unknown_format_is_import_error() {
+    let err = detect_and_import(b"just some text", None).unwrap_err();
+
+    assert!(matches!(err, PaletteError::Import { .. }));
+}
+
+#[test]
+fn import_path_names_nameless_themes_after_file_stem() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("tomorrow night.toml");
+    std::fs::write(&path, ALACRITTY).unwrap();
+
+    let manifests = import_path(&path).unwrap();
+    let meta = manifests[0].meta.as_ref().unwrap();
+    assert_eq!(meta.name.as_ref(), "tomorrow night");
+    assert_eq!(meta.preset_id.as_ref(), "tomorrow_night");
+}
+
+#[test]
+fn import_path_keeps_embedded_names() {
+    let mut file = tempfile::Builder::new().suffix(".json").tempfile().unwrap();
+    file.write_all(VSCODE.as_bytes()).unwrap();
+
+    let manifests = import_path(file.path()).unwrap();
+    assert_eq!(
+        manifests[0].meta.as_ref().unwrap().name.as_ref(),
+        "Code Test"
+    );
+}
+
+#[test]
+fn registry_import_file_registers_every_scheme() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("fragment.json");
+    std::fs::write(
+        &path,
+        r##"{ "schemes": [
+            { "name": "Alpha", "background": "#000000" },
+            { "name": "Beta", "background": "#ffffff" }
+        ] }"##,
+    )
+    .unwrap();
+
+    let mut reg = Registry::new();
+    let ids = reg.import_file(&path).unwrap();
+
+    assert_eq!(
+        ids.iter().map(|id| id.as_ref()).collect::<Vec<_>>(),
+        ["alpha", "beta"]
+    );
+    assert!(reg.load("beta").unwrap().resolve().is_light());
+}
+
+#[test]
+fn registry_import_file_missing_is_io_error() {
+    let mut reg = Registry::new();
+    let err = reg
+        .import_file(std::path::Path::new("/nonexistent/theme.json"))
+        .unwrap_err();
+
+    assert!(matches!(err, PaletteError::Io { .. }));
+}
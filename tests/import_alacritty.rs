@@ -0,0 +1,152 @@
+#![cfg(feature = "import")]
+
+use palette_core::error::PaletteError;
+use palette_core::import::alacritty::{Alacritty, DEFAULT_NAME, from_toml, from_yaml};
+use palette_core::import::{Importer, set_name};
+
+mod common;
+
+use common::hex;
+
+const TOML: &str = r##"
+[colors.primary]
+background = "#1e1e2e"
+foreground = "#cdd6f4"
+dim_foreground = "#7f849c"
+
+[colors.cursor]
+text = "#1e1e2e"
+cursor = "#f5e0dc"
+
+[colors.selection]
+text = "CellForeground"
+background = "#585b70"
+
+[colors.normal]
+black = "#45475a"
+red = "#f38ba8"
+green = "#a6e3a1"
+yellow = "#f9e2af"
+blue = "#89b4fa"
+magenta = "#f5c2e7"
+cyan = "#94e2d5"
+white = "#bac2de"
+
+[colors.bright]
+black = "#585b70"
+red = "#f38ba8"
+green = "#a6e3a1"
+yellow = "#f9e2af"
+blue = "#89b4fa"
+magenta = "#f5c2e7"
+cyan = "#94e2d5"
+white = "#a6adc8"
+"##;
+
+const YAML: &str = r#"
+colors:
+  primary:
+    background: '0xfbf1c7'
+    foreground: '0x3c3836'
+  normal:
+    black: 0xfbf1c7
+    red: '0xcc241d'
+  bright:
+    black: '0x928374'
+"#;
+
+#[test]
+fn toml_maps_primary_and_cursor() {
+    let manifest = from_toml(TOML).unwrap();
+
+    assert_eq!(hex(&manifest.base, "background"), "#1E1E2E");
+    assert_eq!(hex(&manifest.base, "foreground"), "#CDD6F4");
+    assert_eq!(hex(&manifest.base, "foreground_dark"), "#7F849C");
+    assert_eq!(hex(&manifest.editor, "cursor"), "#F5E0DC");
+    assert_eq!(hex(&manifest.editor, "selection_bg"), "#585B70");
+}
+
+#[test]
+fn cell_color_keywords_are_skipped() {
+    let manifest = from_toml(TOML).unwrap();
+
+    assert!(!manifest.editor.contains_key("selection_fg"));
+}
+
+#[test]
+fn normal_and_bright_fill_terminal() {
+    let manifest = from_toml(TOML).unwrap();
+
+    assert_eq!(hex(&manifest.terminal, "red"), "#F38BA8");
+    assert_eq!(hex(&manifest.terminal, "bright_black"), "#585B70");
+    assert_eq!(hex(&manifest.terminal, "bright_white"), "#A6ADC8");
+    assert_eq!(manifest.terminal.len(), 16);
+}
+
+#[test]
+fn semantic_colors_derive_from_ansi() {
+    let manifest = from_toml(TOML).unwrap();
+
+    assert_eq!(hex(&manifest.semantic, "error"), "#F38BA8");
+    assert_eq!(hex(&manifest.semantic, "success"), "#A6E3A1");
+    assert_eq!(hex(&manifest.typography, "comment"), "#585B70");
+    // Explicit dim_foreground wins over the ANSI-derived value.
+    assert_eq!(hex(&manifest.base, "foreground_dark"), "#7F849C");
+}
+
+#[test]
+fn yaml_accepts_0x_strings_and_integers() {
+    let manifest = from_yaml(YAML).unwrap();
+
+    assert_eq!(hex(&manifest.base, "background"), "#FBF1C7");
+    assert_eq!(hex(&manifest.terminal, "black"), "#FBF1C7");
+    assert_eq!(hex(&manifest.terminal, "red"), "#CC241D");
+    assert_eq!(manifest.meta.as_ref().unwrap().style.as_ref(), "light");
+}
+
+#[test]
+fn default_meta_can_be_renamed() {
+    let mut manifest = from_toml(TOML).unwrap();
+    assert_eq!(manifest.meta.as_ref().unwrap().name.as_ref(), DEFAULT_NAME);
+
+    set_name(&mut manifest, "Catppuccin Mocha");
+    let meta = manifest.meta.as_ref().unwrap();
+    assert_eq!(meta.preset_id.as_ref(), "catppuccin_mocha");
+    assert_eq!(meta.style.as_ref(), "dark");
+}
+
+#[test]
+fn imported_manifest_builds_palette() {
+    common::assert_builds_palette(&from_toml(TOML).unwrap());
+}
+
+#[test]
+fn malformed_toml_is_import_error() {
+    let err = from_toml("[colors.primary\n").unwrap_err();
+
+    assert!(matches!(err, PaletteError::Import { .. }));
+}
+
+#[test]
+fn file_without_alacritty_colors_is_import_error() {
+    let err = from_toml("[window]\nopacity = 0.9\n").unwrap_err();
+
+    assert!(matches!(err, PaletteError::Import { .. }));
+    assert!(err.to_string().contains("no Alacritty colors found"));
+}
+
+#[test]
+fn malformed_toml_reports_the_toml_error() {
+    let err = Alacritty
+        .import("[colors.primary]\nbackground = \"#1d1f21\n")
+        .unwrap_err();
+
+    assert!(err.to_string().contains("TOML parse error"), "{err}");
+}
+
+#[test]
+fn yaml_is_imported_through_the_importer() {
+    let manifests = Alacritty.import(YAML).unwrap();
+
+    assert_eq!(hex(&manifests[0].base, "background"), "#FBF1C7");
+}
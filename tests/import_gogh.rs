@@ -0,0 +1,103 @@
+#![cfg(feature = "import")]
+
+use palette_core::Registry;
+use palette_core::error::PaletteError;
+use palette_core::import::gogh::{from_json, from_yaml};
+
+mod common;
+
+use common::hex;
+
+const YAML: &str = r#"---
+name: 'Dracula Test'
+author: ''
+variant: 'dark'
+
+color_01: '#262626'    # Black (Host)
+color_02: '#E64747'    # Red (Syntax string)
+color_03: '#42E66C'    # Green (Command)
+color_04: '#E4F34A'    # Yellow (Command second)
+color_05: '#9B6BDF'    # Blue (Path)
+color_06: '#E356A7'    # Magenta (Syntax var)
+color_07: '#75D7EC'    # Cyan (Prompt)
+color_08: '#EFA554'    # White
+
+color_09: '#7A7A7A'    # Bright Black
+color_10: '#FF5555'    # Bright Red (Command error)
+color_11: '#50FA7B'    # Bright Green (Exec)
+color_12: '#F1FA8C'    # Bright Yellow
+color_13: '#BD93F9'    # Bright Blue (Folder)
+color_14: '#FF79C6'    # Bright Magenta
+color_15: '#8BE9FD'    # Bright Cyan
+color_16: '#F8F8F2'    # Bright White
+
+background: '#282A36'  # Background
+foreground: '#F8F8F2'  # Foreground (Text)
+
+cursor: '#F8F8F2'      # Cursor
+"#;
+
+const JSON: &str = r##"[
+    { "name": "Night Test", "color_01": "#000000", "color_02": "#AA0000", "background": "#101010", "foreground": "#EEEEEE", "cursor": "#EEEEEE" },
+    { "name": "Paper Test", "color_01": "#222222", "background": "#F2EEDE", "foreground": "#000000", "cursor": "#000000" }
+]"##;
+
+#[test]
+fn yaml_theme_maps_all_colors() {
+    let manifests = from_yaml(YAML).unwrap();
+    assert_eq!(manifests.len(), 1);
+    let m = &manifests[0];
+
+    assert_eq!(hex(&m.base, "background"), "#282A36");
+    assert_eq!(hex(&m.base, "foreground"), "#F8F8F2");
+    assert_eq!(hex(&m.editor, "cursor"), "#F8F8F2");
+    assert_eq!(hex(&m.terminal, "black"), "#262626");
+    assert_eq!(hex(&m.terminal, "white"), "#EFA554");
+    assert_eq!(hex(&m.terminal, "bright_black"), "#7A7A7A");
+    assert_eq!(hex(&m.terminal, "bright_white"), "#F8F8F2");
+    assert_eq!(m.terminal.len(), 16);
+}
+
+#[test]
+fn yaml_meta_uses_name_and_variant() {
+    let m = &from_yaml(YAML).unwrap()[0];
+    let meta = m.meta.as_ref().unwrap();
+
+    assert_eq!(meta.name.as_ref(), "Dracula Test");
+    assert_eq!(meta.preset_id.as_ref(), "dracula_test");
+    assert_eq!(meta.style.as_ref(), "dark");
+}
+
+#[test]
+fn json_collection_yields_each_theme() {
+    let manifests = from_json(JSON).unwrap();
+    let styles: Vec<&str> = manifests
+        .iter()
+        .map(|m| m.meta.as_ref().unwrap().style.as_ref())
+        .collect();
+
+    assert_eq!(manifests.len(), 2);
+    assert_eq!(styles, ["dark", "light"]);
+    assert_eq!(hex(&manifests[0].terminal, "red"), "#AA0000");
+}
+
+#[test]
+fn collection_registers_every_theme() {
+    let mut reg = Registry::new();
+    for manifest in from_json(JSON).unwrap() {
+        reg.add_manifest(manifest).unwrap();
+    }
+
+    assert!(reg.load("night_test").is_ok());
+    assert!(
+        reg.list()
+            .any(|t| t.id.as_ref() == "paper_test" && t.is_light)
+    );
+}
+
+#[test]
+fn theme_without_name_is_import_error() {
+    let err = from_yaml("background: '#000000'").unwrap_err();
+
+    assert!(matches!(err, PaletteError::Import { .. }));
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "import")]
+
+use palette_core::Registry;
+use palette_core::error::PaletteError;
+use palette_core::export::find_exporter;
+use palette_core::import::pywal::from_json;
+
+mod common;
+
+use common::hex;
+
+const COLORS: &str = r##"{
+    "wallpaper": "/home/user/wallpapers/forest.jpg",
+    "alpha": "100",
+    "special": {
+        "background": "#0f1a14",
+        "foreground": "#c3c6c4",
+        "cursor": "#c3c6c4"
+    },
+    "colors": {
+        "color0": "#0f1a14", "color1": "#4C6B4F", "color2": "#5E7F5A", "color3": "#7C8C5B",
+        "color4": "#4F7A7B", "color5": "#6B8A7A", "color6": "#7FA39A", "color7": "#c3c6c4",
+        "color8": "#888a89", "color9": "#4C6B4F", "color10": "#5E7F5A", "color11": "#7C8C5B",
+        "color12": "#4F7A7B", "color13": "#6B8A7A", "color14": "#7FA39A", "color15": "#c3c6c4"
+    }
+}"##;
+
+#[test]
+fn special_colors_map_to_base_and_cursor() {
+    let m = from_json(COLORS).unwrap();
+
+    assert_eq!(hex(&m.base, "background"), "#0F1A14");
+    assert_eq!(hex(&m.base, "foreground"), "#C3C6C4");
+    assert_eq!(hex(&m.editor, "cursor"), "#C3C6C4");
+}
+
+#[test]
+fn numbered_colors_fill_terminal() {
+    let m = from_json(COLORS).unwrap();
+
+    assert_eq!(hex(&m.terminal, "black"), "#0F1A14");
+    assert_eq!(hex(&m.terminal, "red"), "#4C6B4F");
+    assert_eq!(hex(&m.terminal, "bright_black"), "#888A89");
+    assert_eq!(m.terminal.len(), 16);
+    assert_eq!(hex(&m.semantic, "error"), "#4C6B4F");
+}
+
+#[test]
+fn preset_id_is_stable() {
+    let m = from_json(COLORS).unwrap();
+    let meta = m.meta.as_ref().unwrap();
+
+    assert_eq!(meta.preset_id.as_ref(), "pywal");
+    assert_eq!(meta.style.as_ref(), "dark");
+}
+
+#[test]
+fn registered_palette_can_be_inherited_and_exported() {
+    let mut reg = Registry::new();
+    reg.add_manifest(from_json(COLORS).unwrap()).unwrap();
+    reg.add_toml(
+        r##"
+[meta]
+name = "Pywal Accent"
+preset_id = "pywal_accent"
+schema_version = "1"
+style = "dark"
+kind = "preset-variant"
+inherits = "pywal"
+
+[base]
+border_highlight = "#7FA39A"
+"##,
+    )
+    .unwrap();
+
+    let palette = reg.load("pywal_accent").unwrap();
+    assert_eq!(
+        palette.base.background.unwrap().to_hex().as_ref(),
+        "#0F1A14"
+    );
+
+    let files = find_exporter("termux").unwrap().export(&palette);
+    assert!(files[0].contents.contains("background=#0F1A14"));
+}
+
+#[test]
+fn missing_sections_is_import_error() {
+    let err = from_json(r#"{ "wallpaper": "x" }"#).unwrap_err();
+
+    assert!(matches!(err, PaletteError::Import { .. }));
+}
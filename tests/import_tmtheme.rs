@@ -0,0 +1,140 @@
+#![cfg(feature = "import")]
+
+use palette_core::error::PaletteError;
+use palette_core::import::tmtheme::from_xml;
+
+mod common;
+
+use common::hex;
+
+const THEME: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>name</key>
+    <string>Monokai Test</string>
+    <key>settings</key>
+    <array>
+        <dict>
+            <key>settings</key>
+            <dict>
+                <key>background</key>
+                <string>#272822</string>
+                <key>foreground</key>
+                <string>#F8F8F2</string>
+                <key>caret</key>
+                <string>#F8F8F0</string>
+                <key>selection</key>
+                <string>#FFFFFF40</string>
+                <key>lineHighlight</key>
+                <string>#3E3D32</string>
+            </dict>
+        </dict>
+        <dict>
+            <key>name</key>
+            <string>Comment</string>
+            <key>scope</key>
+            <string>comment</string>
+            <key>settings</key>
+            <dict>
+                <key>foreground</key>
+                <string>#75715E</string>
+                <key>fontStyle</key>
+                <string>italic</string>
+            </dict>
+        </dict>
+        <dict>
+            <key>scope</key>
+            <string>keyword, storage</string>
+            <key>settings</key>
+            <dict>
+                <key>foreground</key>
+                <string>#F92672</string>
+            </dict>
+        </dict>
+        <dict>
+            <key>scope</key>
+            <string>string</string>
+            <key>settings</key>
+            <dict>
+                <key>foreground</key>
+                <string>#E6DB74</string>
+            </dict>
+        </dict>
+        <dict>
+            <key>scope</key>
+            <string>invalid.deprecated, meta.diff.header</string>
+            <key>settings</key>
+            <dict>
+                <key>foreground</key>
+                <string>#AE81FF</string>
+            </dict>
+        </dict>
+    </array>
+</dict>
+</plist>
+"##;
+
+#[test]
+fn global_settings_map_to_base_and_editor() {
+    let m = from_xml(THEME).unwrap().manifest;
+
+    assert_eq!(hex(&m.base, "background"), "#272822");
+    assert_eq!(hex(&m.base, "foreground"), "#F8F8F2");
+    assert_eq!(hex(&m.base, "background_highlight"), "#3E3D32");
+    assert_eq!(hex(&m.editor, "cursor"), "#F8F8F0");
+}
+
+#[test]
+fn translucent_selection_composites_over_background() {
+    let m = from_xml(THEME).unwrap().manifest;
+
+    // #FFFFFF at 0x40 alpha over #272822.
+    assert_eq!(hex(&m.editor, "selection_bg"), "#5D5E59");
+}
+
+#[test]
+fn scope_rules_map_to_syntax() {
+    let m = from_xml(THEME).unwrap().manifest;
+
+    assert_eq!(hex(&m.syntax, "comments"), "#75715E");
+    assert_eq!(hex(&m.syntax, "keywords"), "#F92672");
+    assert_eq!(hex(&m.syntax, "types_builtin"), "#F92672");
+    assert_eq!(hex(&m.syntax, "strings"), "#E6DB74");
+    assert_eq!(hex(&m.syntax_style, "comments"), "italic");
+}
+
+#[test]
+fn unmapped_scopes_are_reported() {
+    let import = from_xml(THEME).unwrap();
+
+    assert_eq!(
+        import.unmapped_scopes.as_ref(),
+        [
+            Box::from("invalid.deprecated"),
+            Box::from("meta.diff.header")
+        ]
+    );
+}
+
+#[test]
+fn meta_from_plist_name() {
+    let m = from_xml(THEME).unwrap().manifest;
+    let meta = m.meta.as_ref().unwrap();
+
+    assert_eq!(meta.name.as_ref(), "Monokai Test");
+    assert_eq!(meta.preset_id.as_ref(), "monokai_test");
+    assert_eq!(meta.style.as_ref(), "dark");
+}
+
+#[test]
+fn imported_manifest_builds_palette() {
+    common::assert_builds_palette(&from_xml(THEME).unwrap().manifest);
+}
+
+#[test]
+fn malformed_plist_is_import_error() {
+    let err = from_xml("<plist><dict>").unwrap_err();
+
+    assert!(matches!(err, PaletteError::Import { .. }));
+}
@@ -0,0 +1,117 @@
+#![cfg(feature = "import")]
+
+use palette_core::error::PaletteError;
+use palette_core::import::vscode::{DEFAULT_NAME, from_json};
+
+mod common;
+
+use common::hex;
+
+const THEME: &str = r##"{
+    // VS Code accepts comments and trailing commas in theme files.
+    "name": "Midnight Test",
+    "type": "dark",
+    "colors": {
+        "editor.background": "#101020",
+        "editor.foreground": "#e0e0f0",
+        "editor.selectionBackground": "#ffffff80",
+        "editorCursor.foreground": "#f0c000",
+        "editorError.foreground": "#ff5555",
+        "statusBar.background": "#181830",
+        "terminal.ansiRed": "#e06c75",
+        "terminal.ansiBrightWhite": "#fff",
+    },
+    "tokenColors": [
+        { "settings": { "foreground": "#c0c0c0" } },
+        { "scope": "comment", "settings": { "foreground": "#606080", "fontStyle": "italic" } },
+        { "scope": "keyword", "settings": { "foreground": "#c678dd" } },
+        { "scope": "keyword.control.import", "settings": { "foreground": "#56b6c2" } },
+        { "scope": ["string", "string.quoted"], "settings": { "foreground": "#98c379" } },
+        { "scope": "entity.name.function, support.function", "settings": { "foreground": "#61afef", "fontStyle": "bold" } },
+    ],
+}"##;
+
+#[test]
+fn maps_workbench_colors_to_groups() {
+    let manifest = from_json(THEME).unwrap();
+
+    assert_eq!(hex(&manifest.base, "background"), "#101020");
+    assert_eq!(hex(&manifest.base, "foreground"), "#E0E0F0");
+    assert_eq!(hex(&manifest.editor, "cursor"), "#F0C000");
+    assert_eq!(hex(&manifest.semantic, "error"), "#FF5555");
+    assert_eq!(hex(&manifest.surface, "statusline"), "#181830");
+    assert_eq!(hex(&manifest.terminal, "red"), "#E06C75");
+    assert_eq!(hex(&manifest.terminal, "bright_white"), "#FFFFFF");
+}
+
+#[test]
+fn translucent_colors_composite_over_background() {
+    let manifest = from_json(THEME).unwrap();
+
+    // #ffffff at 0x80 alpha over #101020.
+    assert_eq!(hex(&manifest.editor, "selection_bg"), "#888890");
+}
+
+#[test]
+fn token_rules_use_most_specific_scope() {
+    let manifest = from_json(THEME).unwrap();
+
+    assert_eq!(hex(&manifest.syntax, "keywords"), "#C678DD");
+    assert_eq!(hex(&manifest.syntax, "keywords_control"), "#C678DD");
+    assert_eq!(hex(&manifest.syntax, "keywords_import"), "#56B6C2");
+    assert_eq!(hex(&manifest.syntax, "strings"), "#98C379");
+    assert_eq!(hex(&manifest.syntax, "functions"), "#61AFEF");
+    assert_eq!(hex(&manifest.syntax, "functions_builtin"), "#61AFEF");
+}
+
+#[test]
+fn font_styles_map_to_syntax_style() {
+    let manifest = from_json(THEME).unwrap();
+
+    assert_eq!(hex(&manifest.syntax_style, "comments"), "italic");
+    assert_eq!(hex(&manifest.syntax_style, "functions"), "bold");
+    assert!(!manifest.syntax_style.contains_key("keywords"));
+}
+
+#[test]
+fn comment_rule_fills_typography_comment() {
+    let manifest = from_json(THEME).unwrap();
+
+    assert_eq!(hex(&manifest.typography, "comment"), "#606080");
+}
+
+#[test]
+fn meta_from_name_and_type() {
+    let manifest = from_json(THEME).unwrap();
+    let meta = manifest.meta.as_ref().unwrap();
+
+    assert_eq!(meta.name.as_ref(), "Midnight Test");
+    assert_eq!(meta.preset_id.as_ref(), "midnight_test");
+    assert_eq!(meta.style.as_ref(), "dark");
+}
+
+#[test]
+fn missing_name_and_type_fall_back() {
+    let manifest = from_json(r##"{ "colors": { "editor.background": "#fafafa" } }"##).unwrap();
+    let meta = manifest.meta.as_ref().unwrap();
+
+    assert_eq!(meta.name.as_ref(), DEFAULT_NAME);
+    assert_eq!(meta.style.as_ref(), "light");
+}
+
+#[test]
+fn imported_manifest_builds_palette() {
+    let palette = common::assert_builds_palette(&from_json(THEME).unwrap());
+
+    assert_eq!(
+        palette.base.background.unwrap().to_hex().as_ref(),
+        "#101020"
+    );
+}
+
+#[test]
+fn invalid_json_is_import_error() {
+    let err = from_json("{ not json").unwrap_err();
+
+    assert!(matches!(err, PaletteError::Import { ref format, .. } if format.as_ref() == "vscode"));
+}
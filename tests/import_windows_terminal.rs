@@ -0,0 +1,118 @@
+#![cfg(feature = "import")]
+
+use palette_core::Registry;
+use palette_core::error::PaletteError;
+use palette_core::import::windows_terminal::from_json;
+
+mod common;
+
+use common::hex;
+
+const SCHEME: &str = r##"{
+    "name": "Campbell Test",
+    "background": "#0C0C0C",
+    "foreground": "#CCCCCC",
+    "cursorColor": "#FFFFFF",
+    "selectionBackground": "#FFFFFF",
+    "black": "#0C0C0C",
+    "red": "#C50F1F",
+    "green": "#13A10E",
+    "yellow": "#C19C00",
+    "blue": "#0037DA",
+    "purple": "#881798",
+    "cyan": "#3A96DD",
+    "white": "#CCCCCC",
+    "brightBlack": "#767676",
+    "brightRed": "#E74856",
+    "brightGreen": "#16C60C",
+    "brightYellow": "#F9F1A5",
+    "brightBlue": "#3B78FF",
+    "brightPurple": "#B4009E",
+    "brightCyan": "#61D6D6",
+    "brightWhite": "#F2F2F2"
+}"##;
+
+const FRAGMENT: &str = r##"{
+    "profiles": [{ "name": "Shell", "colorScheme": "Night Owl Test" }],
+    "schemes": [
+        { "name": "Night Owl Test", "background": "#011627", "foreground": "#D6DEEB", "red": "#EF5350" },
+        { "name": "Light Owl Test", "background": "#FBFBFB", "foreground": "#403F53" },
+    ]
+}"##;
+
+#[test]
+fn single_scheme_maps_base_and_editor() {
+    let manifests = from_json(SCHEME).unwrap();
+    assert_eq!(manifests.len(), 1);
+    let m = &manifests[0];
+
+    assert_eq!(hex(&m.base, "background"), "#0C0C0C");
+    assert_eq!(hex(&m.base, "foreground"), "#CCCCCC");
+    assert_eq!(hex(&m.editor, "cursor"), "#FFFFFF");
+    assert_eq!(hex(&m.editor, "selection_bg"), "#FFFFFF");
+}
+
+#[test]
+fn css_color_functions_are_accepted() {
+    let json = SCHEME.replace("\"#0C0C0C\"", "\"rgb(12, 12, 12)\"");
+    let m = &from_json(&json).unwrap()[0];
+
+    assert_eq!(hex(&m.base, "background"), "#0C0C0C");
+}
+
+#[test]
+fn purple_maps_to_magenta() {
+    let m = &from_json(SCHEME).unwrap()[0];
+
+    assert_eq!(hex(&m.terminal, "magenta"), "#881798");
+    assert_eq!(hex(&m.terminal, "bright_magenta"), "#B4009E");
+    assert_eq!(m.terminal.len(), 16);
+}
+
+#[test]
+fn scheme_name_becomes_meta() {
+    let m = &from_json(SCHEME).unwrap()[0];
+    let meta = m.meta.as_ref().unwrap();
+
+    assert_eq!(meta.name.as_ref(), "Campbell Test");
+    assert_eq!(meta.preset_id.as_ref(), "campbell_test");
+    assert_eq!(meta.style.as_ref(), "dark");
+}
+
+#[test]
+fn fragment_yields_every_scheme() {
+    let manifests = from_json(FRAGMENT).unwrap();
+    let ids: Vec<&str> = manifests
+        .iter()
+        .map(|m| m.meta.as_ref().unwrap().preset_id.as_ref())
+        .collect();
+
+    assert_eq!(ids, ["night_owl_test", "light_owl_test"]);
+    assert_eq!(manifests[1].meta.as_ref().unwrap().style.as_ref(), "light");
+}
+
+#[test]
+fn fragment_schemes_register_individually() {
+    let mut reg = Registry::new();
+    for manifest in from_json(FRAGMENT).unwrap() {
+        reg.add_manifest(manifest).unwrap();
+    }
+
+    let night = reg.load("night_owl_test").unwrap();
+    assert_eq!(night.terminal.red.unwrap().to_hex().as_ref(), "#EF5350");
+    assert!(reg.load("light_owl_test").is_ok());
+}
+
+#[test]
+fn bare_array_of_schemes_is_accepted() {
+    let json = format!("[{SCHEME}]");
+
+    assert_eq!(from_json(&json).unwrap().len(), 1);
+}
+
+#[test]
+fn scheme_without_name_is_import_error() {
+    let err = from_json(r##"{ "background": "#000000" }"##).unwrap_err();
+
+    assert!(matches!(err, PaletteError::Import { .. }));
+}
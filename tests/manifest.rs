@@ -256,3 +256,535 @@ stops = ["#FF0000", "#0000FF"]
     let plain = manifest.gradient.get("plain").unwrap();
     assert!(plain.space.is_none());
 }
+
+#[test]
+fn known_slot_keys_are_shared_across_manifests() {
+    let first = PaletteManifest::from_toml(BASE_TOML).unwrap();
+    let second = PaletteManifest::from_toml(BASE_TOML).unwrap();
+    let key = |m: &PaletteManifest| {
+        m.base
+            .keys()
+            .find(|k| k.as_ref() == "background")
+            .cloned()
+            .unwrap()
+    };
+    assert!(Arc::ptr_eq(&key(&first), &key(&second)));
+}
+
+#[test]
+fn unknown_slot_keys_are_kept() {
+    let toml = "[base]\nbackground = \"#000000\"\nnot_a_slot = \"#ffffff\"\n";
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    assert_eq!(
+        manifest.base.get("not_a_slot").map(|v| v.as_ref()),
+        Some("#ffffff")
+    );
+}
+
+#[test]
+fn to_toml_round_trips() {
+    let manifest = PaletteManifest::from_toml(BASE_TOML).unwrap();
+    let reparsed = PaletteManifest::from_toml(&manifest.to_toml()).unwrap();
+    assert_eq!(
+        palette_core::Palette::from_manifest(&reparsed).unwrap(),
+        palette_core::Palette::from_manifest(&manifest).unwrap(),
+    );
+    assert_eq!(reparsed.meta.unwrap().name.as_ref(), "Test Theme");
+}
+
+#[test]
+fn from_toml_with_warnings_reports_without_failing() {
+    let toml = r##"
+[base]
+background = "#000000"
+foregroud = "#ffffff"
+
+[semntic]
+error = "#ff0000"
+"##;
+
+    let (manifest, warnings) = PaletteManifest::from_toml_with_warnings(toml).unwrap();
+    assert_eq!(manifest.base.len(), 2);
+    let codes: Vec<_> = warnings
+        .iter()
+        .map(|w| (w.code, w.field.as_deref().or(w.section.as_deref())))
+        .collect();
+    assert!(codes.contains(&("PC0102", Some("semntic"))));
+    assert!(codes.contains(&("PC0100", Some("foregroud"))));
+    assert!(codes.contains(&("PC0104", Some("foreground"))));
+    assert!(codes.contains(&("PC0104", Some("error"))));
+}
+
+#[test]
+fn from_toml_with_warnings_skips_recommended_slots_for_variants() {
+    let toml = r##"
+[meta]
+name = "Variant"
+preset_id = "variant"
+schema_version = "1"
+style = "dark"
+kind = "preset-variant"
+inherits = "tokyonight"
+
+[base]
+background = "#000000"
+"##;
+
+    let (_, warnings) = PaletteManifest::from_toml_with_warnings(toml).unwrap();
+    assert!(warnings.is_empty(), "{warnings:?}");
+}
+
+#[test]
+fn from_toml_with_warnings_still_fails_on_errors() {
+    let err = PaletteManifest::from_toml_with_warnings("[semantic]\nerror = \"#ff0000\"\n");
+    assert!(matches!(err, Err(PaletteError::MissingBase)));
+}
+
+const COLORS_TOML: &str = r##"
+[colors]
+blue = "#7aa2f7"
+night = "#1a1b26"
+
+[base]
+background = "$night"
+foreground = "#c0caf5"
+border = "$blue"
+
+[semantic]
+info = "$blue"
+
+[gradient.sky]
+stops = ["$night", "$blue"]
+"##;
+
+#[test]
+fn color_variables_expand_into_slots() {
+    let manifest = PaletteManifest::from_toml(COLORS_TOML).unwrap();
+    assert_eq!(
+        manifest.base.get("border").map(AsRef::as_ref),
+        Some("$blue")
+    );
+
+    let palette = palette_core::Palette::from_manifest(&manifest).unwrap();
+    let blue = palette_core::Color::from_hex("#7aa2f7").unwrap();
+    assert_eq!(palette.base.border, Some(blue));
+    assert_eq!(palette.semantic.info, Some(blue));
+    assert_eq!(
+        palette.base.background,
+        Some(palette_core::Color::from_hex("#1a1b26").unwrap())
+    );
+    assert!(palette.resolve().gradient("sky").is_some());
+}
+
+#[test]
+fn expand_colors_borrows_without_references() {
+    let manifest = PaletteManifest::from_toml(BASE_TOML).unwrap();
+    assert!(matches!(
+        manifest.expand_colors().unwrap(),
+        std::borrow::Cow::Borrowed(_)
+    ));
+}
+
+#[test]
+fn unknown_color_variable_is_an_error() {
+    let toml = "[colors]\nblue = \"#7aa2f7\"\n\n[base]\nbackground = \"$bleu\"\n";
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    let err = palette_core::Palette::from_manifest(&manifest).unwrap_err();
+    assert!(matches!(
+        &err,
+        PaletteError::UnknownColorVariable { section, field, name }
+            if section.as_ref() == "base" && field.as_ref() == "background" && name.as_ref() == "bleu"
+    ));
+    assert_eq!(err.code(), "PC0018");
+    assert_eq!(
+        err.to_string(),
+        "unknown color variable `$bleu` in [base].background"
+    );
+}
+
+#[test]
+fn invalid_color_variable_is_an_error() {
+    let toml = "[colors]\nblue = \"#7aa2\"\n\n[base]\n";
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    let err = palette_core::Palette::from_manifest(&manifest).unwrap_err();
+    assert!(matches!(
+        err,
+        PaletteError::InvalidHex { section, field, .. }
+            if section.as_ref() == "colors" && field.as_ref() == "blue"
+    ));
+}
+
+#[test]
+fn colors_section_round_trips_through_to_toml() {
+    let manifest = PaletteManifest::from_toml(COLORS_TOML).unwrap();
+    let toml = manifest.to_toml();
+    assert!(toml.contains("[colors]"), "{toml}");
+    let reparsed = PaletteManifest::from_toml(&toml).unwrap();
+    assert_eq!(reparsed.colors, manifest.colors);
+    assert_eq!(reparsed.base, manifest.base);
+}
+
+#[test]
+fn colors_section_is_not_an_unknown_section() {
+    let (_, warnings) = PaletteManifest::from_toml_with_warnings(COLORS_TOML).unwrap();
+    assert!(warnings.iter().all(|w| w.code != "PC0102"), "{warnings:?}");
+}
+
+#[test]
+fn strict_accepts_known_keys() {
+    let manifest = PaletteManifest::from_toml_strict(BASE_TOML).unwrap();
+    assert_eq!(manifest.meta.unwrap().preset_id.as_ref(), "test_theme");
+}
+
+#[test]
+fn strict_rejects_misspelled_slot() {
+    let toml = "[base]\nbackground = \"#000000\"\nforground = \"#ffffff\"\n";
+    assert!(PaletteManifest::from_toml(toml).is_ok());
+    let err = PaletteManifest::from_toml_strict(toml).unwrap_err();
+    assert!(matches!(
+        &err,
+        PaletteError::UnknownField { section, field }
+            if section.as_ref() == "base" && field.as_ref() == "forground"
+    ));
+    assert_eq!(
+        err.diagnostic().suggestion.as_deref(),
+        Some("did you mean `foreground`?")
+    );
+}
+
+#[test]
+fn strict_rejects_unknown_section() {
+    let toml = "[base]\n\n[semantc]\nerror = \"#ff0000\"\n";
+    let err = PaletteManifest::from_toml_strict(toml).unwrap_err();
+    assert!(matches!(&err, PaletteError::UnknownSection(name) if name.as_ref() == "semantc"));
+    assert_eq!(err.code(), "PC0019");
+    assert_eq!(
+        err.diagnostic().suggestion.as_deref(),
+        Some("did you mean `semantic`?")
+    );
+}
+
+#[test]
+fn strict_reports_first_unknown_key_deterministically() {
+    let toml = "[base]\nzzz = \"#000000\"\naaa = \"#000000\"\n";
+    let err = PaletteManifest::from_toml_strict(toml).unwrap_err();
+    assert!(matches!(err, PaletteError::UnknownField { field, .. } if field.as_ref() == "aaa"));
+}
+
+const EXTENDED_TOML: &str = r##"
+[base]
+background = "#1a1b26"
+
+[x-myapp]
+accent_width = 2
+font = "Iosevka"
+
+[x-myapp.statusbar]
+compact = true
+"##;
+
+#[test]
+fn unknown_sections_are_kept_as_extensions() {
+    let manifest = PaletteManifest::from_toml(EXTENDED_TOML).unwrap();
+    let ext = &manifest.extensions["x-myapp"];
+    assert_eq!(ext["accent_width"].as_integer(), Some(2));
+    assert_eq!(ext["font"].as_str(), Some("Iosevka"));
+    assert_eq!(ext["statusbar"]["compact"].as_bool(), Some(true));
+    assert!(!manifest.extensions.contains_key("base"));
+}
+
+#[test]
+fn extensions_round_trip_through_to_toml() {
+    let manifest = PaletteManifest::from_toml(EXTENDED_TOML).unwrap();
+    let reparsed = PaletteManifest::from_toml(&manifest.to_toml()).unwrap();
+    assert_eq!(reparsed.extensions, manifest.extensions);
+}
+
+#[test]
+fn top_level_values_are_not_extensions() {
+    let manifest = PaletteManifest::from_toml("stray = 1\n\n[base]\n").unwrap();
+    assert!(manifest.extensions.is_empty());
+}
+
+const TRANSLUCENT_TOML: &str = r##"
+[base]
+background = "#000000"
+
+[surface]
+selection = { color = "#7aa2f7", opacity = 0.5 }
+overlay = { color = "#ffffff", opacity = 0.25, blend = true }
+"##;
+
+#[test]
+fn translucent_slots_record_opacity() {
+    let manifest = PaletteManifest::from_toml(TRANSLUCENT_TOML).unwrap();
+    assert_eq!(
+        manifest.surface.get("selection").unwrap().as_ref(),
+        "#7aa2f7"
+    );
+    let surface = &manifest.opacity["surface"];
+    assert_eq!(surface["selection"].opacity, 0.5);
+    assert!(!surface["selection"].blend);
+    assert!(surface["overlay"].blend);
+}
+
+#[test]
+fn translucent_slots_round_trip_through_to_toml() {
+    let manifest = PaletteManifest::from_toml(TRANSLUCENT_TOML).unwrap();
+    let toml = manifest.to_toml();
+    assert!(toml.contains("overlay = { color = \"#ffffff\", opacity = 0.25, blend = true }"));
+    let reparsed = PaletteManifest::from_toml(&toml).unwrap();
+    assert_eq!(reparsed.opacity, manifest.opacity);
+}
+
+#[test]
+fn opacity_outside_unit_range_is_rejected() {
+    let toml = "[base]\nbackground = { color = \"#000000\", opacity = 1.5 }\n";
+    let err = PaletteManifest::from_toml(toml).unwrap_err();
+    assert!(matches!(err, PaletteError::Parse(_)));
+}
+
+#[test]
+fn opacity_is_rejected_outside_color_slots() {
+    let toml = "[base]\n\n[colors]\nblue = { color = \"#7aa2f7\", opacity = 0.5 }\n";
+    assert!(PaletteManifest::from_toml(toml).is_err());
+}
+
+#[test]
+fn inherits_accepts_an_array() {
+    let toml = BASE_TOML.replace(
+        "kind = \"preset-base\"",
+        "kind = \"preset-variant\"\ninherits = [\"tokyonight\", \"syntax_pack\"]",
+    );
+    let manifest = PaletteManifest::from_toml(&toml).unwrap();
+    let parents: Vec<&str> = manifest.parents().iter().map(AsRef::as_ref).collect();
+    assert_eq!(parents, ["tokyonight", "syntax_pack"]);
+    assert_eq!(manifest.inherits_from(), Some("tokyonight"));
+
+    let reparsed = PaletteManifest::from_toml(&manifest.to_toml()).unwrap();
+    assert_eq!(reparsed.parents(), manifest.parents());
+}
+
+const MODES_TOML: &str = r##"
+[base]
+background = "#1a1b26"
+foreground = "#c0caf5"
+
+[surface]
+menu = { color = "#16161e", opacity = 0.5 }
+
+[when.high_contrast.base]
+background = "#000000"
+foreground = "#ffffff"
+
+[when.transparent.surface]
+menu = { color = "#16161e", opacity = 0.2 }
+
+[when.transparent.x-myapp]
+blur = true
+"##;
+
+#[test]
+fn when_sections_are_kept_as_modes() {
+    let manifest = PaletteManifest::from_toml(MODES_TOML).unwrap();
+    assert_eq!(manifest.base["background"].as_ref(), "#1a1b26");
+    let contrast = &manifest.modes["high_contrast"];
+    assert_eq!(contrast.base["background"].as_ref(), "#000000");
+    assert!(contrast.surface.is_empty());
+    assert!(!manifest.extensions.contains_key("when"));
+}
+
+#[test]
+fn with_modes_overlays_requested_modes_in_order() {
+    let manifest = PaletteManifest::from_toml(MODES_TOML).unwrap();
+    assert!(matches!(
+        manifest.with_modes(&["unknown"]),
+        std::borrow::Cow::Borrowed(_)
+    ));
+
+    let applied = manifest.with_modes(&["high_contrast", "transparent"]);
+    assert_eq!(applied.base["background"].as_ref(), "#000000");
+    assert_eq!(applied.base["foreground"].as_ref(), "#ffffff");
+    assert_eq!(applied.opacity["surface"]["menu"].opacity, 0.2);
+    assert_eq!(applied.extensions["x-myapp"]["blur"].as_bool(), Some(true));
+}
+
+#[test]
+fn modes_round_trip_through_to_toml() {
+    let manifest = PaletteManifest::from_toml(MODES_TOML).unwrap();
+    let toml = manifest.to_toml();
+    assert!(toml.contains("[when.high_contrast.base]"));
+    let reparsed = PaletteManifest::from_toml_strict(&toml).unwrap();
+    assert_eq!(
+        reparsed.modes["high_contrast"].base,
+        manifest.modes["high_contrast"].base
+    );
+    assert_eq!(
+        reparsed.modes["transparent"].opacity,
+        manifest.modes["transparent"].opacity
+    );
+    assert_eq!(
+        reparsed.modes["transparent"].extensions,
+        manifest.modes["transparent"].extensions
+    );
+}
+
+#[test]
+fn unknown_keys_in_modes_are_reported() {
+    let toml = "[base]\n\n[when.high_contrast.base]\nforground = \"#ffffff\"\n";
+    let err = PaletteManifest::from_toml_strict(toml).unwrap_err();
+    assert!(matches!(
+        &err,
+        PaletteError::UnknownField { section, field }
+            if section.as_ref() == "when.high_contrast.base" && field.as_ref() == "forground"
+    ));
+}
+
+const ALIASED_TOML: &str = r##"
+[base]
+bg = "#1a1b26"
+fg = "#c0caf5"
+
+[syntax]
+comment = { color = "#565f89", opacity = 0.8 }
+"##;
+
+#[test]
+fn aliases_load_as_their_current_field() {
+    let manifest = PaletteManifest::from_toml(ALIASED_TOML).unwrap();
+    assert_eq!(manifest.base["background"].as_ref(), "#1a1b26");
+    assert_eq!(manifest.base["foreground"].as_ref(), "#c0caf5");
+    assert!(!manifest.base.contains_key("bg"));
+    assert_eq!(manifest.syntax["comments"].as_ref(), "#565f89");
+    assert_eq!(manifest.opacity["syntax"]["comments"].opacity, 0.8);
+    assert!(PaletteManifest::from_toml_strict(ALIASED_TOML).is_ok());
+}
+
+#[test]
+fn aliases_are_reported_as_deprecated() {
+    let (manifest, warnings) = PaletteManifest::from_toml_with_warnings(ALIASED_TOML).unwrap();
+    assert!(manifest.base.contains_key("background"));
+    let deprecated: Vec<_> = warnings
+        .iter()
+        .filter(|w| w.code == "PC0103")
+        .map(|w| (w.field.as_deref(), w.suggestion.as_deref()))
+        .collect();
+    assert_eq!(
+        deprecated,
+        [
+            (Some("bg"), Some("rename it to `background`")),
+            (Some("fg"), Some("rename it to `foreground`")),
+            (Some("comment"), Some("rename it to `comments`")),
+        ]
+    );
+    assert!(
+        !warnings
+            .iter()
+            .any(|w| w.code == "PC0104" && w.section.as_deref() == Some("base"))
+    );
+}
+
+#[test]
+fn current_field_wins_over_its_alias() {
+    let toml = "[base]\nbg = \"#000000\"\nbackground = \"#1a1b26\"\n";
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    assert_eq!(manifest.base["background"].as_ref(), "#1a1b26");
+    assert_eq!(manifest.base.len(), 1);
+}
+
+#[test]
+fn aliases_resolve_inside_modes() {
+    let toml = "[base]\n\n[when.high_contrast.base]\nbg = \"#000000\"\n";
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    assert_eq!(
+        manifest.modes["high_contrast"].base["background"].as_ref(),
+        "#000000"
+    );
+}
+
+#[test]
+fn attribution_round_trips_through_to_toml() {
+    let toml = BASE_TOML.replace(
+        "kind = \"preset-base\"",
+        "kind = \"preset-base\"\nauthor = \"Jane Doe\"\nlicense = \"MIT\"\ntags = [\"dim\", \"warm\"]",
+    );
+    let manifest = PaletteManifest::from_toml(&toml).unwrap();
+    let meta = manifest.meta.as_ref().unwrap();
+    assert_eq!(meta.author.as_deref(), Some("Jane Doe"));
+    assert_eq!(meta.version, None);
+
+    let reparsed = PaletteManifest::from_toml(&manifest.to_toml()).unwrap();
+    let reparsed = reparsed.meta.unwrap();
+    assert_eq!(reparsed.license.as_deref(), Some("MIT"));
+    assert_eq!(reparsed.tags, meta.tags);
+}
+
+fn with_meta(fields: &str) -> String {
+    BASE_TOML.replace(
+        "kind = \"preset-base\"",
+        &format!("kind = \"preset-base\"\n{fields}"),
+    )
+}
+
+#[test]
+fn meta_placeholders_expand_from_other_fields() {
+    let toml = with_meta(
+        "description = \"{name}, {style} {{variant}}\"\nauthor = \"{license}\"\nlicense = \"MIT\"",
+    )
+    .replace("name = \"Test Theme\"", "name = \"Test ({style})\"");
+    let manifest = PaletteManifest::from_toml(&toml).unwrap();
+    let meta = manifest.meta.unwrap();
+    assert_eq!(meta.name.as_ref(), "Test (dark)");
+    assert_eq!(
+        meta.description.as_deref(),
+        Some("Test (dark), dark {variant}")
+    );
+    assert_eq!(meta.author.as_deref(), Some("MIT"));
+}
+
+#[test]
+fn meta_placeholder_naming_unset_field_is_an_error() {
+    let err = PaletteManifest::from_toml(&with_meta("description = \"by {author}\"")).unwrap_err();
+    assert!(matches!(
+        &err,
+        PaletteError::UnresolvedMetaPlaceholder { field, placeholder }
+            if field.as_ref() == "description" && placeholder.as_ref() == "author"
+    ));
+    assert_eq!(err.code(), "PC0021");
+}
+
+#[test]
+fn meta_placeholder_cycle_is_an_error() {
+    let toml = with_meta("author = \"{description}\"\ndescription = \"{author}\"");
+    let err = PaletteManifest::from_toml(&toml).unwrap_err();
+    assert!(matches!(
+        err,
+        PaletteError::UnresolvedMetaPlaceholder { .. }
+    ));
+    let suggestion = err.diagnostic().suggestion.unwrap();
+    assert!(suggestion.contains("leads back"), "{suggestion}");
+}
+
+#[test]
+fn unknown_meta_placeholder_suggests_a_field() {
+    let toml = with_meta("description = \"{styl}\"");
+    let err = PaletteManifest::from_toml(&toml).unwrap_err();
+    assert_eq!(
+        err.diagnostic().suggestion.as_deref(),
+        Some("did you mean `style`?")
+    );
+    assert!(err.span_in(&toml).is_some());
+}
+
+#[test]
+fn kebab_case_slot_keys_are_read_as_snake_case() {
+    let toml = "[base]\nbackground-dark = \"#131322\"\n\n[editor]\ncursor-text = { color = \"#505068\", opacity = 0.5 }\n\n[colors]\nmy-blue = \"#7aa2f7\"\n";
+    let manifest = PaletteManifest::from_toml_strict(toml).unwrap();
+    assert_eq!(
+        manifest.base.get("background_dark").map(AsRef::as_ref),
+        Some("#131322")
+    );
+    assert!(manifest.editor.contains_key("cursor_text"));
+    assert!(manifest.opacity["editor"].contains_key("cursor_text"));
+    assert!(manifest.colors.contains_key("my-blue"));
+}
@@ -1,7 +1,12 @@
+use palette_core::ColorSpace;
 use palette_core::color::Color;
+use palette_core::contrast::contrast_ratio;
+use palette_core::load_preset;
 use palette_core::manipulation::{
-    blend, lerp_oklab, lerp_oklch, oklab_to_srgb, oklch_to_oklab, srgb_to_oklab, srgb_to_oklch,
+    ColorScale, Lab, OkLch, blend, clamp_to_srgb_gamut, delta_e_2000, gradient, lerp_oklab,
+    lerp_oklch, mix, oklab_to_srgb, oklch_to_oklab, over, ramp, srgb_to_oklab, srgb_to_oklch,
 };
+use palette_core::palette::Palette;
 
 fn color(hex: &str) -> Color {
     Color::from_hex(hex).unwrap()
@@ -66,6 +71,14 @@ fn lighten_midgray_gets_lighter() {
     );
 }
 
+#[test]
+fn hsl_adjustments_keep_alpha() {
+    let original = color("#80808040");
+    assert_eq!(original.lighten(0.1).a, 0x40);
+    assert_eq!(original.darken(0.1).a, 0x40);
+    assert_eq!(original.rotate_hue(90.0).a, 0x40);
+}
+
 // --- darken ---
 
 #[test]
@@ -155,8 +168,56 @@ fn rotate_negative_equals_positive() {
     assert_channel_eq(neg90, pos270, 1, "-90 == 270");
 }
 
+// --- OKLab lighten / darken ---
+
+#[test]
+fn lighten_oklab_zero_identity() {
+    let c = color("#7AA2F7");
+    assert_channel_eq(c.lighten_oklab(0.0), c, 1, "lighten_oklab 0");
+}
+
+#[test]
+fn lighten_oklab_steps_lightness_and_keeps_hue() {
+    let c = color("#3D59A1");
+    let before = c.to_oklch();
+    let after = c.lighten_oklab(0.1).to_oklch();
+    assert!(
+        (after.l - before.l - 0.1).abs() < 0.01,
+        "L: {} -> {}",
+        before.l,
+        after.l
+    );
+    assert!(
+        (after.h - before.h).abs() < 2.0,
+        "hue drifted: {} -> {}",
+        before.h,
+        after.h
+    );
+}
+
+#[test]
+fn darken_oklab_reaches_black_and_keeps_alpha() {
+    let c = color("#F7768E80");
+    assert_eq!(c.darken_oklab(1.0), color("#00000080"));
+    assert!(c.darken_oklab(0.2).to_oklch().l < c.to_oklch().l);
+}
+
+#[test]
+fn oklab_adjust_non_finite_is_identity() {
+    let c = color("#F7768E");
+    assert_eq!(c.lighten_oklab(f64::NAN), c);
+    assert_eq!(c.darken_oklab(f64::INFINITY), c);
+}
+
 // --- blend ---
 
+#[test]
+fn blend_over_opaque_bg_is_opaque() {
+    let fg = color("#FF000080");
+    let bg = color("#0000FF");
+    assert!(blend(fg, bg, 0.5).is_opaque());
+}
+
 #[test]
 fn blend_alpha_zero_returns_bg() {
     let fg = color("#FF0000");
@@ -180,6 +241,7 @@ fn blend_half_averages() {
         r: 128,
         g: 0,
         b: 128,
+        a: 255,
     };
     assert_channel_eq(result, expected, 1, "blend alpha=0.5");
 }
@@ -198,6 +260,42 @@ fn blend_clamps_alpha_below_zero() {
     assert_channel_eq(blend(fg, bg, -0.5), bg, 0, "blend alpha<0 clamps");
 }
 
+// --- mix ---
+
+#[test]
+fn mix_weight_selects_endpoints() {
+    let a = color("#FF0000");
+    let b = color("#0000FF");
+    for space in [ColorSpace::Srgb, ColorSpace::OkLch] {
+        assert_eq!(mix(a, b, 1.0, space), a);
+        assert_eq!(mix(a, b, 0.0, space), b);
+        assert_eq!(mix(a, b, 2.0, space), a);
+    }
+}
+
+#[test]
+fn mix_matches_sass_in_srgb() {
+    // Sass: mix(#FF0000, #0000FF, 25%) == #4000BF
+    let mixed = color("#FF0000").mix(color("#0000FF"), 0.25, ColorSpace::Srgb);
+    assert_eq!(mixed, color("#4000BF"));
+}
+
+#[test]
+fn mix_is_symmetric() {
+    let a = color("#7AA2F7");
+    let b = color("#F7768E");
+    assert_eq!(
+        mix(a, b, 0.3, ColorSpace::OkLab),
+        mix(b, a, 0.7, ColorSpace::OkLab)
+    );
+}
+
+#[test]
+fn mix_non_finite_weight_returns_first() {
+    let a = color("#7AA2F7");
+    assert_eq!(mix(a, color("#000000"), f64::NAN, ColorSpace::OkLch), a);
+}
+
 // --- NaN guards ---
 
 #[test]
@@ -254,7 +352,12 @@ fn blend_infinity_returns_bg() {
 
 #[test]
 fn oklab_round_trip_black() {
-    let c = Color { r: 0, g: 0, b: 0 };
+    let c = Color {
+        r: 0,
+        g: 0,
+        b: 0,
+        a: 255,
+    };
     let lab = srgb_to_oklab(c);
     let back = oklab_to_srgb(lab);
     assert_eq!(back, c);
@@ -266,6 +369,7 @@ fn oklab_round_trip_white() {
         r: 255,
         g: 255,
         b: 255,
+        a: 255,
     };
     let lab = srgb_to_oklab(c);
     let back = oklab_to_srgb(lab);
@@ -275,9 +379,33 @@ fn oklab_round_trip_white() {
 #[test]
 fn oklab_round_trip_primary_colors() {
     for (label, c) in [
-        ("red", Color { r: 255, g: 0, b: 0 }),
-        ("green", Color { r: 0, g: 255, b: 0 }),
-        ("blue", Color { r: 0, g: 0, b: 255 }),
+        (
+            "red",
+            Color {
+                r: 255,
+                g: 0,
+                b: 0,
+                a: 255,
+            },
+        ),
+        (
+            "green",
+            Color {
+                r: 0,
+                g: 255,
+                b: 0,
+                a: 255,
+            },
+        ),
+        (
+            "blue",
+            Color {
+                r: 0,
+                g: 0,
+                b: 255,
+                a: 255,
+            },
+        ),
     ] {
         let back = oklab_to_srgb(srgb_to_oklab(c));
         assert_channel_eq(back, c, 1, &format!("{label} oklab round-trip"));
@@ -321,6 +449,39 @@ fn oklab_known_reference_values() {
     assert!(gray_lab.b.abs() < 0.002, "gray b: {}", gray_lab.b);
 }
 
+#[test]
+fn color_oklab_methods_round_trip() {
+    let c = color("#7AA2F7");
+    let lab = c.to_oklab();
+    assert_eq!(lab, srgb_to_oklab(c));
+    assert_channel_eq(Color::from_oklab(lab.l, lab.a, lab.b), c, 1, "oklab");
+}
+
+#[test]
+fn color_oklch_methods_round_trip() {
+    let c = color("#F7768E");
+    let lch = c.to_oklch();
+    assert_eq!(lch, srgb_to_oklch(c));
+    assert_channel_eq(Color::from_oklch(lch.l, lch.c, lch.h), c, 1, "oklch");
+}
+
+#[test]
+fn from_oklch_normalizes_hue() {
+    let lch = color("#F7768E").to_oklch();
+    assert_eq!(
+        Color::from_oklch(lch.l, lch.c, lch.h + 360.0),
+        Color::from_oklch(lch.l, lch.c, lch.h)
+    );
+}
+
+#[test]
+fn from_oklch_out_of_gamut_keeps_hue() {
+    // Chroma far beyond sRGB at this hue.
+    let c = Color::from_oklch(0.7, 0.5, 150.0);
+    let hue = c.to_oklch().h;
+    assert!((hue - 150.0).abs() < 5.0, "hue drifted to {hue}");
+}
+
 #[test]
 fn oklab_midpoint_not_muddy() {
     let blue = srgb_to_oklab(color("#0000FF"));
@@ -347,6 +508,99 @@ fn channel_spread(c: Color) -> u8 {
     max - min
 }
 
+// --- CIELAB / CIEDE2000 ---
+
+#[test]
+fn lab_known_reference_values() {
+    let white = color("#FFFFFF").to_lab();
+    assert!((white.l - 100.0).abs() < 0.01, "white L: {}", white.l);
+    assert!(
+        white.a.abs() < 0.01 && white.b.abs() < 0.01,
+        "white: {white:?}"
+    );
+
+    let red = color("#FF0000").to_lab();
+    assert!((red.l - 53.24).abs() < 0.05, "red L: {}", red.l);
+    assert!((red.a - 80.09).abs() < 0.05, "red a: {}", red.a);
+    assert!((red.b - 67.20).abs() < 0.05, "red b: {}", red.b);
+}
+
+#[test]
+fn delta_e_2000_matches_sharma_test_data() {
+    let lab = |l, a, b| Lab { l, a, b };
+    for (lab1, lab2, expected) in [
+        (
+            lab(50.0, 2.6772, -79.7751),
+            lab(50.0, 0.0, -82.7485),
+            2.0425,
+        ),
+        (lab(50.0, 0.0, 0.0), lab(50.0, -1.0, 2.0), 2.3669),
+        (lab(50.0, 2.5, 0.0), lab(73.0, 25.0, -18.0), 27.1492),
+        (
+            lab(60.2574, -34.0099, 36.2677),
+            lab(60.4626, -34.1751, 39.4387),
+            1.2644,
+        ),
+    ] {
+        let de = delta_e_2000(lab1, lab2);
+        assert!((de - expected).abs() < 1e-4, "{lab1:?} vs {lab2:?}: {de}");
+        let reverse = delta_e_2000(lab2, lab1);
+        assert!(
+            (de - reverse).abs() < 1e-9,
+            "not symmetric: {de} vs {reverse}"
+        );
+    }
+}
+
+#[test]
+fn delta_e_identical_is_zero() {
+    let c = color("#7AA2F7");
+    assert_eq!(c.delta_e(&c), 0.0);
+}
+
+#[test]
+fn delta_e_orders_by_similarity() {
+    let blue = color("#7AA2F7");
+    let near = color("#7DA4F5");
+    let far = color("#F7768E");
+    assert!(blue.delta_e(&near) < 2.0);
+    assert!(blue.delta_e(&far) > blue.delta_e(&near));
+}
+
+// --- HSV ---
+
+#[test]
+fn hsv_primaries() {
+    let red = color("#FF0000").to_hsv();
+    assert_eq!((red.h, red.s, red.v), (0.0, 1.0, 1.0));
+    let blue = color("#0000FF").to_hsv();
+    assert_eq!((blue.h, blue.s, blue.v), (240.0, 1.0, 1.0));
+}
+
+#[test]
+fn hsv_gray_is_achromatic() {
+    let gray = color("#808080").to_hsv();
+    assert_eq!((gray.h, gray.s), (0.0, 0.0));
+    assert!((gray.v - 128.0 / 255.0).abs() < 1e-9);
+}
+
+#[test]
+fn hsv_round_trip() {
+    for hex in [
+        "#000000", "#FFFFFF", "#7AA2F7", "#F7768E", "#9ECE6A", "#E0AF68",
+    ] {
+        let c = color(hex);
+        let hsv = c.to_hsv();
+        assert_eq!(Color::from_hsv(hsv.h, hsv.s, hsv.v), c, "{hex}");
+    }
+}
+
+#[test]
+fn from_hsv_clamps_and_wraps() {
+    assert_eq!(Color::from_hsv(-120.0, 2.0, 1.0), color("#0000FF"));
+    assert_eq!(Color::from_hsv(0.0, 0.0, 0.0), color("#000000"));
+}
+
 // --- OKLCH ---
 
 #[test]
@@ -423,3 +677,495 @@ fn oklch_achromatic_hue_handled() {
         "interpolation with achromatic should produce valid color"
     );
 }
+
+// --- Two-color gradient ---
+
+#[test]
+fn gradient_endpoints_are_exact() {
+    let from = color("#1A1B26");
+    let to = color("#F7768E");
+    let ramp = gradient(from, to, 7);
+    assert_eq!(ramp.len(), 7);
+    assert_eq!(ramp[0], from);
+    assert_eq!(ramp[6], to);
+}
+
+#[test]
+fn gradient_lightness_is_monotonic() {
+    let ramp = gradient(color("#000000"), color("#FFFFFF"), 10);
+    let lightness: Vec<f64> = ramp.iter().map(|c| srgb_to_oklab(*c).l).collect();
+    assert!(
+        lightness.windows(2).all(|w| w[0] < w[1]),
+        "not monotonic: {lightness:?}"
+    );
+}
+
+#[test]
+fn gradient_degenerate_step_counts() {
+    let from = color("#FF0000");
+    assert!(gradient(from, color("#0000FF"), 0).is_empty());
+    assert_eq!(&*gradient(from, color("#0000FF"), 1), &[from]);
+}
+
+// --- Ramps and shades ---
+
+#[test]
+fn ramp_endpoints_are_exact_and_keep_chroma() {
+    let from = color("#7AA2F7");
+    let to = color("#F7768E");
+    let series = ramp(from, to, 5);
+    assert_eq!((series[0], series[4]), (from, to));
+
+    let min_chroma = from.to_oklch().c.min(to.to_oklch().c);
+    let lab_mid = gradient(from, to, 5)[2].to_oklch().c;
+    let lch_mid = series[2].to_oklch().c;
+    assert!(lch_mid > lab_mid, "oklch {lch_mid} vs oklab {lab_mid}");
+    assert!(
+        lch_mid > min_chroma * 0.8,
+        "mid chroma collapsed: {lch_mid}"
+    );
+}
+
+#[test]
+fn ramp_degenerate_step_counts() {
+    let from = color("#FF0000");
+    assert!(ramp(from, color("#0000FF"), 0).is_empty());
+    assert_eq!(&*ramp(from, color("#0000FF"), 1), &[from]);
+}
+
+#[test]
+fn shades_run_light_to_dark_through_self() {
+    let accent = color("#7AA2F7");
+    let scale = accent.shades(9);
+    assert_eq!(scale.len(), 9);
+    assert_eq!(scale[4], accent);
+
+    let lightness: Vec<f64> = scale.iter().map(|c| c.to_oklch().l).collect();
+    assert!(
+        lightness.windows(2).all(|w| w[0] > w[1]),
+        "not monotonic: {lightness:?}"
+    );
+    let hue = accent.to_oklch().h;
+    for shade in &scale[1..8] {
+        let h = shade.to_oklch().h;
+        assert!((h - hue).abs() < 8.0, "{shade} hue {h} vs {hue}");
+    }
+}
+
+#[test]
+fn shades_degenerate_counts_and_alpha() {
+    let c = color("#F7768E80");
+    assert!(c.shades(0).is_empty());
+    assert_eq!(&*c.shades(1), &[c]);
+    assert!(c.shades(5).iter().all(|s| s.a == 0x80));
+}
+
+// --- Monochrome and sepia ---
+
+fn hue_distance(a: f64, b: f64) -> f64 {
+    let d = (a - b).rem_euclid(360.0);
+    d.min(360.0 - d)
+}
+
+/// Every (slot, background) ratio within 3% of the original.
+fn assert_contrast_preserved(original: &Palette, toned: &Palette, label: &str) {
+    let bg = original.base.background.unwrap();
+    let toned_bg = toned.base.background.unwrap();
+    let pairs = original
+        .syntax
+        .populated_slots()
+        .zip(toned.syntax.populated_slots())
+        .chain(
+            original
+                .semantic
+                .populated_slots()
+                .zip(toned.semantic.populated_slots()),
+        );
+    for ((name, before), (_, after)) in pairs {
+        let (a, b) = (
+            contrast_ratio(before, &bg),
+            contrast_ratio(after, &toned_bg),
+        );
+        assert!((a - b).abs() / a < 0.03, "{label} {name}: {a:.2} -> {b:.2}");
+    }
+}
+
+#[test]
+fn monochrome_uses_a_single_hue() {
+    let palette = load_preset("catppuccin").unwrap().monochrome(250.0);
+    for (name, c) in palette.syntax.populated_slots() {
+        let lch = srgb_to_oklch(*c);
+        match lch.c > 0.03 {
+            true => assert!(hue_distance(lch.h, 250.0) < 10.0, "{name}: {lch:?}"),
+            false => {}
+        }
+    }
+}
+
+#[test]
+fn monochrome_and_sepia_preserve_contrast() {
+    for id in ["tokyonight", "github_light", "gruvbox_dark"] {
+        let palette = load_preset(id).unwrap();
+        assert_contrast_preserved(&palette, &palette.monochrome(140.0), id);
+        assert_contrast_preserved(&palette, &palette.sepia(), id);
+    }
+}
+
+#[test]
+fn sepia_tints_neutrals() {
+    let palette = load_preset("github_light").unwrap().sepia();
+    let bg = srgb_to_oklch(palette.base.background.unwrap());
+    let fg = srgb_to_oklch(palette.base.foreground.unwrap());
+    assert!(hue_distance(fg.h, 70.0) < 15.0, "{fg:?}");
+    assert!(bg.l > 0.9, "{bg:?}");
+}
+
+#[test]
+fn tone_transforms_keep_meta_and_unset_slots() {
+    let mut palette = load_preset("tokyonight").unwrap();
+    palette.syntax.tag = None;
+    let toned = palette.sepia();
+    assert_eq!(toned.meta, palette.meta);
+    assert_eq!(toned.syntax.tag, None);
+    assert_eq!(toned.syntax_style, palette.syntax_style);
+}
+
+#[test]
+fn palette_shifts_match_per_color_manipulations() {
+    let palette = load_preset("gruvbox_dark").unwrap();
+    let keywords = palette.syntax.keywords.unwrap();
+    let bg = palette.base.background.unwrap();
+
+    assert_eq!(
+        palette.rotate_hue(-30.0).syntax.keywords,
+        Some(keywords.rotate_hue(-30.0))
+    );
+    assert_eq!(
+        palette.saturate(0.1).syntax.keywords,
+        Some(keywords.saturate(0.1))
+    );
+    assert_eq!(
+        palette.desaturate(0.1).syntax.keywords,
+        Some(keywords.desaturate(0.1))
+    );
+    assert_eq!(
+        palette.lighten(0.05).base.background,
+        Some(bg.lighten(0.05))
+    );
+    assert_eq!(palette.darken(0.05).base.background, Some(bg.darken(0.05)));
+    assert_eq!(palette.rotate_hue(-30.0).meta, palette.meta);
+}
+
+// --- Inverted scheme ---
+
+#[test]
+fn inverted_scheme_flips_brightness_both_ways() {
+    use palette_core::Brightness;
+    use palette_core::contrast::{ContrastLevel, validate_palette};
+
+    for (id, expected) in [
+        ("tokyonight", Brightness::Light),
+        ("gruvbox_dark", Brightness::Light),
+        ("github_light", Brightness::Dark),
+    ] {
+        let inverted = load_preset(id).unwrap().to_inverted_scheme();
+        assert_eq!(inverted.brightness(), Some(expected), "{id}");
+        assert_eq!(
+            &*inverted.meta.as_ref().unwrap().style,
+            expected.as_str(),
+            "{id}"
+        );
+        let foreground = validate_palette(&inverted, ContrastLevel::AaNormal)
+            .iter()
+            .filter(|v| &*v.foreground_label == "base.foreground")
+            .count();
+        assert_eq!(foreground, 0, "{id}");
+    }
+}
+
+#[test]
+fn inverted_scheme_keeps_accent_hues() {
+    let palette = load_preset("tokyonight").unwrap();
+    let inverted = palette.to_inverted_scheme();
+    let before = srgb_to_oklch(palette.syntax.keywords.unwrap());
+    let after = srgb_to_oklch(inverted.syntax.keywords.unwrap());
+    assert!(
+        hue_distance(before.h, after.h) < 15.0,
+        "{before:?} -> {after:?}"
+    );
+    assert_eq!(inverted.syntax.tag.is_some(), palette.syntax.tag.is_some());
+}
+
+// --- Interaction states ---
+
+#[test]
+fn interaction_states_step_away_from_background() {
+    use palette_core::SlotId;
+
+    let slot: SlotId = "surface.highlight".parse().unwrap();
+    for (id, lighter) in [("tokyonight", true), ("github_light", false)] {
+        let states = load_preset(id).unwrap().interaction_states(slot).unwrap();
+        let l = |c: Color| srgb_to_oklch(c).l;
+        let steps = [states.normal, states.hover, states.active, states.pressed].map(l);
+        for pair in steps.windows(2) {
+            assert_eq!(pair[1] > pair[0], lighter, "{id}: {steps:?}");
+        }
+    }
+}
+
+#[test]
+fn interaction_states_turn_around_at_the_lightness_limit() {
+    use palette_core::SlotId;
+
+    let mut palette = load_preset("tokyonight").unwrap();
+    palette.base.foreground = Some(Color::rgb(0xff, 0xff, 0xff));
+    let slot: SlotId = "base.foreground".parse().unwrap();
+    let states = palette.interaction_states(slot).unwrap();
+    assert_ne!(states.hover, states.normal);
+    assert!(srgb_to_oklch(states.pressed).l < srgb_to_oklch(states.hover).l);
+}
+
+#[test]
+fn interaction_states_disabled_fades_toward_background() {
+    use palette_core::SlotId;
+
+    let palette = load_preset("tokyonight").unwrap();
+    let bg = palette.base.background.unwrap();
+    let slot: SlotId = "semantic.error".parse().unwrap();
+    let states = palette.interaction_states(slot).unwrap();
+    assert!(contrast_ratio(&states.disabled, &bg) < contrast_ratio(&states.normal, &bg));
+
+    let mut palette = palette;
+    palette.semantic.error = None;
+    assert_eq!(palette.interaction_states(slot), None);
+}
+
+// --- Palette interpolation ---
+
+#[test]
+fn interpolate_moves_every_shared_slot_between_endpoints() {
+    let night = load_preset("tokyonight").unwrap();
+    let day = load_preset("tokyonight_day").unwrap();
+    let mid = night.interpolate(&day, 0.5);
+
+    let (a, b, m) = (
+        night.base.background.unwrap().relative_luminance(),
+        day.base.background.unwrap().relative_luminance(),
+        mid.base.background.unwrap().relative_luminance(),
+    );
+    assert!(a < m && m < b, "{a} {m} {b}");
+    assert_eq!(mid.meta, day.meta);
+    assert_eq!(night.interpolate(&day, 0.49).meta, night.meta);
+    assert_eq!(night.interpolate(&day, 2.0).base, day.base);
+    assert_eq!(night.interpolate(&day, f64::NAN), night);
+}
+
+#[test]
+fn interpolate_keeps_one_sided_slots() {
+    let night = load_preset("tokyonight").unwrap();
+    let mut day = load_preset("tokyonight_day").unwrap();
+    day.syntax.tag = None;
+    for t in [0.0, 0.3, 0.7, 1.0] {
+        assert_eq!(
+            night.interpolate(&day, t).syntax.tag,
+            night.syntax.tag,
+            "{t}"
+        );
+    }
+}
+
+// --- linear sRGB ---
+
+#[test]
+fn to_linear_endpoints() {
+    assert_eq!(color("#000000").to_linear(), [0.0, 0.0, 0.0]);
+    assert_eq!(color("#FFFFFF").to_linear(), [1.0, 1.0, 1.0]);
+    let [mid, _, _] = color("#808080").to_linear();
+    assert!((mid - 0.2158).abs() < 1e-4, "mid gray: {mid}");
+}
+
+#[test]
+fn linear_round_trip() {
+    for hex in ["#000000", "#7AA2F7", "#F7768E", "#010203", "#FFFFFF"] {
+        let c = color(hex);
+        assert_eq!(Color::from_linear(c.to_linear()), c, "{hex}");
+    }
+}
+
+#[test]
+fn from_linear_clips_out_of_range() {
+    assert_eq!(Color::from_linear([2.0, -1.0, 0.0]), color("#FF0000"));
+}
+
+#[test]
+fn clamp_to_srgb_gamut_keeps_in_gamut_colors() {
+    for hex in ["#000000", "#FFFFFF", "#7AA2F7", "#FF0000", "#00FF00"] {
+        let lch = srgb_to_oklch(color(hex));
+        assert_eq!(clamp_to_srgb_gamut(lch), lch, "{hex}");
+    }
+}
+
+#[test]
+fn clamp_to_srgb_gamut_reduces_only_chroma() {
+    for h in (0..360).step_by(30) {
+        let vivid = OkLch {
+            l: 0.65,
+            c: 0.5,
+            h: f64::from(h),
+        };
+        let fitted = clamp_to_srgb_gamut(vivid);
+        assert_eq!((fitted.l, fitted.h), (vivid.l, vivid.h));
+        assert!(fitted.c < vivid.c, "h={h}");
+
+        let back = srgb_to_oklch(oklab_to_srgb(oklch_to_oklab(fitted)));
+        assert!((back.l - fitted.l).abs() < 0.01, "h={h}: {back:?}");
+        let dh = (back.h - fitted.h + 540.0).rem_euclid(360.0) - 180.0;
+        assert!(dh.abs() < 2.0, "h={h}: hue drifted to {}", back.h);
+    }
+}
+
+#[test]
+fn clamp_to_srgb_gamut_is_maximal() {
+    let fitted = clamp_to_srgb_gamut(OkLch {
+        l: 0.6,
+        c: 0.4,
+        h: 250.0,
+    });
+    let beyond = OkLch {
+        c: fitted.c + 0.01,
+        ..fitted
+    };
+    assert_ne!(clamp_to_srgb_gamut(beyond), beyond);
+}
+
+#[test]
+fn clamp_to_srgb_gamut_clamps_lightness() {
+    let lch = clamp_to_srgb_gamut(OkLch {
+        l: 1.3,
+        c: 0.2,
+        h: 40.0,
+    });
+    assert_eq!(lch.l, 1.0);
+    assert!(lch.c < 1e-3);
+}
+
+#[test]
+fn invert_flips_channels_and_keeps_alpha() {
+    assert_eq!(color("#000000").invert(), color("#FFFFFF"));
+    assert_eq!(color("#1A2B3C80").invert(), color("#E5D4C380"));
+    let c = color("#7AA2F7");
+    assert_eq!(c.invert().invert(), c);
+}
+
+#[test]
+fn grayscale_preserves_luminance() {
+    for hex in ["#7AA2F7", "#F7768E", "#9ECE6A", "#1A1B26", "#E0AF68"] {
+        let c = color(hex);
+        let g = c.grayscale();
+        assert!(g.r == g.g && g.g == g.b, "{hex}: {g:?}");
+        assert!(
+            (g.relative_luminance() - c.relative_luminance()).abs() < 0.005,
+            "{hex}: {g:?}"
+        );
+    }
+    assert_eq!(color("#80808040").grayscale(), color("#80808040"));
+}
+
+#[test]
+fn grayscale_keeps_contrast() {
+    let fg = color("#C0CAF5");
+    let bg = color("#1A1B26");
+    let before = contrast_ratio(&fg, &bg);
+    let after = contrast_ratio(&fg.grayscale(), &bg.grayscale());
+    assert!((before - after).abs() < 0.1, "{before} vs {after}");
+}
+
+#[test]
+fn over_opaque_fg_replaces_bg() {
+    let fg = color("#7AA2F7");
+    assert_eq!(fg.over(color("#1A1B2680")), fg);
+}
+
+#[test]
+fn over_transparent_fg_keeps_bg() {
+    let bg = color("#1A1B2680");
+    assert_eq!(color("#FF000000").over(bg), bg);
+    assert_eq!(
+        over(color("#FF000000"), color("#00FF0000")),
+        color("#00000000")
+    );
+}
+
+#[test]
+fn over_opaque_bg_matches_blend() {
+    let fg = color("#FF000080");
+    let bg = color("#0000FF");
+    assert_eq!(fg.over(bg), blend(fg, bg, fg.alpha_f64()));
+}
+
+#[test]
+fn over_combines_translucent_alpha() {
+    let result = color("#FFFFFF80").over(color("#00000080"));
+    // 0.5 + 0.5 * 0.5 = 0.75 coverage; white contributes 2/3 of it.
+    assert_eq!(result.a, 192);
+    assert_channel_eq(result, color("#AAAAAA"), 1, "half over half");
+}
+
+#[test]
+fn over_chains_associatively() {
+    let a = color("#7AA2F740");
+    let b = color("#F7768E80");
+    let c = color("#1A1B26");
+    let left = a.over(b).over(c);
+    let right = a.over(b.over(c));
+    assert_channel_eq(left, right, 1, "associativity");
+    assert!(left.is_opaque());
+}
+
+#[test]
+fn scale_has_tailwind_steps() {
+    let scale = color("#7AA2F7").scale();
+    let steps: Vec<u16> = scale.iter().map(|(step, _)| step).collect();
+    assert_eq!(steps, ColorScale::STEPS);
+    assert_eq!(scale.get(500), Some(scale.colors()[5]));
+    assert_eq!(scale.get(550), None);
+    assert_eq!(scale.get(950), None);
+}
+
+#[test]
+fn scale_runs_light_to_dark_and_keeps_hue() {
+    for hex in ["#7AA2F7", "#F7768E", "#9ECE6A", "#E0AF68", "#BB9AF7"] {
+        let source = color(hex);
+        let scale = source.scale();
+        assert!(scale.colors().contains(&source), "{hex}");
+        for pair in scale.colors().windows(2) {
+            assert!(
+                pair[0].relative_luminance() > pair[1].relative_luminance(),
+                "{hex}: {pair:?}"
+            );
+        }
+        let hue = source.to_oklch().h;
+        for c in &scale.colors()[2..] {
+            let dh = (c.to_oklch().h - hue + 540.0).rem_euclid(360.0) - 180.0;
+            assert!(dh.abs() < 6.0, "{hex}: {c:?}");
+        }
+    }
+}
+
+#[test]
+fn scale_aligns_across_accents() {
+    let blue = color("#7AA2F7").scale();
+    let red = color("#F7768E").scale();
+    for step in [50, 300, 900] {
+        let (Some(b), Some(r)) = (blue.get(step), red.get(step)) else {
+            panic!("missing step {step}");
+        };
+        assert!((b.to_oklch().l - r.to_oklch().l).abs() < 0.02, "{step}");
+    }
+}
+
+#[test]
+fn scale_keeps_alpha() {
+    let scale = color("#7AA2F780").scale();
+    assert!(scale.colors().iter().all(|c| c.a == 0x80));
+}
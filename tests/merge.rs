@@ -2,7 +2,10 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use palette_core::manifest::{ManifestSection, PaletteManifest};
-use palette_core::merge::merge_manifests;
+use palette_core::merge::{
+    MergeOptions, SlotChange, merge_layers, merge_layers_with_provenance, merge_manifests,
+    merge_manifests_mapped, merge_manifests_with,
+};
 
 fn section(pairs: &[(&str, &str)]) -> ManifestSection {
     pairs
@@ -192,11 +195,21 @@ stops = ["#FF0000", "#0000FF"]
     // Variant's red and blue, not base's black and white
     assert_eq!(
         stops[0].color,
-        palette_core::color::Color { r: 255, g: 0, b: 0 }
+        palette_core::color::Color {
+            r: 255,
+            g: 0,
+            b: 0,
+            a: 255
+        }
     );
     assert_eq!(
         stops[1].color,
-        palette_core::color::Color { r: 0, g: 0, b: 255 }
+        palette_core::color::Color {
+            r: 0,
+            g: 0,
+            b: 255,
+            a: 255
+        }
     );
 }
 
@@ -267,3 +280,247 @@ fn real_preset_tokyonight_storm_merge() {
     let meta = merged.meta.as_ref().unwrap();
     assert_eq!(&*meta.preset_id, "tokyonight_storm");
 }
+
+#[test]
+fn extensions_survive_merge() {
+    let base = PaletteManifest::from_toml(
+        "[base]\n\n[x-myapp]\nfont = \"Iosevka\"\nwidth = 2\n\n[x-other]\nkeep = true\n",
+    )
+    .unwrap();
+    let variant = PaletteManifest::from_toml("[base]\n\n[x-myapp]\nwidth = 3\n").unwrap();
+    let merged = merge_manifests(&variant, &base);
+
+    let ext = &merged.extensions["x-myapp"];
+    assert_eq!(ext["font"].as_str(), Some("Iosevka"));
+    assert_eq!(ext["width"].as_integer(), Some(3));
+    assert_eq!(merged.extensions["x-other"]["keep"].as_bool(), Some(true));
+}
+
+#[test]
+fn diff_classifies_variant_slots() {
+    let variant = make_manifest(
+        "V",
+        "v",
+        section(&[("bg", "#111"), ("fg", "#aaa"), ("border", "#333")]),
+        empty(),
+    );
+    let base = make_manifest(
+        "B",
+        "b",
+        section(&[("bg", "#222"), ("fg", "#aaa"), ("cursor", "#fff")]),
+        section(&[("black", "#000")]),
+    );
+
+    let diff = variant.diff(&base);
+
+    let fields = |changes: &[SlotChange]| -> Vec<String> {
+        changes
+            .iter()
+            .map(|c| format!("{}.{}", c.section, c.field))
+            .collect()
+    };
+    assert_eq!(fields(&diff.overridden), ["base.bg"]);
+    assert_eq!(fields(&diff.added), ["base.border"]);
+    assert_eq!(fields(&diff.inherited), ["base.cursor", "terminal.black"]);
+    assert_eq!(fields(&diff.redundant), ["base.fg"]);
+    assert_eq!(diff.overridden[0].parent.as_deref(), Some("#222"));
+    assert_eq!(diff.overridden[0].variant.as_deref(), Some("#111"));
+    assert!(!diff.is_unchanged());
+}
+
+#[test]
+fn diff_counts_opacity_as_part_of_the_value() {
+    let parent = PaletteManifest::from_toml("[base]\n\n[surface]\nmenu = \"#16161e\"\n").unwrap();
+    let variant = PaletteManifest::from_toml(
+        "[base]\n\n[surface]\nmenu = { color = \"#16161e\", opacity = 0.5 }\n",
+    )
+    .unwrap();
+
+    assert_eq!(variant.diff(&parent).overridden.len(), 1);
+    assert!(parent.diff(&parent).is_unchanged());
+}
+
+#[test]
+fn unset_removes_parent_slot() {
+    let parent = PaletteManifest::from_toml(
+        "[base]\nbackground = \"#1a1b26\"\nborder = \"#15161e\"\n\n[surface]\nmenu = { color = \"#16161e\", opacity = 0.5 }\n",
+    )
+    .unwrap();
+    let variant =
+        PaletteManifest::from_toml("[base]\nborder = \"unset\"\n\n[surface]\nmenu = \"unset\"\n")
+            .unwrap();
+
+    let merged = merge_manifests(&variant, &parent);
+
+    assert!(!merged.base.contains_key("border"));
+    assert!(merged.base.contains_key("background"));
+    assert!(!merged.surface.contains_key("menu"));
+    assert!(
+        !merged
+            .opacity
+            .get("surface")
+            .is_some_and(|o| o.contains_key("menu"))
+    );
+    assert!(!variant.base.contains_key("border"));
+}
+
+#[test]
+fn unset_slot_can_be_set_again_further_down() {
+    let grandparent = PaletteManifest::from_toml("[base]\nborder = \"#15161e\"\n").unwrap();
+    let parent = PaletteManifest::from_toml("[base]\nborder = \"unset\"\n").unwrap();
+    let child = PaletteManifest::from_toml("[base]\nborder = \"#000000\"\n").unwrap();
+
+    let resolved_parent = merge_manifests(&parent, &grandparent);
+    assert!(!resolved_parent.base.contains_key("border"));
+
+    let merged = merge_manifests(&child, &resolved_parent);
+    assert_eq!(merged.base["border"].as_ref(), "#000000");
+}
+
+#[test]
+fn unset_in_mode_overlay_removes_slot() {
+    let manifest = PaletteManifest::from_toml(
+        "[base]\nborder = \"#15161e\"\n\n[when.flat.base]\nborder = \"unset\"\n",
+    )
+    .unwrap();
+
+    assert!(!manifest.with_modes(&["flat"]).base.contains_key("border"));
+    assert!(manifest.base.contains_key("border"));
+}
+
+#[test]
+fn unset_round_trips_through_to_toml() {
+    let manifest = PaletteManifest::from_toml("[base]\n\n[editor]\ncursor = \"unset\"\n").unwrap();
+    let reparsed = PaletteManifest::from_toml(&manifest.to_toml()).unwrap();
+    assert_eq!(reparsed.unset, manifest.unset);
+}
+
+#[test]
+fn unset_is_rejected_outside_color_slots() {
+    assert!(PaletteManifest::from_toml("[base]\n\n[colors]\nblue = \"unset\"\n").is_err());
+}
+
+#[test]
+fn diff_lists_removed_slots() {
+    let parent = PaletteManifest::from_toml("[base]\nborder = \"#15161e\"\n").unwrap();
+    let variant = PaletteManifest::from_toml("[base]\nborder = \"unset\"\n").unwrap();
+
+    let diff = variant.diff(&parent);
+
+    assert_eq!(diff.removed.len(), 1);
+    assert_eq!(diff.removed[0].parent.as_deref(), Some("#15161e"));
+    assert!(diff.inherited.is_empty());
+}
+
+#[test]
+fn merge_layers_applies_later_layers_last() {
+    let base = make_manifest(
+        "Base",
+        "base",
+        section(&[("bg", "#111"), ("foreground", "#aaa"), ("border", "#333")]),
+        empty(),
+    );
+    let preset = make_manifest("Preset", "preset", section(&[("bg", "#222")]), empty());
+    let user =
+        PaletteManifest::from_toml("[base]\nforeground = \"#fff\"\nborder = \"unset\"\n").unwrap();
+
+    let merged = merge_layers(&[&base, &preset, &user]);
+
+    assert_eq!(merged.base["bg"].as_ref(), "#222");
+    assert_eq!(merged.base["foreground"].as_ref(), "#fff");
+    assert!(!merged.base.contains_key("border"));
+    assert_eq!(merged.meta.unwrap().preset_id.as_ref(), "preset");
+}
+
+#[test]
+fn merge_layers_of_one_or_none() {
+    let base = make_manifest("Base", "base", section(&[("bg", "#111")]), empty());
+    assert_eq!(merge_layers(&[&base]).base, base.base);
+    assert!(merge_layers(&[]).base.is_empty());
+}
+
+#[test]
+fn provenance_names_the_layer_that_supplied_each_slot() {
+    let base = make_manifest(
+        "Base",
+        "base",
+        section(&[("bg", "#111"), ("border", "#333")]),
+        section(&[("black", "#000")]),
+    );
+    let preset = make_manifest("Preset", "preset", section(&[("bg", "#222")]), empty());
+    let user = PaletteManifest::from_toml(
+        "[base]\nborder = \"unset\"\n\n[terminal]\nblack = \"#010101\"\n",
+    )
+    .unwrap();
+
+    let (merged, provenance) = merge_layers_with_provenance(&[&base, &preset, &user]);
+
+    assert_eq!(merged.base, merge_layers(&[&base, &preset, &user]).base);
+    assert_eq!(provenance.source("base", "bg"), Some(1));
+    assert_eq!(provenance.source("base", "border"), None);
+    assert_eq!(provenance.source("terminal", "black"), Some(2));
+    let from_user: Vec<_> = provenance.from_layer(2).collect();
+    assert_eq!(from_user, [("terminal", "black")]);
+}
+
+#[test]
+fn merge_with_inherits_only_listed_sections() {
+    let parent = PaletteManifest::from_toml(
+        "[base]\nbackground = \"#1a1b26\"\n\n[surface]\nmenu = { color = \"#16161e\", opacity = 0.5 }\n\n[terminal]\nred = \"#f7768e\"\n\n[x-app]\nkey = 1\n",
+    )
+    .unwrap();
+    let variant = PaletteManifest::from_toml("[base]\nforeground = \"#c0caf5\"\n").unwrap();
+
+    let options = MergeOptions::default().section("surface").section("x-app");
+    let merged = merge_manifests_with(&variant, &parent, &options);
+
+    assert!(!merged.base.contains_key("background"));
+    assert!(merged.base.contains_key("foreground"));
+    assert!(merged.terminal.is_empty());
+    assert!(merged.opacity["surface"].contains_key("menu"));
+    assert!(merged.extensions.contains_key("x-app"));
+
+    let all = merge_manifests_with(&variant, &parent, &MergeOptions::default());
+    assert_eq!(all.terminal, parent.terminal);
+}
+
+#[test]
+fn merge_options_read_from_meta() {
+    let variant = PaletteManifest::from_toml(
+        "[meta]\nname = \"V\"\npreset_id = \"v\"\nschema_version = \"1\"\nstyle = \"dark\"\nkind = \"preset-variant\"\ninherit_sections = [\"syntax\"]\n\n[base]\n",
+    )
+    .unwrap();
+
+    let options = MergeOptions::from_manifest(&variant);
+    assert_eq!(options, MergeOptions::default().section("syntax"));
+
+    let reparsed = PaletteManifest::from_toml(&variant.to_toml()).unwrap();
+    assert_eq!(MergeOptions::from_manifest(&reparsed), options);
+}
+
+#[test]
+fn mapped_merge_rewrites_only_inherited_colors() {
+    let parent = PaletteManifest::from_toml(
+        "[colors]\nnight = \"#202020\"\n\n[base]\nbackground = \"#808080\"\nforeground = \"#c0caf5\"\nborder = \"$night\"\n",
+    )
+    .unwrap();
+    let variant = PaletteManifest::from_toml("[base]\nforeground = \"#ffffff\"\n").unwrap();
+
+    let mut seen = Vec::new();
+    let merged = merge_manifests_mapped(
+        &variant,
+        &parent,
+        &MergeOptions::default(),
+        |section, field, _| {
+            seen.push(format!("{section}.{field}"));
+            palette_core::Color::rgb(0, 0, 0)
+        },
+    );
+    seen.sort();
+
+    assert_eq!(seen, ["base.background", "colors.night"]);
+    assert_eq!(merged.base["background"].as_ref(), "#000000");
+    assert_eq!(merged.base["foreground"].as_ref(), "#ffffff");
+    assert_eq!(merged.base["border"].as_ref(), "$night");
+    assert_eq!(merged.colors["night"].as_ref(), "#000000");
+}
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use palette_core::color::Color;
 use palette_core::error::PaletteError;
+use palette_core::gradient::GradientColor;
 use palette_core::manifest::PaletteManifest;
 use palette_core::merge::merge_manifests;
 use palette_core::palette::Palette;
@@ -50,6 +52,39 @@ background = "#000000"
     assert!(palette.terminal.red.is_none());
 }
 
+#[test]
+fn css_color_functions_resolve() {
+    let toml = r##"
+[base]
+background = "rgb(26 27 42)"
+foreground = "hsl(0, 100%, 50%)"
+border = "rgba(255, 255, 255, 0.25)"
+"##;
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    let palette = Palette::from_manifest(&manifest).unwrap();
+
+    assert_eq!(palette.base.background, Some(Color::rgb(26, 27, 42)));
+    assert_eq!(palette.base.foreground, Some(Color::rgb(255, 0, 0)));
+    assert_eq!(palette.base.border, Some(Color::rgba(255, 255, 255, 64)));
+}
+
+#[test]
+fn css_color_function_gradient_stop() {
+    let toml = r##"
+[base]
+background = "#000000"
+
+[gradient.fade]
+stops = ["rgb(0 0 0)", "hsl(0 0% 100%)"]
+"##;
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    let palette = Palette::from_manifest(&manifest).unwrap();
+    let resolved = palette.resolve();
+    let gradient = resolved.gradient("fade").unwrap();
+
+    assert_eq!(gradient.at(1.0), Color::rgb(255, 255, 255));
+}
+
 #[test]
 fn invalid_hex_returns_error() {
     let toml = r##"
@@ -271,3 +306,243 @@ stops = [
         "expected MixedGradientStopKinds, got: {err:?}",
     );
 }
+
+#[test]
+fn map_colors_touches_slots_and_literal_gradient_stops() {
+    let toml = r##"
+[base]
+background = "#102030"
+
+[gradient.mixed]
+stops = ["#405060", "base.background"]
+"##;
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    let palette = Palette::from_manifest(&manifest).unwrap();
+    let invert = |c: Color| Color {
+        r: 255 - c.r,
+        g: 255 - c.g,
+        b: 255 - c.b,
+        a: c.a,
+    };
+    let inverted = palette.map_colors(invert);
+
+    assert_eq!(
+        inverted.base.background,
+        Some(invert(Color::from_hex("#102030").unwrap()))
+    );
+    assert_eq!(inverted.base.foreground, None);
+    let stops = inverted.gradients[0].1.stops();
+    assert_eq!(
+        stops[0].0,
+        GradientColor::Literal(invert(Color::from_hex("#405060").unwrap()))
+    );
+    assert_eq!(stops[1].0, palette.gradients[0].1.stops()[1].0);
+    // Token stops follow the mapped slot on resolve.
+    let resolved = inverted.resolve();
+    assert_eq!(
+        resolved.gradient("mixed").unwrap().at(1.0),
+        inverted.base.background.unwrap()
+    );
+}
+
+#[test]
+fn palette_exposes_extensions() {
+    let manifest = PaletteManifest::from_toml("[base]\n\n[x-myapp]\nfont = \"Iosevka\"\n").unwrap();
+    let palette = Palette::from_manifest(&manifest).unwrap();
+    let ext = palette.extension("x-myapp").unwrap();
+    assert_eq!(ext["font"].as_str(), Some("Iosevka"));
+    assert!(palette.extension("x-missing").is_none());
+}
+
+fn translucent(toml: &str) -> Palette {
+    Palette::from_manifest(&PaletteManifest::from_toml(toml).unwrap()).unwrap()
+}
+
+#[test]
+fn opacity_resolves_to_alpha() {
+    let palette = translucent(
+        "[base]\nbackground = \"#000000\"\n\n\
+         [surface]\nselection = { color = \"#7aa2f7\", opacity = 0.5 }\n",
+    );
+    assert_eq!(
+        palette.surface.selection,
+        Some(Color::from_hex("#7AA2F780").unwrap())
+    );
+}
+
+#[test]
+fn blended_opacity_resolves_over_background() {
+    let palette = translucent(
+        "[base]\nbackground = \"#000000\"\n\n\
+         [surface]\noverlay = { color = \"#ffffff\", opacity = 0.25, blend = true }\n",
+    );
+    assert_eq!(
+        palette.surface.overlay,
+        Some(Color::from_hex("#404040").unwrap())
+    );
+}
+
+#[test]
+fn opacity_applies_to_color_variables() {
+    let palette = translucent(
+        "[colors]\nblue = \"#7aa2f7\"\n\n[base]\n\n\
+         [surface]\nselection = { color = \"$blue\", opacity = 0.5 }\n",
+    );
+    assert_eq!(palette.surface.selection.unwrap().a, 0x80);
+}
+
+#[test]
+fn variant_color_without_opacity_is_opaque() {
+    let base = PaletteManifest::from_toml(
+        "[base]\n\n[surface]\nselection = { color = \"#7aa2f7\", opacity = 0.5 }\n\
+         menu = { color = \"#16161e\", opacity = 0.5 }\n",
+    )
+    .unwrap();
+    let variant = PaletteManifest::from_toml("[base]\n\n[surface]\nmenu = \"#1f2335\"\n").unwrap();
+    let palette = Palette::from_manifest(&merge_manifests(&variant, &base)).unwrap();
+    assert_eq!(palette.surface.selection.unwrap().a, 0x80);
+    assert_eq!(
+        palette.surface.menu,
+        Some(Color::from_hex("#1f2335").unwrap())
+    );
+}
+
+const MANY_ERRORS_TOML: &str = r##"
+[colors]
+night = "#1a1b26"
+broken = "#12345"
+
+[base]
+background = "$night"
+foreground = "#nothex"
+
+[semantic]
+error = "$missing"
+warning = "$broken"
+
+[syntax_style]
+keywords = "bold+shiny"
+
+[gradient.one]
+stops = ["#000000"]
+
+[gradient.two]
+stops = [{ color = "#000000", at = 0.5 }, { color = "#ffffff", at = 0.2 }]
+
+[gradient.fine]
+stops = ["$night", "base.background"]
+"##;
+
+#[test]
+fn collecting_reports_every_error() {
+    let manifest = PaletteManifest::from_toml(MANY_ERRORS_TOML).unwrap();
+    let errors = Palette::from_manifest_collecting(&manifest).unwrap_err();
+
+    assert!(matches!(
+        errors[0],
+        PaletteError::InsufficientStops { count: 1 }
+    ));
+    assert!(matches!(errors[1], PaletteError::UnsortedStops));
+    let slots: Vec<(&str, &str)> = errors[2..]
+        .iter()
+        .map(|err| match err {
+            PaletteError::InvalidHex { section, field, .. }
+            | PaletteError::InvalidStyle { section, field, .. }
+            | PaletteError::UnknownColorVariable { section, field, .. } => {
+                (section.as_ref(), field.as_ref())
+            }
+            other => panic!("unexpected error: {other:?}"),
+        })
+        .collect();
+    assert_eq!(
+        slots,
+        [
+            ("base", "foreground"),
+            ("colors", "broken"),
+            ("semantic", "error"),
+            ("syntax_style", "keywords"),
+        ]
+    );
+}
+
+#[test]
+fn collecting_matches_from_manifest_for_valid_manifests() {
+    let manifest = common::load_preset("tokyonight");
+    assert_eq!(
+        Palette::from_manifest_collecting(&manifest).unwrap(),
+        Palette::from_manifest(&manifest).unwrap()
+    );
+}
+
+#[test]
+fn collecting_reports_a_single_error_alone() {
+    let manifest = PaletteManifest::from_toml("[base]\nbackground = \"#nothex\"\n").unwrap();
+    let errors = Palette::from_manifest_collecting(&manifest).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0], PaletteError::InvalidHex { .. }));
+}
+
+#[test]
+fn merged_over_matches_manifest_merge() {
+    let base = common::load_preset("tokyonight");
+    let storm = common::load_preset("tokyonight_storm");
+    let expected = Palette::from_manifest(&merge_manifests(&storm, &base)).unwrap();
+
+    let merged = Palette::from_manifest(&storm)
+        .unwrap()
+        .merged_over(&Palette::from_manifest(&base).unwrap());
+
+    assert_eq!(merged.base, expected.base);
+    assert_eq!(merged.terminal, expected.terminal);
+    assert_eq!(merged.syntax_style, expected.syntax_style);
+    assert_eq!(merged.meta, expected.meta);
+}
+
+#[test]
+fn merged_over_keeps_other_gradients_and_meta() {
+    let theme = Palette::from_manifest(&PaletteManifest::from_toml(
+        "[meta]\nname = \"T\"\npreset_id = \"t\"\nschema_version = \"1\"\nstyle = \"dark\"\nkind = \"preset-base\"\n\n[base]\nbackground = \"#1a1b26\"\n\n[gradient.heat]\nstops = [\"#000000\", \"#ffffff\"]\n",
+    ).unwrap())
+    .unwrap();
+    let user = Palette::from_manifest(
+        &PaletteManifest::from_toml("[base]\nforeground = \"#c0caf5\"\n").unwrap(),
+    )
+    .unwrap();
+
+    let merged = user.merged_over(&theme);
+
+    assert_eq!(merged.base.background, theme.base.background);
+    assert_eq!(merged.base.foreground, user.base.foreground);
+    assert_eq!(merged.gradients.len(), 1);
+    assert_eq!(merged.meta, theme.meta);
+}
+
+#[test]
+fn brightness_follows_background_not_style_tag() {
+    use palette_core::Brightness;
+
+    for (id, expected) in [
+        ("tokyonight_storm", Brightness::Dark),
+        ("rose_pine_dawn", Brightness::Light),
+        ("catppuccin_latte", Brightness::Light),
+    ] {
+        let palette = palette_core::load_preset(id).unwrap();
+        assert_eq!(palette.brightness(), Some(expected), "{id}");
+        assert_eq!(palette.resolve().brightness(), expected, "{id}");
+    }
+    assert!(palette_core::load_preset("ayu_mirage").unwrap().is_dark());
+}
+
+#[test]
+fn brightness_without_background_uses_plain_style_tag() {
+    use palette_core::Brightness;
+
+    let mut palette = palette_core::load_preset("github_light").unwrap();
+    palette.base.background = None;
+    assert_eq!(palette.brightness(), Some(Brightness::Light));
+
+    let mut palette = palette_core::load_preset("tokyonight_day").unwrap();
+    palette.base.background = None;
+    assert_eq!(palette.brightness(), None);
+    assert!(!palette.is_dark());
+}
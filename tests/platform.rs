@@ -1,6 +1,10 @@
 #![cfg(feature = "platform")]
 
+use palette_core::color::Color;
+use palette_core::contrast::{ContrastLevel, validate_palette};
+use palette_core::manifest::PaletteManifest;
 use palette_core::palette::Palette;
+use palette_core::platform::PlatformOverride;
 use palette_core::registry::load_preset;
 
 mod common;
@@ -77,3 +81,70 @@ fn platform_override_resolves_hex_values() {
     let bg = terminal.background.unwrap();
     assert_eq!(&*bg.to_hex(), "#16161E");
 }
+
+#[test]
+fn validate_checks_platform_background() {
+    let mut palette = load_preset("tokyonight").unwrap();
+    let foreground = palette.base.foreground;
+    palette.platform.insert(
+        "web".into(),
+        PlatformOverride {
+            background: foreground,
+            foreground: None,
+        },
+    );
+
+    let violations = validate_palette(&palette, ContrastLevel::AaNormal);
+    let v = violations
+        .iter()
+        .find(|v| v.background_label.as_ref() == "platform.web.background")
+        .unwrap();
+    assert_eq!(v.foreground_label.as_ref(), "base.foreground");
+    assert_eq!(v.ratio, 1.0);
+}
+
+#[test]
+fn validate_uses_platform_foreground() {
+    let mut palette = load_preset("tokyonight").unwrap();
+    palette.platform.insert(
+        "terminal".into(),
+        PlatformOverride {
+            background: None,
+            foreground: palette.base.background,
+        },
+    );
+
+    let violations = validate_palette(&palette, ContrastLevel::AaNormal);
+    assert!(violations.iter().any(|v| {
+        v.foreground_label.as_ref() == "platform.terminal.foreground"
+            && v.background_label.as_ref() == "base.background"
+    }));
+}
+
+#[test]
+fn validate_passes_readable_platform_override() {
+    let mut palette = load_preset("tokyonight").unwrap();
+    palette.platform.clear();
+    let baseline = validate_palette(&palette, ContrastLevel::AaNormal).len();
+    palette.platform.insert(
+        "web".into(),
+        PlatformOverride {
+            background: Some(Color::from_hex("#000000").unwrap()),
+            foreground: None,
+        },
+    );
+
+    let violations = validate_palette(&palette, ContrastLevel::AaNormal);
+    assert_eq!(violations.len(), baseline);
+}
+
+#[test]
+fn platform_opacity_blends_over_platform_background() {
+    let toml = "[base]\nbackground = \"#000000\"\n\n\
+                [platform.web]\nbackground = \"#ffffff\"\n\
+                foreground = { color = \"#000000\", opacity = 0.5, blend = true }\n";
+    let manifest = PaletteManifest::from_toml(toml).unwrap();
+    let palette = Palette::from_manifest(&manifest).unwrap();
+    let web = palette.platform.get("web").unwrap();
+    assert_eq!(web.foreground, Some(Color::from_hex("#808080").unwrap()));
+}
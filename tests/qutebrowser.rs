@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use palette_core::export::find_exporter;
+use palette_core::export::qutebrowser::{FILE_NAME, to_qutebrowser};
+use palette_core::load_preset;
+
+#[test]
+fn statusbar_uses_surface_statusline() {
+    let resolved = load_preset("one_dark").unwrap().resolve();
+    let py = to_qutebrowser(&resolved);
+
+    assert!(py.contains(&format!(
+        "c.colors.statusbar.normal.bg = \"{}\"\n",
+        resolved.surface.statusline
+    )));
+}
+
+#[test]
+fn messages_use_semantic_colors() {
+    let resolved = load_preset("one_dark").unwrap().resolve();
+    let py = to_qutebrowser(&resolved);
+
+    assert!(py.contains(&format!(
+        "c.colors.messages.error.bg = \"{}\"\n",
+        resolved.semantic.error
+    )));
+    assert!(py.contains(&format!(
+        "c.colors.messages.warning.bg = \"{}\"\n",
+        resolved.semantic.warning
+    )));
+}
+
+#[test]
+fn covers_tabs_completion_and_hints() {
+    let resolved = load_preset("one_dark").unwrap().resolve();
+    let py = to_qutebrowser(&resolved);
+
+    for prefix in ["c.colors.tabs.", "c.colors.completion.", "c.colors.hints."] {
+        assert!(py.contains(prefix), "missing {prefix}");
+    }
+}
+
+#[test]
+fn every_line_is_comment_or_assignment() {
+    let resolved = load_preset("one_dark").unwrap().resolve();
+    let py = to_qutebrowser(&resolved);
+
+    assert!(
+        py.lines()
+            .all(|l| l.starts_with("# ") || l.starts_with("c.colors."))
+    );
+}
+
+#[test]
+fn line_breaks_in_theme_name_stay_in_the_comment() {
+    let mut resolved = load_preset("one_dark").unwrap().resolve();
+    if let Some(meta) = resolved.meta.as_mut() {
+        Arc::make_mut(meta).name = Arc::from("One\nimport os\rDark");
+    }
+    let py = to_qutebrowser(&resolved);
+
+    assert!(py.starts_with("# Generated by palette-core from One import os Dark.\n"));
+    assert!(
+        py.lines()
+            .all(|l| l.starts_with("# ") || l.starts_with("c.colors."))
+    );
+}
+
+#[test]
+fn exporter_writes_python_file() {
+    let palette = load_preset("nord").unwrap();
+    let files = find_exporter("qutebrowser").unwrap().export(&palette);
+
+    assert_eq!(files.len(), 1);
+    assert_eq!(&*files[0].path, FILE_NAME);
+}
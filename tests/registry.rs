@@ -2,8 +2,12 @@ use std::io::Write;
 use std::sync::Arc;
 
 use palette_core::color::Color;
-use palette_core::error::PaletteError;
-use palette_core::registry::{load_preset, load_preset_file, preset_ids};
+use palette_core::error::{ChainLink, PaletteError};
+use palette_core::manifest::PaletteManifest;
+use palette_core::registry::{
+    LoadOptions, load_preset, load_preset_file, load_preset_file_with, preset, preset_ids,
+    preset_manifest,
+};
 use palette_core::{Registry, ThemeInfo};
 
 #[test]
@@ -63,6 +67,39 @@ fn load_preset_resolves_inheritance() {
     );
 }
 
+#[test]
+fn compiled_presets_match_source_toml() {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("presets");
+    for id in preset_ids() {
+        let from_source = load_preset_file(&dir.join(format!("{id}.toml"))).unwrap();
+        assert_eq!(load_preset(id).unwrap(), from_source, "{id}");
+    }
+}
+
+#[test]
+fn compiled_manifests_match_parsed_source() {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("presets");
+    for id in preset_ids() {
+        let source = std::fs::read_to_string(dir.join(format!("{id}.toml"))).unwrap();
+        let parsed = PaletteManifest::from_toml(&source).unwrap();
+        assert_eq!(preset_manifest(id).unwrap(), parsed, "{id}");
+    }
+}
+
+#[test]
+fn preset_returns_shared_cached_palette() {
+    let first = preset("tokyonight_storm").unwrap();
+    let second = preset("tokyonight_storm").unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(*first, load_preset("tokyonight_storm").unwrap());
+}
+
+#[test]
+fn preset_unknown_id_returns_error() {
+    let result = preset("nonexistent");
+    assert!(matches!(result, Err(PaletteError::UnknownPreset(_))));
+}
+
 #[test]
 fn preset_ids_list_is_complete() {
     assert_eq!(preset_ids().len(), 31);
@@ -209,7 +246,79 @@ fn file_preset_missing_parent_returns_error() {
     let path = write_temp_file(&dir, "orphan.toml", VARIANT_MISSING_PARENT_TOML);
 
     let result = load_preset_file(&path);
-    assert!(matches!(result, Err(PaletteError::UnknownPreset(_))));
+    let Err(PaletteError::Inheritance { chain, source }) = result else {
+        panic!("expected inheritance error, got {result:?}");
+    };
+    assert!(
+        matches!(*source, PaletteError::UnknownPreset(ref id) if id.as_ref() == "no_such_preset")
+    );
+    let expected_path: Arc<str> = Arc::from(path.to_string_lossy().as_ref());
+    assert_eq!(
+        *chain,
+        [
+            ChainLink {
+                id: Arc::from("orphan"),
+                path: Some(expected_path),
+            },
+            ChainLink {
+                id: Arc::from("no_such_preset"),
+                path: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn file_preset_blames_parent_for_inherited_invalid_value() {
+    let dir = tempfile::tempdir().unwrap();
+    let base_path = write_temp_file(
+        &dir,
+        "sibling_base.toml",
+        &BASE_TOML.replace("#eeeeee", "#nothex"),
+    );
+    let path = write_temp_file(&dir, "sibling_variant.toml", VARIANT_SIBLING_TOML);
+
+    let err = load_preset_file(&path).unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("sibling_variant ("), "{message}");
+    assert!(
+        message.contains(&format!("sibling_base ({})", base_path.display())),
+        "{message}"
+    );
+    assert_eq!(err.code(), "PC0005");
+    let PaletteError::Inheritance { source, .. } = err else {
+        panic!("expected inheritance error");
+    };
+    assert!(matches!(*source, PaletteError::InvalidHex { .. }));
+}
+
+#[test]
+fn file_preset_own_invalid_value_is_not_wrapped() {
+    let dir = tempfile::tempdir().unwrap();
+    write_temp_file(&dir, "sibling_base.toml", BASE_TOML);
+    let variant = VARIANT_SIBLING_TOML.replace("[base]\n", "[base]\nborder = \"oops\"\n");
+    assert_ne!(variant, VARIANT_SIBLING_TOML);
+    let path = write_temp_file(&dir, "sibling_variant.toml", &variant);
+
+    let result = load_preset_file(&path);
+    assert!(matches!(result, Err(PaletteError::InvalidHex { .. })));
+}
+
+#[test]
+fn registry_missing_parent_names_registered_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_temp_file(&dir, "orphan.toml", VARIANT_MISSING_PARENT_TOML);
+    let mut reg = Registry::new();
+    reg.add_file(&path).unwrap();
+
+    let err = reg.load("orphan").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "while resolving orphan ({}) → no_such_preset: unknown preset: no_such_preset",
+            path.display()
+        )
+    );
 }
 
 // ---------------------------------------------------------------------------
@@ -329,6 +438,149 @@ fn registry_add_dir_loads_all_toml_files() {
     assert_eq!(reg.list().count(), 33);
 }
 
+#[test]
+fn registry_add_dir_reports_malformed_file() {
+    let dir = tempfile::tempdir().unwrap();
+    write_temp_file(&dir, "broken.toml", "[meta\nname = ");
+
+    let mut reg = Registry::new();
+    let result = reg.add_dir(dir.path());
+    assert!(matches!(result, Err(PaletteError::Parse(_))), "{result:?}");
+}
+
+#[test]
+fn registry_add_file_defers_full_parse_to_load() {
+    let toml = r##"
+[meta]
+name = "Lazy"
+preset_id = "lazy"
+schema_version = "1"
+style = "light"
+kind = "preset-base"
+
+[base]
+background = "#fafafa"
+
+[syntax]
+keywords = 5
+"##;
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_temp_file(&dir, "lazy.toml", toml);
+
+    let mut reg = Registry::new();
+    reg.add_file(&path).unwrap();
+    let info = reg.list().find(|t| t.id.as_ref() == "lazy").unwrap();
+    assert!(info.is_light);
+    assert!(matches!(reg.load("lazy"), Err(PaletteError::Parse(_))));
+}
+
+#[test]
+fn registry_add_dir_reads_inherited_background_for_is_light() {
+    let dir = tempfile::tempdir().unwrap();
+    write_temp_file(&dir, "sibling_base.toml", BASE_TOML);
+    let variant = r##"
+[meta]
+name = "No Background"
+preset_id = "no_background"
+schema_version = "1"
+style = "dark"
+kind = "preset-variant"
+inherits = "tokyonight_day"
+
+[base]
+foreground = "#111111"
+"##;
+    write_temp_file(&dir, "no_background.toml", variant);
+
+    let mut reg = Registry::new();
+    reg.add_dir(dir.path()).unwrap();
+    let info = reg
+        .list()
+        .find(|t| t.id.as_ref() == "no_background")
+        .unwrap();
+    assert!(info.is_light, "background inherited from tokyonight_day");
+    assert_eq!(
+        reg.load("no_background").unwrap().base.foreground,
+        Some(Color::from_hex("#111111").unwrap()),
+    );
+}
+
+#[test]
+fn variant_redefines_parent_color_variable() {
+    let dir = tempfile::tempdir().unwrap();
+    let base = r##"
+[meta]
+name = "Vars"
+preset_id = "vars_base"
+schema_version = "1"
+style = "dark"
+kind = "preset-base"
+
+[colors]
+bg = "#101010"
+accent = "#7aa2f7"
+
+[base]
+background = "$bg"
+border = "$accent"
+"##;
+    let variant = r##"
+[meta]
+name = "Vars Light"
+preset_id = "vars_light"
+schema_version = "1"
+style = "light"
+kind = "preset-variant"
+inherits = "vars_base"
+
+[colors]
+bg = "#f5f5f5"
+
+[base]
+"##;
+    write_temp_file(&dir, "vars_base.toml", base);
+    write_temp_file(&dir, "vars_light.toml", variant);
+
+    let mut reg = Registry::new();
+    reg.add_dir(dir.path()).unwrap();
+    let info = reg.list().find(|t| t.id.as_ref() == "vars_light").unwrap();
+    assert!(
+        info.is_light,
+        "background resolved through the variant's [colors]"
+    );
+
+    let palette = reg.load("vars_light").unwrap();
+    assert_eq!(
+        palette.base.background,
+        Some(Color::from_hex("#f5f5f5").unwrap())
+    );
+    assert_eq!(
+        palette.base.border,
+        Some(Color::from_hex("#7aa2f7").unwrap())
+    );
+}
+
+#[test]
+fn registry_load_all_matches_list_order() {
+    let dir = tempfile::tempdir().unwrap();
+    write_temp_file(&dir, "test_theme.toml", MINIMAL_TOML);
+    write_temp_file(&dir, "sibling_base.toml", BASE_TOML);
+
+    let mut reg = Registry::new();
+    reg.add_dir(dir.path()).unwrap();
+    let warm = reg.load("dracula").unwrap();
+
+    let all = reg.load_all().unwrap();
+    assert_eq!(all.len(), reg.list().count());
+    for (info, palette) in reg.list().zip(&all) {
+        assert_eq!(*palette, reg.load(&info.id).unwrap(), "{}", info.id);
+    }
+    assert_eq!(
+        all[reg.list().position(|t| t.id.as_ref() == "dracula").unwrap()],
+        warm
+    );
+}
+
 #[test]
 fn registry_duplicate_id_replaces_entry() {
     let replacement_toml = r##"
@@ -388,6 +640,26 @@ fn registry_add_toml_registers_custom_theme() {
     assert_eq!(last.id.as_ref(), "test_theme");
 }
 
+#[test]
+fn registry_add_manifest_registers_parsed_manifest() {
+    let mut reg = Registry::new();
+    let manifest = PaletteManifest::from_toml(MINIMAL_TOML).unwrap();
+    reg.add_manifest(manifest).unwrap();
+
+    let palette = reg.load("test_theme").unwrap();
+    assert_eq!(palette.meta.unwrap().preset_id.as_ref(), "test_theme");
+}
+
+#[test]
+fn registry_add_manifest_requires_meta() {
+    let mut reg = Registry::new();
+    let manifest = PaletteManifest::default();
+    assert!(matches!(
+        reg.add_manifest(manifest),
+        Err(PaletteError::MissingMeta)
+    ));
+}
+
 #[test]
 fn registry_builtin_metadata_matches_expected() {
     let reg = Registry::new();
@@ -399,6 +671,11 @@ fn registry_builtin_metadata_matches_expected() {
             name: Arc::from("TokyoNight (Night)"),
             style: Arc::from("night"),
             is_light: false,
+            author: None,
+            license: None,
+            version: None,
+            description: None,
+            tags: Box::default(),
         }
     );
 }
@@ -511,3 +788,322 @@ foreground = "#111111"
         "custom inherited theme should use inherited light background"
     );
 }
+
+#[test]
+fn builtin_presets_load_without_warnings() {
+    let reg = Registry::new();
+    for id in preset_ids() {
+        let (_, warnings) = reg.load_with_warnings(id).unwrap();
+        assert!(warnings.is_empty(), "{id}: {warnings:?}");
+    }
+}
+
+#[test]
+fn load_with_warnings_checks_recommended_slots_after_merge() {
+    let variant_toml = r##"
+[meta]
+name = "Sparse Variant"
+preset_id = "sparse_variant"
+schema_version = "1"
+style = "dark"
+kind = "preset-variant"
+inherits = "tokyonight"
+
+[base]
+backgruond = "#000000"
+
+[extras]
+accent = "#ff00ff"
+"##;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_temp_file(&dir, "sparse_variant.toml", variant_toml);
+    let mut reg = Registry::new();
+    reg.add_file(&path).unwrap();
+    let (palette, warnings) = reg.load_with_warnings("sparse_variant").unwrap();
+    assert_eq!(
+        palette.base.background,
+        load_preset("tokyonight").unwrap().base.background
+    );
+    let codes: Vec<_> = warnings.iter().map(|w| w.code).collect();
+    assert_eq!(codes, ["PC0102", "PC0100"]);
+}
+
+const SYNTAX_PACK_TOML: &str = r##"
+[meta]
+name = "Syntax Pack"
+preset_id = "syntax_pack"
+schema_version = "1"
+style = "dark"
+kind = "partial"
+
+[base]
+foreground = "#dddddd"
+
+[syntax]
+keywords = "#bb9af7"
+"##;
+
+const MIXIN_VARIANT_TOML: &str = r##"
+[meta]
+name = "Mixin Variant"
+preset_id = "mixin_variant"
+schema_version = "1"
+style = "dark"
+kind = "preset-variant"
+inherits = ["sibling_base", "syntax_pack"]
+
+[semantic]
+error = "#ff0000"
+
+[base]
+"##;
+
+#[test]
+fn mixins_merge_in_order() {
+    let dir = tempfile::tempdir().unwrap();
+    write_temp_file(&dir, "sibling_base.toml", BASE_TOML);
+    write_temp_file(&dir, "syntax_pack.toml", SYNTAX_PACK_TOML);
+    let path = write_temp_file(&dir, "mixin_variant.toml", MIXIN_VARIANT_TOML);
+
+    let palette = load_preset_file(&path).unwrap();
+    let hex = |s| Some(Color::from_hex(s).unwrap());
+    assert_eq!(
+        palette.base.background,
+        hex("#111111"),
+        "from the first parent"
+    );
+    assert_eq!(palette.base.foreground, hex("#dddddd"), "later parent wins");
+    assert_eq!(palette.syntax.keywords, hex("#bb9af7"));
+    assert_eq!(palette.semantic.success, hex("#00ff00"));
+    assert_eq!(palette.semantic.error, hex("#ff0000"));
+
+    let mut reg = Registry::new();
+    reg.add_file(&dir.path().join("sibling_base.toml")).unwrap();
+    reg.add_file(&dir.path().join("syntax_pack.toml")).unwrap();
+    reg.add_file(&path).unwrap();
+    assert_eq!(reg.load("mixin_variant").unwrap(), palette);
+}
+
+#[test]
+fn missing_mixin_names_the_mixin() {
+    let dir = tempfile::tempdir().unwrap();
+    write_temp_file(&dir, "sibling_base.toml", BASE_TOML);
+    let path = write_temp_file(&dir, "mixin_variant.toml", MIXIN_VARIANT_TOML);
+
+    let Err(PaletteError::Inheritance { chain, .. }) = load_preset_file(&path) else {
+        panic!("expected inheritance error");
+    };
+    assert_eq!(chain[1].id.as_ref(), "syntax_pack");
+}
+
+#[test]
+fn invalid_mixin_value_blames_the_mixin() {
+    let dir = tempfile::tempdir().unwrap();
+    write_temp_file(&dir, "sibling_base.toml", BASE_TOML);
+    write_temp_file(
+        &dir,
+        "syntax_pack.toml",
+        &SYNTAX_PACK_TOML.replace("#bb9af7", "#nothex"),
+    );
+    let path = write_temp_file(&dir, "mixin_variant.toml", MIXIN_VARIANT_TOML);
+
+    let Err(PaletteError::Inheritance { chain, .. }) = load_preset_file(&path) else {
+        panic!("expected inheritance error");
+    };
+    assert_eq!(chain[1].id.as_ref(), "syntax_pack");
+}
+
+const MODE_BASE_TOML: &str = r##"
+[meta]
+name = "Mode Base"
+preset_id = "mode_base"
+schema_version = "1"
+style = "dark"
+kind = "preset-base"
+
+[base]
+background = "#111111"
+foreground = "#cccccc"
+
+[when.high_contrast.base]
+foreground = "#ffffff"
+"##;
+
+const MODE_VARIANT_TOML: &str = r##"
+[meta]
+name = "Mode Variant"
+preset_id = "mode_variant"
+schema_version = "1"
+style = "dark"
+kind = "preset-variant"
+inherits = "mode_base"
+
+[base]
+background = "#222222"
+
+[when.high_contrast.base]
+background = "#000000"
+"##;
+
+#[test]
+fn modes_are_merged_only_when_requested() {
+    let dir = tempfile::tempdir().unwrap();
+    write_temp_file(&dir, "mode_base.toml", MODE_BASE_TOML);
+    let path = write_temp_file(&dir, "mode_variant.toml", MODE_VARIANT_TOML);
+    let hex = |h: &str| Some(Color::from_hex(h).unwrap());
+
+    let plain = load_preset_file(&path).unwrap();
+    assert_eq!(plain.base.background, hex("#222222"));
+    assert_eq!(plain.base.foreground, hex("#cccccc"));
+
+    let options = LoadOptions::default().mode("high_contrast");
+    let contrast = load_preset_file_with(&path, &options).unwrap();
+    assert_eq!(contrast.base.background, hex("#000000"));
+    assert_eq!(
+        contrast.base.foreground,
+        hex("#ffffff"),
+        "parent's overlay applies to the variant"
+    );
+
+    let mut reg = Registry::new();
+    reg.add_file(&dir.path().join("mode_base.toml")).unwrap();
+    reg.add_file(&path).unwrap();
+    assert_eq!(reg.load_with("mode_variant", &options).unwrap(), contrast);
+    assert_eq!(
+        reg.load("mode_variant").unwrap(),
+        plain,
+        "modes are not cached"
+    );
+}
+
+const SECTION_BASE_TOML: &str = r##"
+[meta]
+name = "Section Base"
+preset_id = "section_base"
+schema_version = "1"
+style = "dark"
+kind = "preset-base"
+
+[base]
+background = "#111111"
+foreground = "#cccccc"
+
+[terminal]
+red = "#ff0000"
+"##;
+
+const SECTION_VARIANT_TOML: &str = r##"
+[meta]
+name = "Section Variant"
+preset_id = "section_variant"
+schema_version = "1"
+style = "dark"
+kind = "preset-variant"
+inherits = "section_base"
+inherit_sections = ["terminal"]
+
+[base]
+background = "#222222"
+"##;
+
+#[test]
+fn inherit_sections_limits_what_a_variant_takes() {
+    let dir = tempfile::tempdir().unwrap();
+    write_temp_file(&dir, "section_base.toml", SECTION_BASE_TOML);
+    let path = write_temp_file(&dir, "section_variant.toml", SECTION_VARIANT_TOML);
+
+    let palette = load_preset_file(&path).unwrap();
+
+    assert_eq!(
+        palette.base.background,
+        Some(Color::from_hex("#222222").unwrap())
+    );
+    assert_eq!(palette.base.foreground, None);
+    assert_eq!(
+        palette.terminal.red,
+        Some(Color::from_hex("#ff0000").unwrap())
+    );
+}
+
+#[test]
+fn theme_info_ignores_parent_background_outside_inherit_sections() {
+    let dir = tempfile::tempdir().unwrap();
+    let variant = r##"
+[meta]
+name = "Syntax Only"
+preset_id = "syntax_only"
+schema_version = "1"
+style = "dark"
+kind = "preset-variant"
+inherits = "tokyonight_day"
+inherit_sections = ["syntax"]
+
+[base]
+foreground = "#eeeeee"
+"##;
+    write_temp_file(&dir, "syntax_only.toml", variant);
+
+    let mut reg = Registry::new();
+    reg.add_dir(dir.path()).unwrap();
+    let info = reg.list().find(|t| t.id.as_ref() == "syntax_only").unwrap();
+    let palette = reg.load("syntax_only").unwrap();
+
+    assert_eq!(palette.base.background, None);
+    assert!(palette.syntax.keywords.is_some());
+    assert!(
+        !info.is_light,
+        "base is not inherited from the light parent"
+    );
+    assert_eq!(info.is_light, palette.resolve().is_light());
+}
+
+const ATTRIBUTED_TOML: &str = r##"
+[meta]
+name = "Attributed"
+preset_id = "attributed"
+schema_version = "1"
+style = "dark"
+kind = "preset-base"
+author = "Jane Doe"
+license = "MIT"
+version = "2.1.0"
+description = "A dim theme for late nights."
+tags = ["dim", "warm"]
+
+[base]
+background = "#1a1b26"
+"##;
+
+#[test]
+fn theme_info_carries_attribution() {
+    let mut reg = Registry::new();
+    reg.add_toml(ATTRIBUTED_TOML).unwrap();
+    let info = reg
+        .list()
+        .find(|info| info.id.as_ref() == "attributed")
+        .unwrap();
+    assert_eq!(info.author.as_deref(), Some("Jane Doe"));
+    assert_eq!(info.license.as_deref(), Some("MIT"));
+    assert_eq!(info.version.as_deref(), Some("2.1.0"));
+    assert_eq!(
+        info.description.as_deref(),
+        Some("A dim theme for late nights.")
+    );
+    let tags: Vec<&str> = info.tags.iter().map(AsRef::as_ref).collect();
+    assert_eq!(tags, ["dim", "warm"]);
+
+    let meta = reg.load("attributed").unwrap().meta.unwrap();
+    assert_eq!(meta.author, info.author);
+    assert_eq!(meta.tags, info.tags);
+}
+
+#[test]
+fn theme_info_reads_attribution_from_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_temp_file(&dir, "attributed.toml", ATTRIBUTED_TOML);
+    let mut reg = Registry::new();
+    reg.add_file(&path).unwrap();
+    let info = reg.list().last().unwrap();
+    assert_eq!(info.license.as_deref(), Some("MIT"));
+}
@@ -45,6 +45,7 @@ fn sparse_palette_fills_gaps_from_default() {
                 r: 0xFF,
                 g: 0,
                 b: 0,
+                a: 255,
             }),
             ..BaseColors::default()
         },
@@ -59,6 +60,7 @@ fn sparse_palette_fills_gaps_from_default() {
                 r: 0xFF,
                 g: 0,
                 b: 0,
+                a: 255,
             }),
             ..BaseColors::default()
         },
@@ -71,8 +73,11 @@ fn sparse_palette_fills_gaps_from_default() {
         terminal: AnsiColors::default(),
         syntax_style: SyntaxStyles::default(),
         gradients: Arc::from([]),
+        groups: Default::default(),
         #[cfg(feature = "platform")]
         platform: Default::default(),
+        #[cfg(feature = "toml")]
+        extensions: Default::default(),
     };
 
     let resolved = very_sparse.resolve();
@@ -83,7 +88,8 @@ fn sparse_palette_fills_gaps_from_default() {
         Color {
             r: 0xFF,
             g: 0,
-            b: 0
+            b: 0,
+            a: 255
         }
     );
 
@@ -108,16 +114,19 @@ fn resolve_with_custom_fallback_precedence() {
         r: 0xFF,
         g: 0,
         b: 0,
+        a: 255,
     };
     let green = Color {
         r: 0,
         g: 0xFF,
         b: 0,
+        a: 255,
     };
     let blue = Color {
         r: 0,
         g: 0,
         b: 0xFF,
+        a: 255,
     };
 
     let primary = Palette {
@@ -135,8 +144,11 @@ fn resolve_with_custom_fallback_precedence() {
         terminal: AnsiColors::default(),
         syntax_style: SyntaxStyles::default(),
         gradients: Arc::from([]),
+        groups: Default::default(),
         #[cfg(feature = "platform")]
         platform: Default::default(),
+        #[cfg(feature = "toml")]
+        extensions: Default::default(),
     };
 
     let fallback = Palette {
@@ -155,8 +167,11 @@ fn resolve_with_custom_fallback_precedence() {
         terminal: AnsiColors::default(),
         syntax_style: SyntaxStyles::default(),
         gradients: Arc::from([]),
+        groups: Default::default(),
         #[cfg(feature = "platform")]
         platform: Default::default(),
+        #[cfg(feature = "toml")]
+        extensions: Default::default(),
     };
 
     let resolved = primary.resolve_with(&fallback);
@@ -269,7 +284,12 @@ fn meta_preserved_through_resolution() {
 #[test]
 fn merge_prefers_self_over_fallback() {
     let a = BaseColors {
-        background: Some(Color { r: 1, g: 2, b: 3 }),
+        background: Some(Color {
+            r: 1,
+            g: 2,
+            b: 3,
+            a: 255,
+        }),
         ..BaseColors::default()
     };
     let b = BaseColors {
@@ -277,23 +297,34 @@ fn merge_prefers_self_over_fallback() {
             r: 10,
             g: 20,
             b: 30,
+            a: 255,
         }),
         foreground: Some(Color {
             r: 40,
             g: 50,
             b: 60,
+            a: 255,
         }),
         ..BaseColors::default()
     };
 
     let merged = a.merge(&b);
-    assert_eq!(merged.background, Some(Color { r: 1, g: 2, b: 3 }));
+    assert_eq!(
+        merged.background,
+        Some(Color {
+            r: 1,
+            g: 2,
+            b: 3,
+            a: 255
+        })
+    );
     assert_eq!(
         merged.foreground,
         Some(Color {
             r: 40,
             g: 50,
-            b: 60
+            b: 60,
+            a: 255
         })
     );
 }
@@ -304,6 +335,7 @@ fn syntax_fallback_resolves_from_parent() {
         r: 0xFF,
         g: 0,
         b: 0,
+        a: 255,
     };
     let palette = Palette {
         syntax: SyntaxColors {
@@ -328,11 +360,13 @@ fn syntax_fallback_explicit_overrides_parent() {
         r: 0xFF,
         g: 0,
         b: 0,
+        a: 255,
     };
     let blue = Color {
         r: 0,
         g: 0,
         b: 0xFF,
+        a: 255,
     };
     let palette = Palette {
         syntax: SyntaxColors {
@@ -357,6 +391,7 @@ fn syntax_fallback_all_sub_tokens_resolve_from_parent() {
         r: 0,
         g: 0xFF,
         b: 0,
+        a: 255,
     };
 
     let palette = Palette {
@@ -418,6 +453,7 @@ fn resolved_is_light_threshold_boundary() {
         r: 124,
         g: 124,
         b: 124,
+        a: 255,
     };
     assert!(
         above.relative_luminance() > 0.179,
@@ -439,6 +475,7 @@ fn resolved_is_light_threshold_boundary() {
         r: 115,
         g: 115,
         b: 115,
+        a: 255,
     };
     assert!(
         below.relative_luminance() <= 0.179,
@@ -474,13 +511,22 @@ stops = ["base.background", "base.foreground"]
         .gradient("brand")
         .expect("gradient 'brand' should exist");
     let stops = gradient.stops();
-    assert_eq!(stops[0].color, Color { r: 0, g: 0, b: 0 });
+    assert_eq!(
+        stops[0].color,
+        Color {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 255
+        }
+    );
     assert_eq!(
         stops[stops.len() - 1].color,
         Color {
             r: 255,
             g: 255,
             b: 255,
+            a: 255,
         }
     );
 }
@@ -0,0 +1,121 @@
+use palette_core::error::PaletteError;
+use palette_core::manifest::PaletteManifest;
+use palette_core::schema::{self, CURRENT_VERSION, Change, Migration};
+
+fn theme(version: &str, body: &str) -> String {
+    format!(
+        "[meta]\nname = \"Old\"\npreset_id = \"old\"\nschema_version = \"{version}\"\n\
+         style = \"dark\"\nkind = \"preset-base\"\n\n{body}"
+    )
+}
+
+const MIGRATIONS: &[Migration] = &[Migration {
+    version: 0,
+    changes: &[
+        Change::RenameSlot {
+            section: "base",
+            from: "bg",
+            to: "background",
+        },
+        Change::MoveSlot {
+            field: "comment",
+            from: "syntax",
+            to: "typography",
+        },
+        Change::MoveSection {
+            from: "ui",
+            to: "surface",
+        },
+    ],
+}];
+
+fn migrate(toml: &str) -> Result<toml::Table, PaletteError> {
+    let mut table: toml::Table = toml::from_str(toml).unwrap();
+    schema::migrate(&mut table, MIGRATIONS)?;
+    Ok(table)
+}
+
+#[test]
+fn current_version_parses() {
+    let manifest = PaletteManifest::from_toml(&theme("1", "[base]\n")).unwrap();
+    assert_eq!(
+        manifest.meta.unwrap().schema_version.as_ref(),
+        CURRENT_VERSION.to_string()
+    );
+}
+
+#[test]
+fn future_version_is_rejected() {
+    let err = PaletteManifest::from_toml(&theme("2", "[base]\n")).unwrap_err();
+    assert!(matches!(&err, PaletteError::UnsupportedSchemaVersion(v) if v.as_ref() == "2"));
+    assert_eq!(err.code(), "PC0020");
+}
+
+#[test]
+fn malformed_version_is_rejected() {
+    let err = PaletteManifest::from_toml_strict(&theme("one", "[base]\n")).unwrap_err();
+    assert!(matches!(err, PaletteError::UnsupportedSchemaVersion(_)));
+}
+
+#[test]
+fn older_version_without_migration_is_rejected() {
+    let err = PaletteManifest::from_toml(&theme("0", "[base]\n")).unwrap_err();
+    assert!(matches!(&err, PaletteError::UnsupportedSchemaVersion(v) if v.as_ref() == "0"));
+}
+
+#[test]
+fn manifest_without_meta_needs_no_version() {
+    assert!(PaletteManifest::from_toml("[base]\nbackground = \"#1a1b26\"\n").is_ok());
+}
+
+#[test]
+fn migration_renames_and_moves_slots() {
+    let table = migrate(&theme(
+        "0",
+        "[base]\nbg = \"#1a1b26\"\n\n[syntax]\ncomment = \"#565f89\"\nkeywords = \"#bb9af7\"\n",
+    ))
+    .unwrap();
+    let manifest = PaletteManifest::from_toml(&table.to_string()).unwrap();
+
+    assert_eq!(manifest.base.get("background").unwrap().as_ref(), "#1a1b26");
+    assert!(!manifest.base.contains_key("bg"));
+    assert_eq!(
+        manifest.typography.get("comment").unwrap().as_ref(),
+        "#565f89"
+    );
+    assert!(!manifest.syntax.contains_key("comment"));
+    assert_eq!(manifest.syntax.get("keywords").unwrap().as_ref(), "#bb9af7");
+}
+
+#[test]
+fn migration_moves_sections() {
+    let table = migrate(&theme(
+        "0",
+        "[base]\n\n[ui]\nmenu = \"#16161e\"\nsidebar = \"#1f2335\"\n\n[surface]\nmenu = \"#000000\"\n",
+    ))
+    .unwrap();
+    assert!(!table.contains_key("ui"));
+    let manifest = PaletteManifest::from_toml_strict(&table.to_string()).unwrap();
+    assert_eq!(manifest.surface.get("menu").unwrap().as_ref(), "#000000");
+    assert_eq!(manifest.surface.get("sidebar").unwrap().as_ref(), "#1f2335");
+}
+
+#[test]
+fn migration_bumps_declared_version() {
+    let table = migrate(&theme("0", "[base]\n")).unwrap();
+    let version = table["meta"]["schema_version"].as_str().unwrap();
+    assert_eq!(version, CURRENT_VERSION.to_string());
+}
+
+#[test]
+fn current_version_is_left_untouched() {
+    let source = theme("1", "[base]\nbg = \"#1a1b26\"\n");
+    let table = migrate(&source).unwrap();
+    assert_eq!(table, toml::from_str::<toml::Table>(&source).unwrap());
+}
+
+#[test]
+fn parse_version_accepts_current() {
+    assert_eq!(schema::parse_version(" 1 ").unwrap(), 1);
+    assert!(schema::parse_version("1.0").is_err());
+}
@@ -0,0 +1,292 @@
+mod common;
+
+use palette_core::color::Color;
+use palette_core::error::PaletteError;
+use palette_core::load_preset;
+use palette_core::palette::Palette;
+use palette_core::slot::{Section, SlotId};
+
+fn slot(s: &str) -> SlotId {
+    s.parse().unwrap()
+}
+
+// --- Parsing ---
+
+#[test]
+fn parse_and_display_roundtrip() {
+    let id = slot("syntax.keywords_control");
+    assert_eq!(id.section(), Section::Syntax);
+    assert_eq!(id.field(), "keywords_control");
+    assert_eq!(id.to_string(), "syntax.keywords_control");
+}
+
+#[test]
+fn new_matches_parse() {
+    assert_eq!(
+        SlotId::new(Section::Terminal, "bright_red").unwrap(),
+        slot("terminal.bright_red")
+    );
+}
+
+#[test]
+fn unknown_field_is_rejected() {
+    let err = "base.nope".parse::<SlotId>().unwrap_err();
+    assert!(
+        matches!(&err, PaletteError::UnknownField { section, field } if &**section == "base" && &**field == "nope"),
+        "{err}"
+    );
+}
+
+#[test]
+fn unknown_section_is_rejected() {
+    assert!(matches!(
+        "widgets.background".parse::<SlotId>(),
+        Err(PaletteError::UnknownField { .. })
+    ));
+    assert!("background".parse::<SlotId>().is_err());
+}
+
+#[test]
+fn all_covers_every_field_once() {
+    let total: usize = Section::ALL.iter().map(|s| s.fields().len()).sum();
+    let all: Vec<SlotId> = SlotId::all().collect();
+    assert_eq!(all.len(), total);
+    assert_eq!(all[0], slot("base.background"));
+    for id in &all {
+        assert_eq!(&id.to_string().parse::<SlotId>().unwrap(), id);
+    }
+}
+
+// --- Lookup ---
+
+#[test]
+fn get_reads_populated_slots() {
+    let palette = Palette::default();
+    assert_eq!(palette.get(slot("semantic.error")), palette.semantic.error);
+    assert_eq!(palette.get(slot("diff.added")), palette.diff.added);
+}
+
+#[test]
+fn resolved_get_matches_fields() {
+    let resolved = load_preset("tokyonight").unwrap().resolve();
+    assert_eq!(resolved.get(slot("terminal.cyan")), resolved.terminal.cyan);
+    assert_eq!(resolved.get(slot("editor.cursor")), resolved.editor.cursor);
+}
+
+// --- Gradients ---
+
+#[test]
+fn palette_gradient_spans_the_two_slots() {
+    let palette = load_preset("tokyonight").unwrap();
+    let ramp = palette.gradient(slot("semantic.success"), slot("semantic.error"), 5);
+    assert_eq!(ramp.len(), 5);
+    assert_eq!(Some(ramp[0]), palette.semantic.success);
+    assert_eq!(Some(ramp[4]), palette.semantic.error);
+}
+
+#[test]
+fn palette_gradient_resolves_unset_slots() {
+    let black = Color {
+        r: 0,
+        g: 0,
+        b: 0,
+        a: 255,
+    };
+    let mut palette = Palette::default();
+    palette.base.background = Some(black);
+    palette.syntax = Default::default();
+
+    let ramp = palette.gradient(slot("base.background"), slot("syntax.keywords"), 3);
+    assert_eq!(ramp[0], black);
+    assert_eq!(ramp[2], palette.resolve().syntax.keywords);
+}
+
+// --- Luminance ranking ---
+
+#[test]
+fn slots_by_luminance_orders_dark_to_light() {
+    let palette = load_preset("tokyonight").unwrap();
+    let ranked = palette.slots_by_luminance();
+    assert!(
+        ranked
+            .windows(2)
+            .all(|w| w[0].1.relative_luminance() <= w[1].1.relative_luminance())
+    );
+    let populated = SlotId::all().filter(|s| palette.get(*s).is_some()).count();
+    assert_eq!(ranked.len(), populated);
+}
+
+#[test]
+fn darkest_background_and_lightest_foreground() {
+    let mut palette = load_preset("tokyonight").unwrap();
+    palette.surface.float = Some(Color::from_hex("#000000").unwrap());
+    palette.base.foreground_dark = Some(Color::from_hex("#FFFFFF").unwrap());
+
+    let (bg_slot, bg) = palette.darkest_background().unwrap();
+    assert_eq!(bg_slot, slot("surface.float"));
+    assert_eq!(bg, Color::from_hex("#000000").unwrap());
+    let (fg_slot, _) = palette.lightest_foreground().unwrap();
+    assert_eq!(fg_slot, slot("base.foreground_dark"));
+}
+
+#[test]
+fn ranking_helpers_on_empty_palette() {
+    let palette = Palette::from_manifest(&common::manifest_with_base(Default::default())).unwrap();
+    assert!(palette.slots_by_luminance().is_empty());
+    assert_eq!(palette.darkest_background(), None);
+    assert_eq!(palette.lightest_foreground(), None);
+}
+
+#[test]
+fn set_and_get_by_path() {
+    let mut palette = load_preset("tokyonight").unwrap();
+    let red = Color::from_hex("#ff0000").unwrap();
+
+    palette.set_path("editor.cursor", Some(red)).unwrap();
+    assert_eq!(palette.editor.cursor, Some(red));
+    assert_eq!(palette.get_path("editor.cursor").unwrap(), Some(red));
+
+    palette.set(slot("syntax.keywords"), None);
+    assert_eq!(palette.get_path("syntax.keywords").unwrap(), None);
+}
+
+#[test]
+fn set_path_rejects_unknown_slot() {
+    let mut palette = Palette::default();
+    let err = palette.set_path("editor.cursr", None).unwrap_err();
+    assert!(matches!(err, PaletteError::UnknownField { .. }));
+    assert!(palette.get_path("nope.cursor").is_err());
+}
+
+#[test]
+fn all_slots_walks_every_slot_in_order() {
+    let palette = load_preset("tokyonight").unwrap();
+    let slots: Vec<_> = palette.all_slots().collect();
+
+    assert_eq!(slots.len(), SlotId::all().count());
+    assert_eq!(slots[0].0, Section::Base);
+    assert_eq!(slots[0].1, "background");
+    for (section, field, color) in slots {
+        let slot = SlotId::new(section, field).unwrap();
+        assert_eq!(color.copied(), palette.get(slot));
+    }
+}
+
+#[test]
+fn map_passes_each_populated_slot_once() {
+    let palette = load_preset("tokyonight").unwrap();
+    let mut seen = Vec::new();
+    let mapped = palette.map(|slot, color| {
+        seen.push(slot);
+        color.darken(0.1)
+    });
+
+    let populated: Vec<_> = SlotId::all()
+        .filter(|s| palette.get(*s).is_some())
+        .collect();
+    assert_eq!(seen, populated);
+    for slot in SlotId::all() {
+        assert_eq!(mapped.get(slot), palette.get(slot).map(|c| c.darken(0.1)));
+    }
+    assert_eq!(mapped.meta, palette.meta);
+}
+
+// --- Fallbacks ---
+
+#[test]
+fn with_fallbacks_fills_empty_slots_from_donors() {
+    let selection = Color::rgb(0x28, 0x34, 0x57);
+    let comment = Color::rgb(0x56, 0x5f, 0x89);
+    let border = Color::rgb(0x40, 0x40, 0x40);
+    let palette = palette_core::PaletteBuilder::new()
+        .surface_selection(selection)
+        .syntax_comments(comment)
+        .border(border)
+        .build()
+        .with_fallbacks();
+
+    assert_eq!(palette.editor.selection_bg, Some(selection));
+    assert_eq!(palette.typography.comment, Some(comment));
+    assert_eq!(palette.base.border_highlight, Some(border.lighten(0.1)));
+    assert_eq!(palette.base.foreground, None);
+}
+
+#[test]
+fn with_fallbacks_keeps_populated_slots() {
+    let palette = load_preset("tokyonight").unwrap();
+    let filled = palette.with_fallbacks();
+    for slot in SlotId::all() {
+        if let Some(color) = palette.get(slot) {
+            assert_eq!(filled.get(slot), Some(color), "{slot}");
+        }
+    }
+}
+
+// --- Fingerprint ---
+
+#[test]
+fn fingerprint_is_pinned_across_releases() {
+    let palette = palette_core::PaletteBuilder::new()
+        .background(Color::rgb(0x1a, 0x1b, 0x26))
+        .build();
+    assert_eq!(palette.fingerprint(), 13_311_060_537_422_360_082);
+}
+
+#[test]
+fn fingerprint_tracks_slots_and_meta() {
+    let palette = load_preset("tokyonight").unwrap();
+    assert_eq!(
+        palette.fingerprint(),
+        load_preset("tokyonight").unwrap().fingerprint()
+    );
+    assert_ne!(
+        palette.fingerprint(),
+        load_preset("tokyonight_storm").unwrap().fingerprint()
+    );
+
+    let mut cleared = palette.clone();
+    cleared.syntax.tag = None;
+    assert_ne!(cleared.fingerprint(), palette.fingerprint());
+
+    let renamed = palette_core::PaletteBuilder::from(palette.clone())
+        .meta("Renamed", "tokyonight", "night")
+        .build();
+    assert_ne!(renamed.fingerprint(), palette.fingerprint());
+}
+
+// --- Effective accessors ---
+
+#[test]
+fn effective_prefers_the_slot_itself() {
+    let palette = load_preset("tokyonight").unwrap();
+    assert_eq!(
+        Some(palette.effective_background()),
+        palette.base.background
+    );
+    assert_eq!(
+        Some(palette.effective_selection_bg()),
+        palette.editor.selection_bg
+    );
+    assert_eq!(Some(palette.effective_error()), palette.semantic.error);
+}
+
+#[test]
+fn effective_walks_fallback_chain_with_derivations() {
+    let border = Color::rgb(0x40, 0x40, 0x40);
+    let selection = Color::rgb(0x28, 0x34, 0x57);
+    let palette = palette_core::PaletteBuilder::new()
+        .border(border)
+        .surface_selection(selection)
+        .build();
+
+    assert_eq!(palette.effective_border_highlight(), border.lighten(0.1));
+    assert_eq!(palette.effective_selection_bg(), selection);
+    assert_eq!(
+        palette.effective_comment(),
+        Palette::default().typography.comment.unwrap()
+    );
+    assert_eq!(
+        palette.effective_error(),
+        Palette::default().semantic.error.unwrap()
+    );
+}
@@ -49,3 +49,34 @@ fn snapshot_omits_none_colors() {
     assert!(base.get("background").unwrap().is_string());
     assert!(base.get("foreground").unwrap().is_null());
 }
+
+#[test]
+fn snapshot_keeps_alpha() {
+    let manifest = common::manifest_with_base(
+        [("background".into(), "#11223380".into())]
+            .into_iter()
+            .collect(),
+    );
+    let palette = Palette::from_manifest(&manifest).unwrap();
+    let value = palette.to_json_value().unwrap();
+
+    let bg = value["base"]["background"].as_str().unwrap();
+    assert_eq!(bg, "#11223380");
+}
+
+#[test]
+fn contrast_report_serializes_to_json() {
+    use palette_core::contrast::{ContrastLevel, contrast_report};
+
+    let palette = palette_core::load_preset("nord").unwrap();
+    let report = contrast_report(&palette, ContrastLevel::AaaNormal);
+    let json = serde_json::to_value(&report).unwrap();
+
+    assert_eq!(json["level"], "AaaNormal");
+    assert_eq!(json["failed"], report.failed);
+    assert!(json["worst_ratio"].is_number());
+    assert_eq!(json["sections"][0]["section"], "base");
+    let first = &json["violations"][0];
+    assert!(first["foreground_label"].is_string());
+    assert!(first["foreground"].as_str().unwrap().starts_with('#'));
+}
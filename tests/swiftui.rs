@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use palette_core::export::find_exporter;
+use palette_core::export::swiftui::{FILE_NAME, to_swiftui};
+use palette_core::load_preset;
+
+#[test]
+fn declares_color_extension() {
+    let resolved = load_preset("tokyonight").unwrap().resolve();
+    let swift = to_swiftui(&resolved, None);
+
+    assert!(swift.contains("import SwiftUI"));
+    assert!(swift.contains("public extension Color {"));
+    assert!(swift.trim_end().ends_with('}'));
+}
+
+#[test]
+fn static_let_per_slot_with_srgb_components() {
+    let resolved = load_preset("tokyonight").unwrap().resolve();
+    let swift = to_swiftui(&resolved, None);
+    let bg = resolved.base.background;
+    let expected = format!(
+        "static let baseBackground = Color(.sRGB, red: {:.3}, green: {:.3}, blue: {:.3}, opacity: 1)",
+        f64::from(bg.r) / 255.0,
+        f64::from(bg.g) / 255.0,
+        f64::from(bg.b) / 255.0,
+    );
+
+    assert!(
+        swift.contains(&expected),
+        "missing:\n{expected}\nin:\n{swift}"
+    );
+    assert!(swift.contains("static let syntaxKeywordsControl = "));
+    assert!(swift.contains("static let terminalBrightWhite = "));
+}
+
+#[test]
+fn single_palette_has_no_dynamic_helper() {
+    let resolved = load_preset("nord").unwrap().resolve();
+    let swift = to_swiftui(&resolved, None);
+
+    assert!(!swift.contains(".dynamic("));
+    assert!(!swift.contains("import UIKit"));
+}
+
+#[test]
+fn two_palettes_emit_dynamic_colors() {
+    let light = load_preset("github_light").unwrap().resolve();
+    let dark = load_preset("github_dark").unwrap().resolve();
+    let swift = to_swiftui(&light, Some(&dark));
+
+    assert!(swift.contains("static let baseBackground = .dynamic(light: Color("));
+    assert!(swift.contains("static func dynamic(light: Color, dark: Color) -> Color"));
+    assert!(swift.contains("import UIKit"));
+}
+
+#[test]
+fn line_breaks_in_theme_name_stay_in_the_comment() {
+    let mut resolved = load_preset("nord").unwrap().resolve();
+    if let Some(meta) = resolved.meta.as_mut() {
+        Arc::make_mut(meta).name = Arc::from("Nord\nimport Evil\u{2028}Kit");
+    }
+    let swift = to_swiftui(&resolved, None);
+
+    assert!(
+        swift.starts_with("// Generated by palette-core from Nord import Evil Kit. Do not edit.\n")
+    );
+    assert!(!swift.contains("\nimport Evil"));
+}
+
+#[test]
+fn exporter_writes_single_swift_file() {
+    let palette = load_preset("dracula").unwrap();
+    let files = find_exporter("swiftui").unwrap().export(&palette);
+
+    assert_eq!(files.len(), 1);
+    assert_eq!(&*files[0].path, FILE_NAME);
+    assert!(files[0].contents.contains("from Dracula"));
+}
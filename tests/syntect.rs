@@ -225,12 +225,14 @@ fn light_theme_has_dark_foreground() {
         r: fg.r,
         g: fg.g,
         b: fg.b,
+        a: 255,
     }
     .relative_luminance();
     let bg_lum = palette_core::color::Color {
         r: bg.r,
         g: bg.g,
         b: bg.b,
+        a: 255,
     }
     .relative_luminance();
 
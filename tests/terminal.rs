@@ -7,7 +7,9 @@ use ratatui::style::Color as RatatuiColor;
 
 use palette_core::color::Color;
 use palette_core::palette::Palette;
-use palette_core::terminal::{to_ratatui_color, to_resolved_terminal_theme, to_terminal_theme};
+use palette_core::terminal::{
+    render_swatches, to_ratatui_color, to_resolved_terminal_theme, to_terminal_theme,
+};
 
 mod common;
 
@@ -17,6 +19,7 @@ fn single_color_converts_rgb() {
         r: 26,
         g: 27,
         b: 42,
+        a: 255,
     };
     assert_eq!(to_ratatui_color(&color), RatatuiColor::Rgb(26, 27, 42));
 }
@@ -126,3 +129,27 @@ fn chromatic_returns_12_non_grayscale_colors() {
     assert!(!colors.contains(&theme.terminal.bright_black));
     assert!(!colors.contains(&theme.terminal.bright_white));
 }
+
+#[test]
+fn render_swatches_lists_populated_slots_with_truecolor_blocks() {
+    let palette = Palette::from_manifest(&common::load_preset("nord")).unwrap();
+    let rendered = render_swatches(&palette);
+
+    assert!(rendered.starts_with("[base]\n"));
+    let background = rendered
+        .lines()
+        .find(|line| line.contains(" background "))
+        .unwrap();
+    assert!(background.contains("\x1b[48;2;46;52;64m"));
+    assert!(background.ends_with("#2E3440"));
+    assert!(rendered.contains("\n[terminal]\n"));
+}
+
+#[test]
+fn render_swatches_skips_empty_sections() {
+    let manifest =
+        palette_core::manifest::PaletteManifest::from_toml("[base]\nbackground = \"#000000\"\n")
+            .unwrap();
+    let rendered = render_swatches(&Palette::from_manifest(&manifest).unwrap());
+    assert_eq!(rendered.lines().count(), 2);
+}
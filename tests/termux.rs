@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use palette_core::export::find_exporter;
+use palette_core::export::termux::{FILE_NAME, to_termux_properties};
+use palette_core::load_preset;
+
+#[test]
+fn emits_base_and_cursor_keys() {
+    let resolved = load_preset("one_dark").unwrap().resolve();
+    let props = to_termux_properties(&resolved);
+
+    assert!(props.contains(&format!("foreground={}\n", resolved.base.foreground)));
+    assert!(props.contains(&format!("background={}\n", resolved.base.background)));
+    assert!(props.contains(&format!("cursor={}\n", resolved.editor.cursor)));
+}
+
+#[test]
+fn ansi_colors_follow_slot_order() {
+    let resolved = load_preset("one_dark").unwrap().resolve();
+    let props = to_termux_properties(&resolved);
+
+    assert!(props.contains(&format!("color0={}\n", resolved.terminal.black)));
+    assert!(props.contains(&format!("color1={}\n", resolved.terminal.red)));
+    assert!(props.contains(&format!("color8={}\n", resolved.terminal.bright_black)));
+    assert!(props.contains(&format!("color15={}\n", resolved.terminal.bright_white)));
+    assert!(!props.contains("color16="));
+}
+
+#[test]
+fn every_line_is_comment_or_property() {
+    let resolved = load_preset("nord").unwrap().resolve();
+    let props = to_termux_properties(&resolved);
+
+    assert!(
+        props
+            .lines()
+            .all(|l| l.starts_with("# ") || l.contains("=#"))
+    );
+}
+
+#[test]
+fn line_breaks_in_theme_name_stay_in_the_comment() {
+    let mut resolved = load_preset("nord").unwrap().resolve();
+    if let Some(meta) = resolved.meta.as_mut() {
+        Arc::make_mut(meta).name = Arc::from("Nord\ncolor0=#ff0000");
+    }
+    let props = to_termux_properties(&resolved);
+
+    assert!(props.starts_with("# Generated by palette-core from Nord color0=#ff0000.\n"));
+    assert_eq!(props.matches("\ncolor0=").count(), 1);
+}
+
+#[test]
+fn exporter_writes_properties_file() {
+    let palette = load_preset("nord").unwrap();
+    let files = find_exporter("termux").unwrap().export(&palette);
+
+    assert_eq!(files.len(), 1);
+    assert_eq!(&*files[0].path, FILE_NAME);
+}
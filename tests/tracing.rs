@@ -0,0 +1,138 @@
+#![cfg(feature = "tracing")]
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use palette_core::Registry;
+use palette_core::export::find_exporter;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+use tracing_core::span::Current;
+
+/// Records `name key=value ...` for every span and event.
+#[derive(Clone, Default)]
+struct Recorder {
+    lines: Arc<Mutex<Vec<String>>>,
+    spans: Arc<Mutex<Vec<&'static Metadata<'static>>>>,
+}
+
+thread_local! {
+    static ENTERED: RefCell<Vec<Id>> = const { RefCell::new(Vec::new()) };
+}
+
+struct Line(String);
+
+impl Visit for Line {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.push_str(&format!(" {}={value:?}", field.name()));
+    }
+}
+
+impl Recorder {
+    fn lines(&self) -> Vec<String> {
+        self.lines.lock().unwrap().clone()
+    }
+
+    fn push(&self, line: Line) {
+        self.lines.lock().unwrap().push(line.0);
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut line = Line(span.metadata().name().to_owned());
+        span.record(&mut line);
+        self.push(line);
+        let mut spans = self.spans.lock().unwrap();
+        spans.push(span.metadata());
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, _: &Id, values: &Record<'_>) {
+        let mut line = Line("record".to_owned());
+        values.record(&mut line);
+        self.push(line);
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut line = Line("event".to_owned());
+        event.record(&mut line);
+        self.push(line);
+    }
+
+    fn enter(&self, id: &Id) {
+        ENTERED.with(|stack| stack.borrow_mut().push(id.clone()));
+    }
+
+    fn exit(&self, _: &Id) {
+        ENTERED.with(|stack| stack.borrow_mut().pop());
+    }
+
+    fn current_span(&self) -> Current {
+        let Some(id) = ENTERED.with(|stack| stack.borrow().last().cloned()) else {
+            return Current::none();
+        };
+        let metadata = self.spans.lock().unwrap()[id.into_u64() as usize - 1];
+        Current::new(id, metadata)
+    }
+}
+
+fn record(f: impl FnOnce()) -> Vec<String> {
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), f);
+    recorder.lines()
+}
+
+fn has(lines: &[String], needle: &str) -> bool {
+    lines.iter().any(|line| line.contains(needle))
+}
+
+#[test]
+fn add_dir_and_load_trace_parsing_and_inheritance() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("variant.toml"),
+        "[meta]\nname = \"Variant\"\npreset_id = \"variant\"\nschema_version = \"1\"\nstyle = \"dark\"\nkind = \"preset-variant\"\ninherits = \"nord\"\n\n[base]\nbackground = \"#000000\"\n",
+    )
+    .unwrap();
+
+    let lines = record(|| {
+        let mut registry = Registry::new();
+        registry.add_dir(dir.path()).unwrap();
+        registry.load("variant").unwrap();
+        registry.load("variant").unwrap();
+    });
+    assert!(has(&lines, "add_dir dir="), "{lines:#?}");
+    assert!(has(&lines, "files=1"), "{lines:#?}");
+    assert!(has(&lines, "registry_load id=\"variant\""), "{lines:#?}");
+    assert!(has(&lines, "parse_manifest bytes="), "{lines:#?}");
+    assert!(has(&lines, "record id=\"variant\""), "{lines:#?}");
+    assert!(has(&lines, "resolve id=\"variant\" path="), "{lines:#?}");
+    assert!(has(&lines, "parent=\"nord\""), "{lines:#?}");
+    assert!(has(&lines, "cache hit"), "{lines:#?}");
+}
+
+#[test]
+fn exports_trace_target_and_theme() {
+    let palette = palette_core::load_preset("nord").unwrap();
+    let lines = record(|| {
+        palette.to_css();
+        find_exporter("termux").unwrap().export(&palette);
+    });
+    assert!(
+        has(&lines, "export target=\"css\" id=\"nord\""),
+        "{lines:#?}"
+    );
+    assert!(
+        has(&lines, "export target=\"termux\" id=\"nord\""),
+        "{lines:#?}"
+    );
+}
@@ -135,11 +135,12 @@ fn js_color_relative_luminance_white() {
 fn contrast_level_strings_accepted() {
     let black = JsColor::from_hex("#000000").unwrap();
     let white = JsColor::from_hex("#FFFFFF").unwrap();
-    // All four level strings should parse without error on a high-contrast pair.
+    // Every level string should parse without error on a high-contrast pair.
     assert!(meets_contrast_level_js(&black, &white, "aa").unwrap());
     assert!(meets_contrast_level_js(&black, &white, "aa-large").unwrap());
     assert!(meets_contrast_level_js(&black, &white, "aaa").unwrap());
     assert!(meets_contrast_level_js(&black, &white, "aaa-large").unwrap());
+    assert!(meets_contrast_level_js(&black, &white, "ui").unwrap());
 }
 
 #[test]
@@ -0,0 +1,90 @@
+use palette_core::export::find_exporter;
+use palette_core::export::xcassets::{CATALOG_DIR, to_xcassets};
+use palette_core::load_preset;
+
+#[test]
+fn catalog_root_is_first_file() {
+    let resolved = load_preset("tokyonight").unwrap().resolve();
+    let files = to_xcassets(&resolved, None);
+
+    assert_eq!(&*files[0].path, "Palette.xcassets/Contents.json");
+    assert!(files[0].contents.contains(r#""version" : 1"#));
+}
+
+#[test]
+fn one_colorset_per_slot() {
+    let resolved = load_preset("tokyonight").unwrap().resolve();
+    let files = to_xcassets(&resolved, None);
+
+    let colorsets = files
+        .iter()
+        .filter(|f| f.path.ends_with(".colorset/Contents.json"))
+        .count();
+    assert_eq!(colorsets, files.len() - 1);
+    assert!(
+        files
+            .iter()
+            .all(|f| f.path.starts_with(&format!("{CATALOG_DIR}/")))
+    );
+}
+
+#[test]
+fn colorset_names_are_camel_case() {
+    let resolved = load_preset("tokyonight").unwrap().resolve();
+    let files = to_xcassets(&resolved, None);
+
+    let bg_dark = files
+        .iter()
+        .find(|f| &*f.path == "Palette.xcassets/baseBackgroundDark.colorset/Contents.json");
+    assert!(bg_dark.is_some(), "missing baseBackgroundDark color set");
+}
+
+#[test]
+fn components_are_hex_bytes() {
+    let resolved = load_preset("tokyonight").unwrap().resolve();
+    let files = to_xcassets(&resolved, None);
+    let bg = resolved.base.background;
+
+    let file = files
+        .iter()
+        .find(|f| f.path.contains("/baseBackground.colorset/"))
+        .unwrap();
+    assert!(
+        file.contents
+            .contains(&format!(r#""red" : "0x{:02X}""#, bg.r)),
+        "got:\n{}",
+        file.contents
+    );
+    assert!(file.contents.contains(r#""color-space" : "srgb""#));
+    assert!(!file.contents.contains("appearances"));
+}
+
+#[test]
+fn dark_palette_adds_luminosity_appearance() {
+    let light = load_preset("tokyonight_day").unwrap().resolve();
+    let dark = load_preset("tokyonight").unwrap().resolve();
+    let files = to_xcassets(&light, Some(&dark));
+
+    let file = files
+        .iter()
+        .find(|f| f.path.contains("/baseBackground.colorset/"))
+        .unwrap();
+    assert!(file.contents.contains(r#""value" : "dark""#));
+    assert!(
+        file.contents
+            .contains(&format!(r#""red" : "0x{:02X}""#, dark.base.background.r))
+    );
+}
+
+#[test]
+fn exporter_lookup_by_target() {
+    let palette = load_preset("nord").unwrap();
+    let exporter = find_exporter("xcassets").unwrap();
+
+    assert_eq!(exporter.target(), "xcassets");
+    assert_eq!(
+        exporter.export(&palette),
+        to_xcassets(&palette.resolve(), None)
+    );
+    assert!(find_exporter("nope").is_none());
+}
@@ -0,0 +1,75 @@
+use palette_core::export::find_exporter;
+use palette_core::export::xcode::{DEFAULT_FONT, file_name, to_xccolortheme};
+use palette_core::{Palette, load_preset};
+
+#[test]
+fn background_uses_xcode_float_components() {
+    let resolved = load_preset("one_dark").unwrap().resolve();
+    let plist = to_xccolortheme(&resolved);
+    let bg = resolved.base.background;
+    let expected = format!(
+        "<key>DVTSourceTextBackground</key>\n\t<string>{:.6} {:.6} {:.6} 1</string>",
+        f64::from(bg.r) / 255.0,
+        f64::from(bg.g) / 255.0,
+        f64::from(bg.b) / 255.0,
+    );
+
+    assert!(plist.contains(&expected));
+}
+
+#[test]
+fn every_syntax_color_has_a_font() {
+    let resolved = load_preset("one_dark").unwrap().resolve();
+    let plist = to_xccolortheme(&resolved);
+    let (colors, fonts) = plist
+        .split_once("<key>DVTSourceTextSyntaxFonts</key>")
+        .unwrap();
+    let color_keys = colors.matches("<key>xcode.syntax.").count();
+
+    assert!(color_keys > 0);
+    assert_eq!(fonts.matches("<key>xcode.syntax.").count(), color_keys);
+    assert_eq!(fonts.matches(DEFAULT_FONT).count(), color_keys);
+}
+
+#[test]
+fn plist_is_well_formed() {
+    let resolved = load_preset("nord").unwrap().resolve();
+    let plist = to_xccolortheme(&resolved);
+
+    assert!(plist.starts_with("<?xml"));
+    assert!(plist.trim_end().ends_with("</plist>"));
+    assert_eq!(
+        plist.matches("<dict>").count(),
+        plist.matches("</dict>").count()
+    );
+    // Every key maps to a string, except keys for the nested dicts.
+    let nested = plist.matches("<dict>").count() - 1;
+    assert_eq!(
+        plist.matches("<key>").count(),
+        plist.matches("<string>").count() + nested
+    );
+}
+
+#[test]
+fn file_name_uses_theme_name() {
+    let resolved = load_preset("one_dark").unwrap().resolve();
+    let name = &resolved.meta.as_ref().unwrap().name;
+
+    assert_eq!(file_name(&resolved), format!("{name}.xccolortheme"));
+}
+
+#[test]
+fn file_name_defaults_without_metadata() {
+    let resolved = Palette::default().resolve();
+
+    assert_eq!(file_name(&resolved), "Palette.xccolortheme");
+}
+
+#[test]
+fn exporter_writes_single_theme_file() {
+    let palette = load_preset("nord").unwrap();
+    let files = find_exporter("xcode").unwrap().export(&palette);
+
+    assert_eq!(files.len(), 1);
+    assert!(files[0].path.ends_with(".xccolortheme"));
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use palette_core::export::find_exporter;
+use palette_core::export::zathura::{FILE_NAME, to_zathurarc};
+use palette_core::load_preset;
+
+#[test]
+fn default_colors_from_base() {
+    let resolved = load_preset("gruvbox_dark").unwrap().resolve();
+    let rc = to_zathurarc(&resolved);
+
+    assert!(rc.contains(&format!(
+        "set default-bg \"{}\"\n",
+        resolved.base.background
+    )));
+    assert!(rc.contains(&format!(
+        "set default-fg \"{}\"\n",
+        resolved.base.foreground
+    )));
+}
+
+#[test]
+fn recolor_pair_follows_background_and_foreground() {
+    let resolved = load_preset("gruvbox_dark").unwrap().resolve();
+    let rc = to_zathurarc(&resolved);
+
+    assert!(rc.contains(&format!(
+        "set recolor-lightcolor \"{}\"\n",
+        resolved.base.background
+    )));
+    assert!(rc.contains(&format!(
+        "set recolor-darkcolor \"{}\"\n",
+        resolved.base.foreground
+    )));
+}
+
+#[test]
+fn statusbar_and_highlight_from_surface() {
+    let resolved = load_preset("gruvbox_dark").unwrap().resolve();
+    let rc = to_zathurarc(&resolved);
+
+    assert!(rc.contains(&format!(
+        "set statusbar-bg \"{}\"\n",
+        resolved.surface.statusline
+    )));
+    assert!(rc.contains(&format!(
+        "set highlight-color \"{}\"\n",
+        resolved.surface.search
+    )));
+}
+
+#[test]
+fn every_line_is_comment_or_set() {
+    let resolved = load_preset("nord").unwrap().resolve();
+    let rc = to_zathurarc(&resolved);
+
+    assert!(
+        rc.lines()
+            .all(|l| l.starts_with("# ") || l.starts_with("set "))
+    );
+}
+
+#[test]
+fn line_breaks_in_theme_name_stay_in_the_comment() {
+    let mut resolved = load_preset("nord").unwrap().resolve();
+    if let Some(meta) = resolved.meta.as_mut() {
+        Arc::make_mut(meta).name = Arc::from("Nord\nset font \"x\"");
+    }
+    let rc = to_zathurarc(&resolved);
+
+    assert!(rc.starts_with("# Generated by palette-core from Nord set font \"x\".\n"));
+    assert!(
+        rc.lines()
+            .all(|l| l.starts_with("# ") || l.starts_with("set "))
+    );
+}
+
+#[test]
+fn exporter_writes_zathurarc() {
+    let palette = load_preset("nord").unwrap();
+    let files = find_exporter("zathura").unwrap().export(&palette);
+
+    assert_eq!(files.len(), 1);
+    assert_eq!(&*files[0].path, FILE_NAME);
+}